@@ -52,6 +52,8 @@ pub enum ArrowDataType {
     Int64,
     /// An [`i128`]
     Int128,
+    /// An [`u128`]
+    UInt128,
     /// An [`u8`]
     UInt8,
     /// An [`u16`]
@@ -297,6 +299,7 @@ impl ArrowDataType {
             Float32 => PhysicalType::Primitive(PrimitiveType::Float32),
             Float64 => PhysicalType::Primitive(PrimitiveType::Float64),
             Int128 => PhysicalType::Primitive(PrimitiveType::Int128),
+            UInt128 => PhysicalType::Primitive(PrimitiveType::UInt128),
             Interval(IntervalUnit::DayTime) => PhysicalType::Primitive(PrimitiveType::DaysMs),
             Interval(IntervalUnit::MonthDayNano) => {
                 PhysicalType::Primitive(PrimitiveType::MonthDayNano)
@@ -417,6 +420,7 @@ impl ArrowDataType {
                 | D::UInt16
                 | D::UInt32
                 | D::UInt64
+                | D::UInt128
                 | D::Float32
                 | D::Float64
                 | D::Decimal(_, _)
@@ -448,6 +452,7 @@ impl ArrowDataType {
             | D::UInt32
             | D::UInt64
             | D::Int128
+            | D::UInt128
             | D::Float16
             | D::Float32
             | D::Float64
@@ -516,7 +521,7 @@ impl From<PrimitiveType> for ArrowDataType {
             PrimitiveType::Float64 => ArrowDataType::Float64,
             PrimitiveType::DaysMs => ArrowDataType::Interval(IntervalUnit::DayTime),
             PrimitiveType::MonthDayNano => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
-            PrimitiveType::UInt128 => unimplemented!(),
+            PrimitiveType::UInt128 => ArrowDataType::UInt128,
         }
     }
 }
@@ -273,6 +273,7 @@ unsafe fn to_dtype(schema: &ArrowSchema) -> PolarsResult<ArrowDataType> {
         "l" => ArrowDataType::Int64,
         "L" => ArrowDataType::UInt64,
         "_pli128" => ArrowDataType::Int128,
+        "_plu128" => ArrowDataType::UInt128,
         "e" => ArrowDataType::Float16,
         "f" => ArrowDataType::Float32,
         "g" => ArrowDataType::Float64,
@@ -462,6 +463,7 @@ fn to_format(dtype: &ArrowDataType) -> String {
         ArrowDataType::UInt64 => "L".to_string(),
         // Doesn't exist in arrow, '_pl' prefixed is Polars specific
         ArrowDataType::Int128 => "_pli128".to_string(),
+        ArrowDataType::UInt128 => "_plu128".to_string(),
         ArrowDataType::Float16 => "e".to_string(),
         ArrowDataType::Float32 => "f".to_string(),
         ArrowDataType::Float64 => "g".to_string(),
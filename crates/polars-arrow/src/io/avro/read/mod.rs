@@ -1,5 +1,6 @@
 //! APIs to read from Avro format to arrow.
-use std::io::Read;
+use std::io::{Cursor, Read};
+use std::ops::Range;
 
 use avro_schema::file::FileMetadata;
 use avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
@@ -8,7 +9,7 @@ use avro_schema::schema::Field as AvroField;
 
 mod deserialize;
 pub use deserialize::deserialize;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_bail, polars_err};
 
 mod nested;
 mod schema;
@@ -66,3 +67,52 @@ impl<R: Read> Iterator for Reader<R> {
             .map(|maybe_block| deserialize(maybe_block?, fields, avro_fields, projection))
     }
 }
+
+/// The location of a block of an Avro file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockLocation {
+    /// Number of rows in the block.
+    pub num_rows: usize,
+    /// Range of bytes of the block in the file, including its sync marker.
+    pub range: Range<usize>,
+}
+
+/// Locates the blocks of an Avro file, without decompressing or decoding them.
+///
+/// `data` holds the whole file and `offset` is the position of the first block, i.e. the end of
+/// the header. The bytes of consecutive blocks can be read with a [`Reader`].
+pub fn read_block_locations(
+    data: &[u8],
+    offset: usize,
+    marker: [u8; 16],
+) -> PolarsResult<Vec<BlockLocation>> {
+    let mut locations = vec![];
+    let mut position = offset;
+
+    while position < data.len() {
+        let mut cursor = Cursor::new(&data[position..]);
+        let num_rows = util::zigzag_i64(&mut cursor)?;
+        let num_bytes = util::zigzag_i64(&mut cursor)?;
+        let (Ok(num_rows), Ok(num_bytes)) = (usize::try_from(num_rows), usize::try_from(num_bytes))
+        else {
+            polars_bail!(oos = "negative block size - corrupt avro file")
+        };
+
+        let end = (position + cursor.position() as usize)
+            .checked_add(num_bytes)
+            .and_then(|end| end.checked_add(marker.len()))
+            .filter(|&end| end <= data.len())
+            .ok_or_else(|| polars_err!(oos = "block exceeds the file - corrupt avro file"))?;
+        if data[end - marker.len()..end] != marker {
+            polars_bail!(oos = "sync marker does not match - corrupt avro file")
+        }
+
+        locations.push(BlockLocation {
+            num_rows,
+            range: position..end,
+        });
+        position = end;
+    }
+
+    Ok(locations)
+}
@@ -191,6 +191,10 @@ fn serialize_type(dtype: &ArrowDataType) -> arrow_format::ipc::Type {
             bit_width: 128,
             is_signed: true,
         })),
+        UInt128 => ipc::Type::Int(Box::new(ipc::Int {
+            bit_width: 128,
+            is_signed: false,
+        })),
         Float16 => ipc::Type::FloatingPoint(Box::new(ipc::FloatingPoint {
             precision: ipc::Precision::Half,
         })),
@@ -296,6 +300,7 @@ fn serialize_children(
         | UInt32
         | UInt64
         | Int128
+        | UInt128
         | Float16
         | Float32
         | Float64
@@ -457,7 +457,7 @@ pub fn canonical_f16(x: f16) -> f16 {
     // zero out the sign bit if the f16 is zero.
     let convert_zero = f16(x.0 & (0x7FFF | (u16::from(x.0 & 0x7FFF == 0) << 15)));
     if convert_zero.is_nan() {
-        f16::from_bits(0x7c00) // Canonical quiet NaN.
+        f16::from_bits(0x7e00) // Canonical quiet NaN.
     } else {
         convert_zero
     }
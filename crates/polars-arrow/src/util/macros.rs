@@ -14,6 +14,7 @@ macro_rules! with_match_primitive_type {(
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
         Int128 => __with_ty__! { i128 },
+        UInt128 => __with_ty__! { u128 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
         _ => panic!("operator does not support primitive `{:?}`",
@@ -38,6 +39,7 @@ macro_rules! with_match_primitive_type_full {(
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
         Int128 => __with_ty__! { i128 },
+        UInt128 => __with_ty__! { u128 },
         Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
@@ -163,6 +163,11 @@ impl_bitwise_kernel! {
     (i128, identity, identity),
 }
 
+#[cfg(feature = "dtype-u128")]
+impl_bitwise_kernel! {
+    (u128, identity, identity),
+}
+
 impl BitwiseKernel for BooleanArray {
     type Scalar = bool;
 
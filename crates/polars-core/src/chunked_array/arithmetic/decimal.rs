@@ -1,13 +1,221 @@
 use super::*;
+use crate::chunked_array::ops::aggregate::quantile_idx;
+use crate::prelude::arity::broadcast_try_binary_elementwise;
+
+fn overflow_err() -> PolarsError {
+    polars_err!(ComputeError: "decimal arithmetic overflowed")
+}
+
+/// Check that an aggregated value fits in the maximum precision.
+fn check_max_precision(v: i128) -> PolarsResult<i128> {
+    if v.unsigned_abs() < 10_u128.pow(DEC128_MAX_PRECISION as u32) {
+        Ok(v)
+    } else {
+        Err(overflow_err())
+    }
+}
+
+/// Apply `op` to the values, raising an error if the result overflows.
+fn checked_arithmetic(
+    lhs: &Int128Chunked,
+    rhs: &Int128Chunked,
+    op: fn(i128, i128) -> Option<i128>,
+) -> PolarsResult<Int128Chunked> {
+    broadcast_try_binary_elementwise(lhs, rhs, |l, r| match (l, r) {
+        (Some(l), Some(r)) => op(l, r).map(Some).ok_or_else(overflow_err),
+        _ => Ok(None),
+    })
+}
+
+/// Sum physical decimal values, raising an error if the sum overflows.
+pub fn _decimal_checked_sum(values: impl IntoIterator<Item = i128>) -> PolarsResult<i128> {
+    values
+        .into_iter()
+        .try_fold(0_i128, |acc, v| acc.checked_add(v))
+        .ok_or_else(overflow_err)
+        .and_then(check_max_precision)
+}
+
+/// Mean of `count` physical decimal values that sum to `sum`, with `scale_increase` more decimal
+/// places than the values. The result is truncated, like decimal division.
+pub fn _decimal_checked_mean(sum: i128, count: usize, scale_increase: usize) -> PolarsResult<i128> {
+    polars_ensure!(count > 0, ComputeError: "cannot take the mean of zero values");
+    10_i128
+        .checked_pow(scale_increase as u32)
+        .and_then(|factor| sum.checked_mul(factor))
+        .map(|scaled| scaled / count as i128)
+        .ok_or_else(overflow_err)
+        .and_then(check_max_precision)
+}
+
+/// The sum, sum of squares and number of physical decimal values, raising an error if either sum
+/// overflows.
+pub fn _decimal_checked_moments(
+    values: impl IntoIterator<Item = i128>,
+) -> PolarsResult<(i128, i128, usize)> {
+    values
+        .into_iter()
+        .try_fold((0_i128, 0_i128, 0_usize), |(sum, sum_squares, count), v| {
+            let sum_squares = v.checked_mul(v)?.checked_add(sum_squares)?;
+            Some((sum.checked_add(v)?, sum_squares, count + 1))
+        })
+        .ok_or_else(overflow_err)
+}
+
+/// `num * 10^scale_increase / divisor`, truncated. The remainder is scaled separately, such that
+/// a large numerator doesn't overflow before it is divided.
+fn checked_div_rescaled(num: i128, divisor: i128, scale_increase: usize) -> Option<i128> {
+    let factor = 10_i128.checked_pow(scale_increase as u32)?;
+    let quotient = (num / divisor).checked_mul(factor)?;
+    let remainder = (num % divisor).checked_mul(factor)? / divisor;
+    quotient.checked_add(remainder)
+}
+
+/// Variance of physical decimal values with `scale` decimal places, from their sum, sum of
+/// squares and count. The result has `out_scale` decimal places and is truncated, like decimal
+/// division. It is `None` if there are no more than `ddof` values.
+fn checked_var(
+    (sum, sum_squares, count): (i128, i128, usize),
+    ddof: u8,
+    scale: usize,
+    out_scale: usize,
+) -> PolarsResult<Option<i128>> {
+    if count <= ddof as usize {
+        return Ok(None);
+    }
+
+    // This has `2 * scale` decimal places and is never negative.
+    let n = count as i128;
+    let numerator = n
+        .checked_mul(sum_squares)
+        .zip(sum.checked_mul(sum))
+        .and_then(|(a, b)| a.checked_sub(b));
+    let divisor = n.checked_mul(n - ddof as i128);
+    let var = numerator.zip(divisor).and_then(|(numerator, divisor)| {
+        if out_scale >= 2 * scale {
+            checked_div_rescaled(numerator, divisor, out_scale - 2 * scale)
+        } else {
+            let factor = 10_i128.checked_pow((2 * scale - out_scale) as u32)?;
+            Some(numerator / divisor / factor)
+        }
+    });
+    var.map(Some).ok_or_else(overflow_err)
+}
+
+/// Exact variance of physical decimal values, see [`_get_decimal_scale_var`] for the scale of the
+/// result.
+pub fn _decimal_checked_var(
+    moments: (i128, i128, usize),
+    ddof: u8,
+    scale: usize,
+) -> PolarsResult<Option<i128>> {
+    checked_var(moments, ddof, scale, _get_decimal_scale_var(scale))?
+        .map(check_max_precision)
+        .transpose()
+}
+
+/// Standard deviation of physical decimal values, see [`_get_decimal_scale_std`] for the scale
+/// of the result. This is the truncated square root of the exact variance.
+pub fn _decimal_checked_std(
+    moments: (i128, i128, usize),
+    ddof: u8,
+    scale: usize,
+) -> PolarsResult<Option<i128>> {
+    // The square root of a value with twice the decimal places has the output scale.
+    let var = checked_var(moments, ddof, scale, 2 * _get_decimal_scale_std(scale))?;
+    var.map(|var| check_max_precision(var.unsigned_abs().isqrt() as i128))
+        .transpose()
+}
+
+/// `x * proportion` for `0 <= proportion < 1`, rounded to the nearest integer. This is exact with
+/// respect to the float, which is expanded as `mantissa / 2^shift`.
+fn mul_proportion(x: u128, proportion: f64) -> u128 {
+    let (mantissa, exponent, _) = num_traits::Float::integer_decode(proportion);
+    if mantissa == 0 {
+        return 0;
+    }
+    let shift = exponent.unsigned_abs() as u32;
+
+    // `x * mantissa = hi * 2^64 + lo`, the mantissa has at most 53 bits so neither overflows.
+    let lo = (x as u64 as u128) * mantissa as u128;
+    let hi = (x >> 64) * mantissa as u128 + (lo >> 64);
+    let lo = lo as u64 as u128;
+    let bits_from = |n: u32| {
+        if n >= 64 {
+            hi.checked_shr(n - 64).unwrap_or(0)
+        } else {
+            (hi << (64 - n)) | (lo >> n)
+        }
+    };
+    // The proportion is below 1, so the shift is at least 53 and the product fits.
+    bits_from(shift) + (bits_from(shift - 1) & 1)
+}
+
+/// Interpolate between two physical decimal values with `lower <= upper`, with `scale_increase`
+/// more decimal places than the values. The interpolation is rounded to the nearest value at the
+/// output scale.
+fn checked_interpolate(
+    lower: i128,
+    upper: i128,
+    proportion: f64,
+    scale_increase: usize,
+) -> PolarsResult<i128> {
+    let factor = 10_i128
+        .checked_pow(scale_increase as u32)
+        .ok_or_else(overflow_err)?;
+    // The difference of two values of at most 38 digits fits in an unsigned integer.
+    let diff = (upper.wrapping_sub(lower) as u128)
+        .checked_mul(factor as u128)
+        .ok_or_else(overflow_err)?;
+    lower
+        .checked_mul(factor)
+        .and_then(|lower| lower.checked_add_unsigned(mul_proportion(diff, proportion)))
+        .ok_or_else(overflow_err)
+        .and_then(check_max_precision)
+}
+
+/// Quantile of physical decimal values, with `scale_increase` more decimal places than the
+/// values. Interpolated quantiles are computed exactly and rounded to the output scale.
+pub fn _decimal_checked_quantile(
+    mut values: Vec<i128>,
+    quantile: f64,
+    method: QuantileMethod,
+    scale_increase: usize,
+) -> PolarsResult<Option<i128>> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0",
+    );
+    if values.is_empty() {
+        return Ok(None);
+    }
+
+    values.sort_unstable();
+    let (idx, float_idx, top_idx) = quantile_idx(quantile, values.len(), 0, method);
+    let lower = values[idx];
+    let (upper, proportion) = match method {
+        _ if idx == top_idx => (lower, 0.0),
+        QuantileMethod::Midpoint => (values[idx + 1], 0.5),
+        QuantileMethod::Linear => (values[idx + 1], float_idx - idx as f64),
+        _ => (lower, 0.0),
+    };
+    checked_interpolate(lower, upper, proportion, scale_increase).map(Some)
+}
 
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
+        let precision = _get_decimal_precision_add_sub(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
         let lhs = self.to_scale(scale)?;
         let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.phys + &rhs.phys).into_decimal_unchecked(None, scale))
+        checked_arithmetic(&lhs.phys, &rhs.phys, i128::checked_add)?.into_decimal(precision, scale)
     }
 }
 
@@ -16,9 +224,15 @@ impl Sub for &DecimalChunked {
 
     fn sub(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
+        let precision = _get_decimal_precision_add_sub(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
         let lhs = self.to_scale(scale)?;
         let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.phys - &rhs.phys).into_decimal_unchecked(None, scale))
+        checked_arithmetic(&lhs.phys, &rhs.phys, i128::checked_sub)?.into_decimal(precision, scale)
     }
 }
 
@@ -27,7 +241,8 @@ impl Mul for &DecimalChunked {
 
     fn mul(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_mul(self.scale(), rhs.scale());
-        Ok((&self.phys * &rhs.phys).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_mul(self.precision(), rhs.precision());
+        checked_arithmetic(&self.phys, &rhs.phys, i128::checked_mul)?.into_decimal(precision, scale)
     }
 }
 
@@ -36,12 +251,16 @@ impl Div for &DecimalChunked {
 
     fn div(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_div(self.scale());
+        let precision = _get_decimal_precision_div(self.precision(), self.scale(), rhs.scale());
         let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.phys / &rhs.phys).into_decimal_unchecked(None, scale))
+        (&lhs.phys / &rhs.phys).into_decimal(precision, scale)
     }
 }
 
 // Used by polars-plan to determine schema.
+//
+// The precision follows the SQL standard: it is large enough to hold every possible result of the
+// operation, but never exceeds the maximum precision. An unknown precision stays unknown.
 pub fn _get_decimal_scale_add_sub(scale_left: usize, scale_right: usize) -> usize {
     scale_left.max(scale_right)
 }
@@ -54,3 +273,84 @@ pub fn _get_decimal_scale_div(scale_left: usize) -> usize {
     // Follow postgres and MySQL adding a fixed scale increment of 4
     scale_left + 4
 }
+
+pub fn _get_decimal_scale_mean(scale: usize) -> usize {
+    // Follow SQL Server, which gives averages at least 6 decimal places.
+    scale.max(6)
+}
+
+pub fn _get_decimal_precision_add_sub(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    precision_right: Option<usize>,
+    scale_right: usize,
+) -> Option<usize> {
+    let integer_digits = precision_left?
+        .saturating_sub(scale_left)
+        .max(precision_right?.saturating_sub(scale_right))
+        + 1;
+    let scale = _get_decimal_scale_add_sub(scale_left, scale_right);
+    Some((integer_digits + scale).min(DEC128_MAX_PRECISION))
+}
+
+pub fn _get_decimal_precision_mul(
+    precision_left: Option<usize>,
+    precision_right: Option<usize>,
+) -> Option<usize> {
+    Some((precision_left? + precision_right? + 1).min(DEC128_MAX_PRECISION))
+}
+
+pub fn _get_decimal_precision_div(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    scale_right: usize,
+) -> Option<usize> {
+    // Dividing by a value with `scale_right` decimal places can add as many integer digits.
+    let integer_digits = precision_left?.saturating_sub(scale_left) + scale_right;
+    let scale = _get_decimal_scale_div(scale_left);
+    Some((integer_digits + scale).min(DEC128_MAX_PRECISION))
+}
+
+/// Precision of the sum of many decimals, which can have any number of integer digits.
+pub fn _get_decimal_precision_sum(precision: Option<usize>) -> Option<usize> {
+    precision.map(|_| DEC128_MAX_PRECISION)
+}
+
+pub fn _get_decimal_scale_var(scale: usize) -> usize {
+    // The squares have twice the decimal places, and we give at least as many as a mean.
+    _get_decimal_scale_mean(2 * scale).min(DEC128_MAX_PRECISION)
+}
+
+pub fn _get_decimal_scale_std(scale: usize) -> usize {
+    _get_decimal_scale_mean(scale)
+}
+
+pub fn _get_decimal_scale_quantile(scale: usize) -> usize {
+    _get_decimal_scale_mean(scale)
+}
+
+/// Precision of a quantile of decimals, which lies between the smallest and largest value.
+pub fn _get_decimal_precision_quantile(precision: Option<usize>, scale: usize) -> Option<usize> {
+    let scale_increase = _get_decimal_scale_quantile(scale) - scale;
+    precision.map(|p| (p + scale_increase).min(DEC128_MAX_PRECISION))
+}
+
+/// Scale of a decimal rounded to `decimals` places. Rounding never adds decimal places.
+pub fn _get_decimal_scale_round(scale: usize, decimals: u32) -> usize {
+    scale.min(decimals as usize)
+}
+
+/// Precision of a decimal rounded to `decimals` places, rounding up can carry into a new integer
+/// digit.
+pub fn _get_decimal_precision_round(
+    precision: Option<usize>,
+    scale: usize,
+    decimals: u32,
+) -> Option<usize> {
+    let new_scale = _get_decimal_scale_round(scale, decimals);
+    if new_scale == scale {
+        return precision;
+    }
+    let integer_digits = precision?.saturating_sub(scale) + 1;
+    Some((integer_digits + new_scale).min(DEC128_MAX_PRECISION))
+}
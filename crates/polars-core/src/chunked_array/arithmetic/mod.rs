@@ -7,7 +7,15 @@ use std::ops::{Add, Div, Mul, Rem, Sub};
 
 use arrow::compute::utils::combine_validities_and;
 #[cfg(feature = "dtype-decimal")]
-pub use decimal::{_get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul};
+pub use decimal::{
+    _decimal_checked_mean, _decimal_checked_moments, _decimal_checked_quantile,
+    _decimal_checked_std, _decimal_checked_sum, _decimal_checked_var,
+    _get_decimal_precision_add_sub, _get_decimal_precision_div, _get_decimal_precision_mul,
+    _get_decimal_precision_quantile, _get_decimal_precision_round, _get_decimal_precision_sum,
+    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mean,
+    _get_decimal_scale_mul, _get_decimal_scale_quantile, _get_decimal_scale_round,
+    _get_decimal_scale_std, _get_decimal_scale_var,
+};
 use num_traits::{Num, NumCast, ToPrimitive};
 pub use numeric::ArithmeticChunked;
 
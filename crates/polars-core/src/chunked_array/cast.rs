@@ -94,6 +94,15 @@ fn cast_impl_inner(
             }
             chunks
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            polars_bail!(InvalidOperation: "only durations can be cast to {}", dtype)
+        },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let out = cast_impl_inner(name, chunks, &DataType::Float32, options)?;
+            return Ok(out.f32().unwrap().to_float16().into_series());
+        },
         _ => cast_chunks(chunks, &dtype.to_physical(), options)?,
     };
 
@@ -493,6 +502,8 @@ impl ChunkCast for ListChunked {
                     ))
                 }
             },
+            #[cfg(feature = "dtype-map")]
+            Map(..) => ca.to_map()?.cast_with_options(dtype, options),
             #[cfg(feature = "dtype-u8")]
             Binary => {
                 polars_ensure!(
@@ -1,6 +1,12 @@
 use std::borrow::Cow;
 
 use super::*;
+use crate::chunked_array::arithmetic::{
+    _decimal_checked_mean, _decimal_checked_moments, _decimal_checked_quantile,
+    _decimal_checked_std, _decimal_checked_sum, _decimal_checked_var,
+    _get_decimal_precision_quantile, _get_decimal_precision_sum, _get_decimal_scale_mean,
+    _get_decimal_scale_quantile, _get_decimal_scale_std, _get_decimal_scale_var,
+};
 use crate::chunked_array::cast::cast_chunks;
 use crate::prelude::*;
 
@@ -13,23 +19,23 @@ impl Int128Chunked {
         unsafe { DecimalChunked::new_logical(self, DataType::Decimal(precision, Some(scale))) }
     }
 
+    /// Convert to a decimal, raising an error if a value doesn't fit in the precision. Without a
+    /// precision the values are checked against the maximum precision of 38.
     pub fn into_decimal(
         self,
         precision: Option<usize>,
         scale: usize,
     ) -> PolarsResult<DecimalChunked> {
-        // TODO: if precision is None, do we check that the value fits within precision of 38?...
-        if let Some(precision) = precision {
-            let precision_max = 10_i128.pow(precision as u32);
-            if let Some((min, max)) = self.min_max() {
-                let max_abs = max.abs().max(min.abs());
-                polars_ensure!(
-                    max_abs < precision_max,
-                    ComputeError: "decimal precision {} can't fit values with {} digits",
-                    precision,
-                    max_abs.to_string().len()
-                );
-            }
+        let max_precision = precision.unwrap_or(DEC128_MAX_PRECISION);
+        let precision_max = 10_u128.pow(max_precision as u32);
+        if let Some((min, max)) = self.min_max() {
+            let max_abs = max.unsigned_abs().max(min.unsigned_abs());
+            polars_ensure!(
+                max_abs < precision_max,
+                ComputeError: "decimal precision {} can't fit values with {} digits",
+                max_precision,
+                max_abs.to_string().len()
+            );
         }
         Ok(self.into_decimal_unchecked(precision, scale))
     }
@@ -117,14 +123,65 @@ impl DecimalChunked {
         if let Some(ref mut precision) = precision {
             if self.scale() < scale {
                 *precision += scale;
-                *precision = (*precision).min(38);
+                *precision = (*precision).min(DEC128_MAX_PRECISION);
             }
         }
 
+        // Rescaling can overflow, which should raise rather than silently produce nulls.
         let s = self.cast_with_options(
             &DataType::Decimal(precision, Some(scale)),
-            CastOptions::NonStrict,
+            CastOptions::Strict,
         )?;
         Ok(Cow::Owned(s.decimal().unwrap().clone()))
     }
+
+    /// Get the exact mean of the values, with at least 6 decimal places.
+    pub fn mean_reduce(&self) -> PolarsResult<Scalar> {
+        let precision = _get_decimal_precision_sum(self.precision());
+        let scale = _get_decimal_scale_mean(self.scale());
+        let count = self.len() - self.null_count();
+        let av = if count == 0 {
+            AnyValue::Null
+        } else {
+            let sum = _decimal_checked_sum(self.phys.iter().flatten())?;
+            let mean = _decimal_checked_mean(sum, count, scale - self.scale())?;
+            AnyValue::Decimal(mean, scale)
+        };
+        Ok(Scalar::new(DataType::Decimal(precision, Some(scale)), av))
+    }
+
+    /// Get the exact variance of the values, with at least 6 decimal places.
+    pub fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        let moments = _decimal_checked_moments(self.phys.iter().flatten())?;
+        let var = _decimal_checked_var(moments, ddof, self.scale())?;
+        Ok(self.moment_scalar(var, _get_decimal_scale_var(self.scale())))
+    }
+
+    /// Get the standard deviation of the values, truncated to at least 6 decimal places.
+    pub fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        let moments = _decimal_checked_moments(self.phys.iter().flatten())?;
+        let std = _decimal_checked_std(moments, ddof, self.scale())?;
+        Ok(self.moment_scalar(std, _get_decimal_scale_std(self.scale())))
+    }
+
+    /// Get the quantile of the values, with at least 6 decimal places.
+    pub fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        let precision = _get_decimal_precision_quantile(self.precision(), self.scale());
+        let scale = _get_decimal_scale_quantile(self.scale());
+        let values = self.phys.iter().flatten().collect();
+        let quantile = _decimal_checked_quantile(values, quantile, method, scale - self.scale())?;
+        let av = quantile.map_or(AnyValue::Null, |v| AnyValue::Decimal(v, scale));
+        Ok(Scalar::new(DataType::Decimal(precision, Some(scale)), av))
+    }
+
+    /// Get the median of the values, with at least 6 decimal places.
+    pub fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.quantile_reduce(0.5, QuantileMethod::Linear)
+    }
+
+    fn moment_scalar(&self, value: Option<i128>, scale: usize) -> Scalar {
+        let precision = _get_decimal_precision_sum(self.precision());
+        let av = value.map_or(AnyValue::Null, |v| AnyValue::Decimal(v, scale));
+        Scalar::new(DataType::Decimal(precision, Some(scale)), av)
+    }
 }
@@ -54,6 +54,15 @@ impl LogicalType for DurationChunked {
                 };
                 Ok(out.into_duration(to_unit).into_series())
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ns = self.cast_with_options(&Duration(Nanoseconds), cast_options)?;
+                let ns = ns.duration()?.physical();
+                let out: Int128Chunked = ns.apply_nonnull_values_generic(DataType::Int128, |v| {
+                    interval_to_i128(months_days_ns::new(0, 0, v))
+                });
+                Ok(out.into_interval().into_series())
+            },
             dt if dt.is_primitive_numeric() => self.phys.cast_with_options(dtype, cast_options),
             dt => {
                 polars_bail!(
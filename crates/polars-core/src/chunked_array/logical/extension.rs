@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+
+use arrow::array::*;
+use arrow::datatypes::{PhysicalType, PrimitiveType};
+use arrow::types::months_days_ns;
+use arrow::{match_integer_type, with_match_primitive_type_full};
+
+use crate::prelude::*;
+
+/// A series of a user-defined [`DataType::Extension`] type. The values are stored in a series
+/// of the storage type.
+#[derive(Clone)]
+pub struct ExtensionChunked {
+    storage: Series,
+    dtype: DataType,
+}
+
+impl ExtensionChunked {
+    /// Wrap a series of the storage type in the extension type `ext`.
+    pub fn from_storage(ext: ExtensionType, storage: Series) -> Self {
+        let dtype = DataType::Extension(ext, Box::new(storage.dtype().clone()));
+        Self { storage, dtype }
+    }
+
+    /// Create a new [`ExtensionChunked`] of the same extension type from a series of the same
+    /// storage type.
+    pub fn with_storage(&self, storage: Series) -> Self {
+        debug_assert_eq!(storage.dtype(), self.storage.dtype());
+        Self {
+            storage,
+            dtype: self.dtype.clone(),
+        }
+    }
+
+    pub fn extension_type(&self) -> &ExtensionType {
+        match &self.dtype {
+            DataType::Extension(ext, _) => ext,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the series that holds the values of the extension type.
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub(crate) fn storage_mut(&mut self) -> &mut Series {
+        &mut self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        self.storage.name()
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) {
+        self.storage.rename(name);
+    }
+
+    pub fn field(&self) -> Field {
+        Field::new(self.name().clone(), self.dtype.clone())
+    }
+
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    /// Format the value at `index` with the implementation registered for the extension type.
+    pub fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        let ext = self.extension_type();
+        if ext.get_impl().is_none() {
+            return self.storage.str_value(index);
+        }
+        let value = self.storage.get(index)?;
+        Ok(Cow::Owned(ExtensionValueDisplay { ext, value }.to_string()))
+    }
+
+    pub fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        if dtype == self.dtype() {
+            return Ok(self.clone().into_series());
+        }
+        if let Some(out) = self
+            .extension_type()
+            .get_impl()
+            .and_then(|ext| ext.cast_to(self, dtype, cast_options))
+        {
+            return out;
+        }
+        self.storage.cast_with_options(dtype, cast_options)
+    }
+}
+
+/// Cast a series that is not of an extension type to the extension type `dtype`.
+pub(crate) fn cast_to_extension(
+    s: &Series,
+    dtype: &DataType,
+    cast_options: CastOptions,
+) -> PolarsResult<Series> {
+    let DataType::Extension(ext, storage) = dtype else {
+        unreachable!()
+    };
+    if let Some(out) = ext
+        .get_impl()
+        .and_then(|ext| ext.cast_from(s, dtype, cast_options))
+    {
+        return out;
+    }
+    let storage = s.cast_with_options(storage, cast_options)?;
+    Ok(ExtensionChunked::from_storage(ext.clone(), storage).into_series())
+}
+
+/// Replace the data type of an array by `dtype`, which must have the same physical layout.
+///
+/// This is used to switch an array between an Arrow extension type and its storage type. Inside
+/// of Polars the arrays of an extension type always have the storage type.
+pub(crate) fn with_arrow_dtype(arr: ArrayRef, dtype: &ArrowDataType) -> ArrayRef {
+    if arr.dtype() == dtype {
+        return arr;
+    }
+
+    fn downcast<T: 'static>(arr: &ArrayRef) -> &T {
+        arr.as_any().downcast_ref::<T>().unwrap()
+    }
+
+    let dtype = dtype.clone();
+    let validity = arr.validity().cloned();
+    match arr.dtype().to_physical_type() {
+        PhysicalType::Null => NullArray::new(dtype, arr.len()).boxed(),
+        PhysicalType::Boolean => {
+            let arr = downcast::<BooleanArray>(&arr);
+            BooleanArray::new(dtype, arr.values().clone(), validity).boxed()
+        },
+        PhysicalType::Primitive(PrimitiveType::MonthDayNano) => {
+            downcast::<PrimitiveArray<months_days_ns>>(&arr)
+                .clone()
+                .to(dtype)
+                .boxed()
+        },
+        PhysicalType::Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            downcast::<PrimitiveArray<$T>>(&arr).clone().to(dtype).boxed()
+        }),
+        PhysicalType::Binary => {
+            let arr = downcast::<BinaryArray<i32>>(&arr);
+            BinaryArray::new(dtype, arr.offsets().clone(), arr.values().clone(), validity).boxed()
+        },
+        PhysicalType::LargeBinary => {
+            let arr = downcast::<BinaryArray<i64>>(&arr);
+            BinaryArray::new(dtype, arr.offsets().clone(), arr.values().clone(), validity).boxed()
+        },
+        PhysicalType::Utf8 => {
+            let arr = downcast::<Utf8Array<i32>>(&arr);
+            // SAFETY: the values are already valid utf8.
+            unsafe {
+                Utf8Array::new_unchecked(
+                    dtype,
+                    arr.offsets().clone(),
+                    arr.values().clone(),
+                    validity,
+                )
+            }
+            .boxed()
+        },
+        PhysicalType::LargeUtf8 => {
+            let arr = downcast::<Utf8Array<i64>>(&arr);
+            // SAFETY: the values are already valid utf8.
+            unsafe {
+                Utf8Array::new_unchecked(
+                    dtype,
+                    arr.offsets().clone(),
+                    arr.values().clone(),
+                    validity,
+                )
+            }
+            .boxed()
+        },
+        PhysicalType::FixedSizeBinary => downcast::<FixedSizeBinaryArray>(&arr)
+            .clone()
+            .to(dtype)
+            .boxed(),
+        PhysicalType::List => {
+            let arr = downcast::<ListArray<i32>>(&arr);
+            ListArray::new(dtype, arr.offsets().clone(), arr.values().clone(), validity).boxed()
+        },
+        PhysicalType::LargeList => {
+            let arr = downcast::<ListArray<i64>>(&arr);
+            ListArray::new(dtype, arr.offsets().clone(), arr.values().clone(), validity).boxed()
+        },
+        PhysicalType::FixedSizeList => {
+            let arr = downcast::<FixedSizeListArray>(&arr);
+            FixedSizeListArray::new(dtype, arr.len(), arr.values().clone(), validity).boxed()
+        },
+        PhysicalType::Struct => {
+            let arr = downcast::<StructArray>(&arr);
+            StructArray::new(dtype, arr.len(), arr.values().to_vec(), validity).boxed()
+        },
+        PhysicalType::Map => {
+            let arr = downcast::<MapArray>(&arr);
+            MapArray::new(dtype, arr.offsets().clone(), arr.field().clone(), validity).boxed()
+        },
+        PhysicalType::Dictionary(key) => match_integer_type!(key, |$K| {
+            let arr = downcast::<DictionaryArray<$K>>(&arr);
+            // SAFETY: the keys and values are taken from a valid dictionary array.
+            unsafe {
+                DictionaryArray::try_new_unchecked(dtype, arr.keys().clone(), arr.values().clone())
+            }
+            .unwrap()
+            .boxed()
+        }),
+        // The data type of these arrays is fixed.
+        PhysicalType::BinaryView | PhysicalType::Utf8View | PhysicalType::Union => arr,
+    }
+}
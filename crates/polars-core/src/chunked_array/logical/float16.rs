@@ -0,0 +1,56 @@
+use super::*;
+use crate::prelude::*;
+
+/// Half precision floats. Every row is backed by the bits of the float in an `u16`, computations
+/// are done on the values widened to `f32`.
+pub type Float16Chunked = Logical<Float16Type, UInt16Type>;
+
+impl UInt16Chunked {
+    /// Interpret the values as the bits of half precision floats.
+    pub fn into_float16(self) -> Float16Chunked {
+        // SAFETY: every u16 is a valid f16.
+        unsafe { Float16Chunked::new_logical(self, DataType::Float16) }
+    }
+}
+
+impl Float32Chunked {
+    /// Round every value to the nearest half precision float.
+    pub fn to_float16(&self) -> Float16Chunked {
+        let bits: UInt16Chunked =
+            self.apply_nonnull_values_generic(DataType::UInt16, |v| f16::from_f32(v).to_bits());
+        bits.into_float16()
+    }
+}
+
+impl Float16Chunked {
+    /// Widen every value to an `f32`. This is lossless.
+    pub fn to_float32(&self) -> Float32Chunked {
+        self.phys
+            .apply_nonnull_values_generic(DataType::Float32, |v| f16::from_bits(v).to_f32())
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Float16
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i).map(|av| av.as_float16())
+    }
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i).as_float16()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            DataType::Float32 => Ok(self.to_float32().into_series()),
+            _ => self.to_float32().cast_with_options(dtype, cast_options),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+use crate::prelude::*;
+
+/// A calendar interval of months, days and nanoseconds. Every row is backed by an `i128` that
+/// packs the three components, see [`interval_to_i128`].
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+const DAYS_SIGN: u32 = 1 << 31;
+const NS_SIGN: u64 = 1 << 63;
+
+/// Pack an interval into the physical `i128` representation.
+///
+/// The months take the upper 32 bits, the days the next 32 bits and the nanoseconds the lower
+/// 64 bits. The sign bits of the days and nanoseconds are flipped so that the physical order
+/// is the lexicographic order of `(months, days, nanoseconds)`.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    ((v.months() as i128) << 96)
+        | (((v.days() as u32 ^ DAYS_SIGN) as i128) << 64)
+        | ((v.ns() as u64 ^ NS_SIGN) as i128)
+}
+
+/// Unpack the physical `i128` representation of an interval, see [`interval_to_i128`].
+#[inline]
+pub fn i128_to_interval(v: i128) -> months_days_ns {
+    months_days_ns::new(
+        (v >> 96) as i32,
+        ((v >> 64) as u32 ^ DAYS_SIGN) as i32,
+        (v as u64 ^ NS_SIGN) as i64,
+    )
+}
+
+impl Int128Chunked {
+    pub fn into_interval(self) -> IntervalChunked {
+        // SAFETY: every i128 is a valid packed interval.
+        unsafe { IntervalChunked::new_logical(self, DataType::Interval) }
+    }
+}
+
+impl IntervalChunked {
+    /// Create an [`IntervalChunked`] from intervals given as `(months, days, nanoseconds)`.
+    pub fn from_intervals<I: IntoIterator<Item = Option<months_days_ns>>>(
+        name: PlSmallStr,
+        v: I,
+    ) -> Self {
+        Int128Chunked::from_iter_options(name, v.into_iter().map(|v| v.map(interval_to_i128)))
+            .into_interval()
+    }
+
+    /// Negate every component of every interval.
+    pub fn negate(&self) -> Self {
+        self.phys
+            .apply_values(|v| interval_to_i128(-i128_to_interval(v)))
+            .into_interval()
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Interval
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i).map(|av| av.as_interval())
+    }
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i).as_interval()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        _cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Interval => Ok(self.clone().into_series()),
+            _ => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dtype
+                )
+            },
+        }
+    }
+}
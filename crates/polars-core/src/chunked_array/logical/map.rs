@@ -0,0 +1,153 @@
+use std::borrow::Cow;
+
+use super::*;
+use crate::prelude::*;
+
+/// A map from keys to values. Every row is backed by a list of `{key, value}` structs.
+pub type MapChunked = Logical<MapType, ListType>;
+
+impl ListChunked {
+    /// Interpret a list of structs with two fields as a map.
+    ///
+    /// The first field of the structs holds the keys and the second field holds the values.
+    /// The keys are not allowed to be null.
+    pub fn to_map(&self) -> PolarsResult<MapChunked> {
+        let DataType::Struct(fields) = self.inner_dtype() else {
+            polars_bail!(
+                InvalidOperation: "cannot convert a list of '{}' to a map, expected a list of structs",
+                self.inner_dtype()
+            );
+        };
+        polars_ensure!(
+            fields.len() == 2,
+            InvalidOperation: "cannot convert a list of structs with {} fields to a map, expected 2 fields (key and value)",
+            fields.len()
+        );
+
+        let ca = self
+            .trim_lists_to_normalized_offsets()
+            .map_or(Cow::Borrowed(self), Cow::Owned);
+        let ca = ca.propagate_nulls().map_or(ca, Cow::Owned);
+
+        let ca = ca.apply_to_inner(&|entries| {
+            let entries = entries.struct_()?;
+            let fields = entries.fields_as_series();
+            let key = fields[0].clone().with_name(MAP_KEY_NAME);
+            let value = fields[1].clone().with_name(MAP_VALUE_NAME);
+            StructChunked::from_series(PlSmallStr::EMPTY, entries.len(), [key, value].iter())
+                .map(|ca| ca.into_series())
+        })?;
+
+        MapChunked::try_from_entries(ca)
+    }
+}
+
+impl MapChunked {
+    /// Create a [`MapChunked`] from a list of `{key, value}` structs.
+    ///
+    /// Errors if any of the keys is null. The keys are not marked as sorted.
+    pub fn try_from_entries(entries: ListChunked) -> PolarsResult<Self> {
+        let DataType::List(inner) = entries.dtype() else {
+            unreachable!()
+        };
+        let DataType::Struct(fields) = inner.as_ref() else {
+            polars_bail!(InvalidOperation: "map entries must be structs, got '{}'", inner);
+        };
+        polars_ensure!(
+            fields.len() == 2
+                && fields[0].name() == &MAP_KEY_NAME
+                && fields[1].name() == &MAP_VALUE_NAME,
+            InvalidOperation: "map entries must be structs with a `key` and a `value` field"
+        );
+
+        let inner = entries.get_inner();
+        let key = &inner.struct_()?.fields_as_series()[0];
+        polars_ensure!(
+            key.null_count() == 0,
+            ComputeError: "map keys cannot be null"
+        );
+
+        let dtype = DataType::Map(
+            Box::new(fields[0].dtype().clone()),
+            Box::new(fields[1].dtype().clone()),
+            false,
+        );
+        // SAFETY: we just checked the layout of the entries.
+        Ok(unsafe { Self::new_logical(entries, dtype) })
+    }
+
+    pub fn key_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(key, _, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(_, value, _) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Mark whether the keys within every map are sorted.
+    pub fn set_keys_sorted(&mut self, keys_sorted: bool) {
+        if let DataType::Map(_, _, sorted) = &mut self.dtype {
+            *sorted = keys_sorted;
+        }
+    }
+
+    /// Get the `{key, value}` entries of the map as a list of structs.
+    pub fn entries(&self) -> &ListChunked {
+        &self.phys
+    }
+
+    /// Create a new [`MapChunked`] with the same data type from a physical list of entries.
+    ///
+    /// # Safety
+    /// The list must hold the entries of a map of the same data type.
+    pub unsafe fn with_entries(&self, entries: ListChunked) -> Self {
+        Self::new_logical(entries, self.dtype.clone())
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Map(key, value, _) => {
+                if dtype == self.dtype() {
+                    return Ok(self.clone().into_series());
+                }
+                let entries_dtype =
+                    DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+                let entries = self
+                    .phys
+                    .cast_with_options(&DataType::List(Box::new(entries_dtype)), cast_options)?;
+                let entries = entries.list()?.clone();
+                Ok(Self::try_from_entries(entries)?.into_series())
+            },
+            DataType::List(_) => self.phys.cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
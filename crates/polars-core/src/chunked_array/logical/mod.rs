@@ -14,6 +14,22 @@ pub use decimal::*;
 mod duration;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-extension")]
+pub use extension::*;
+#[cfg(feature = "dtype-f16")]
+mod float16;
+#[cfg(feature = "dtype-f16")]
+pub use float16::*;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
+#[cfg(feature = "dtype-map")]
+mod map;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
 #[cfg(feature = "dtype-time")]
@@ -665,6 +665,7 @@ mod test {
         assert_eq!(
             ca.into_series()
                 .mean_reduce()
+                .unwrap()
                 .value()
                 .extract::<f32>()
                 .unwrap(),
@@ -674,7 +675,11 @@ mod test {
         let ca = Float32Chunked::full_null(PlSmallStr::EMPTY, 3);
         assert_eq!(ca.mean(), None);
         assert_eq!(
-            ca.into_series().mean_reduce().value().extract::<f32>(),
+            ca.into_series()
+                .mean_reduce()
+                .unwrap()
+                .value()
+                .extract::<f32>(),
             None
         );
     }
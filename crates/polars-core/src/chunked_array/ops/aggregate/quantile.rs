@@ -10,7 +10,7 @@ pub trait QuantileAggSeries {
 }
 
 /// helper
-fn quantile_idx(
+pub(crate) fn quantile_idx(
     quantile: f64,
     length: usize,
     null_count: usize,
@@ -39,6 +39,7 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
         DataType::UInt16 => downcast_and_pack!(UInt16Array, UInt16),
         DataType::UInt32 => downcast_and_pack!(UInt32Array, UInt32),
         DataType::UInt64 => downcast_and_pack!(UInt64Array, UInt64),
+        DataType::UInt128 => downcast_and_pack!(UInt128Array, UInt128),
         DataType::Int8 => downcast_and_pack!(Int8Array, Int8),
         DataType::Int16 => downcast_and_pack!(Int16Array, Int16),
         DataType::Int32 => downcast_and_pack!(Int32Array, Int32),
@@ -62,6 +63,19 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
                 AnyValue::List(s)
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value, _) => {
+            let v: ArrayRef = downcast!(LargeListArray);
+            let entries = DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+            let s = Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![v],
+                &entries.to_physical(),
+            )
+            .from_physical_unchecked(&entries)
+            .unwrap();
+            AnyValue::List(s)
+        },
         #[cfg(feature = "dtype-array")]
         DataType::Array(dt, width) => {
             let v: ArrayRef = downcast!(FixedSizeListArray);
@@ -126,12 +140,26 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Time(v)
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(i128_to_interval(v))
+        },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let arr = &*(arr as *const dyn Array as *const UInt16Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Float32(f16::from_bits(v).to_f32())
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let arr = &*(arr as *const dyn Array as *const Int128Array);
             let v = arr.value_unchecked(idx);
             AnyValue::Decimal(v, scale.unwrap_or_else(|| unreachable!()))
         },
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension(_, storage) => arr_to_any_value(arr, idx, storage),
         #[cfg(feature = "object")]
         DataType::Object(_) => {
             // We should almost never hit this. The only known exception is when we put objects in
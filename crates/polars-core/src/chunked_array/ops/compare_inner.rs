@@ -230,3 +230,30 @@ impl<'a, T: PolarsCategoricalType> IntoTotalOrdInner<'a> for &'a CategoricalChun
         }
     }
 }
+
+/// Half precision floats are compared on their widened values, not on their bits.
+#[cfg(feature = "dtype-f16")]
+struct Float16Values<'a>(&'a UInt16Chunked);
+
+#[cfg(feature = "dtype-f16")]
+impl GetInner for Float16Values<'_> {
+    type Item = Option<f32>;
+    unsafe fn get_unchecked(&self, idx: usize) -> Self::Item {
+        let bits = self.0.get_unchecked(idx)?;
+        Some(f16::from_bits(bits).to_f32())
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> IntoTotalEqInner<'a> for &'a Float16Chunked {
+    fn into_total_eq_inner(self) -> Box<dyn TotalEqInner + 'a> {
+        Box::new(Float16Values(&self.phys))
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> IntoTotalOrdInner<'a> for &'a Float16Chunked {
+    fn into_total_ord_inner(self) -> Box<dyn TotalOrdInner + 'a> {
+        Box::new(Float16Values(&self.phys))
+    }
+}
@@ -79,6 +79,7 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::UInt16
         | DataType::UInt32
         | DataType::UInt64
+        | DataType::UInt128
         | DataType::Int8
         | DataType::Int16
         | DataType::Int32
@@ -94,6 +95,10 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Date
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => None,
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => Some(RowEncodingContext::Float16),
 
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
@@ -120,6 +125,13 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         #[cfg(feature = "dtype-array")]
         DataType::Array(dtype, _) => get_row_encoding_context(dtype),
         DataType::List(dtype) => get_row_encoding_context(dtype),
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension(_, storage) => get_row_encoding_context(storage),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value, _) => get_row_encoding_context(&DataType::map_entries(
+            key.as_ref().clone(),
+            value.as_ref().clone(),
+        )),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(fs) => {
             let mut ctxts = Vec::new();
@@ -141,6 +141,15 @@ enum SerializableDataType {
     Decimal(Option<usize>, Option<usize>),
     #[cfg(feature = "object")]
     Object(String),
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>, bool),
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    #[cfg(feature = "dtype-extension")]
+    Extension(ExtensionType, Box<SerializableDataType>),
+    UInt128,
+    #[cfg(feature = "dtype-f16")]
+    Float16,
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -191,6 +200,21 @@ impl From<&DataType> for SerializableDataType {
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, keys_sorted) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+                *keys_sorted,
+            ),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext, storage) => {
+                Self::Extension(ext.clone(), Box::new(storage.as_ref().into()))
+            },
+            UInt128 => Self::UInt128,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
         }
     }
 }
@@ -249,6 +273,19 @@ impl From<SerializableDataType> for DataType {
             Decimal(precision, scale) => Self::Decimal(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, keys_sorted) => Self::Map(
+                Box::new((*key).into()),
+                Box::new((*value).into()),
+                keys_sorted,
+            ),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext, storage) => Self::Extension(ext, Box::new((*storage).into())),
+            UInt128 => Self::UInt128,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
         }
     }
 }
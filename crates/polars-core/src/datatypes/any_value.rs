@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use arrow::types::PrimitiveType;
 use polars_compute::cast::SerPrimitive;
 use polars_error::feature_gated;
+use polars_utils::format_pl_smallstr;
 use polars_utils::total_ord::ToTotalOrd;
 
 use super::*;
@@ -38,6 +39,8 @@ pub enum AnyValue<'a> {
     UInt32(u32),
     /// An unsigned 64-bit integer number.
     UInt64(u64),
+    /// An unsigned 128-bit integer number.
+    UInt128(u128),
     /// An 8-bit integer number.
     Int8(i8),
     /// A 16-bit integer number.
@@ -70,6 +73,9 @@ pub enum AnyValue<'a> {
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     #[cfg(feature = "dtype-time")]
     Time(i64),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
     #[cfg(feature = "dtype-categorical")]
     Categorical(CatSize, &'a Arc<CategoricalMapping>),
     #[cfg(feature = "dtype-categorical")]
@@ -118,6 +124,10 @@ impl AnyValue<'static> {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(unit) => AnyValue::Duration(0, *unit),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => AnyValue::Interval(months_days_ns::default()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => AnyValue::Float32(0.0),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_p, s) => {
                 AnyValue::Decimal(0, s.expect("unknown scale during execution"))
@@ -144,11 +154,14 @@ impl AnyValue<'static> {
             DT::UInt16 => AV::UInt16(numeric_to_one.into()),
             DT::UInt32 => AV::UInt32(numeric_to_one.into()),
             DT::UInt64 => AV::UInt64(numeric_to_one.into()),
+            DT::UInt128 => AV::UInt128(numeric_to_one.into()),
             DT::Int8 => AV::Int8(numeric_to_one.into()),
             DT::Int16 => AV::Int16(numeric_to_one.into()),
             DT::Int32 => AV::Int32(numeric_to_one.into()),
             DT::Int64 => AV::Int64(numeric_to_one.into()),
             DT::Int128 => AV::Int128(numeric_to_one.into()),
+            #[cfg(feature = "dtype-f16")]
+            DT::Float16 => AV::Float32(numeric_to_one.into()),
             DT::Float32 => AV::Float32(numeric_to_one.into()),
             DT::Float64 => AV::Float64(numeric_to_one.into()),
             #[cfg(feature = "dtype-decimal")]
@@ -165,6 +178,8 @@ impl AnyValue<'static> {
                 feature_gated!("dtype-duration", AV::Duration(0, *time_unit))
             },
             DT::Time => feature_gated!("dtype-time", AV::Time(0)),
+            #[cfg(feature = "dtype-interval")]
+            DT::Interval => AV::Interval(months_days_ns::default()),
             #[cfg(feature = "dtype-array")]
             DT::Array(inner_dtype, width) => {
                 let inner_value =
@@ -186,6 +201,15 @@ impl AnyValue<'static> {
                     .into_series(PlSmallStr::EMPTY)
                     .new_from_index(0, num_list_values)
             }),
+            #[cfg(feature = "dtype-map")]
+            DT::Map(key, value, _) => AV::List(Series::new_empty(
+                PlSmallStr::EMPTY,
+                &DataType::map_entries(key.as_ref().clone(), value.as_ref().clone()),
+            )),
+            #[cfg(feature = "dtype-extension")]
+            DT::Extension(_, storage) => {
+                AnyValue::default_value(storage, numeric_to_one, num_list_values)
+            },
             #[cfg(feature = "object")]
             DT::Object(_) => AV::Null,
             DT::Null => AV::Null,
@@ -228,6 +252,7 @@ impl<'a> AnyValue<'a> {
             UInt16(_) => DataType::UInt16,
             UInt32(_) => DataType::UInt32,
             UInt64(_) => DataType::UInt64,
+            UInt128(_) => DataType::UInt128,
             Float32(_) => DataType::Float32,
             Float64(_) => DataType::Float64,
             String(_) | StringOwned(_) => DataType::String,
@@ -244,6 +269,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-duration")]
             Duration(_, tu) => DataType::Duration(*tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | CategoricalOwned(_, _) => {
                 unimplemented!("can not get dtype of Categorical AnyValue")
@@ -281,6 +308,7 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => NumCast::from(*v),
             UInt32(v) => NumCast::from(*v),
             UInt64(v) => NumCast::from(*v),
+            UInt128(v) => NumCast::from(*v),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-date")]
@@ -353,7 +381,11 @@ impl<'a> AnyValue<'a> {
     pub fn is_unsigned_integer(&self) -> bool {
         matches!(
             self,
-            AnyValue::UInt8(_) | AnyValue::UInt16(_) | AnyValue::UInt32(_) | AnyValue::UInt64(_)
+            AnyValue::UInt8(_)
+                | AnyValue::UInt16(_)
+                | AnyValue::UInt32(_)
+                | AnyValue::UInt64(_)
+                | AnyValue::UInt128(_)
         )
     }
 
@@ -395,6 +427,12 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int32) => AnyValue::Int32(av.extract::<i32>()?),
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
             (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
+            (av, DataType::UInt128) => AnyValue::UInt128(av.extract::<u128>()?),
+            // 16-bit floats are represented by their value as a 32-bit float.
+            #[cfg(feature = "dtype-f16")]
+            (av, DataType::Float16) => {
+                AnyValue::Float32(f16::from_f32(av.extract::<f32>()?).to_f32())
+            },
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
 
@@ -408,6 +446,7 @@ impl<'a> AnyValue<'a> {
             (AnyValue::Int32(v), DataType::Boolean) => AnyValue::Boolean(*v != i32::default()),
             (AnyValue::Int64(v), DataType::Boolean) => AnyValue::Boolean(*v != i64::default()),
             (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
+            (AnyValue::UInt128(v), DataType::Boolean) => AnyValue::Boolean(*v != u128::default()),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
 
@@ -476,6 +515,9 @@ impl<'a> AnyValue<'a> {
             (AnyValue::String(v), DataType::String) => AnyValue::String(v),
             (AnyValue::StringOwned(v), DataType::String) => AnyValue::StringOwned(v.clone()),
 
+            (AnyValue::UInt128(v), DataType::String) => {
+                AnyValue::StringOwned(format_pl_smallstr!("{v}"))
+            },
             (av, DataType::String) => {
                 let mut tmp = vec![];
                 if av.is_unsigned_integer() {
@@ -584,6 +626,17 @@ impl<'a> AnyValue<'a> {
                 *tu_r,
             ),
 
+            // to interval
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Duration(v, tu), DataType::Interval) => {
+                let factor = match tu {
+                    TimeUnit::Nanoseconds => 1i64,
+                    TimeUnit::Microseconds => 1_000i64,
+                    TimeUnit::Milliseconds => 1_000_000i64,
+                };
+                AnyValue::Interval(months_days_ns::new(0, 0, v.checked_mul(factor)?))
+            },
+
             // to decimal
             #[cfg(feature = "dtype-decimal")]
             (av, DataType::Decimal(prec, scale)) if av.is_integer() => {
@@ -675,6 +728,7 @@ impl<'a> AnyValue<'a> {
             | Self::UInt16(_)
             | Self::UInt32(_)
             | Self::UInt64(_)
+            | Self::UInt128(_)
             | Self::Int8(_)
             | Self::Int16(_)
             | Self::Int32(_)
@@ -695,6 +749,8 @@ impl<'a> AnyValue<'a> {
             Self::Duration(v, _) => Self::Int64(v),
             #[cfg(feature = "dtype-time")]
             Self::Time(v) => Self::Int64(v),
+            #[cfg(feature = "dtype-interval")]
+            Self::Interval(v) => Self::Int128(interval_to_i128(v)),
 
             #[cfg(feature = "dtype-categorical")]
             Self::Categorical(v, _)
@@ -781,6 +837,7 @@ impl AnyValue<'_> {
             UInt16(v) => v.hash(state),
             UInt32(v) => v.hash(state),
             UInt64(v) => v.hash(state),
+            UInt128(v) => v.hash(state),
             String(v) => v.hash(state),
             StringOwned(v) => v.hash(state),
             Float32(v) => v.to_ne_bytes().hash(state),
@@ -821,6 +878,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             Time(v) => v.hash(state),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
             #[cfg(feature = "dtype-categorical")]
             Categorical(v, _) | CategoricalOwned(v, _) | Enum(v, _) | EnumOwned(v, _) => {
                 v.hash(state)
@@ -907,6 +966,24 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn as_interval(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::Int128(v) => AnyValue::Interval(i128_to_interval(*v)),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create interval from other type. dtype: {dt}"),
+        }
+    }
+
+    #[cfg(feature = "dtype-f16")]
+    pub(crate) fn as_float16(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::UInt16(v) => AnyValue::Float32(f16::from_bits(*v).to_f32()),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create float16 from other type. dtype: {dt}"),
+        }
+    }
+
     pub(crate) fn to_i128(&self) -> Option<i128> {
         match self {
             AnyValue::UInt8(v) => Some((*v).into()),
@@ -918,6 +995,7 @@ impl<'a> AnyValue<'a> {
             AnyValue::Int32(v) => Some((*v).into()),
             AnyValue::Int64(v) => Some((*v).into()),
             AnyValue::Int128(v) => Some(*v),
+            AnyValue::UInt128(v) => (*v).try_into().ok(),
             _ => None,
         }
     }
@@ -995,6 +1073,7 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => UInt16(v),
             UInt32(v) => UInt32(v),
             UInt64(v) => UInt64(v),
+            UInt128(v) => UInt128(v),
             Boolean(v) => Boolean(v),
             Float32(v) => Float32(v),
             Float64(v) => Float64(v),
@@ -1008,6 +1087,8 @@ impl<'a> AnyValue<'a> {
             Duration(v, tu) => Duration(v, tu),
             #[cfg(feature = "dtype-time")]
             Time(v) => Time(v),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
             List(v) => List(v),
             #[cfg(feature = "dtype-array")]
             Array(s, size) => Array(s, size),
@@ -1151,6 +1232,7 @@ impl AnyValue<'_> {
             (UInt16(l), UInt16(r)) => *l == *r,
             (UInt32(l), UInt32(r)) => *l == *r,
             (UInt64(l), UInt64(r)) => *l == *r,
+            (UInt128(l), UInt128(r)) => *l == *r,
             (Int8(l), Int8(r)) => *l == *r,
             (Int16(l), Int16(r)) => *l == *r,
             (Int32(l), Int32(r)) => *l == *r,
@@ -1195,6 +1277,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
 
             #[cfg(feature = "dtype-struct")]
             (StructOwned(l), StructOwned(r)) => struct_eq_missing(
@@ -1331,6 +1415,7 @@ impl PartialOrd for AnyValue<'_> {
             (UInt16(l), UInt16(r)) => l.partial_cmp(r),
             (UInt32(l), UInt32(r)) => l.partial_cmp(r),
             (UInt64(l), UInt64(r)) => l.partial_cmp(r),
+            (UInt128(l), UInt128(r)) => l.partial_cmp(r),
             (Int8(l), Int8(r)) => l.partial_cmp(r),
             (Int16(l), Int16(r)) => l.partial_cmp(r),
             (Int32(l), Int32(r)) => l.partial_cmp(r),
@@ -1362,6 +1447,8 @@ impl PartialOrd for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-categorical")]
             (Categorical(l_cat, l_map), Categorical(r_cat, r_map)) => unsafe {
                 let l_str = l_map.cat_to_str_unchecked(*l_cat);
@@ -1557,6 +1644,16 @@ impl GetAnyValue for ArrayRef {
                     Some(v) => AnyValue::UInt64(v),
                 }
             },
+            ArrowDataType::UInt128 => {
+                let arr = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u128>>()
+                    .unwrap_unchecked();
+                match arr.get_unchecked(index) {
+                    None => AnyValue::Null,
+                    Some(v) => AnyValue::UInt128(v),
+                }
+            },
             ArrowDataType::Float32 => {
                 let arr = self
                     .as_any()
@@ -1615,6 +1712,9 @@ impl<K: NumericNative> From<K> for AnyValue<'static> {
                 PrimitiveType::UInt16 => AnyValue::UInt16(NumCast::from(value).unwrap_unchecked()),
                 PrimitiveType::UInt32 => AnyValue::UInt32(NumCast::from(value).unwrap_unchecked()),
                 PrimitiveType::UInt64 => AnyValue::UInt64(NumCast::from(value).unwrap_unchecked()),
+                PrimitiveType::UInt128 => {
+                    AnyValue::UInt128(NumCast::from(value).unwrap_unchecked())
+                },
                 PrimitiveType::Float32 => {
                     AnyValue::Float32(NumCast::from(value).unwrap_unchecked())
                 },
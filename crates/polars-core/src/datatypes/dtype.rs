@@ -1,5 +1,9 @@
 use std::collections::BTreeMap;
 
+#[cfg(feature = "dtype-extension")]
+use arrow::datatypes::ExtensionType as ArrowExtensionType;
+#[cfg(feature = "dtype-interval")]
+use arrow::datatypes::IntervalUnit;
 use arrow::datatypes::{
     DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY, DTYPE_ENUM_VALUES_NEW, Metadata,
 };
@@ -18,6 +22,20 @@ use crate::utils::materialize_dyn_int;
 static MAINTAIN_PL_TYPE: &str = "maintain_type";
 static PL_KEY: &str = "pl";
 
+/// Name of the struct field that holds the keys of a [`DataType::Map`].
+#[cfg(feature = "dtype-map")]
+pub const MAP_KEY_NAME: PlSmallStr = PlSmallStr::from_static("key");
+/// Name of the struct field that holds the values of a [`DataType::Map`].
+#[cfg(feature = "dtype-map")]
+pub const MAP_VALUE_NAME: PlSmallStr = PlSmallStr::from_static("value");
+/// Name of the Arrow field that holds the key-value entries of a map.
+#[cfg(feature = "dtype-map")]
+pub const MAP_ENTRIES_NAME: PlSmallStr = PlSmallStr::from_static("entries");
+/// Maximum precision of a [`DataType::Decimal`], the number of digits that always fit in an
+/// `i128`.
+#[cfg(feature = "dtype-decimal")]
+pub const DEC128_MAX_PRECISION: usize = 38;
+
 pub trait MetaDataExt: IntoMetadata {
     fn pl_enum_metadata(&self) -> Option<&str> {
         let md = self.into_metadata_ref();
@@ -93,11 +111,16 @@ pub enum DataType {
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Int8,
     Int16,
     Int32,
     Int64,
     Int128,
+    /// A 16-bit half precision float. This is backed by the bits of the float in an unsigned
+    /// 16-bit integer, computations are done on the value widened to [`DataType::Float32`].
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -119,6 +142,11 @@ pub enum DataType {
     Duration(TimeUnit),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
+    /// A calendar interval of months, days and nanoseconds. Unlike [`DataType::Duration`], the
+    /// length of the months and days depends on the date they are added to.
+    /// This is backed by a signed 128-bit integer that packs the three parts.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A nested list with a fixed size in each row
     #[cfg(feature = "dtype-array")]
     Array(Box<DataType>, usize),
@@ -136,6 +164,15 @@ pub enum DataType {
     Enum(Arc<FrozenCategories>, Arc<CategoricalMapping>),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A map from keys to values. Every row holds a variable number of key-value entries.
+    /// This is backed by a list of structs with a `key` and a `value` field. The flag tells
+    /// whether the keys within every map are sorted.
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>, bool),
+    /// A user-defined logical type with a name and metadata, see [`ExtensionType`].
+    /// This is backed by the storage data type.
+    #[cfg(feature = "dtype-extension")]
+    Extension(ExtensionType, Box<DataType>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -186,6 +223,15 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                // Whether the keys are sorted doesn't change the type.
+                (Map(left_key, left_value, _), Map(right_key, right_value, _)) => {
+                    left_key == right_key && left_value == right_value
+                },
+                #[cfg(feature = "dtype-extension")]
+                (Extension(left_ext, left_storage), Extension(right_ext, right_storage)) => {
+                    left_ext == right_ext && left_storage == right_storage
+                },
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -236,6 +282,10 @@ impl DataType {
             DataType::Array(inner, _) => inner.is_known(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value, _) => key.is_known() && value.is_known(),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_, storage) => storage.is_known(),
             DataType::Unknown(_) => false,
             _ => true,
         }
@@ -275,6 +325,17 @@ impl DataType {
                     })
                     .try_collect_vec()?,
             )),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value, keys_sorted) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown(allow_unknown)?),
+                Box::new(value.materialize_unknown(allow_unknown)?),
+                keys_sorted,
+            )),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext, storage) => Ok(DataType::Extension(
+                ext,
+                Box::new(storage.materialize_unknown(allow_unknown)?),
+            )),
             _ => Ok(self),
         }
     }
@@ -420,6 +481,23 @@ impl DataType {
 
                 true
             },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(l_key, l_value, _), D::Map(r_key, r_value, _)) => {
+                l_key.can_cast_to(r_key)? && l_value.can_cast_to(r_value)?
+            },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(key, value, _), D::List(inner)) | (D::List(inner), D::Map(key, value, _)) => {
+                match &**inner {
+                    D::Struct(fields) if fields.len() == 2 => {
+                        key.can_cast_to(fields[0].dtype())?
+                            && value.can_cast_to(fields[1].dtype())?
+                    },
+                    _ => false,
+                }
+            },
+            // The registered implementation decides which casts are allowed.
+            #[cfg(feature = "dtype-extension")]
+            (D::Extension(_, _), _) | (_, D::Extension(_, _)) => return None,
 
             // @NOTE: we are being conversative
             _ => return None,
@@ -439,6 +517,10 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
             #[cfg(feature = "dtype-categorical")]
@@ -456,10 +538,26 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, _) => List(Box::new(Self::map_entries(
+                key.to_physical(),
+                value.to_physical(),
+            ))),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, storage) => storage.to_physical(),
             _ => self.clone(),
         }
     }
 
+    /// The struct type of the entries of a map with the given key and value types.
+    #[cfg(feature = "dtype-map")]
+    pub fn map_entries(key: DataType, value: DataType) -> DataType {
+        Struct(vec![
+            Field::new(MAP_KEY_NAME, key),
+            Field::new(MAP_VALUE_NAME, value),
+        ])
+    }
+
     pub fn is_supported_list_arithmetic_input(&self) -> bool {
         self.is_primitive_numeric() || self.is_bool() || self.is_null()
     }
@@ -513,7 +611,31 @@ impl DataType {
     }
 
     pub fn is_nested(&self) -> bool {
-        self.is_list() || self.is_struct() || self.is_array()
+        self.is_list() || self.is_struct() || self.is_array() || self.is_map()
+    }
+
+    /// Check if this [`DataType`] is a map.
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(..))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is an extension type.
+    pub fn is_extension(&self) -> bool {
+        #[cfg(feature = "dtype-extension")]
+        {
+            matches!(self, DataType::Extension(_, _))
+        }
+        #[cfg(not(feature = "dtype-extension"))]
+        {
+            false
+        }
     }
 
     /// Check if this [`DataType`] is a struct
@@ -543,6 +665,17 @@ impl DataType {
         matches!(self, DataType::Duration(..))
     }
 
+    pub fn is_interval(&self) -> bool {
+        #[cfg(feature = "dtype-interval")]
+        {
+            matches!(self, DataType::Interval)
+        }
+        #[cfg(not(feature = "dtype-interval"))]
+        {
+            false
+        }
+    }
+
     pub fn is_object(&self) -> bool {
         #[cfg(feature = "object")]
         {
@@ -567,6 +700,10 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, _) => key.contains_views() || value.contains_views(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, storage) => storage.contains_views(),
             _ => false,
         }
     }
@@ -583,6 +720,10 @@ impl DataType {
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, _) => key.contains_categoricals() || value.contains_categoricals(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, storage) => storage.contains_categoricals(),
             _ => false,
         }
     }
@@ -597,6 +738,10 @@ impl DataType {
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, _) => key.contains_objects() || value.contains_objects(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, storage) => storage.contains_objects(),
             _ => false,
         }
     }
@@ -611,6 +756,10 @@ impl DataType {
             D::Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_list_recursive()),
+            #[cfg(feature = "dtype-map")]
+            D::Map(..) => true,
+            #[cfg(feature = "dtype-extension")]
+            D::Extension(_, storage) => storage.contains_list_recursive(),
             _ => false,
         }
     }
@@ -624,6 +773,10 @@ impl DataType {
             D::Array(inner, _) => inner.contains_unknown(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields.iter().any(|field| field.dtype.contains_unknown()),
+            #[cfg(feature = "dtype-map")]
+            D::Map(key, value, _) => key.contains_unknown() || value.contains_unknown(),
+            #[cfg(feature = "dtype-extension")]
+            D::Extension(_, storage) => storage.contains_unknown(),
             _ => false,
         }
     }
@@ -657,6 +810,19 @@ impl DataType {
         )
     }
 
+    /// Check if this [`DataType`] is a 16-bit float. These are not a basic floating point type
+    /// as they are backed by their bits.
+    pub fn is_float16(&self) -> bool {
+        #[cfg(feature = "dtype-f16")]
+        {
+            matches!(self, DataType::Float16)
+        }
+        #[cfg(not(feature = "dtype-f16"))]
+        {
+            false
+        }
+    }
+
     /// Check if this [`DataType`] is an integer. Note, this also includes `Unknown(UnknownKind::Int)`.
     pub fn is_integer(&self) -> bool {
         matches!(
@@ -670,6 +836,7 @@ impl DataType {
                 | DataType::UInt16
                 | DataType::UInt32
                 | DataType::UInt64
+                | DataType::UInt128
                 | DataType::Unknown(UnknownKind::Int(_))
         )
     }
@@ -685,7 +852,11 @@ impl DataType {
     pub fn is_unsigned_integer(&self) -> bool {
         matches!(
             self,
-            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64,
+            DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::UInt128,
         )
     }
 
@@ -782,6 +953,10 @@ impl DataType {
             UInt16 => Scalar::from(u16::MAX),
             UInt32 => Scalar::from(u32::MAX),
             UInt64 => Scalar::from(u64::MAX),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => Scalar::from(u128::MAX),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float32(f32::INFINITY)),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -804,6 +979,10 @@ impl DataType {
             UInt16 => Scalar::from(u16::MIN),
             UInt32 => Scalar::from(u32::MIN),
             UInt64 => Scalar::from(u64::MIN),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => Scalar::from(u128::MIN),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float32(f32::NEG_INFINITY)),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -828,17 +1007,20 @@ impl DataType {
             UInt16 => Ok(ArrowDataType::UInt16),
             UInt32 => Ok(ArrowDataType::UInt32),
             UInt64 => Ok(ArrowDataType::UInt64),
+            UInt128 => Ok(ArrowDataType::UInt128),
             Int8 => Ok(ArrowDataType::Int8),
             Int16 => Ok(ArrowDataType::Int16),
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
             Int128 => Ok(ArrowDataType::Int128),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => {
-                let precision = (*precision).unwrap_or(38);
-                polars_ensure!(precision <= 38 && precision > 0, InvalidOperation: "decimal precision should be <= 38 & >= 1");
+                let precision = (*precision).unwrap_or(DEC128_MAX_PRECISION);
+                polars_ensure!(precision <= DEC128_MAX_PRECISION && precision > 0, InvalidOperation: "decimal precision should be <= 38 & >= 1");
 
                 Ok(ArrowDataType::Decimal(
                     precision,
@@ -868,6 +1050,8 @@ impl DataType {
             )),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(IntervalUnit::MonthDayNano)),
             #[cfg(feature = "dtype-array")]
             Array(dt, size) => Ok(dt
                 .try_to_arrow(compat_level)?
@@ -906,6 +1090,25 @@ impl DataType {
                     .collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, keys_sorted) => {
+                // Arrow requires the keys of a map to be non-nullable.
+                let mut key = key.to_arrow_field(MAP_KEY_NAME, compat_level);
+                key.is_nullable = false;
+                let value = value.to_arrow_field(MAP_VALUE_NAME, compat_level);
+                let entries = ArrowField::new(
+                    MAP_ENTRIES_NAME,
+                    ArrowDataType::Struct(vec![key, value]),
+                    false,
+                );
+                Ok(ArrowDataType::Map(Box::new(entries), *keys_sorted))
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext, storage) => Ok(ArrowDataType::Extension(Box::new(ArrowExtensionType {
+                name: ext.name.clone(),
+                inner: storage.try_to_arrow(compat_level)?,
+                metadata: ext.metadata.clone(),
+            }))),
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             Unknown(kind) => {
                 let dt = match kind {
@@ -930,6 +1133,10 @@ impl DataType {
             Array(field, _) => field.is_nested_null(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, _) => key.is_nested_null() && value.is_nested_null(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, storage) => storage.is_nested_null(),
             _ => false,
         }
     }
@@ -956,6 +1163,14 @@ impl DataType {
                 }
                 matches
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv, _), DataType::Map(rk, rv, _)) => {
+                lk.does_match_schema_type(rk) && lv.does_match_schema_type(rv)
+            },
+            #[cfg(feature = "dtype-extension")]
+            (DataType::Extension(l, ls), DataType::Extension(r, rs)) => {
+                l == r && ls.does_match_schema_type(rs)
+            },
             (DataType::Null, DataType::Null) => true,
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(_, s1), DataType::Decimal(_, s2)) => s1 == s2,
@@ -991,6 +1206,14 @@ impl DataType {
                 }
                 Ok(must_cast)
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv, _), DataType::Map(rk, rv, _)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
+            #[cfg(feature = "dtype-extension")]
+            (DataType::Extension(l, ls), DataType::Extension(r, rs)) if l == r => {
+                ls.matches_schema_type(rs)
+            },
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(_, s1), DataType::Decimal(_, s2)) => Ok(s1 != s2),
@@ -1066,7 +1289,7 @@ impl DataType {
     }
 
     pub fn is_numeric(&self) -> bool {
-        self.is_integer() || self.is_float() || self.is_decimal()
+        self.is_integer() || self.is_float() || self.is_decimal() || self.is_float16()
     }
 }
 
@@ -1079,11 +1302,14 @@ impl Display for DataType {
             DataType::UInt16 => "u16",
             DataType::UInt32 => "u32",
             DataType::UInt64 => "u64",
+            DataType::UInt128 => "u128",
             DataType::Int8 => "i8",
             DataType::Int16 => "i16",
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
             DataType::Int128 => "i128",
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -1108,6 +1334,8 @@ impl Display for DataType {
             },
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
             DataType::Time => "time",
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let tp = self.array_leaf_dtype().unwrap();
@@ -1129,6 +1357,10 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value, _) => return write!(f, "map[{key}, {value}]"),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext, _) => return write!(f, "ext[{}]", ext.name),
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Ufunc => "unknown ufunc",
                 UnknownKind::Any => "unknown",
@@ -1175,6 +1407,16 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
             let merged = merge_dtypes(inner_l, inner_r)?;
             Array(Box::new(merged), *width_l)
         },
+        #[cfg(feature = "dtype-map")]
+        (Map(key_l, value_l, sorted_l), Map(key_r, value_r, sorted_r)) => Map(
+            Box::new(merge_dtypes(key_l, key_r)?),
+            Box::new(merge_dtypes(value_l, value_r)?),
+            *sorted_l && *sorted_r,
+        ),
+        #[cfg(feature = "dtype-extension")]
+        (Extension(ext_l, storage_l), Extension(ext_r, storage_r)) if ext_l == ext_r => {
+            Extension(ext_l.clone(), Box::new(merge_dtypes(storage_l, storage_r)?))
+        },
         (left, right) if left == right => left.clone(),
         _ => polars_bail!(ComputeError: "unable to merge datatypes"),
     })
@@ -1208,6 +1450,14 @@ fn collect_nested_types(
                 collect_nested_types(field.dtype(), result, include_compound_types);
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value, _) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            collect_nested_types(key, result, include_compound_types);
+            collect_nested_types(value, result, include_compound_types);
+        },
         _ => {
             result.insert(dtype.clone());
         },
@@ -0,0 +1,112 @@
+//! User-defined logical types on top of a storage data type.
+//!
+//! An extension type is identified by its name and carries opaque metadata. Polars keeps both
+//! around when the data is moved through the engine and written as an Arrow extension type.
+//! Crates that define an extension type can register an [`ExtensionTypeImpl`] under its name to
+//! customize how its values are formatted and cast.
+use std::fmt;
+use std::sync::{LazyLock, RwLock};
+
+use super::*;
+
+/// The name and metadata of an extension type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    any(feature = "serde", feature = "serde-lazy"),
+    derive(Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ExtensionType {
+    pub name: PlSmallStr,
+    pub metadata: Option<PlSmallStr>,
+}
+
+impl ExtensionType {
+    pub fn new(name: PlSmallStr, metadata: Option<PlSmallStr>) -> Self {
+        Self { name, metadata }
+    }
+
+    /// Get the implementation registered for this extension type, if any.
+    pub fn get_impl(&self) -> Option<Arc<dyn ExtensionTypeImpl>> {
+        get_extension_type(&self.name)
+    }
+}
+
+/// Behavior of an extension type that is registered with [`register_extension_type`].
+///
+/// All methods have a default that treats the values as values of the storage type.
+pub trait ExtensionTypeImpl: Send + Sync {
+    /// Format a single non-null value. `value` is the value of the storage type.
+    fn fmt_value(
+        &self,
+        f: &mut Formatter<'_>,
+        value: &AnyValue<'_>,
+        metadata: Option<&str>,
+    ) -> fmt::Result {
+        let _ = metadata;
+        write!(f, "{value}")
+    }
+
+    /// Cast a series of this extension type to `dtype`.
+    ///
+    /// Returns `None` to fall back to casting the storage.
+    fn cast_to(
+        &self,
+        ca: &ExtensionChunked,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        let _ = (ca, dtype, options);
+        None
+    }
+
+    /// Cast a series of another type to this extension type. `dtype` is the extension
+    /// [`DataType`] that is cast to.
+    ///
+    /// Returns `None` to fall back to casting to the storage type.
+    fn cast_from(
+        &self,
+        s: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        let _ = (s, dtype, options);
+        None
+    }
+}
+
+type ExtensionRegistry = PlHashMap<PlSmallStr, Arc<dyn ExtensionTypeImpl>>;
+
+static EXTENSION_REGISTRY: LazyLock<RwLock<ExtensionRegistry>> = LazyLock::new(Default::default);
+
+/// Register the implementation of the extension type with the given name.
+///
+/// This replaces any implementation that was registered before under the same name.
+pub fn register_extension_type(name: PlSmallStr, ext: Arc<dyn ExtensionTypeImpl>) {
+    EXTENSION_REGISTRY.write().unwrap().insert(name, ext);
+}
+
+/// Remove the implementation of the extension type with the given name.
+pub fn unregister_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeImpl>> {
+    EXTENSION_REGISTRY.write().unwrap().remove(name)
+}
+
+/// Get the implementation of the extension type with the given name.
+pub fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeImpl>> {
+    EXTENSION_REGISTRY.read().unwrap().get(name).cloned()
+}
+
+/// Display a storage value of an extension type with the registered implementation.
+pub(crate) struct ExtensionValueDisplay<'a> {
+    pub(crate) ext: &'a ExtensionType,
+    pub(crate) value: AnyValue<'a>,
+}
+
+impl fmt::Display for ExtensionValueDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (&self.value, self.ext.get_impl()) {
+            (AnyValue::Null, _) | (_, None) => write!(f, "{}", self.value),
+            (value, Some(ext)) => ext.fmt_value(f, value, self.ext.metadata.as_deref()),
+        }
+    }
+}
@@ -162,6 +162,8 @@ impl DataType {
             ArrowDataType::UInt16 => DataType::UInt16,
             ArrowDataType::UInt32 => DataType::UInt32,
             ArrowDataType::UInt64 => DataType::UInt64,
+            #[cfg(feature = "dtype-u128")]
+            ArrowDataType::UInt128 => DataType::UInt128,
             ArrowDataType::Int8 => DataType::Int8,
             ArrowDataType::Int16 => DataType::Int16,
             ArrowDataType::Int32 => DataType::Int32,
@@ -169,6 +171,9 @@ impl DataType {
             #[cfg(feature = "dtype-i128")]
             ArrowDataType::Int128 => DataType::Int128,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
@@ -186,6 +191,10 @@ impl DataType {
             ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
             ArrowDataType::Date64 => DataType::Datetime(TimeUnit::Milliseconds, None),
             ArrowDataType::Time64(_) | ArrowDataType::Time32(_) => DataType::Time,
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano) => {
+                DataType::Interval
+            },
 
             #[cfg(feature = "dtype-categorical")]
             ArrowDataType::Dictionary(_, value_type, _) => {
@@ -256,8 +265,16 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             },
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext) => DataType::Extension(
+                ExtensionType::new(ext.name.clone(), ext.metadata.clone()),
+                Self::from_arrow(&ext.inner, md).boxed(),
+            ),
             #[cfg(feature = "dtype-decimal")]
-            ArrowDataType::Decimal(precision, scale) => {
+            ArrowDataType::Decimal(precision, scale)
+            | ArrowDataType::Decimal32(precision, scale)
+            | ArrowDataType::Decimal64(precision, scale)
+            | ArrowDataType::Decimal256(precision, scale) => {
                 DataType::Decimal(Some(*precision), Some(*scale))
             },
             ArrowDataType::Utf8View | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => {
@@ -274,6 +291,17 @@ impl DataType {
             },
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, keys_sorted) => match inner.dtype() {
+                ArrowDataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    Self::from_arrow_field(&fields[0]).boxed(),
+                    Self::from_arrow_field(&fields[1]).boxed(),
+                    *keys_sorted,
+                ),
+                // Polars maps need key/value entries, read anything else as a list.
+                _ => DataType::List(Self::from_arrow_field(inner).boxed()),
+            },
+            #[cfg(not(feature = "dtype-map"))]
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
@@ -11,6 +11,8 @@ mod _serde;
 mod aliases;
 mod any_value;
 mod dtype;
+#[cfg(feature = "dtype-extension")]
+mod extension;
 mod field;
 mod into_scalar;
 #[cfg(feature = "object")]
@@ -35,8 +37,14 @@ use arrow::datatypes::IntegerType;
 pub use arrow::datatypes::reshape::*;
 pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::NativeType;
+#[cfg(feature = "dtype-f16")]
+pub use arrow::types::f16;
+#[cfg(feature = "dtype-interval")]
+pub use arrow::types::months_days_ns;
 use bytemuck::Zeroable;
 pub use dtype::*;
+#[cfg(feature = "dtype-extension")]
+pub use extension::*;
 pub use field::*;
 pub use into_scalar::*;
 use num_traits::{AsPrimitive, Bounded, FromPrimitive, Num, NumCast, One, Zero};
@@ -218,6 +226,8 @@ impl_polars_num_datatype!(PolarsIntegerType, Int32Type, Int32, i32, i32);
 impl_polars_num_datatype!(PolarsIntegerType, Int64Type, Int64, i64, i64);
 #[cfg(feature = "dtype-i128")]
 impl_polars_num_datatype!(PolarsIntegerType, Int128Type, Int128, i128, i128);
+#[cfg(feature = "dtype-u128")]
+impl_polars_num_datatype!(PolarsIntegerType, UInt128Type, UInt128, u128, u128);
 impl_polars_num_datatype!(PolarsFloatType, Float32Type, Float32, f32, f32);
 impl_polars_num_datatype!(PolarsFloatType, Float64Type, Float64, f64, f64);
 
@@ -233,6 +243,12 @@ impl_polars_datatype!(DurationType, unimplemented!(), PrimitiveArray<i64>, 'a, i
 impl_polars_datatype!(CategoricalType, unimplemented!(), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT);
 impl_polars_datatype!(DateType, DataType::Date, PrimitiveArray<i32>, 'a, i32, i32, i32, FalseT);
 impl_polars_datatype!(TimeType, DataType::Time, PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, DataType::Float16, PrimitiveArray<u16>, 'a, u16, u16, u16, FalseT);
+#[cfg(feature = "dtype-interval")]
+impl_polars_datatype!(IntervalType, DataType::Interval, PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
+#[cfg(feature = "dtype-map")]
+impl_polars_datatype!(MapType, unimplemented!(), ListArray<i64>, 'a, Box<dyn Array>, Option<Box<dyn Array>>, Box<dyn Array>, FalseT);
 
 impl_polars_categorical_datatype!(Categorical8Type, UInt8Type, u8, U8);
 impl_polars_categorical_datatype!(Categorical16Type, UInt16Type, u16, U16);
@@ -373,6 +389,8 @@ impl_phys_dtype!(BooleanType);
 
 #[cfg(feature = "dtype-i128")]
 impl_phys_dtype!(Int128Type);
+#[cfg(feature = "dtype-u128")]
+impl_phys_dtype!(UInt128Type);
 
 #[cfg(feature = "dtype-array")]
 impl_phys_dtype!(FixedSizeListType);
@@ -395,6 +413,8 @@ pub type UInt8Chunked = ChunkedArray<UInt8Type>;
 pub type UInt16Chunked = ChunkedArray<UInt16Type>;
 pub type UInt32Chunked = ChunkedArray<UInt32Type>;
 pub type UInt64Chunked = ChunkedArray<UInt64Type>;
+#[cfg(feature = "dtype-u128")]
+pub type UInt128Chunked = ChunkedArray<UInt128Type>;
 pub type Int8Chunked = ChunkedArray<Int8Type>;
 pub type Int16Chunked = ChunkedArray<Int16Type>;
 pub type Int32Chunked = ChunkedArray<Int32Type>;
@@ -479,6 +499,11 @@ impl NumericNative for u64 {
     type PolarsType = UInt64Type;
     type TrueDivPolarsType = Float64Type;
 }
+#[cfg(feature = "dtype-u128")]
+impl NumericNative for u128 {
+    type PolarsType = UInt128Type;
+    type TrueDivPolarsType = Float64Type;
+}
 impl NumericNative for f32 {
     type PolarsType = Float32Type;
     type TrueDivPolarsType = Float32Type;
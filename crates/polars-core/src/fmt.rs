@@ -243,6 +243,43 @@ fn format_object_array(
     }
 }
 
+#[cfg(feature = "dtype-extension")]
+fn format_extension_array(
+    f: &mut Formatter<'_>,
+    ca: &ExtensionChunked,
+    name: &str,
+    array_type: &str,
+) -> fmt::Result {
+    write!(
+        f,
+        "shape: ({},)\n{}: '{}' [{}]\n[\n",
+        fmt_int_string_custom(&ca.len().to_string(), 3, "_"),
+        array_type,
+        name,
+        ca.dtype()
+    )?;
+
+    let ellipsis = get_ellipsis();
+    let limit = get_row_limit();
+    if ca.len() > limit {
+        let half = limit / 2;
+        let rest = limit % 2;
+
+        for i in 0..(half + rest) {
+            writeln!(f, "\t{}", ca.str_value(i).unwrap())?;
+        }
+        writeln!(f, "\t{ellipsis}")?;
+        for i in (ca.len() - half)..ca.len() {
+            writeln!(f, "\t{}", ca.str_value(i).unwrap())?;
+        }
+    } else {
+        for i in 0..ca.len() {
+            writeln!(f, "\t{}", ca.str_value(i).unwrap())?;
+        }
+    }
+    write!(f, "]")
+}
+
 impl<T> Debug for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -365,6 +402,16 @@ impl Debug for Series {
                     format_array!(f, self.i128().unwrap(), "i128", self.name(), "Series")
                 )
             },
+            DataType::UInt128 => {
+                feature_gated!(
+                    "dtype-u128",
+                    format_array!(f, self.u128().unwrap(), "u128", self.name(), "Series")
+                )
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                format_array!(f, self.float16().unwrap(), "f16", self.name(), "Series")
+            },
             DataType::Float32 => {
                 format_array!(f, self.f32().unwrap(), "f32", self.name(), "Series")
             },
@@ -385,6 +432,14 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.duration().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => format_array!(
+                f,
+                self.interval().unwrap(),
+                "interval",
+                self.name(),
+                "Series"
+            ),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -399,6 +454,15 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(..) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_, _) => {
+                format_extension_array(f, self.extension().unwrap(), self.name(), "Series")
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -1052,6 +1116,30 @@ pub fn fmt_duration_string<W: Write>(f: &mut W, v: i64, unit: TimeUnit) -> fmt::
     Ok(())
 }
 
+#[cfg(feature = "dtype-interval")]
+pub fn fmt_interval_string<W: Write>(f: &mut W, v: months_days_ns) -> fmt::Result {
+    // the calendar parts come first, eg: "1mo 3d 12h"
+    let mut buffer = itoa::Buffer::new();
+    let mut wrote_part = false;
+    for (value, suffix) in [(v.months(), "mo"), (v.days(), "d")] {
+        if value != 0 {
+            if wrote_part {
+                f.write_char(' ')?;
+            }
+            f.write_str(buffer.format(value))?;
+            f.write_str(suffix)?;
+            wrote_part = true;
+        }
+    }
+    if v.ns() != 0 || !wrote_part {
+        if wrote_part {
+            f.write_char(' ')?;
+        }
+        fmt_duration_string(f, v.ns(), TimeUnit::Nanoseconds)?;
+    }
+    Ok(())
+}
+
 #[cfg(feature = "dtype-duration")]
 pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     if v == 0 {
@@ -1159,6 +1247,7 @@ impl Display for AnyValue<'_> {
             AnyValue::Int32(v) => fmt_integer(f, width, *v),
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
             AnyValue::Int128(v) => feature_gated!("dtype-i128", fmt_integer(f, width, *v)),
+            AnyValue::UInt128(v) => feature_gated!("dtype-u128", fmt_integer(f, width, *v)),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
@@ -1176,6 +1265,8 @@ impl Display for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             AnyValue::Duration(v, tu) => fmt_duration_string(f, *v, *tu),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval_string(f, *v),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
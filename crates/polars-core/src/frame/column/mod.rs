@@ -396,6 +396,14 @@ impl Column {
     pub fn try_duration(&self) -> Option<&DurationChunked> {
         self.as_materialized_series().try_duration()
     }
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_float16(&self) -> Option<&Float16Chunked> {
+        self.as_materialized_series().try_float16()
+    }
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        self.as_materialized_series().try_interval()
+    }
 
     // # To Chunked Arrays
     pub fn bool(&self) -> PolarsResult<&BooleanChunked> {
@@ -417,6 +425,10 @@ impl Column {
     pub fn i128(&self) -> PolarsResult<&Int128Chunked> {
         self.as_materialized_series().i128()
     }
+    #[cfg(feature = "dtype-u128")]
+    pub fn u128(&self) -> PolarsResult<&UInt128Chunked> {
+        self.as_materialized_series().u128()
+    }
     pub fn u8(&self) -> PolarsResult<&UInt8Chunked> {
         self.as_materialized_series().u8()
     }
@@ -458,6 +470,14 @@ impl Column {
     pub fn struct_(&self) -> PolarsResult<&StructChunked> {
         self.as_materialized_series().struct_()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map()
+    }
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.as_materialized_series().extension()
+    }
     #[cfg(feature = "dtype-decimal")]
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
         self.as_materialized_series().decimal()
@@ -490,6 +510,14 @@ impl Column {
     pub fn duration(&self) -> PolarsResult<&DurationChunked> {
         self.as_materialized_series().duration()
     }
+    #[cfg(feature = "dtype-f16")]
+    pub fn float16(&self) -> PolarsResult<&Float16Chunked> {
+        self.as_materialized_series().float16()
+    }
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.as_materialized_series().interval()
+    }
 
     // # Casting
     pub fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Self> {
@@ -1560,6 +1588,10 @@ impl Column {
     }
 
     pub(crate) fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        #[cfg(feature = "dtype-extension")]
+        if self.dtype().is_extension() {
+            return self.as_materialized_series().str_value(index);
+        }
         Ok(self.get(index)?.str_value())
     }
 
@@ -1596,7 +1628,7 @@ impl Column {
             },
         }
     }
-    pub fn mean_reduce(&self) -> Scalar {
+    pub fn mean_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.mean_reduce(),
             Column::Partitioned(s) => s.as_materialized_series().mean_reduce(),
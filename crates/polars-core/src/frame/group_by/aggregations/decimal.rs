@@ -0,0 +1,231 @@
+use std::sync::Mutex;
+
+use super::*;
+use crate::chunked_array::arithmetic::{
+    _decimal_checked_mean, _decimal_checked_moments, _decimal_checked_quantile,
+    _decimal_checked_std, _decimal_checked_sum, _decimal_checked_var,
+    _get_decimal_precision_quantile, _get_decimal_precision_sum, _get_decimal_scale_mean,
+    _get_decimal_scale_quantile, _get_decimal_scale_std, _get_decimal_scale_var,
+};
+
+/// Aggregate the non-null values of every group with `f`.
+///
+/// A group for which this fails, e.g. because its sum overflows, becomes null. The first such
+/// error is returned alongside the output.
+unsafe fn agg_values<F>(
+    ca: &Int128Chunked,
+    groups: &GroupsType,
+    f: F,
+) -> (Int128Chunked, Option<PolarsError>)
+where
+    F: Fn(&mut dyn Iterator<Item = i128>) -> PolarsResult<Option<i128>> + Send + Sync,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let first_err = Mutex::new(None);
+    let agg = |idx: &mut dyn Iterator<Item = usize>| {
+        let mut values = idx.filter_map(|i| unsafe { arr.get_unchecked(i) });
+        f(&mut values).unwrap_or_else(|e| {
+            first_err.lock().unwrap().get_or_insert(e);
+            None
+        })
+    };
+
+    let out = match groups {
+        GroupsType::Idx(groups) => _agg_helper_idx::<Int128Type, _>(groups, |(_, idx)| {
+            debug_assert!(idx.len() <= arr.len());
+            agg(&mut idx.iter().map(|i| *i as usize))
+        }),
+        GroupsType::Slice { groups, .. } => {
+            _agg_helper_slice::<Int128Type, _>(groups, |[first, len]| {
+                debug_assert!(first + len <= arr.len() as IdxSize);
+                agg(&mut (first as usize..(first + len) as usize))
+            })
+        },
+    };
+    (out.i128().unwrap().clone(), first_err.into_inner().unwrap())
+}
+
+/// Sum and count the non-null values of every group and compute the output with `finish`, see
+/// [`agg_values`].
+unsafe fn agg_sum_count<F>(
+    ca: &Int128Chunked,
+    groups: &GroupsType,
+    finish: F,
+) -> (Int128Chunked, Option<PolarsError>)
+where
+    F: Fn(i128, usize) -> PolarsResult<Option<i128>> + Send + Sync,
+{
+    unsafe {
+        agg_values(ca, groups, |values| {
+            let mut count = 0;
+            let sum = _decimal_checked_sum(values.inspect(|_| count += 1))?;
+            finish(sum, count)
+        })
+    }
+}
+
+impl DecimalChunked {
+    unsafe fn agg_sum_impl(&self, groups: &GroupsType) -> (Series, Option<PolarsError>) {
+        let precision = _get_decimal_precision_sum(self.precision());
+        let (sum, err) = agg_sum_count(self.physical(), groups, |sum, _| Ok(Some(sum)));
+        let sum = sum.into_decimal_unchecked(precision, self.scale());
+        (sum.into_series(), err)
+    }
+
+    unsafe fn agg_mean_impl(&self, groups: &GroupsType) -> (Series, Option<PolarsError>) {
+        let precision = _get_decimal_precision_sum(self.precision());
+        let scale = _get_decimal_scale_mean(self.scale());
+        let scale_increase = scale - self.scale();
+        let (mean, err) = agg_sum_count(self.physical(), groups, |sum, count| {
+            if count == 0 {
+                return Ok(None);
+            }
+            _decimal_checked_mean(sum, count, scale_increase).map(Some)
+        });
+        let mean = mean.into_decimal_unchecked(precision, scale);
+        (mean.into_series(), err)
+    }
+
+    unsafe fn agg_var_std_impl(
+        &self,
+        groups: &GroupsType,
+        ddof: u8,
+        is_std: bool,
+    ) -> (Series, Option<PolarsError>) {
+        let precision = _get_decimal_precision_sum(self.precision());
+        let scale = self.scale();
+        let (out, err) = unsafe {
+            agg_values(self.physical(), groups, |values| {
+                let moments = _decimal_checked_moments(values)?;
+                if is_std {
+                    _decimal_checked_std(moments, ddof, scale)
+                } else {
+                    _decimal_checked_var(moments, ddof, scale)
+                }
+            })
+        };
+        let out_scale = if is_std {
+            _get_decimal_scale_std(scale)
+        } else {
+            _get_decimal_scale_var(scale)
+        };
+        let out = out.into_decimal_unchecked(precision, out_scale);
+        (out.into_series(), err)
+    }
+
+    unsafe fn agg_quantile_impl(
+        &self,
+        groups: &GroupsType,
+        quantile: f64,
+        method: QuantileMethod,
+    ) -> (Series, Option<PolarsError>) {
+        let precision = _get_decimal_precision_quantile(self.precision(), self.scale());
+        let scale = _get_decimal_scale_quantile(self.scale());
+        let scale_increase = scale - self.scale();
+        let (out, err) = unsafe {
+            agg_values(self.physical(), groups, |values| {
+                _decimal_checked_quantile(values.collect(), quantile, method, scale_increase)
+            })
+        };
+        let out = out.into_decimal_unchecked(precision, scale);
+        (out.into_series(), err)
+    }
+
+    /// Groups of which the sum overflows are null, see [`DecimalChunked::try_agg_sum`].
+    pub(crate) unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        unsafe { self.agg_sum_impl(groups) }.0
+    }
+
+    /// Groups of which the sum overflows are null, see [`DecimalChunked::try_agg_mean`].
+    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsType) -> Series {
+        unsafe { self.agg_mean_impl(groups) }.0
+    }
+
+    /// Groups of which the sums overflow are null, see [`DecimalChunked::try_agg_std`].
+    pub(crate) unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        unsafe { self.agg_var_std_impl(groups, ddof, true) }.0
+    }
+
+    /// Groups of which the sums overflow are null, see [`DecimalChunked::try_agg_var`].
+    pub(crate) unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        unsafe { self.agg_var_std_impl(groups, ddof, false) }.0
+    }
+
+    /// Groups of which the quantile overflows are null, see [`DecimalChunked::try_agg_quantile`].
+    pub(crate) unsafe fn agg_quantile(
+        &self,
+        groups: &GroupsType,
+        quantile: f64,
+        method: QuantileMethod,
+    ) -> Series {
+        unsafe { self.agg_quantile_impl(groups, quantile, method) }.0
+    }
+
+    /// Sum every group, raising an error if any of the sums overflows.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    pub unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Series> {
+        match unsafe { self.agg_sum_impl(groups) } {
+            (_, Some(err)) => Err(err),
+            (sum, None) => Ok(sum),
+        }
+    }
+
+    /// Compute the exact mean of every group, raising an error if any of the sums overflows.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    pub unsafe fn try_agg_mean(&self, groups: &GroupsType) -> PolarsResult<Series> {
+        match unsafe { self.agg_mean_impl(groups) } {
+            (_, Some(err)) => Err(err),
+            (mean, None) => Ok(mean),
+        }
+    }
+
+    /// Compute the standard deviation of every group from the exact variance, raising an error
+    /// if any of the sums overflows.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    pub unsafe fn try_agg_std(&self, groups: &GroupsType, ddof: u8) -> PolarsResult<Series> {
+        match unsafe { self.agg_var_std_impl(groups, ddof, true) } {
+            (_, Some(err)) => Err(err),
+            (std, None) => Ok(std),
+        }
+    }
+
+    /// Compute the exact variance of every group, raising an error if any of the sums overflows.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    pub unsafe fn try_agg_var(&self, groups: &GroupsType, ddof: u8) -> PolarsResult<Series> {
+        match unsafe { self.agg_var_std_impl(groups, ddof, false) } {
+            (_, Some(err)) => Err(err),
+            (var, None) => Ok(var),
+        }
+    }
+
+    /// Compute the exact quantile of every group, raising an error if any of the quantiles
+    /// overflows the maximum precision.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    pub unsafe fn try_agg_quantile(
+        &self,
+        groups: &GroupsType,
+        quantile: f64,
+        method: QuantileMethod,
+    ) -> PolarsResult<Series> {
+        match unsafe { self.agg_quantile_impl(groups, quantile, method) } {
+            (_, Some(err)) => Err(err),
+            (quantile, None) => Ok(quantile),
+        }
+    }
+}
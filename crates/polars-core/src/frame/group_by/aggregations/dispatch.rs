@@ -135,9 +135,16 @@ impl Series {
             Boolean => s.cast(&Float64).unwrap().agg_mean(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_mean(groups)
+                .cast(&Float16)
+                .unwrap(),
             dt if dt.is_primitive_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
             #[cfg(feature = "dtype-decimal")]
-            Decimal(_, _) => self.cast(&Float64).unwrap().agg_mean(groups),
+            Decimal(_, _) => s.decimal().unwrap().agg_mean(groups),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
                 .to_physical_repr()
@@ -189,11 +196,21 @@ impl Series {
             Boolean => s.cast(&Float64).unwrap().agg_median(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_median(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_median(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_median(groups)
+                .cast(&Float16)
+                .unwrap(),
             dt if dt.is_primitive_numeric() => {
                 apply_method_physical_integer!(s, agg_median, groups)
             },
             #[cfg(feature = "dtype-decimal")]
-            Decimal(_, _) => self.cast(&Float64).unwrap().agg_median(groups),
+            Decimal(_, _) => s
+                .decimal()
+                .unwrap()
+                .agg_quantile(groups, 0.5, QuantileMethod::Linear),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
                 .to_physical_repr()
@@ -249,6 +266,13 @@ impl Series {
         match s.dtype() {
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, method),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, method),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_quantile(groups, quantile, method)
+                .cast(&Float16)
+                .unwrap(),
             dt if dt.is_primitive_numeric() || dt.is_temporal() => {
                 let ca = s.to_physical_repr();
                 let physical_type = ca.dtype();
@@ -261,6 +285,8 @@ impl Series {
                     s
                 }
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => s.decimal().unwrap().agg_quantile(groups, quantile, method),
             _ => Series::full_null(PlSmallStr::EMPTY, groups.len(), s.dtype()),
         }
     }
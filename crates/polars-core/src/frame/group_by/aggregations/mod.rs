@@ -1,5 +1,7 @@
 mod agg_list;
 mod boolean;
+#[cfg(feature = "dtype-decimal")]
+mod decimal;
 mod dispatch;
 mod string;
 
@@ -46,6 +46,8 @@ impl_named_from_owned!(Vec<u8>, UInt8Type);
 impl_named_from_owned!(Vec<u16>, UInt16Type);
 impl_named_from_owned!(Vec<u32>, UInt32Type);
 impl_named_from_owned!(Vec<u64>, UInt64Type);
+#[cfg(feature = "dtype-u128")]
+impl_named_from_owned!(Vec<u128>, UInt128Type);
 impl_named_from_owned!(Vec<f32>, Float32Type);
 impl_named_from_owned!(Vec<f64>, Float64Type);
 
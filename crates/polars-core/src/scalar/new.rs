@@ -35,6 +35,11 @@ impl Scalar {
         Scalar::new(DataType::Time, AnyValue::Time(value))
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub fn new_interval(value: crate::prelude::months_days_ns) -> Self {
+        Scalar::new(DataType::Interval, AnyValue::Interval(value))
+    }
+
     pub fn new_list(values: Series) -> Self {
         Scalar::new(
             DataType::List(Box::new(values.dtype().clone())),
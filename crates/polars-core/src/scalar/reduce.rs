@@ -10,6 +10,11 @@ pub fn mean_reduce(value: Option<f64>, dtype: DataType) -> Scalar {
             let val = value.map(|m| m as f32);
             Scalar::new(dtype, val.into())
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let val = value.map(|m| crate::prelude::f16::from_f32(m as f32).to_f32());
+            Scalar::new(dtype, val.into())
+        },
         dt if dt.is_primitive_numeric() || dt.is_decimal() || dt.is_bool() => {
             Scalar::new(DataType::Float64, value.into())
         },
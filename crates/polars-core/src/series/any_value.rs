@@ -109,6 +109,14 @@ impl Series {
             DataType::UInt16 => any_values_to_integer::<UInt16Type>(values, strict)?.into_series(),
             DataType::UInt32 => any_values_to_integer::<UInt32Type>(values, strict)?.into_series(),
             DataType::UInt64 => any_values_to_integer::<UInt64Type>(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                any_values_to_integer::<UInt128Type>(values, strict)?.into_series()
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f32(values, strict)?
+                .to_float16()
+                .into_series(),
             DataType::Float32 => any_values_to_f32(values, strict)?.into_series(),
             DataType::Float64 => any_values_to_f64(values, strict)?.into_series(),
             DataType::Boolean => any_values_to_bool(values, strict)?.into_series(),
@@ -125,6 +133,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(tu) => any_values_to_duration(values, *tu, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(_, _) | DataType::Enum(_, _)) => {
                 any_values_to_categorical(values, dt, strict)?
@@ -134,6 +144,19 @@ impl Series {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value, _) => {
+                let entries = DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+                any_values_to_list(values, &entries, strict)?
+                    .to_map()?
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext, storage) => {
+                let storage =
+                    Self::from_any_values_and_dtype(name.clone(), values, storage, strict)?;
+                ExtensionChunked::from_storage(ext.clone(), storage).into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
@@ -446,6 +469,27 @@ fn any_values_to_duration(
     Ok(builder.finish().into_duration(time_unit))
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_i128(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Interval, av));
+                }
+                match av.cast(&DataType::Interval) {
+                    AnyValue::Interval(v) => builder.append_value(interval_to_i128(v)),
+                    _ => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn any_values_to_categorical(
     values: &[AnyValue],
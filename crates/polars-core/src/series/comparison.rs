@@ -1,11 +1,29 @@
 //! Comparison operations on Series.
 
+#[cfg(feature = "dtype-f16")]
+use std::borrow::Cow;
+
 use polars_error::feature_gated;
 
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
 use crate::series::nulls::replace_non_null;
 
+/// Half precision floats are compared on their values widened to `Float32`, not on their bits.
+#[cfg(feature = "dtype-f16")]
+fn widen_float16<'a>(
+    lhs: Cow<'a, Series>,
+    rhs: Cow<'a, Series>,
+) -> PolarsResult<(Cow<'a, Series>, Cow<'a, Series>)> {
+    if !lhs.dtype().is_float16() {
+        return Ok((lhs, rhs));
+    }
+    Ok((
+        Cow::Owned(lhs.cast(&DataType::Float32)?),
+        Cow::Owned(rhs.cast(&DataType::Float32)?),
+    ))
+}
+
 macro_rules! impl_eq_compare {
     ($self:expr, $rhs:expr, $method:ident) => {{
         use DataType::*;
@@ -55,6 +73,8 @@ macro_rules! impl_eq_compare {
                     SchemaMismatch: "could not evaluate comparison between series '{}' of dtype: {} and series '{}' of dtype: {}",
                     lhs.name(), lhs.dtype(), rhs.name(), rhs.dtype()
             ))?;
+        #[cfg(feature = "dtype-f16")]
+        let (lhs, rhs) = widen_float16(lhs, rhs)?;
         let lhs = lhs.to_physical_repr();
         let rhs = rhs.to_physical_repr();
         let mut out = match lhs.dtype() {
@@ -66,6 +86,7 @@ macro_rules! impl_eq_compare {
             UInt16 => feature_gated!("dtype-u16", lhs.u16().unwrap().$method(rhs.u16().unwrap())),
             UInt32 => lhs.u32().unwrap().$method(rhs.u32().unwrap()),
             UInt64 => lhs.u64().unwrap().$method(rhs.u64().unwrap()),
+            UInt128 => feature_gated!("dtype-u128", lhs.u128().unwrap().$method(rhs.u128().unwrap())),
             Int8 => feature_gated!("dtype-i8", lhs.i8().unwrap().$method(rhs.i8().unwrap())),
             Int16 => feature_gated!("dtype-i16", lhs.i16().unwrap().$method(rhs.i16().unwrap())),
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
@@ -152,6 +173,8 @@ macro_rules! impl_ineq_compare {
                 rhs.name(), rhs.dtype()
             )
         )?;
+        #[cfg(feature = "dtype-f16")]
+        let (lhs, rhs) = widen_float16(lhs, rhs)?;
         let lhs = lhs.to_physical_repr();
         let rhs = rhs.to_physical_repr();
         let mut out = match lhs.dtype() {
@@ -163,6 +186,7 @@ macro_rules! impl_ineq_compare {
             UInt16 => feature_gated!("dtype-u16", lhs.u16().unwrap().$method(rhs.u16().unwrap())),
             UInt32 => lhs.u32().unwrap().$method(rhs.u32().unwrap()),
             UInt64 => lhs.u64().unwrap().$method(rhs.u64().unwrap()),
+            UInt128 => feature_gated!("dtype-u128", lhs.u128().unwrap().$method(rhs.u128().unwrap())),
             Int8 => feature_gated!("dtype-i8", lhs.i8().unwrap().$method(rhs.i8().unwrap())),
             Int16 => feature_gated!("dtype-i16", lhs.i16().unwrap().$method(rhs.i16().unwrap())),
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
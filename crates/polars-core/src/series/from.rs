@@ -72,6 +72,8 @@ impl Series {
             UInt64 => UInt64Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-i128")]
             Int128 => Int128Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => UInt128Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-date")]
             Date => Int32Chunked::from_chunks(name, chunks)
                 .into_date()
@@ -84,6 +86,14 @@ impl Series {
             Duration(tu) => Int64Chunked::from_chunks(name, chunks)
                 .into_duration(*tu)
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16Chunked::from_chunks(name, chunks)
+                .into_float16()
+                .into_series(),
             #[cfg(feature = "dtype-datetime")]
             Datetime(tu, tz) => Int64Chunked::from_chunks(name, chunks)
                 .into_datetime(*tu, tz.clone())
@@ -102,6 +112,21 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value, _) => {
+                let entries = DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+                let entries = ListChunked::from_chunks_and_dtype_unchecked(
+                    name,
+                    chunks,
+                    List(Box::new(entries)),
+                );
+                MapChunked::new_logical(entries, dtype.clone()).into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext, storage) => {
+                let storage = Series::from_chunks_and_dtype_unchecked(name, chunks, storage);
+                ExtensionChunked::from_storage(ext.clone(), storage).into_series()
+            },
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -228,6 +253,24 @@ impl Series {
                 "dtype-i128",
                 Ok(Int128Chunked::from_chunks(name, chunks).into_series())
             ),
+            ArrowDataType::UInt128 => feature_gated!(
+                "dtype-u128",
+                Ok(UInt128Chunked::from_chunks(name, chunks).into_series())
+            ),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref::<PrimitiveArray<f16>>().unwrap();
+                        arr.clone().transmute::<u16>().to_boxed()
+                    })
+                    .collect();
+                Ok(UInt16Chunked::from_chunks(name, chunks)
+                    .into_float16()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -356,13 +399,40 @@ impl Series {
                     Ok(s)
                 })
             },
-            ArrowDataType::Decimal(precision, scale)
-            | ArrowDataType::Decimal256(precision, scale) => {
+            ArrowDataType::Decimal256(precision, scale) => {
+                feature_gated!("dtype-decimal", {
+                    polars_ensure!(*scale <= *precision, InvalidOperation: "invalid decimal precision and scale (prec={precision}, scale={scale})");
+                    polars_ensure!(*precision <= 38, InvalidOperation: "polars does not support decimals above 38 precision");
+
+                    let mut chunks = chunks;
+                    for chunk in chunks.iter_mut() {
+                        let old_chunk = chunk
+                            .as_any_mut()
+                            .downcast_mut::<PrimitiveArray<arrow::types::i256>>()
+                            .unwrap();
+
+                        // The precision is at most 38, so every value fits in an i128.
+                        let (_, values, validity) = std::mem::take(old_chunk).into_inner();
+                        *chunk = PrimitiveArray::new(
+                            ArrowDataType::Int128,
+                            values.iter().map(|v| v.0.as_i128()).collect(),
+                            validity,
+                        )
+                        .to_boxed();
+                    }
+
+                    // @NOTE: We cannot cast here as that will lower the scale.
+                    let s = Int128Chunked::from_chunks(name, chunks)
+                        .into_decimal_unchecked(Some(*precision), *scale)
+                        .into_series();
+                    Ok(s)
+                })
+            },
+            ArrowDataType::Decimal(precision, scale) => {
                 feature_gated!("dtype-decimal", {
                     polars_ensure!(*scale <= *precision, InvalidOperation: "invalid decimal precision and scale (prec={precision}, scale={scale})");
                     polars_ensure!(*precision <= 38, InvalidOperation: "polars does not support decimals above 38 precision");
 
-                    // Q? I don't think this is correct for Decimal256?
                     let mut chunks = chunks;
                     for chunk in chunks.iter_mut() {
                         *chunk = std::mem::take(
@@ -382,6 +452,29 @@ impl Series {
                     Ok(s)
                 })
             },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano) => {
+                let mut chunks = chunks;
+                for chunk in chunks.iter_mut() {
+                    let old_chunk = chunk
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                        .unwrap();
+                    *chunk = PrimitiveArray::new(
+                        ArrowDataType::Int128,
+                        old_chunk
+                            .values()
+                            .iter()
+                            .map(|&v| interval_to_i128(v))
+                            .collect(),
+                        old_chunk.validity().cloned(),
+                    )
+                    .to_boxed();
+                }
+                Ok(Int128Chunked::from_chunks(name, chunks)
+                    .into_interval()
+                    .into_series())
+            },
             ArrowDataType::Null => Ok(new_null(name, &chunks)),
             #[cfg(not(feature = "dtype-categorical"))]
             ArrowDataType::Dictionary(_, _, _) => {
@@ -502,6 +595,17 @@ impl Series {
                 };
                 Ok(s)
             },
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext) => {
+                let chunks = chunks
+                    .into_iter()
+                    .map(|arr| with_arrow_dtype(arr, &ext.inner))
+                    .collect();
+                let storage =
+                    Series::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                let ext = ExtensionType::new(ext.name.clone(), ext.metadata.clone());
+                Ok(ExtensionChunked::from_storage(ext, storage).into_series())
+            },
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(_) => {
                 let (chunks, dtype) = to_physical_and_dtype(chunks, md);
@@ -517,7 +621,7 @@ impl Series {
                 let chunks = cast_chunks(&chunks, &DataType::Binary, CastOptions::NonStrict)?;
                 Ok(BinaryChunked::from_chunks(name, chunks).into_series())
             },
-            ArrowDataType::Map(field, _is_ordered) => {
+            ArrowDataType::Map(field, keys_sorted) => {
                 let struct_arrays = chunks
                     .iter()
                     .map(|arr| {
@@ -536,26 +640,32 @@ impl Series {
                         let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
                         let offsets: &OffsetsBuffer<i32> = arr.offsets();
 
-                        let validity = values.validity().cloned();
-
                         Box::from(ListArray::<i64>::new(
                             ListArray::<i64>::default_datatype(values.dtype().clone()),
                             OffsetsBuffer::<i64>::from(offsets),
                             values,
-                            validity,
+                            arr.validity().cloned(),
                         )) as ArrayRef
                     })
                     .collect();
 
-                unsafe {
-                    let out = ListChunked::from_chunks_and_dtype_unchecked(
+                let out = unsafe {
+                    ListChunked::from_chunks_and_dtype_unchecked(
                         name,
                         chunks,
                         DataType::List(Box::new(dtype)),
-                    );
+                    )
+                };
 
-                    Ok(out.into_series())
+                #[cfg(feature = "dtype-map")]
+                if matches!(field.dtype(), ArrowDataType::Struct(fields) if fields.len() == 2) {
+                    let mut out = out.to_map()?;
+                    out.set_keys_sorted(*keys_sorted);
+                    return Ok(out.into_series());
                 }
+
+                let _ = keys_sorted;
+                Ok(out.into_series())
             },
             dt => polars_bail!(ComputeError: "cannot create series from {:?}", dt),
         }
@@ -716,7 +826,10 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Date32
         | ArrowDataType::Decimal(_, _)
         | ArrowDataType::Date64
-        | ArrowDataType::Map(_, _)) => {
+        | ArrowDataType::Float16
+        | ArrowDataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano)
+        | ArrowDataType::Map(_, _)
+        | ArrowDataType::Extension(_)) => {
             let dt = dt.clone();
             let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
             let dtype = s.dtype().clone();
@@ -789,7 +902,16 @@ impl TryFrom<(&ArrowField, Vec<ArrayRef>)> for Series {
     fn try_from(field_arr: (&ArrowField, Vec<ArrayRef>)) -> PolarsResult<Self> {
         let (field, chunks) = field_arr;
 
-        let dtype = check_types(&chunks)?;
+        #[allow(unused_mut)]
+        let mut dtype = check_types(&chunks)?;
+        // The arrays of an extension type only carry the storage type, the extension type itself
+        // is kept on the field.
+        #[cfg(feature = "dtype-extension")]
+        if let ArrowDataType::Extension(ext) = &field.dtype {
+            if ext.inner == dtype {
+                dtype = field.dtype.clone();
+            }
+        }
 
         // SAFETY:
         // dtype is checked
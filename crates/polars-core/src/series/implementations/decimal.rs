@@ -1,6 +1,7 @@
 use polars_compute::rolling::QuantileMethod;
 
 use super::*;
+use crate::chunked_array::arithmetic::{_decimal_checked_sum, _get_decimal_precision_sum};
 use crate::prelude::*;
 
 unsafe impl IntoSeries for DecimalChunked {
@@ -30,20 +31,6 @@ impl SeriesWrap<DecimalChunked> {
         10u128.pow(self.0.scale() as u32)
     }
 
-    fn apply_scale(&self, mut scalar: Scalar) -> Scalar {
-        if scalar.is_null() {
-            return scalar;
-        }
-
-        debug_assert_eq!(scalar.dtype(), &DataType::Float64);
-        let v = scalar
-            .value()
-            .try_extract::<f64>()
-            .expect("should be f64 scalar");
-        scalar.update((v / self.scale_factor() as f64).into());
-        scalar
-    }
-
     fn agg_helper<F: Fn(&Int128Chunked) -> Series>(&self, f: F) -> Series {
         let agg_s = f(self.0.physical());
         match agg_s.dtype() {
@@ -151,7 +138,7 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
 
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
-        self.agg_helper(|ca| ca.agg_sum(groups))
+        self.0.agg_sum(groups)
     }
 
     #[cfg(feature = "algorithm_group_by")]
@@ -164,6 +151,16 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         self.agg_helper(|ca| ca.agg_max(groups))
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0.agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0.agg_var(groups, ddof)
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
         self.agg_helper(|ca| ca.agg_list(groups))
@@ -386,14 +383,10 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     }
 
     fn sum_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_physical(|ca| {
-            let sum = ca.sum();
-            let DataType::Decimal(_, Some(scale)) = self.dtype() else {
-                unreachable!()
-            };
-            let av = AnyValue::Decimal(sum.unwrap(), *scale);
-            Scalar::new(self.dtype().clone(), av)
-        }))
+        let sum = _decimal_checked_sum(self.0.physical().iter().flatten())?;
+        let precision = _get_decimal_precision_sum(self.0.precision());
+        let dtype = DataType::Decimal(precision, Some(self.0.scale()));
+        Ok(Scalar::new(dtype, AnyValue::Decimal(sum, self.0.scale())))
     }
     fn min_reduce(&self) -> PolarsResult<Scalar> {
         Ok(self.apply_physical(|ca| {
@@ -442,7 +435,7 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
             .map(|v| v / self.scale_factor() as f64)
     }
     fn median_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_scale(self.0.physical().median_reduce()))
+        self.0.median_reduce()
     }
 
     fn std(&self, ddof: u8) -> Option<f64> {
@@ -452,14 +445,15 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
             .map(|v| v / self.scale_factor() as f64)
     }
     fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
-        Ok(self.apply_scale(self.0.physical().std_reduce(ddof)))
+        self.0.std_reduce(ddof)
+    }
+
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.0.var_reduce(ddof)
     }
 
     fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
-        self.0
-            .physical()
-            .quantile_reduce(quantile, method)
-            .map(|v| self.apply_scale(v))
+        self.0.quantile_reduce(quantile, method)
     }
 
     fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
@@ -0,0 +1,293 @@
+//! Extension types delegate all operations to the series of their storage type and wrap the
+//! result in the extension type again.
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+use crate::series::private::{PrivateSeries, PrivateSeriesNumeric};
+
+unsafe impl IntoSeries for ExtensionChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl SeriesWrap<ExtensionChunked> {
+    fn with_storage(&self, storage: Series) -> Series {
+        self.0.with_storage(storage).into_series()
+    }
+
+    fn other_storage<'a>(&self, other: &'a Series) -> &'a Series {
+        other.extension().unwrap().storage()
+    }
+}
+
+impl PrivateSeries for SeriesWrap<ExtensionChunked> {
+    fn compute_len(&mut self) {
+        self.0.storage_mut()._get_inner_mut().compute_len()
+    }
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.storage().get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.storage_mut()._get_inner_mut()._set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0
+            .storage()
+            .equal_element(idx_self, idx_other, self.other_storage(other))
+    }
+
+    fn vec_hash(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.storage().vec_hash(build_hasher, buf)
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.storage().vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = self.other_storage(other);
+        self.0
+            .storage()
+            .zip_with_same_type(mask, other)
+            .map(|s| self.with_storage(s))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        self.0
+            .storage()
+            .agg_list(groups)
+            .cast(&DataType::List(Box::new(self.dtype().clone())))
+            .unwrap()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.0.storage().group_tuples(multithreaded, sorted)
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.storage().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.storage().into_total_ord_inner()
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.storage().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<ExtensionChunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.storage().chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.storage().chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.storage_mut().chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.storage_mut().shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.with_storage(self.0.storage().slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.storage().split_at(offset);
+        (self.with_storage(a), self.with_storage(b))
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = self.other_storage(other);
+        self.0.storage_mut().append(other)?;
+        Ok(())
+    }
+    fn append_owned(&mut self, other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = self.other_storage(&other).clone();
+        self.0.storage_mut().append_owned(other)?;
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = self.other_storage(other);
+        self.0.storage_mut().extend(other)?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.0
+            .storage()
+            .filter(filter)
+            .map(|s| self.with_storage(s))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.0.storage().take(indices)?))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.with_storage(self.0.storage().take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.0.storage().take_slice(indices)?))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.with_storage(self.0.storage().take_slice_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.with_storage(self.0.storage().rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.with_storage(self.0.storage().new_from_index(index, length))
+    }
+
+    fn trim_lists_to_normalized_offsets(&self) -> Option<Series> {
+        self.0
+            .storage()
+            .trim_lists_to_normalized_offsets()
+            .map(|s| self.with_storage(s))
+    }
+
+    fn propagate_nulls(&self) -> Option<Series> {
+        self.0
+            .storage()
+            .propagate_nulls()
+            .map(|s| self.with_storage(s))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.storage().get_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.0.storage().sort_with(options)?))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.storage().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.storage().null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.storage().has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.0.storage().unique()?))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.storage().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.storage().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.storage().is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.storage().is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.with_storage(self.0.storage().reverse())
+    }
+
+    fn as_single_ptr(&mut self) -> PolarsResult<usize> {
+        self.0.storage_mut().as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.with_storage(self.0.storage().shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.storage().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.storage().as_phys_any()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
+
+impl PrivateSeriesNumeric for SeriesWrap<ExtensionChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        self.0.storage().bit_repr()
+    }
+}
@@ -0,0 +1,456 @@
+//! Half precision floats are backed by their bits in an `u16`. Operations that only move values
+//! around work on these bits, all other operations widen the values to `f32` and narrow the
+//! result back to `f16`.
+use polars_compute::rolling::QuantileMethod;
+use polars_utils::total_ord::TotalEq;
+
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+use crate::series::private::{PrivateSeries, PrivateSeriesNumeric};
+
+unsafe impl IntoSeries for Float16Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+/// Round the value of a scalar to a half precision float.
+fn to_float16_scalar(sc: Scalar) -> Scalar {
+    let av = match sc.value().extract::<f32>() {
+        Some(v) => AnyValue::Float32(f16::from_f32(v).to_f32()),
+        None => AnyValue::Null,
+    };
+    Scalar::new(DataType::Float16, av)
+}
+
+impl SeriesWrap<Float16Chunked> {
+    fn widen(&self) -> Series {
+        self.0.to_float32().into_series()
+    }
+
+    fn apply_widened(
+        &self,
+        rhs: &Series,
+        op: impl Fn(&Series, &Series) -> PolarsResult<Series>,
+    ) -> PolarsResult<Series> {
+        let rhs = rhs.cast(&DataType::Float32)?;
+        op(&self.widen(), &rhs)?.cast(&DataType::Float16)
+    }
+}
+
+impl PrivateSeries for SeriesWrap<Float16Chunked> {
+    fn compute_len(&mut self) {
+        self.0.physical_mut().compute_len()
+    }
+
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.physical().get_flags()
+    }
+
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.physical_mut().set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.float16().unwrap().physical();
+        let a = self.0.physical().get_unchecked(idx_self);
+        let b = other.get_unchecked(idx_other);
+        a.map(|v| f16::from_bits(v).to_f32())
+            .tot_eq(&b.map(|v| f16::from_bits(v).to_f32()))
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.to_physical_repr().into_owned();
+        self.0
+            .physical()
+            .zip_with(mask, other.as_ref().as_ref())
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        (&self.0).into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        (&self.0).into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.to_float32().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.to_float32().vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        self.widen()
+            .agg_min(groups)
+            .cast(&DataType::Float16)
+            .unwrap()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        self.widen()
+            .agg_max(groups)
+            .cast(&DataType::Float16)
+            .unwrap()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        self.widen()
+            .agg_sum(groups)
+            .cast(&DataType::Float16)
+            .unwrap()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.widen()
+            .agg_std(groups, ddof)
+            .cast(&DataType::Float16)
+            .unwrap()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.widen()
+            .agg_var(groups, ddof)
+            .cast(&DataType::Float16)
+            .unwrap()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        self.0
+            .physical()
+            .agg_list(groups)
+            .cast(&DataType::List(Box::new(self.dtype().clone())))
+            .unwrap()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.apply_widened(rhs, |l, r| l - r)
+    }
+
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.apply_widened(rhs, |l, r| l + r)
+    }
+
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.apply_widened(rhs, |l, r| l * r)
+    }
+
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.apply_widened(rhs, |l, r| l / r)
+    }
+
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.apply_widened(rhs, |l, r| l % r)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        IntoGroupsType::group_tuples(&self.0.to_float32(), multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.to_float32().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Float16Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.physical().chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.physical_mut().chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.physical_mut().shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let mut other = other.to_physical_repr().into_owned();
+        self.0
+            .physical_mut()
+            .append_owned(std::mem::take(other._get_inner_mut().as_mut()))
+    }
+
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<Float16Chunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0
+            .physical_mut()
+            .extend(other.as_ref().as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.0
+            .physical()
+            .filter(filter)
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.0.to_float32()._sum_as_f64()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.0.to_float32().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.0.to_float32().median().map(|v| v as f64)
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.0.to_float32().std(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.0.to_float32().var(ddof)
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_float16()
+            .into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_float16()
+            .into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_float16()
+            .into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_float16()
+            .into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0
+            .physical()
+            .rechunk()
+            .into_owned()
+            .into_float16()
+            .into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .physical()
+            .new_from_index(index, length)
+            .into_float16()
+            .into_series()
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        let sorted = self.0.to_float32().sort_with(options);
+        let mut out = sorted.to_float16().into_series();
+        out.set_sorted_flag(sorted.is_sorted_flag());
+        Ok(out)
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.to_float32().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.0
+            .to_float32()
+            .unique()
+            .map(|ca| ca.to_float16().into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.to_float32().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.to_float32().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.physical().reverse().into_float16().into_series()
+    }
+
+    fn as_single_ptr(&mut self) -> PolarsResult<usize> {
+        self.0.physical_mut().as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0
+            .physical()
+            .shift(periods)
+            .into_float16()
+            .into_series()
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        self.widen().sum_reduce().map(to_float16_scalar)
+    }
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        self.widen().max_reduce().map(to_float16_scalar)
+    }
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        self.widen().min_reduce().map(to_float16_scalar)
+    }
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.widen().median_reduce().map(to_float16_scalar)
+    }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.widen().var_reduce(ddof).map(to_float16_scalar)
+    }
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.widen().std_reduce(ddof).map(to_float16_scalar)
+    }
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        self.widen()
+            .quantile_reduce(quantile, method)
+            .map(to_float16_scalar)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.physical().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
+
+impl PrivateSeriesNumeric for SeriesWrap<Float16Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        Some(self.0.to_float32().to_bit_repr())
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-map")]
+use arrow::offset::OffsetsBuffer;
 #[cfg(any(
     feature = "dtype-datetime",
     feature = "dtype-date",
@@ -85,6 +87,46 @@ impl Series {
                 );
                 Box::new(arr)
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(..) => {
+                let entries = self.map().unwrap().entries().clone().into_series();
+                let list = entries.to_arrow(chunk_idx, compat_level);
+                let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+
+                let dtype = self.dtype().to_arrow(compat_level);
+                let ArrowDataType::Map(entries_field, _) = &dtype else {
+                    unreachable!()
+                };
+                let values = list
+                    .values()
+                    .as_any()
+                    .downcast_ref::<StructArray>()
+                    .unwrap();
+                // The entries are never null, only the maps themselves are.
+                let values = StructArray::new(
+                    entries_field.dtype().clone(),
+                    values.len(),
+                    values.values().to_vec(),
+                    None,
+                );
+                let offsets = OffsetsBuffer::<i32>::try_from(list.offsets())
+                    .expect("map entries should fit in 32-bit offsets");
+                Box::new(MapArray::new(
+                    dtype,
+                    offsets,
+                    values.boxed(),
+                    list.validity().cloned(),
+                ))
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_, _) => {
+                let arr = self
+                    .extension()
+                    .unwrap()
+                    .storage()
+                    .to_arrow(chunk_idx, compat_level);
+                with_arrow_dtype(arr, &self.dtype().to_arrow(compat_level))
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, width) => {
                 let ca = self.array().unwrap();
@@ -149,6 +191,27 @@ impl Series {
                 &DataType::Time.to_arrow(compat_level),
             )
             .unwrap(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let arr = self.interval().unwrap().physical().chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i128>>()
+                    .unwrap();
+                PrimitiveArray::<months_days_ns>::new(
+                    DataType::Interval.to_arrow(compat_level),
+                    arr.values().iter().map(|&v| i128_to_interval(v)).collect(),
+                    arr.validity().cloned(),
+                )
+                .to_boxed()
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.float16().unwrap().physical().chunks()[chunk_idx]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u16>>()
+                .unwrap()
+                .clone()
+                .transmute::<f16>()
+                .to_boxed(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => self.decimal().unwrap().physical().chunks()[chunk_idx]
                 .as_any()
@@ -385,7 +385,9 @@ impl Series {
         let do_clone = match dtype {
             D::Unknown(UnknownKind::Any | UnknownKind::Ufunc) => true,
             D::Unknown(UnknownKind::Int(_)) if slf.dtype().is_integer() => true,
-            D::Unknown(UnknownKind::Float) if slf.dtype().is_float() => true,
+            D::Unknown(UnknownKind::Float) if slf.dtype().is_float() | slf.dtype().is_float16() => {
+                true
+            },
             D::Unknown(UnknownKind::Str)
                 if slf.dtype().is_string() | slf.dtype().is_categorical() =>
             {
@@ -462,7 +464,13 @@ impl Series {
             opt => opt,
         };
 
-        let out = slf.0.cast(dtype, new_options)?;
+        let out = match dtype {
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_, _) if !slf.dtype().is_extension() => {
+                cast_to_extension(&slf, dtype, new_options)?
+            },
+            _ => slf.0.cast(dtype, new_options)?,
+        };
         if options.is_strict() {
             handle_casting_failures(slf.as_ref(), &out)?;
         }
@@ -543,6 +551,12 @@ impl Series {
                 feature_gated!("dtype-duration", Ok(self.clone().into_duration(*tu)))
             },
             (D::Int64, D::Time) => feature_gated!("dtype-time", Ok(self.clone().into_time())),
+            #[cfg(feature = "dtype-interval")]
+            (D::Int128, D::Interval) => {
+                Ok(self.i128().unwrap().clone().into_interval().into_series())
+            },
+            #[cfg(feature = "dtype-f16")]
+            (D::UInt16, D::Float16) => Ok(self.u16().unwrap().clone().into_float16().into_series()),
 
             (D::List(_), D::List(to)) => unsafe {
                 self.list()
@@ -564,6 +578,19 @@ impl Series {
                     .from_physical_unchecked(to.as_slice())
                     .map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(key, value, _)) => unsafe {
+                let entries = DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+                self.list()
+                    .unwrap()
+                    .from_physical_unchecked(entries)
+                    .map(|ca| MapChunked::new_logical(ca, dtype.clone()).into_series())
+            },
+            #[cfg(feature = "dtype-extension")]
+            (_, D::Extension(ext, storage)) => {
+                let storage = self.from_physical_unchecked(storage)?;
+                Ok(ExtensionChunked::from_storage(ext.clone(), storage).into_series())
+            },
 
             _ => panic!("invalid from_physical({dtype:?}) for {:?}", self.dtype()),
         }
@@ -620,6 +647,8 @@ impl Series {
             DataType::List(_) => self.list().unwrap().explode(skip_empty),
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => self.array().unwrap().explode(skip_empty),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(..) => self.map().unwrap().entries().explode(skip_empty),
             _ => Ok(self.clone()),
         }
     }
@@ -700,10 +729,14 @@ impl Series {
     /// * Duration -> Int64
     /// * Decimal -> Int128
     /// * Time -> Int64
+    /// * Interval -> Int128
+    /// * Float16 -> UInt16
     /// * Categorical -> U8/U16/U32
     /// * List(inner) -> List(physical of inner)
     /// * Array(inner) -> Array(physical of inner)
     /// * Struct -> Struct with physical repr of each struct column
+    /// * Map(key, value) -> List(Struct) with the physical repr of the keys and values
+    /// * Extension(_, storage) -> physical repr of the storage
     pub fn to_physical_repr(&self) -> Cow<'_, Series> {
         use DataType::*;
         match self.dtype() {
@@ -717,6 +750,10 @@ impl Series {
             Duration(_) => Cow::Owned(self.duration().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Cow::Owned(self.time().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.float16().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-categorical")]
             dt @ (Categorical(_, _) | Enum(_, _)) => {
                 with_match_categorical_physical_type!(dt.cat_physical().unwrap(), |$C| {
@@ -740,6 +777,23 @@ impl Series {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
             },
+            #[cfg(feature = "dtype-map")]
+            Map(..) => Cow::Owned(
+                self.map()
+                    .unwrap()
+                    .entries()
+                    .to_physical_repr()
+                    .into_owned()
+                    .into_series(),
+            ),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, _) => Cow::Owned(
+                self.extension()
+                    .unwrap()
+                    .storage()
+                    .to_physical_repr()
+                    .into_owned(),
+            ),
             _ => Cow::Borrowed(self),
         }
     }
@@ -790,6 +844,8 @@ impl Series {
                 UInt64 => Ok(self.u64().unwrap().prod_reduce()),
                 #[cfg(feature = "dtype-i128")]
                 Int128 => Ok(self.i128().unwrap().prod_reduce()),
+                #[cfg(feature = "dtype-u128")]
+                UInt128 => Ok(self.u128().unwrap().prod_reduce()),
                 Float32 => Ok(self.f32().unwrap().prod_reduce()),
                 Float64 => Ok(self.f64().unwrap().prod_reduce()),
                 dt => {
@@ -918,6 +974,10 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let Some(ca) = self.try_extension() {
+            return ca.str_value(index);
+        }
         Ok(self.0.get(index)?.str_value())
     }
     /// Get the head of the Series.
@@ -933,8 +993,15 @@ impl Series {
         self.slice(-(len as i64), len)
     }
 
-    pub fn mean_reduce(&self) -> Scalar {
-        crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
+    pub fn mean_reduce(&self) -> PolarsResult<Scalar> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.decimal() {
+            return ca.mean_reduce();
+        }
+        Ok(crate::scalar::reduce::mean_reduce(
+            self.mean(),
+            self.dtype().clone(),
+        ))
     }
 
     /// Compute the unique elements, but maintain order. This requires more work
@@ -68,6 +68,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Int128 => Int128Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::UInt128`]
+    #[cfg(feature = "dtype-u128")]
+    pub fn try_u128(&self) -> Option<&UInt128Chunked> {
+        try_unpack_chunked!(self, DataType::UInt128 => UInt128Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn try_f32(&self) -> Option<&Float32Chunked> {
         try_unpack_chunked!(self, DataType::Float32 => Float32Chunked)
@@ -142,6 +148,18 @@ impl Series {
         try_unpack_chunked!(self, DataType::Duration(_) => DurationChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_float16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn try_decimal(&self) -> Option<&DecimalChunked> {
@@ -159,6 +177,18 @@ impl Series {
         try_unpack_chunked!(self, DataType::Array(_, _) => ArrayChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(..) => MapChunked)
+    }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn try_extension(&self) -> Option<&ExtensionChunked> {
+        try_unpack_chunked!(self, DataType::Extension(_, _) => ExtensionChunked)
+    }
+
     #[cfg(feature = "dtype-categorical")]
     pub fn try_cat<T: PolarsCategoricalType>(&self) -> Option<&CategoricalChunked<T>> {
         try_unpack_chunked!(self, dt @ DataType::Enum(_, _) | dt @ DataType::Categorical(_, _) if dt.cat_physical().unwrap() == T::physical() => CategoricalChunked<T>)
@@ -242,6 +272,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Int128"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::UInt128`]
+    #[cfg(feature = "dtype-u128")]
+    pub fn u128(&self) -> PolarsResult<&UInt128Chunked> {
+        self.try_u128()
+            .ok_or_else(|| unpack_chunked_err!(self => "UInt128"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         self.try_f32()
@@ -330,6 +367,20 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Duration"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn float16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_float16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
@@ -350,6 +401,20 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Array"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.try_map()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.try_extension()
+            .ok_or_else(|| unpack_chunked_err!(self => "Extension"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Categorical`] or [`DataType::Enum`].
     #[cfg(feature = "dtype-categorical")]
     pub fn cat<T: PolarsCategoricalType>(&self) -> PolarsResult<&CategoricalChunked<T>> {
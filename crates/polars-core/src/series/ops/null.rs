@@ -17,6 +17,18 @@ impl Series {
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value, _) => {
+                let entries = DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+                let entries = ListChunked::full_null_with_dtype(name, size, &entries);
+                // SAFETY: the entries have the layout of the map.
+                unsafe { MapChunked::new_logical(entries, dtype.clone()) }.into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext, storage) => {
+                let storage = Series::full_null(name, size, storage);
+                ExtensionChunked::from_storage(ext.clone(), storage).into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(_, _) | DataType::Enum(_, _)) => {
                 with_match_categorical_physical_type!(dt.cat_physical().unwrap(), |$C| {
@@ -44,6 +56,14 @@ impl Series {
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
                 .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => UInt16Chunked::full_null(name, size)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, scale.unwrap_or(0))
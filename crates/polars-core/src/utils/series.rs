@@ -31,6 +31,11 @@ pub fn is_deprecated_cast(input_dtype: &DataType, output_dtype: &DataType) -> bo
         (D::List(input_dtype), D::List(output_dtype)) => {
             is_deprecated_cast(input_dtype, output_dtype)
         },
+        #[cfg(feature = "dtype-map")]
+        (D::Map(input_key, input_value, _), D::Map(output_key, output_value, _)) => {
+            is_deprecated_cast(input_key, output_key)
+                || is_deprecated_cast(input_value, output_value)
+        },
         #[cfg(feature = "dtype-array")]
         (D::Array(input_dtype, _), D::Array(output_dtype, _)) => {
             is_deprecated_cast(input_dtype, output_dtype)
@@ -57,6 +57,7 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
         }
     } else if l.is_unsigned_integer() && r.is_unsigned_integer() {
         match (l, r) {
+            (UInt128, _) | (_, UInt128) => Some(UInt128),
             (UInt64, _) | (_, UInt64) => Some(UInt64),
             (UInt32, _) | (_, UInt32) => Some(UInt32),
             (UInt16, _) | (_, UInt16) => Some(UInt16),
@@ -73,6 +74,8 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
         // One side is signed, the other is unsigned. We just need to upcast the
         // unsigned side to a signed integer with the next-largest bit width.
         match (l, r) {
+            // There is no signed integer that can hold all values of an `UInt128`.
+            (UInt128, _) | (_, UInt128) => None,
             (UInt64, _) | (_, UInt64) | (Int128, _) | (_, Int128) => Some(Int128),
             (UInt32, _) | (_, UInt32) | (Int64, _) | (_, Int64) => Some(Int64),
             (UInt16, _) | (_, UInt16) | (Int32, _) | (_, Int32) => Some(Int32),
@@ -194,6 +197,10 @@ pub fn get_supertype_with_options(
             (Int16, Float64) => Some(Float64),
 
 
+            #[cfg(feature = "dtype-u128")]
+            (UInt128, a) | (a, UInt128) if a.is_unsigned_integer() | a.is_bool() => Some(UInt128),
+            #[cfg(feature = "dtype-u128")]
+            (UInt128, a) | (a, UInt128) if a.is_signed_integer() | a.is_float() => Some(Float64), // Follow numpy
             #[cfg(feature = "dtype-i128")]
             (a, Int128) if a.is_integer() | a.is_bool() => Some(Int128),
             #[cfg(feature = "dtype-i128")]
@@ -269,6 +276,11 @@ pub fn get_supertype_with_options(
 
             (Float32, Float64) => Some(Float64),
 
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Boolean) => Some(Float16),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, dt) if dt.is_primitive_numeric() => get_supertype(&Float32, dt),
+
             #[cfg(feature = "dtype-u8")]
             (Float64, UInt8) => Some(Float64),
             #[cfg(feature = "dtype-u16")]
@@ -371,6 +383,15 @@ pub fn get_supertype_with_options(
                 let st = get_supertype(inner_left, inner_right)?;
                 Some(List(Box::new(st)))
             }
+            #[cfg(feature = "dtype-map")]
+            (Map(key_left, value_left, sorted_l), Map(key_right, value_right, sorted_r)) => {
+                let key = get_supertype(key_left, key_right)?;
+                let value = get_supertype(value_left, value_right)?;
+                Some(Map(Box::new(key), Box::new(value), *sorted_l && *sorted_r))
+            }
+            // Extension types only have a supertype if they are the same.
+            #[cfg(feature = "dtype-extension")]
+            (Extension(_, _), _) | (_, Extension(_, _)) => None,
             #[cfg(feature = "dtype-array")]
             (List(inner_left), Array(inner_right, _)) | (Array(inner_left, _), List(inner_right)) => {
                 let st = get_supertype(inner_left, inner_right)?;
@@ -407,7 +428,7 @@ pub fn get_supertype_with_options(
                         }
                     },
                     // numeric vs float|str -> always float|str|decimal
-                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
+                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_float16() | dt.is_decimal() => Some(dt.clone()),
                     UnknownKind::Float if dt.is_integer() => Some(Unknown(UnknownKind::Float)),
                     // Materialize float to float or decimal
                     UnknownKind::Float if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
@@ -119,7 +119,7 @@ impl PhysicalExpr for AggregationExpr {
                 panic!("activate 'propagate_nans' feature")
             },
             GroupByMethod::Median => s.median_reduce().map(|sc| sc.into_column(s.name().clone())),
-            GroupByMethod::Mean => Ok(s.mean_reduce().into_column(s.name().clone())),
+            GroupByMethod::Mean => s.mean_reduce().map(|sc| sc.into_column(s.name().clone())),
             GroupByMethod::First => Ok(if s.is_empty() {
                 Column::full_null(s.name().clone(), 1, s.dtype())
             } else {
@@ -193,17 +193,29 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Median => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_median(&groups);
+                    let agg_c = match c.dtype() {
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => c
+                            .decimal()?
+                            .try_agg_quantile(&groups, 0.5, QuantileMethod::Linear)?
+                            .into(),
+                        _ => c.agg_median(&groups),
+                    };
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Mean => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_mean(&groups);
+                    let agg_c = match c.dtype() {
+                        // Raise decimal overflows instead of returning null for those groups.
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => c.decimal()?.try_agg_mean(&groups)?.into(),
+                        _ => c.agg_mean(&groups),
+                    };
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Sum => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_sum(&groups);
+                    let agg_c = agg_sum_checked(&c, &groups)?;
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Count { include_nulls } => {
@@ -381,12 +393,20 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Std(ddof) => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_std(&groups, ddof);
+                    let agg_c = match c.dtype() {
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => c.decimal()?.try_agg_std(&groups, ddof)?.into(),
+                        _ => c.agg_std(&groups, ddof),
+                    };
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Var(ddof) => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_var(&groups, ddof);
+                    let agg_c = match c.dtype() {
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => c.decimal()?.try_agg_var(&groups, ddof)?.into(),
+                        _ => c.agg_var(&groups, ddof),
+                    };
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Quantile(_, _) => {
@@ -459,6 +479,19 @@ impl PhysicalExpr for AggregationExpr {
     }
 }
 
+/// Sums every group, raising an error instead of returning null for a decimal sum that overflows.
+///
+/// # Safety
+///
+/// Does no bounds checks, groups must be correct.
+unsafe fn agg_sum_checked(c: &Column, groups: &GroupsType) -> PolarsResult<Column> {
+    match c.dtype() {
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => Ok(unsafe { c.decimal()?.try_agg_sum(groups) }?.into()),
+        _ => Ok(unsafe { c.agg_sum(groups) }),
+    }
+}
+
 impl PartitionedAggregation for AggregationExpr {
     fn evaluate_partitioned(
         &self,
@@ -530,7 +563,7 @@ impl PartitionedAggregation for AggregationExpr {
                     Ok(agg)
                 },
                 GroupByMethod::Sum => {
-                    let mut agg = column.agg_sum(groups);
+                    let mut agg = agg_sum_checked(&column, groups)?;
                     agg.rename(column.name().clone());
                     Ok(agg)
                 },
@@ -559,7 +592,7 @@ impl PartitionedAggregation for AggregationExpr {
                 include_nulls: true,
             }
             | GroupByMethod::Sum => {
-                let mut agg = unsafe { partitioned.agg_sum(groups) };
+                let mut agg = unsafe { agg_sum_checked(&partitioned, groups)? };
                 agg.rename(partitioned.name().clone());
                 Ok(agg)
             },
@@ -733,10 +766,15 @@ impl PhysicalExpr for AggQuantileExpr {
 
         // SAFETY:
         // groups are in bounds
-        let mut agg = unsafe {
-            ac.flat_naive()
-                .into_owned()
-                .agg_quantile(ac.groups(), quantile, self.method)
+        let c = ac.flat_naive().into_owned();
+        let mut agg = match c.dtype() {
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => unsafe {
+                c.decimal()?
+                    .try_agg_quantile(ac.groups(), quantile, self.method)?
+                    .into()
+            },
+            _ => unsafe { c.agg_quantile(ac.groups(), quantile, self.method) },
         };
         agg.rename(keep_name);
         Ok(AggregationContext::from_agg_state(
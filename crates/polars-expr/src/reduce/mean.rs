@@ -2,6 +2,11 @@ use std::marker::PhantomData;
 
 use arrow::temporal_conversions::MICROSECONDS_IN_DAY;
 use num_traits::{AsPrimitive, Zero};
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _decimal_checked_mean, _decimal_checked_sum, _get_decimal_precision_sum,
+    _get_decimal_scale_mean,
+};
 use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
@@ -17,7 +22,7 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalMeanReducer)),
 
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
@@ -42,15 +47,6 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
-        #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_prec, scale) => {
-            let inv_scale_factor = 1.0 / 10u128.pow(scale.unwrap() as u32) as f64;
-            let ca: Float64Chunked = values
-                .into_iter()
-                .map(|(s, c)| (c != 0).then(|| s / c as f64 * inv_scale_factor))
-                .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series()
-        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Date => {
             const US_IN_DAY: f64 = MICROSECONDS_IN_DAY as f64;
@@ -167,3 +163,68 @@ impl Reducer for BoolMeanReducer {
         Ok(ca.into_series())
     }
 }
+
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalMeanReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalMeanReducer {
+    type Dtype = Int128Type;
+    // The sum of the physical values, or `None` if it overflowed, and the number of values.
+    type Value = (Option<i128>, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (Some(0), 0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0 = a.0.zip(b.0).and_then(|(a, b)| a.checked_add(b));
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        a.0 = a.0.and_then(|a| a.checked_add(b.unwrap_or(0)));
+        a.1 += b.is_some() as usize;
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        let sum = _decimal_checked_sum(ca.iter().flatten()).ok();
+        self.combine(v, &(sum, ca.len() - ca.null_count()));
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(precision, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let mean_scale = _get_decimal_scale_mean(*scale);
+        let ca: Int128Chunked = v
+            .into_iter()
+            .map(|(sum, count)| {
+                if count == 0 {
+                    return Ok(None);
+                }
+                let Some(sum) = sum else {
+                    polars_bail!(ComputeError: "decimal arithmetic overflowed")
+                };
+                _decimal_checked_mean(sum, count, mean_scale - *scale).map(Some)
+            })
+            .try_collect_ca(PlSmallStr::EMPTY)?;
+        Ok(ca
+            .into_decimal_unchecked(_get_decimal_precision_sum(*precision), mean_scale)
+            .into_series())
+    }
+}
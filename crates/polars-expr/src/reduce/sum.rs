@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use arrow::array::PrimitiveArray;
 use num_traits::Zero;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{_decimal_checked_sum, _get_decimal_precision_sum};
 use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::float::IsFloat;
 
@@ -30,6 +32,8 @@ impl_sum_cast!(
 impl_sum_cast!(u32, u64, i32, i64, f32, f64);
 #[cfg(feature = "dtype-i128")]
 impl_sum_cast!(i128);
+#[cfg(feature = "dtype-u128")]
+impl_sum_cast!(u128);
 
 fn out_dtype(in_dtype: &DataType) -> DataType {
     use DataType::*;
@@ -51,7 +55,7 @@ pub fn new_sum_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumSumReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalSumReducer)),
         Duration(_) => Box::new(VGR::new(dtype, NumSumReducer::<Int64Type>(PhantomData))),
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
@@ -163,3 +167,59 @@ impl Reducer for BoolSumReducer {
         Ok(IdxCa::from_vec(PlSmallStr::EMPTY, v).into_series())
     }
 }
+
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalSumReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalSumReducer {
+    type Dtype = Int128Type;
+    // The sum of the physical values, or `None` if it overflowed.
+    type Value = Option<i128>;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        Some(0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        *a = a.zip(*b).and_then(|(a, b)| a.checked_add(b));
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        *a = a.and_then(|a| a.checked_add(b.unwrap_or(0)));
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        let sum = _decimal_checked_sum(ca.iter().flatten()).ok();
+        self.combine(v, &sum);
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(precision, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let v = v
+            .into_iter()
+            .map(|sum| {
+                sum.ok_or_else(|| polars_err!(ComputeError: "decimal arithmetic overflowed"))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Int128Chunked::from_vec(PlSmallStr::EMPTY, v)
+            .into_decimal(_get_decimal_precision_sum(*precision), *scale)
+            .map(|ca| ca.into_series())
+    }
+}
@@ -2,6 +2,11 @@ use std::marker::PhantomData;
 
 use num_traits::AsPrimitive;
 use polars_compute::moment::VarState;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _decimal_checked_moments, _decimal_checked_std, _decimal_checked_var,
+    _get_decimal_precision_sum, _get_decimal_scale_std, _get_decimal_scale_var,
+};
 use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
@@ -16,21 +21,12 @@ pub fn new_var_std_reduction(dtype: DataType, is_std: bool, ddof: u8) -> Box<dyn
                 Box::new(VGR::new(dtype, VarStdReducer::<$T> {
                     is_std,
                     ddof,
-                    needs_cast: false,
                     _phantom: PhantomData,
                 }))
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(
-            dtype,
-            VarStdReducer::<Float64Type> {
-                is_std,
-                ddof,
-                needs_cast: true,
-                _phantom: PhantomData,
-            },
-        )),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalVarStdReducer { is_std, ddof })),
         Duration(..) => todo!(),
         _ => unimplemented!(),
     }
@@ -39,7 +35,6 @@ pub fn new_var_std_reduction(dtype: DataType, is_std: bool, ddof: u8) -> Box<dyn
 struct VarStdReducer<T> {
     is_std: bool,
     ddof: u8,
-    needs_cast: bool,
     _phantom: PhantomData<T>,
 }
 
@@ -48,7 +43,6 @@ impl<T> Clone for VarStdReducer<T> {
         Self {
             is_std: self.is_std,
             ddof: self.ddof,
-            needs_cast: self.needs_cast,
             _phantom: PhantomData,
         }
     }
@@ -62,14 +56,6 @@ impl<T: PolarsNumericType> Reducer for VarStdReducer<T> {
         VarState::default()
     }
 
-    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
-        if self.needs_cast {
-            Cow::Owned(s.cast(&DataType::Float64).unwrap())
-        } else {
-            Cow::Borrowed(s)
-        }
-    }
-
     fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
         a.combine(b)
     }
@@ -105,6 +91,82 @@ impl<T: PolarsNumericType> Reducer for VarStdReducer<T> {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalVarStdReducer {
+    is_std: bool,
+    ddof: u8,
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalVarStdReducer {
+    type Dtype = Int128Type;
+    // The sum and sum of squares of the physical values and the number of values, or `None` if
+    // either sum overflowed.
+    type Value = Option<(i128, i128, usize)>;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        Some((0, 0, 0))
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        *a = a
+            .zip(*b)
+            .and_then(|(a, b)| Some((a.0.checked_add(b.0)?, a.1.checked_add(b.1)?, a.2 + b.2)));
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        if let Some(x) = b {
+            self.combine(a, &_decimal_checked_moments([x]).ok());
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        let moments = _decimal_checked_moments(ca.iter().flatten()).ok();
+        self.combine(v, &moments);
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(precision, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let ca: Int128Chunked = v
+            .into_iter()
+            .map(|moments| {
+                let Some(moments) = moments else {
+                    polars_bail!(ComputeError: "decimal arithmetic overflowed")
+                };
+                if self.is_std {
+                    _decimal_checked_std(moments, self.ddof, *scale)
+                } else {
+                    _decimal_checked_var(moments, self.ddof, *scale)
+                }
+            })
+            .try_collect_ca(PlSmallStr::EMPTY)?;
+        let out_scale = if self.is_std {
+            _get_decimal_scale_std(*scale)
+        } else {
+            _get_decimal_scale_var(*scale)
+        };
+        Ok(ca
+            .into_decimal_unchecked(_get_decimal_precision_sum(*precision), out_scale)
+            .into_series())
+    }
+}
+
 #[derive(Clone)]
 struct BoolVarStdReducer {
     is_std: bool,
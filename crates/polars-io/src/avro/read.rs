@@ -4,10 +4,17 @@ use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
+#[derive(Clone, Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroScanOptions;
+
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
 /// [Apache Avro]: https://avro.apache.org
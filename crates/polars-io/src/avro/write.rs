@@ -1,11 +1,17 @@
 use std::io::Write;
 
 pub use Compression as AvroCompression;
+use arrow::array::Array;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::file::{Block, CompressedBlock};
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
+use arrow::record_batch::RecordBatchT;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
@@ -67,36 +73,110 @@ where
         let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
         let record = write::to_record(&schema, self.name.clone())?;
 
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+        write_avro_header(&mut self.writer, record.clone(), self.compression)?;
+
+        df.align_chunks();
+        let mut encoder = AvroBlockEncoder::new(record, self.compression);
         for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
-            let mut serializers = chunk
-                .iter()
-                .zip(record.fields.iter())
-                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
-                .collect::<Vec<_>>();
-
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
-
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
-
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+            encoder.write_block(&mut self.writer, &chunk)?;
         }
 
         Ok(())
     }
 }
+
+/// Codecs for the blocks of an Avro file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCodec {
+    Deflate,
+    Snappy,
+}
+
+impl From<AvroCodec> for AvroCompression {
+    fn from(value: AvroCodec) -> Self {
+        match value {
+            AvroCodec::Deflate => Self::Deflate,
+            AvroCodec::Snappy => Self::Snappy,
+        }
+    }
+}
+
+impl From<AvroCompression> for AvroCodec {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => Self::Deflate,
+            AvroCompression::Snappy => Self::Snappy,
+        }
+    }
+}
+
+/// Options for writing Avro files.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Codec used to compress the blocks, if any.
+    pub compression: Option<AvroCodec>,
+    /// Name of the record schema of the file.
+    pub name: PlSmallStr,
+}
+
+/// Write the header of an Avro file, which holds the record schema and the codec.
+pub fn write_avro_header<W: Write>(
+    mut writer: W,
+    record: Record,
+    compression: Option<AvroCompression>,
+) -> PolarsResult<()> {
+    avro_schema::write::write_metadata(&mut writer, record, compression).map_err(to_compute_err)
+}
+
+/// Encodes record batches into Avro blocks.
+///
+/// Every block ends with the same sync marker as the header, so blocks can be encoded
+/// independently of each other and of the header.
+pub struct AvroBlockEncoder {
+    record: Record,
+    compression: Option<AvroCompression>,
+    block: Block,
+    compressed_block: CompressedBlock,
+}
+
+impl AvroBlockEncoder {
+    pub fn new(record: Record, compression: Option<AvroCompression>) -> Self {
+        Self {
+            record,
+            compression,
+            block: Block::default(),
+            compressed_block: CompressedBlock::default(),
+        }
+    }
+
+    /// Encode `chunk` as a single block and write it to `writer`.
+    pub fn write_block<W: Write>(
+        &mut self,
+        writer: &mut W,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+    ) -> PolarsResult<()> {
+        let mut serializers = chunk
+            .iter()
+            .zip(self.record.fields.iter())
+            .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+
+        // Reuse the buffers of the previous block.
+        self.block.number_of_rows = chunk.len();
+        write::serialize(&mut serializers, &mut self.block);
+        self.compressed_block.data.clear();
+        self.compressed_block.number_of_rows = 0;
+        let _was_compressed = avro_schema::write::compress(
+            &mut self.block,
+            &mut self.compressed_block,
+            self.compression,
+        )
+        .map_err(to_compute_err)?;
+
+        avro_schema::write::write_block(writer, &self.compressed_block).map_err(to_compute_err)
+    }
+}
@@ -29,7 +29,7 @@ pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, N
 pub use parser::{count_rows, count_rows_from_slice, count_rows_from_slice_par};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
-pub use schema_inference::infer_file_schema;
+pub use schema_inference::{decompress_schema_inference_prefix, infer_file_schema};
 
 pub mod _csv_read_internal {
     pub use super::buffer::validate_utf8;
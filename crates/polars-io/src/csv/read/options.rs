@@ -423,7 +423,7 @@ impl NullValuesCompiled {
     /// # Safety
     ///
     /// The caller must ensure that `index` is in bounds
-    pub(super) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
+    pub(crate) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
         use NullValuesCompiled::*;
         match self {
             AllColumnsSingle(v) => v.as_bytes() == field,
@@ -7,11 +7,12 @@ use polars_time::chunkedarray::string::infer as date_infer;
 use polars_time::prelude::string::Pattern;
 use polars_utils::format_pl_smallstr;
 
-use super::parser::{SplitLines, is_comment_line, skip_bom, skip_line_ending};
+use super::parser::{CountLines, SplitLines, is_comment_line, skip_bom, skip_line_ending};
 use super::splitfields::SplitFields;
 use super::{CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 use crate::csv::read::parser::skip_lines_naive;
 use crate::mmap::ReaderBytes;
+use crate::utils::compression::DecompressingReader;
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
 #[derive(Clone, Debug, Default)]
@@ -558,3 +559,35 @@ pub fn infer_file_schema(
         )
     }
 }
+
+/// Decompress the start of a compressed CSV file, up to the lines that are needed to infer its
+/// schema from `infer_schema_length` rows. Returns whether the whole file was decompressed.
+pub fn decompress_schema_inference_prefix(
+    reader: &mut DecompressingReader,
+    options: &CsvReadOptions,
+    infer_schema_length: Option<usize>,
+    buf: &mut Vec<u8>,
+) -> PolarsResult<bool> {
+    let Some(infer_schema_length) = infer_schema_length else {
+        reader.read_chunk(buf, usize::MAX)?;
+        return Ok(true);
+    };
+
+    let n_lines = [
+        options.skip_lines,
+        options.skip_rows,
+        options.skip_rows_after_header,
+        usize::from(options.has_header),
+        infer_schema_length,
+        // Make sure that inference stops before the (partial) line at the end of the prefix.
+        1,
+    ]
+    .into_iter()
+    .fold(0usize, usize::saturating_add);
+
+    let line_counter = CountLines::new(
+        options.parse_options.quote_char,
+        options.parse_options.eol_char,
+    );
+    reader.read_lines(buf, n_lines, |bytes| line_counter.count(bytes))
+}
@@ -135,24 +135,9 @@ pub(crate) fn decompress(
 ) -> Option<Vec<u8>> {
     use crate::utils::compression::SupportedCompression;
 
-    if let Some(algo) = SupportedCompression::check(bytes) {
-        match algo {
-            SupportedCompression::GZIP => {
-                let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-            SupportedCompression::ZLIB => {
-                let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-            SupportedCompression::ZSTD => {
-                let mut decoder = zstd::Decoder::with_buffer(bytes).ok()?;
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-        }
-    } else {
-        None
-    }
+    let algo = SupportedCompression::check(bytes)?;
+    let mut decoder = algo.decoder(bytes).ok()?;
+    decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
 }
 
 /// replace double quotes by single ones
@@ -0,0 +1,16 @@
+//! Read fixed-width text files.
+//!
+//! Every column of a fixed-width file is located at the same byte or character span of each
+//! line. The fields are parsed with the buffers and the data type inference of the CSV
+//! reader, and the file is split by line to parse it in parallel.
+mod options;
+mod parser;
+mod reader;
+
+pub use options::*;
+pub use parser::{
+    FixedWidthChunkParser, count_rows, count_rows_from_slice, decompress_schema_inference_prefix,
+    find_end_of_rows, find_next_line_end, find_starting_point, get_line_chunks,
+    infer_fixed_width_schema,
+};
+pub use reader::*;
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use polars_core::schema::SchemaRef;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csv::read::{CsvEncoding, NullValues};
+
+/// A column of a fixed-width file, located at the `start..end` span of every line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FixedWidthColumn {
+    pub name: PlSmallStr,
+    /// Offset of the first byte or character of the column.
+    pub start: usize,
+    /// Offset past the last byte or character of the column.
+    pub end: usize,
+}
+
+impl FixedWidthColumn {
+    pub fn new(name: PlSmallStr, start: usize, end: usize) -> Self {
+        Self { name, start, end }
+    }
+}
+
+/// The unit in which the spans of the columns are given.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FixedWidthSpanUnit {
+    /// The spans are byte offsets.
+    #[default]
+    Bytes,
+    /// The spans are offsets of UTF-8 characters.
+    Chars,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FixedWidthReadOptions {
+    pub columns: Arc<[FixedWidthColumn]>,
+    pub span_unit: FixedWidthSpanUnit,
+    /// Data types of columns that are not inferred.
+    pub schema_overwrite: Option<SchemaRef>,
+    pub infer_schema_length: Option<usize>,
+    /// Number of lines to skip at the start of the file, e.g. a header.
+    pub skip_rows: usize,
+    pub eol_char: u8,
+    pub encoding: CsvEncoding,
+    pub null_values: Option<NullValues>,
+    pub missing_is_null: bool,
+    pub try_parse_dates: bool,
+    pub decimal_comma: bool,
+    pub ignore_errors: bool,
+}
+
+impl Default for FixedWidthReadOptions {
+    fn default() -> Self {
+        Self {
+            columns: Arc::new([]),
+            span_unit: FixedWidthSpanUnit::default(),
+            schema_overwrite: None,
+            infer_schema_length: Some(100),
+            skip_rows: 0,
+            eol_char: b'\n',
+            encoding: CsvEncoding::default(),
+            null_values: None,
+            missing_is_null: true,
+            try_parse_dates: false,
+            decimal_comma: false,
+            ignore_errors: false,
+        }
+    }
+}
+
+impl FixedWidthReadOptions {
+    /// Set the columns and their spans.
+    pub fn with_columns(mut self, columns: Arc<[FixedWidthColumn]>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set whether the spans of the columns are byte or character offsets.
+    pub fn with_span_unit(mut self, span_unit: FixedWidthSpanUnit) -> Self {
+        self.span_unit = span_unit;
+        self
+    }
+
+    /// Overwrite the data types of the columns with the given names instead of inferring
+    /// them.
+    pub fn with_schema_overwrite(mut self, schema_overwrite: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema_overwrite;
+        self
+    }
+
+    /// Set the number of rows to use when inferring the data types. The default is 100 rows.
+    /// Setting to [None] will do a full table scan, which is very slow.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// Skip the first `skip_rows` lines of the file.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Set the character used to indicate an end-of-line (eol).
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    /// Set the encoding used by the file.
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null. The values are matched against
+    /// the fields after the padding is removed.
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Treat empty string fields, and fields beyond the end of a short line, as null.
+    pub fn with_missing_is_null(mut self, missing_is_null: bool) -> Self {
+        self.missing_is_null = missing_is_null;
+        self
+    }
+
+    /// Automatically try to parse dates/datetimes and time. If parsing fails,
+    /// columns remain of dtype [`DataType::String`](polars_core::prelude::DataType::String).
+    pub fn with_try_parse_dates(mut self, try_parse_dates: bool) -> Self {
+        self.try_parse_dates = try_parse_dates;
+        self
+    }
+
+    /// Parse floats with a comma as decimal separator.
+    pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Set fields that fail to parse to null instead of raising an error.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+}
@@ -0,0 +1,423 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use polars_core::prelude::*;
+
+use super::options::{FixedWidthColumn, FixedWidthReadOptions, FixedWidthSpanUnit};
+use crate::csv::read::_csv_read_internal::{NullValuesCompiled, cast_columns, prepare_csv_schema};
+use crate::csv::read::CsvEncoding;
+use crate::csv::read::buffer::{init_buffers, validate_utf8};
+use crate::csv::read::schema_inference::{finish_infer_field_schema, infer_field_schema};
+use crate::utils::compression::DecompressingReader;
+
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// Iterates over the lines of a fixed-width file without their line endings. Empty lines are
+/// skipped.
+struct Lines<'a> {
+    bytes: &'a [u8],
+    eol_char: u8,
+}
+
+impl<'a> Lines<'a> {
+    fn new(bytes: &'a [u8], eol_char: u8) -> Self {
+        Self { bytes, eol_char }
+    }
+
+    /// The bytes after the last returned line.
+    fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.bytes.is_empty() {
+            let line = match memchr::memchr(self.eol_char, self.bytes) {
+                Some(pos) => {
+                    let line = &self.bytes[..pos];
+                    self.bytes = &self.bytes[pos + 1..];
+                    line
+                },
+                None => std::mem::take(&mut self.bytes),
+            };
+            let line = match self.eol_char {
+                b'\n' => line.strip_suffix(b"\r").unwrap_or(line),
+                _ => line,
+            };
+            if !line.is_empty() {
+                return Some(line);
+            }
+        }
+        None
+    }
+}
+
+/// Count the rows in `bytes`, which must not hold a partial line at the start.
+pub fn count_rows_from_slice(bytes: &[u8], eol_char: u8) -> usize {
+    Lines::new(bytes, eol_char).count()
+}
+
+/// Count the rows of a fixed-width file.
+pub fn count_rows(bytes: &[u8], options: &FixedWidthReadOptions) -> usize {
+    let bytes = &bytes[find_starting_point(bytes, options)..];
+    count_rows_from_slice(bytes, options.eol_char)
+}
+
+/// Returns the offset of the first row, after the byte-order-mark and the skipped lines.
+pub fn find_starting_point(bytes: &[u8], options: &FixedWidthReadOptions) -> usize {
+    let mut rest = bytes.strip_prefix(BOM).unwrap_or(bytes);
+    for _ in 0..options.skip_rows {
+        rest = match memchr::memchr(options.eol_char, rest) {
+            Some(pos) => &rest[pos + 1..],
+            None => &[],
+        };
+    }
+    bytes.len() - rest.len()
+}
+
+/// Returns the length of the first `n_rows` rows of `bytes`.
+pub fn find_end_of_rows(bytes: &[u8], n_rows: usize, eol_char: u8) -> usize {
+    let mut lines = Lines::new(bytes, eol_char);
+    lines.by_ref().take(n_rows).for_each(drop);
+    bytes.len() - lines.remainder().len()
+}
+
+/// Decompress the start of a compressed file that holds the rows used to infer the schema.
+/// Returns whether the end of the file was reached. If not, `buf` ends at a line ending.
+pub fn decompress_schema_inference_prefix(
+    reader: &mut DecompressingReader,
+    options: &FixedWidthReadOptions,
+    buf: &mut Vec<u8>,
+) -> PolarsResult<bool> {
+    let Some(infer_schema_length) = options.infer_schema_length else {
+        reader.read_chunk(buf, usize::MAX)?;
+        return Ok(true);
+    };
+
+    let eol_char = options.eol_char;
+    let n_lines = options.skip_rows.saturating_add(infer_schema_length);
+    let reached_end = reader.read_lines(buf, n_lines, |bytes| {
+        match memchr::memrchr(eol_char, bytes) {
+            Some(pos) => (
+                memchr::memchr_iter(eol_char, &bytes[..pos]).count() + 1,
+                pos,
+            ),
+            None => (0, 0),
+        }
+    })?;
+    if !reached_end {
+        // Don't infer the schema from a partial line.
+        let end = memchr::memrchr(eol_char, buf).map_or(0, |pos| pos + 1);
+        buf.truncate(end);
+    }
+    Ok(reached_end)
+}
+
+/// Returns the position after the first line ending at or after `offset`.
+pub fn find_next_line_end(bytes: &[u8], offset: usize, eol_char: u8) -> Option<usize> {
+    let rest = bytes.get(offset..)?;
+    memchr::memchr(eol_char, rest).map(|pos| offset + pos + 1)
+}
+
+/// Split `bytes` into at most `n_chunks` ranges that consist of whole lines.
+pub fn get_line_chunks(bytes: &[u8], n_chunks: usize, eol_char: u8) -> Vec<Range<usize>> {
+    let chunk_size = bytes.len() / n_chunks.max(1);
+    let mut chunks = Vec::with_capacity(n_chunks);
+    let mut start = 0;
+
+    while start < bytes.len() {
+        let end = find_next_line_end(bytes, start + chunk_size, eol_char).unwrap_or(bytes.len());
+        chunks.push(start..end);
+        start = end;
+    }
+    if chunks.is_empty() {
+        chunks.push(0..0);
+    }
+    chunks
+}
+
+/// Returns the byte offsets of the characters of `line` and the length of the line, or `None`
+/// if the spans of the columns can be used as byte offsets.
+fn char_offsets<'a>(
+    line: &[u8],
+    span_unit: FixedWidthSpanUnit,
+    scratch: &'a mut Vec<usize>,
+) -> Option<&'a [usize]> {
+    if span_unit == FixedWidthSpanUnit::Bytes || line.is_ascii() {
+        return None;
+    }
+    scratch.clear();
+    scratch.extend(
+        line.iter()
+            .enumerate()
+            // Skip the continuation bytes of multi-byte characters.
+            .filter(|(_, b)| (**b & 0xC0) != 0x80)
+            .map(|(i, _)| i),
+    );
+    scratch.push(line.len());
+    Some(scratch)
+}
+
+/// Returns the field of `column` in `line` with the padding removed.
+fn get_field<'a>(
+    line: &'a [u8],
+    column: &FixedWidthColumn,
+    char_offsets: Option<&[usize]>,
+) -> &'a [u8] {
+    let (start, end) = match char_offsets {
+        None => (column.start, column.end),
+        Some(offsets) => {
+            let offset = |i: usize| offsets.get(i).copied().unwrap_or(line.len());
+            (offset(column.start), offset(column.end))
+        },
+    };
+    let end = end.min(line.len());
+    let start = start.min(end);
+    line[start..end].trim_ascii()
+}
+
+fn validate_columns(columns: &[FixedWidthColumn]) -> PolarsResult<()> {
+    polars_ensure!(
+        !columns.is_empty(),
+        InvalidOperation: "at least one column must be given to read a fixed-width file"
+    );
+    let mut names = PlHashSet::with_capacity(columns.len());
+    for column in columns {
+        polars_ensure!(
+            column.start < column.end,
+            InvalidOperation: "the span {}..{} of column '{}' is empty",
+            column.start, column.end, column.name
+        );
+        polars_ensure!(
+            names.insert(&column.name),
+            Duplicate: "column with name '{}' has more than one occurrence", column.name
+        );
+    }
+    Ok(())
+}
+
+/// Infer the data types of the columns from the first `infer_schema_length` rows of `bytes`.
+/// The columns in `schema_overwrite` are not inferred.
+pub fn infer_fixed_width_schema(
+    bytes: &[u8],
+    options: &FixedWidthReadOptions,
+) -> PolarsResult<Schema> {
+    let columns = options.columns.as_ref();
+    validate_columns(columns)?;
+
+    let overwritten_dtype = |column: &FixedWidthColumn| {
+        options
+            .schema_overwrite
+            .as_ref()
+            .and_then(|schema| schema.get(&column.name))
+    };
+    let to_infer = (0..columns.len())
+        .filter(|&i| overwritten_dtype(&columns[i]).is_none())
+        .collect::<Vec<_>>();
+    let mut possibilities = vec![PlHashSet::<DataType>::new(); columns.len()];
+
+    if !to_infer.is_empty() {
+        let string_schema = columns
+            .iter()
+            .map(|c| Field::new(c.name.clone(), DataType::String))
+            .collect::<Schema>();
+        let null_values = options
+            .null_values
+            .clone()
+            .map(|nv| nv.compile(&string_schema))
+            .transpose()?;
+
+        let mut scratch = vec![];
+        for line in Lines::new(bytes, options.eol_char)
+            .take(options.infer_schema_length.unwrap_or(usize::MAX))
+        {
+            let offsets = char_offsets(line, options.span_unit, &mut scratch);
+            for &i in &to_infer {
+                let field = get_field(line, &columns[i], offsets);
+                // SAFETY: `i` is in bounds of the schema the null values are compiled with.
+                if field.is_empty()
+                    || null_values
+                        .as_ref()
+                        .is_some_and(|nv| unsafe { nv.is_null(field, i) })
+                {
+                    continue;
+                }
+                let string = match options.encoding {
+                    CsvEncoding::Utf8 => match simdutf8::basic::from_utf8(field) {
+                        Ok(s) => Cow::Borrowed(s),
+                        Err(_) => {
+                            possibilities[i].insert(DataType::String);
+                            continue;
+                        },
+                    },
+                    CsvEncoding::LossyUtf8 => String::from_utf8_lossy(field),
+                };
+                possibilities[i].insert(infer_field_schema(
+                    &string,
+                    options.try_parse_dates,
+                    options.decimal_comma,
+                ));
+            }
+        }
+    }
+
+    Ok(columns
+        .iter()
+        .zip(possibilities)
+        .map(|(column, possibilities)| {
+            let dtype = match overwritten_dtype(column) {
+                Some(dtype) => dtype.clone(),
+                None if possibilities.is_empty() => DataType::String,
+                None => finish_infer_field_schema(&possibilities),
+            };
+            Field::new(column.name.clone(), dtype)
+        })
+        .collect())
+}
+
+/// Parses the rows of a fixed-width file into a [`DataFrame`].
+pub struct FixedWidthChunkParser {
+    columns: Arc<[FixedWidthColumn]>,
+    span_unit: FixedWidthSpanUnit,
+    schema: SchemaRef,
+    fields_to_cast: Vec<Field>,
+    projection: Vec<usize>,
+    null_values: Option<NullValuesCompiled>,
+    encoding: CsvEncoding,
+    eol_char: u8,
+    missing_is_null: bool,
+    decimal_comma: bool,
+    ignore_errors: bool,
+    validate_utf8: bool,
+}
+
+impl FixedWidthChunkParser {
+    /// `schema` is the schema of the file, and `projection` holds the indices of the columns
+    /// to parse.
+    pub fn try_new(
+        options: &FixedWidthReadOptions,
+        mut schema: SchemaRef,
+        projection: Vec<usize>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            schema.len() == options.columns.len(),
+            SchemaMismatch: "expected a schema with {} columns, got {}",
+            options.columns.len(), schema.len()
+        );
+
+        // Data types that the buffers cannot parse are parsed as strings and cast afterwards.
+        let mut fields_to_cast = vec![];
+        prepare_csv_schema(&mut schema, &mut fields_to_cast)?;
+
+        let null_values = options
+            .null_values
+            .clone()
+            .map(|nv| nv.compile(&schema))
+            .transpose()?;
+        let validate_utf8 = matches!(options.encoding, CsvEncoding::Utf8)
+            && projection.iter().any(|&i| {
+                let dtype = schema.get_at_index(i).unwrap().1;
+                dtype.is_string() || dtype.is_categorical() || dtype.is_enum()
+            });
+
+        Ok(Self {
+            columns: options.columns.clone(),
+            span_unit: options.span_unit,
+            schema,
+            fields_to_cast,
+            projection,
+            null_values,
+            encoding: options.encoding,
+            eol_char: options.eol_char,
+            missing_is_null: options.missing_is_null,
+            decimal_comma: options.decimal_comma,
+            ignore_errors: options.ignore_errors,
+            validate_utf8,
+        })
+    }
+
+    /// Parse all rows in `bytes`, which must consist of whole lines.
+    pub fn parse(&self, bytes: &[u8]) -> PolarsResult<DataFrame> {
+        if self.projection.is_empty() {
+            return Ok(DataFrame::empty_with_height(count_rows_from_slice(
+                bytes,
+                self.eol_char,
+            )));
+        }
+        if self.validate_utf8 && !validate_utf8(bytes) {
+            polars_bail!(ComputeError: "invalid utf-8 sequence")
+        }
+
+        let capacity = memchr::memchr_iter(self.eol_char, bytes).count() + 1;
+        let mut buffers = init_buffers(
+            &self.projection,
+            capacity,
+            &self.schema,
+            None,
+            self.encoding,
+            self.decimal_comma,
+        )?;
+
+        let mut scratch = vec![];
+        for line in Lines::new(bytes, self.eol_char) {
+            let offsets = char_offsets(line, self.span_unit, &mut scratch);
+            // Byte spans may split a multi-byte character, so the fields of lines that are not
+            // ASCII are validated separately.
+            let validate_fields = self.validate_utf8 && offsets.is_none() && !line.is_ascii();
+
+            for (buf, &i) in buffers.iter_mut().zip(&self.projection) {
+                let column = &self.columns[i];
+                let field = get_field(line, column, offsets);
+
+                // SAFETY: `i` is in bounds of the schema the null values are compiled with.
+                if self
+                    .null_values
+                    .as_ref()
+                    .is_some_and(|nv| unsafe { nv.is_null(field, i) })
+                {
+                    buf.add_null(!self.missing_is_null && field.is_empty());
+                    continue;
+                }
+                if validate_fields && !validate_utf8(field) {
+                    polars_ensure!(
+                        self.ignore_errors,
+                        ComputeError: "the span of column '{}' splits a multi-byte character",
+                        column.name
+                    );
+                    buf.add_null(false);
+                    continue;
+                }
+
+                buf.add(field, self.ignore_errors, false, self.missing_is_null)
+                    .map_err(|e| {
+                        polars_err!(
+                            ComputeError:
+                            "could not parse `{}` as dtype `{}` at column '{}'\n\n\
+                            You might want to try:\n\
+                            - increasing `infer_schema_length` (e.g. `infer_schema_length=10000`),\n\
+                            - specifying correct dtype with the `schema_overrides` argument\n\
+                            - setting `ignore_errors` to `True`,\n\
+                            - adding `{}` to the `null_values` list.\n\n\
+                            Original error: ```{}```",
+                            String::from_utf8_lossy(field),
+                            buf.dtype(),
+                            column.name,
+                            String::from_utf8_lossy(field),
+                            e
+                        )
+                    })?;
+            }
+        }
+
+        let columns = buffers
+            .into_iter()
+            .map(|buf| buf.into_series().map(Column::from))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut df = unsafe { DataFrame::new_no_checks_height_from_first(columns) };
+        cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)?;
+        Ok(df)
+    }
+}
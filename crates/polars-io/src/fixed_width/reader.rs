@@ -0,0 +1,153 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use rayon::prelude::*;
+
+use super::options::FixedWidthReadOptions;
+use super::parser::{
+    FixedWidthChunkParser, find_end_of_rows, find_starting_point, get_line_chunks,
+    infer_fixed_width_schema,
+};
+use crate::RowIndex;
+use crate::mmap::MmapBytesReader;
+use crate::shared::SerReader;
+use crate::utils::compression::maybe_decompress_bytes;
+use crate::utils::get_reader_bytes;
+
+/// Read a fixed-width text file into a [`DataFrame`].
+///
+/// Every line of the file is a row, and every column is located at the same span of each
+/// line. The padding around the fields is removed before they are parsed.
+///
+/// # Example
+///
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::fixed_width::{FixedWidthColumn, FixedWidthReadOptions, FixedWidthReader};
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("accounts.txt").expect("file not found");
+///     let columns = [
+///         FixedWidthColumn::new("id".into(), 0, 8),
+///         FixedWidthColumn::new("balance".into(), 8, 20),
+///     ];
+///
+///     FixedWidthReader::new(file)
+///         .with_options(FixedWidthReadOptions::default().with_columns(columns.into()))
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct FixedWidthReader<R> {
+    reader: R,
+    options: FixedWidthReadOptions,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<PlSmallStr>>,
+    projection: Option<Vec<usize>>,
+    row_index: Option<RowIndex>,
+}
+
+impl<R: MmapBytesReader> FixedWidthReader<R> {
+    /// Set the options used to parse the file.
+    pub fn with_options(mut self, options: FixedWidthReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<PlSmallStr>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Get the schema of the file, inferring the data types of the columns that are not in
+    /// the `schema_overwrite` of the options.
+    pub fn schema(&mut self) -> PolarsResult<Schema> {
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut out = vec![];
+        let bytes = maybe_decompress_bytes(&reader_bytes, &mut out)?;
+        let bytes = &bytes[find_starting_point(bytes, &self.options)..];
+        infer_fixed_width_schema(bytes, &self.options)
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for FixedWidthReader<R> {
+    fn new(reader: R) -> Self {
+        FixedWidthReader {
+            reader,
+            options: FixedWidthReadOptions::default(),
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            row_index: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut out = vec![];
+        let bytes = maybe_decompress_bytes(&reader_bytes, &mut out)?;
+        let mut bytes = &bytes[find_starting_point(bytes, &self.options)..];
+        if let Some(n_rows) = self.n_rows {
+            bytes = &bytes[..find_end_of_rows(bytes, n_rows, self.options.eol_char)];
+        }
+
+        let schema = infer_fixed_width_schema(bytes, &self.options)?;
+        let projection = match (&self.columns, self.projection) {
+            (Some(columns), _) => columns
+                .iter()
+                .map(|name| schema.try_index_of(name))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            (None, Some(projection)) => projection,
+            (None, None) => (0..schema.len()).collect(),
+        };
+        let parser = FixedWidthChunkParser::try_new(&self.options, Arc::new(schema), projection)?;
+
+        // Split the rows into more chunks than threads to balance the work.
+        let n_chunks = POOL.current_num_threads() * 4;
+        let chunks = get_line_chunks(bytes, n_chunks, self.options.eol_char);
+        let dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|range| parser.parse(&bytes[range]))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+        if let Some(row_index) = &self.row_index {
+            df = df.with_row_index(row_index.name.clone(), Some(row_index.offset))?;
+        }
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
@@ -16,6 +16,8 @@ pub mod cloud;
 pub mod csv;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "fixed_width")]
+pub mod fixed_width;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
@@ -24,6 +26,8 @@ pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 #[cfg(feature = "parquet")]
@@ -0,0 +1,142 @@
+//! ORC streams and the file tail are compressed in chunks of at most the compression block size,
+//! each prefixed by a 3 byte header holding the length of the chunk and whether it is stored
+//! uncompressed.
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure, to_compute_err};
+
+use super::proto::CompressionKind;
+
+const HEADER_LEN: usize = 3;
+
+pub(super) fn decompress(
+    compression: CompressionKind,
+    block_size: usize,
+    mut buf: &[u8],
+) -> PolarsResult<Cow<'_, [u8]>> {
+    if compression == CompressionKind::None {
+        return Ok(Cow::Borrowed(buf));
+    }
+
+    let mut out = Vec::with_capacity(buf.len() * 2);
+    while !buf.is_empty() {
+        polars_ensure!(
+            buf.len() >= HEADER_LEN,
+            oos = "truncated ORC compression chunk header"
+        );
+        let header = u32::from_le_bytes([buf[0], buf[1], buf[2], 0]);
+        let is_original = header & 1 == 1;
+        let len = (header >> 1) as usize;
+        buf = &buf[HEADER_LEN..];
+        polars_ensure!(buf.len() >= len, oos = "truncated ORC compression chunk");
+        let (chunk, rest) = buf.split_at(len);
+        buf = rest;
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+        match compression {
+            CompressionKind::None => unreachable!(),
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk).read_to_end(&mut out)?;
+            },
+            CompressionKind::Snappy => {
+                let decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(to_compute_err)?;
+                out.extend_from_slice(&decompressed);
+            },
+            CompressionKind::Zstd => {
+                zstd::stream::read::Decoder::with_buffer(chunk)?.read_to_end(&mut out)?;
+            },
+            CompressionKind::Lz4 => {
+                let decompressed =
+                    lz4_flex::block::decompress(chunk, block_size).map_err(to_compute_err)?;
+                out.extend_from_slice(&decompressed);
+            },
+            CompressionKind::Lzo => {
+                polars_bail!(ComputeError: "LZO compressed ORC files are not supported")
+            },
+        }
+    }
+    Ok(Cow::Owned(out))
+}
+
+pub(super) fn compress(
+    compression: CompressionKind,
+    block_size: usize,
+    buf: &[u8],
+    out: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    if compression == CompressionKind::None {
+        out.extend_from_slice(buf);
+        return Ok(());
+    }
+
+    let mut compressed = Vec::new();
+    for chunk in buf.chunks(block_size) {
+        compressed.clear();
+        match compression {
+            CompressionKind::None | CompressionKind::Lzo => unreachable!(),
+            CompressionKind::Zlib => {
+                let mut encoder = flate2::write::DeflateEncoder::new(
+                    &mut compressed,
+                    flate2::Compression::default(),
+                );
+                encoder.write_all(chunk)?;
+                encoder.finish()?;
+            },
+            CompressionKind::Snappy => {
+                compressed = snap::raw::Encoder::new()
+                    .compress_vec(chunk)
+                    .map_err(to_compute_err)?;
+            },
+            CompressionKind::Zstd => {
+                compressed = zstd::bulk::compress(chunk, zstd::DEFAULT_COMPRESSION_LEVEL)?;
+            },
+            CompressionKind::Lz4 => compressed = lz4_flex::block::compress(chunk),
+        }
+
+        // Chunks that don't get smaller are stored as is.
+        let (header, data) = if compressed.len() < chunk.len() {
+            ((compressed.len() as u32) << 1, compressed.as_slice())
+        } else {
+            (((chunk.len() as u32) << 1) | 1, chunk)
+        };
+        out.extend_from_slice(&header.to_le_bytes()[..HEADER_LEN]);
+        out.extend_from_slice(data);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let data = (0..100_000u32)
+            .flat_map(|i| (i % 251).to_le_bytes())
+            .collect::<Vec<_>>();
+        for compression in [
+            CompressionKind::None,
+            CompressionKind::Zlib,
+            CompressionKind::Snappy,
+            CompressionKind::Zstd,
+            CompressionKind::Lz4,
+        ] {
+            let mut compressed = Vec::new();
+            compress(compression, 64 * 1024, &data, &mut compressed).unwrap();
+            let decompressed = decompress(compression, 64 * 1024, &compressed).unwrap();
+            assert_eq!(decompressed.as_ref(), data.as_slice(), "{compression:?}");
+        }
+    }
+
+    #[test]
+    fn test_truncated() {
+        let err = decompress(CompressionKind::Zstd, 1024, &[10, 0]).unwrap_err();
+        assert!(err.to_string().contains("truncated"));
+    }
+}
@@ -0,0 +1,444 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use arrow::array::{
+    Array, BooleanArray, ListArray, MutableBinaryViewArray, PrimitiveArray, StructArray,
+};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+use arrow::offset::Offsets;
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+
+use super::OrcMetadata;
+use super::codec::decompress;
+use super::proto::{ColumnEncoding, OrcType, StreamKind, StripeFooter, TypeKind};
+use super::rle::{decode_bools, decode_bytes, decode_ints};
+
+/// Seconds between the unix epoch and the ORC timestamp epoch, 2015-01-01.
+pub(super) const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+impl OrcMetadata {
+    /// Decode the given top-level columns of a stripe, or all columns if `projection` is
+    /// `None`. The columns of the output are in the order of the `projection`.
+    pub fn read_stripe(
+        &self,
+        bytes: &[u8],
+        stripe: usize,
+        projection: Option<&[usize]>,
+    ) -> PolarsResult<DataFrame> {
+        let info = &self.footer.stripes[stripe];
+        let num_rows = info.number_of_rows as usize;
+        let start = info.offset as usize;
+        let end = start + info.length() as usize;
+        polars_ensure!(
+            end <= bytes.len(),
+            oos = "ORC stripe {} is out of bounds",
+            stripe
+        );
+        let stripe_bytes = &bytes[start..end];
+
+        let footer_start = (info.index_length + info.data_length) as usize;
+        let footer = StripeFooter::decode(&self.decompress(&stripe_bytes[footer_start..])?)?;
+        let mut streams = PlHashMap::with_capacity(footer.streams.len());
+        let mut offset = 0;
+        for stream in &footer.streams {
+            let end = offset + stream.length as usize;
+            polars_ensure!(end <= footer_start, oos = "ORC stream is out of bounds");
+            if stream.kind != StreamKind::Other {
+                streams.insert((stream.column, stream.kind), offset..end);
+            }
+            offset = end;
+        }
+        let decoder = StripeDecoder {
+            metadata: self,
+            bytes: stripe_bytes,
+            streams,
+            encodings: footer.columns,
+        };
+
+        let fields = self.arrow_schema();
+        let columns = match projection {
+            Some(projection) => Cow::Borrowed(projection),
+            None => Cow::Owned((0..fields.len()).collect()),
+        };
+        let columns = columns
+            .iter()
+            .map(|&i| {
+                let (name, field) = fields.get_at_index(i).unwrap();
+                let array =
+                    decoder.decode_column(self.field_columns()[i], &field.dtype, num_rows, None)?;
+                Ok(Series::try_from((name.clone(), array))?.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new_with_height(num_rows, columns)
+    }
+
+    fn decompress<'a>(&self, bytes: &'a [u8]) -> PolarsResult<Cow<'a, [u8]>> {
+        decompress(
+            self.postscript.compression,
+            self.postscript.compression_block_size as usize,
+            bytes,
+        )
+    }
+}
+
+struct StripeDecoder<'a> {
+    metadata: &'a OrcMetadata,
+    bytes: &'a [u8],
+    streams: PlHashMap<(u32, StreamKind), Range<usize>>,
+    encodings: Vec<ColumnEncoding>,
+}
+
+impl<'a> StripeDecoder<'a> {
+    fn ty(&self, column: u32) -> &'a OrcType {
+        &self.metadata.footer.types[column as usize]
+    }
+
+    fn encoding(&self, column: u32) -> ColumnEncoding {
+        self.encodings
+            .get(column as usize)
+            .copied()
+            .unwrap_or(ColumnEncoding::DIRECT)
+    }
+
+    fn stream(&self, column: u32, kind: StreamKind) -> PolarsResult<Option<Cow<'a, [u8]>>> {
+        self.streams
+            .get(&(column, kind))
+            .map(|range| self.metadata.decompress(&self.bytes[range.clone()]))
+            .transpose()
+    }
+
+    /// A stream that is only left out if it is empty.
+    fn stream_or_empty(&self, column: u32, kind: StreamKind) -> PolarsResult<Cow<'a, [u8]>> {
+        Ok(self.stream(column, kind)?.unwrap_or_default())
+    }
+
+    fn decode_ints(
+        &self,
+        column: u32,
+        kind: StreamKind,
+        n: usize,
+        signed: bool,
+    ) -> PolarsResult<Vec<i64>> {
+        let v2 = self.encoding(column).is_v2();
+        decode_ints(&self.stream_or_empty(column, kind)?, n, signed, v2)
+    }
+
+    /// The validity of a column. A column has no values for the rows in which its parent is
+    /// null, so the present stream only holds the rows in which the parent is valid.
+    fn decode_validity(
+        &self,
+        column: u32,
+        len: usize,
+        parent_validity: Option<&Bitmap>,
+    ) -> PolarsResult<Option<Bitmap>> {
+        let Some(present) = self.stream(column, StreamKind::Present)? else {
+            return Ok(parent_validity.cloned());
+        };
+        let n = parent_validity.map_or(len, |v| v.set_bits());
+        let present = decode_bools(&present, n)?;
+        let validity = match parent_validity {
+            None => present,
+            Some(parent_validity) => {
+                let mut present = present.iter();
+                parent_validity
+                    .iter()
+                    .map(|valid| valid && present.next().unwrap_or(false))
+                    .collect()
+            },
+        };
+        Ok((validity.unset_bits() > 0).then_some(validity))
+    }
+
+    fn decode_column(
+        &self,
+        column: u32,
+        dtype: &ArrowDataType,
+        len: usize,
+        parent_validity: Option<&Bitmap>,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let validity = self.decode_validity(column, len, parent_validity)?;
+        let n = validity.as_ref().map_or(len, |v| v.set_bits());
+        let ty = self.ty(column);
+
+        Ok(match ty.kind {
+            TypeKind::Boolean => {
+                let values = decode_bools(&self.stream_or_empty(column, StreamKind::Data)?, n)?;
+                let values = match &validity {
+                    None => values,
+                    Some(validity) => {
+                        let mut values = values.iter();
+                        validity
+                            .iter()
+                            .map(|valid| valid && values.next().unwrap_or(false))
+                            .collect()
+                    },
+                };
+                BooleanArray::new(dtype.clone(), values, validity).boxed()
+            },
+            TypeKind::Byte => {
+                let values = decode_bytes(&self.stream_or_empty(column, StreamKind::Data)?, n)?;
+                primitive(dtype, values.into_iter().map(|v| v as i8), validity)
+            },
+            TypeKind::Short => {
+                let values = self.decode_ints(column, StreamKind::Data, n, true)?;
+                primitive(dtype, values.into_iter().map(|v| v as i16), validity)
+            },
+            TypeKind::Int | TypeKind::Date => {
+                let values = self.decode_ints(column, StreamKind::Data, n, true)?;
+                primitive(dtype, values.into_iter().map(|v| v as i32), validity)
+            },
+            TypeKind::Long => {
+                let values = self.decode_ints(column, StreamKind::Data, n, true)?;
+                primitive(dtype, values, validity)
+            },
+            TypeKind::Float => {
+                let data = self.stream_or_empty(column, StreamKind::Data)?;
+                polars_ensure!(data.len() >= n * 4, oos = "truncated ORC float column");
+                let values = data
+                    .chunks_exact(4)
+                    .take(n)
+                    .map(|b| f32::from_le_bytes(b.try_into().unwrap()));
+                primitive(dtype, values, validity)
+            },
+            TypeKind::Double => {
+                let data = self.stream_or_empty(column, StreamKind::Data)?;
+                polars_ensure!(data.len() >= n * 8, oos = "truncated ORC double column");
+                let values = data
+                    .chunks_exact(8)
+                    .take(n)
+                    .map(|b| f64::from_le_bytes(b.try_into().unwrap()));
+                primitive(dtype, values, validity)
+            },
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char => {
+                let array = self.decode_binary(column, n, validity.as_ref(), len)?;
+                array
+                    .to_utf8view()
+                    .map_err(|_| polars_err!(ComputeError: "invalid utf-8 in ORC string column"))?
+                    .boxed()
+            },
+            TypeKind::Binary => self
+                .decode_binary(column, n, validity.as_ref(), len)?
+                .boxed(),
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = self.decode_ints(column, StreamKind::Data, n, true)?;
+                let nanos = self.decode_ints(column, StreamKind::Secondary, n, false)?;
+                let values = seconds.into_iter().zip(nanos).map(|(seconds, nanos)| {
+                    let mut seconds = seconds.wrapping_add(ORC_EPOCH_SECONDS);
+                    let nanos = decode_nanos(nanos as u64);
+                    // Java wrote the seconds of timestamps before the epoch rounded towards
+                    // zero.
+                    if seconds < 0 && nanos > 999_999 {
+                        seconds -= 1;
+                    }
+                    seconds.wrapping_mul(1_000_000_000).wrapping_add(nanos)
+                });
+                primitive(dtype, values, validity)
+            },
+            TypeKind::Decimal => {
+                let ArrowDataType::Decimal(_, scale) = dtype else {
+                    unreachable!()
+                };
+                let mut data: &[u8] = &self.stream_or_empty(column, StreamKind::Data)?;
+                let scales = self.decode_ints(column, StreamKind::Secondary, n, true)?;
+                let values = scales
+                    .into_iter()
+                    .map(|value_scale| {
+                        let value = read_varint_i128(&mut data)?;
+                        Ok(rescale(value, value_scale, *scale as i64))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                primitive(dtype, values, validity)
+            },
+            TypeKind::Struct => {
+                let ArrowDataType::Struct(fields) = dtype else {
+                    unreachable!()
+                };
+                let children = ty
+                    .subtypes
+                    .iter()
+                    .zip(fields)
+                    .map(|(&child, field)| {
+                        self.decode_column(child, &field.dtype, len, validity.as_ref())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                StructArray::new(dtype.clone(), len, children, validity).boxed()
+            },
+            TypeKind::List | TypeKind::Map => {
+                let ArrowDataType::LargeList(field) = dtype else {
+                    unreachable!()
+                };
+                let lengths = self.decode_ints(column, StreamKind::Length, n, false)?;
+                let offsets = Offsets::<i64>::try_from_iter(
+                    rows_with_values(validity.as_ref(), len, lengths.into_iter())
+                        .map(|length| length.unwrap_or(0) as usize),
+                )?;
+                let child_len = *offsets.last() as usize;
+                let values = if ty.kind == TypeKind::List {
+                    self.decode_column(ty.subtypes[0], &field.dtype, child_len, None)?
+                } else {
+                    let ArrowDataType::Struct(entry_fields) = &field.dtype else {
+                        unreachable!()
+                    };
+                    let entries = ty
+                        .subtypes
+                        .iter()
+                        .zip(entry_fields)
+                        .map(|(&child, field)| {
+                            self.decode_column(child, &field.dtype, child_len, None)
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    StructArray::new(field.dtype.clone(), child_len, entries, None).boxed()
+                };
+                ListArray::<i64>::new(dtype.clone(), offsets.into(), values, validity).boxed()
+            },
+            TypeKind::Union => {
+                polars_bail!(ComputeError: "reading ORC union columns is not supported")
+            },
+        })
+    }
+
+    /// Decode a string or binary column, which is either directly or dictionary encoded.
+    fn decode_binary(
+        &self,
+        column: u32,
+        n: usize,
+        validity: Option<&Bitmap>,
+        len: usize,
+    ) -> PolarsResult<arrow::array::BinaryViewArray> {
+        let encoding = self.encoding(column);
+        let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(len);
+
+        if encoding.is_dictionary() {
+            let dict_size = encoding.dictionary_size as usize;
+            let lengths = self.decode_ints(column, StreamKind::Length, dict_size, false)?;
+            let dict_data = self.stream_or_empty(column, StreamKind::DictionaryData)?;
+            let dict = split_values(&dict_data, &lengths)?;
+            let indices = self.decode_ints(column, StreamKind::Data, n, false)?;
+            for index in rows_with_values(validity, len, indices.into_iter()) {
+                match index {
+                    None => out.push_null(),
+                    Some(index) => out.push_value(
+                        dict.get(index as usize)
+                            .ok_or_else(|| polars_err!(oos = "invalid ORC dictionary index"))?,
+                    ),
+                }
+            }
+        } else {
+            let lengths = self.decode_ints(column, StreamKind::Length, n, false)?;
+            let data = self.stream_or_empty(column, StreamKind::Data)?;
+            let mut values = split_values(&data, &lengths)?.into_iter();
+            for valid in rows_with_values(validity, len, std::iter::repeat(())) {
+                match valid {
+                    None => out.push_null(),
+                    Some(()) => out.push_value(values.next().unwrap()),
+                }
+            }
+        }
+        Ok(out.freeze())
+    }
+}
+
+/// Interleave the values of the valid rows with `None` for the null rows.
+fn rows_with_values<T>(
+    validity: Option<&Bitmap>,
+    len: usize,
+    mut values: impl Iterator<Item = T>,
+) -> impl Iterator<Item = Option<T>> {
+    (0..len).map(move |i| {
+        if validity.is_none_or(|v| v.get_bit(i)) {
+            values.next()
+        } else {
+            None
+        }
+    })
+}
+
+fn primitive<T: NativeType>(
+    dtype: &ArrowDataType,
+    values: impl IntoIterator<Item = T>,
+    validity: Option<Bitmap>,
+) -> Box<dyn Array> {
+    let values: Vec<T> = match &validity {
+        None => values.into_iter().collect(),
+        Some(validity) => rows_with_values(Some(validity), validity.len(), values.into_iter())
+            .map(Option::unwrap_or_default)
+            .collect(),
+    };
+    PrimitiveArray::new(dtype.clone(), values.into(), validity).boxed()
+}
+
+fn split_values<'b>(data: &'b [u8], lengths: &[i64]) -> PolarsResult<Vec<&'b [u8]>> {
+    let mut offset = 0;
+    lengths
+        .iter()
+        .map(|&length| {
+            let end = offset + length as usize;
+            polars_ensure!(
+                length >= 0 && end <= data.len(),
+                oos = "ORC value lengths exceed the data"
+            );
+            let value = &data[offset..end];
+            offset = end;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// The trailing decimal zeros of nanoseconds are stored in the 3 lowest bits.
+fn decode_nanos(encoded: u64) -> i64 {
+    let zeros = (encoded & 7) as u32;
+    let nanos = (encoded >> 3) as i64;
+    if zeros == 0 {
+        nanos
+    } else {
+        nanos * 10i64.pow(zeros + 1)
+    }
+}
+
+pub(super) fn encode_nanos(mut nanos: i64) -> u64 {
+    if nanos == 0 || nanos % 100 != 0 {
+        return (nanos as u64) << 3;
+    }
+    nanos /= 100;
+    let mut zeros = 1;
+    while nanos % 10 == 0 && zeros < 7 {
+        nanos /= 10;
+        zeros += 1;
+    }
+    ((nanos as u64) << 3) | zeros
+}
+
+fn read_varint_i128(buf: &mut &[u8]) -> PolarsResult<i128> {
+    let mut value = 0u128;
+    for shift in (0..128).step_by(7) {
+        let (&byte, rest) = buf
+            .split_first()
+            .ok_or_else(|| polars_err!(oos = "unexpected end of ORC decimal column"))?;
+        *buf = rest;
+        value |= ((byte & 0x7F) as u128) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(((value >> 1) as i128) ^ -((value & 1) as i128));
+        }
+    }
+    polars_bail!(oos = "ORC decimal is longer than 128 bits")
+}
+
+pub(super) fn write_varint_i128(buf: &mut Vec<u8>, value: i128) {
+    let mut value = ((value << 1) ^ (value >> 127)) as u128;
+    while value >= 0x80 {
+        buf.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Every decimal value is stored with its own scale.
+fn rescale(value: i128, from: i64, to: i64) -> i128 {
+    match from.cmp(&to) {
+        std::cmp::Ordering::Equal => value,
+        std::cmp::Ordering::Less => value.wrapping_mul(10i128.pow((to - from).min(38) as u32)),
+        std::cmp::Ordering::Greater => value / 10i128.pow((from - to).min(38) as u32),
+    }
+}
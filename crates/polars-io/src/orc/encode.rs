@@ -0,0 +1,392 @@
+use arrow::array::{
+    Array, BinaryViewArray, BooleanArray, ListArray, PrimitiveArray, StructArray, Utf8ViewArray,
+};
+use arrow::datatypes::{ArrowDataType, TimeUnit};
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+
+use super::decode::{ORC_EPOCH_SECONDS, encode_nanos, write_varint_i128};
+use super::proto::{ColumnEncoding, ColumnStatistics, MinMax, OrcType, StreamKind, TypeKind};
+use super::rle::{encode_bools, encode_bytes, encode_ints};
+
+/// Longer strings are left out of the statistics.
+const MAX_STRING_STATISTICS_LEN: usize = 1024;
+
+/// The data type a column is cast to before it is written, as not all data types have an ORC
+/// counterpart.
+pub(super) fn to_orc_compatible_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    Ok(match dtype {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::String
+        | DataType::Binary => dtype.clone(),
+        DataType::UInt8 => DataType::Int16,
+        DataType::UInt16 => DataType::Int32,
+        DataType::UInt32 | DataType::UInt64 => DataType::Int64,
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => DataType::String,
+        DataType::Date | DataType::Datetime(_, _) | DataType::Decimal(_, _) => dtype.clone(),
+        DataType::List(inner) => DataType::List(Box::new(to_orc_compatible_dtype(inner)?)),
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|field| {
+                    Ok(Field::new(
+                        field.name.clone(),
+                        to_orc_compatible_dtype(&field.dtype)?,
+                    ))
+                })
+                .collect::<PolarsResult<_>>()?,
+        ),
+        dtype => polars_bail!(InvalidOperation: "cannot write data type {} to ORC", dtype),
+    })
+}
+
+/// Add the ORC types of a (compatible) data type to `types` in pre-order.
+pub(super) fn push_orc_types(dtype: &DataType, types: &mut Vec<OrcType>) {
+    let kind = match dtype {
+        DataType::Boolean => TypeKind::Boolean,
+        DataType::Int8 => TypeKind::Byte,
+        DataType::Int16 => TypeKind::Short,
+        DataType::Int32 => TypeKind::Int,
+        DataType::Int64 => TypeKind::Long,
+        DataType::Float32 => TypeKind::Float,
+        DataType::Float64 => TypeKind::Double,
+        DataType::String => TypeKind::String,
+        DataType::Binary => TypeKind::Binary,
+        DataType::Date => TypeKind::Date,
+        DataType::Datetime(_, None) => TypeKind::Timestamp,
+        DataType::Datetime(_, Some(_)) => TypeKind::TimestampInstant,
+        DataType::Decimal(precision, scale) => {
+            let mut ty = OrcType::new(TypeKind::Decimal);
+            ty.precision = precision.unwrap_or(38) as u32;
+            ty.scale = scale.unwrap_or(0) as u32;
+            types.push(ty);
+            return;
+        },
+        DataType::List(inner) => {
+            let index = types.len();
+            types.push(OrcType::new(TypeKind::List));
+            types[index].subtypes.push(types.len() as u32);
+            push_orc_types(inner, types);
+            return;
+        },
+        DataType::Struct(fields) => {
+            let index = types.len();
+            types.push(OrcType::new(TypeKind::Struct));
+            for field in fields {
+                types[index].subtypes.push(types.len() as u32);
+                types[index].field_names.push(field.name.clone());
+                push_orc_types(&field.dtype, types);
+            }
+            return;
+        },
+        dtype => unreachable!("{dtype} is not ORC compatible"),
+    };
+    types.push(OrcType::new(kind));
+}
+
+#[derive(Debug, Default)]
+pub(super) struct EncodedStripe {
+    pub streams: Vec<(u32, StreamKind, Vec<u8>)>,
+    pub encodings: Vec<ColumnEncoding>,
+    pub statistics: Vec<ColumnStatistics>,
+}
+
+impl EncodedStripe {
+    pub(super) fn new(types: &[OrcType], num_rows: usize) -> Self {
+        let mut statistics = vec![ColumnStatistics::default(); types.len()];
+        statistics[0] = ColumnStatistics {
+            number_of_values: Some(num_rows as u64),
+            has_null: Some(false),
+            min_max: None,
+        };
+        Self {
+            streams: Vec::new(),
+            encodings: vec![ColumnEncoding::DIRECT; types.len()],
+            statistics,
+        }
+    }
+
+    /// Encode the values of `array` in the given rows, or all rows if `rows` is `None`.
+    ///
+    /// A column has no values in the rows in which its parent is null, so the rows of
+    /// children of nested columns are selected by their parents.
+    pub(super) fn encode_column(
+        &mut self,
+        types: &[OrcType],
+        column: u32,
+        array: &dyn Array,
+        rows: Option<&[usize]>,
+    ) -> PolarsResult<()> {
+        let rows = match rows {
+            Some(rows) => rows.to_vec(),
+            None => (0..array.len()).collect(),
+        };
+        let valid_rows = rows
+            .iter()
+            .copied()
+            .filter(|&i| array.is_valid(i))
+            .collect::<Vec<_>>();
+        let has_null = valid_rows.len() < rows.len();
+        if has_null {
+            let mut present = Vec::new();
+            encode_bools(rows.iter().map(|&i| array.is_valid(i)), &mut present);
+            self.push_stream(column, StreamKind::Present, present);
+        }
+
+        let ty = &types[column as usize];
+        let min_max = match ty.kind {
+            TypeKind::Boolean => {
+                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                let mut data = Vec::new();
+                encode_bools(valid_rows.iter().map(|&i| array.value(i)), &mut data);
+                self.push_stream(column, StreamKind::Data, data);
+                None
+            },
+            TypeKind::Byte => {
+                let values = primitive_values::<i8>(array, &valid_rows);
+                let mut data = Vec::new();
+                encode_bytes(
+                    &values.iter().map(|&v| v as u8).collect::<Vec<_>>(),
+                    &mut data,
+                );
+                self.push_stream(column, StreamKind::Data, data);
+                int_min_max(values.iter().map(|&v| v as i64))
+            },
+            TypeKind::Short => {
+                let values = primitive_values::<i16>(array, &valid_rows);
+                self.push_ints(column, StreamKind::Data, values.iter().map(|&v| v as i64));
+                int_min_max(values.iter().map(|&v| v as i64))
+            },
+            TypeKind::Int => {
+                let values = primitive_values::<i32>(array, &valid_rows);
+                self.push_ints(column, StreamKind::Data, values.iter().map(|&v| v as i64));
+                int_min_max(values.iter().map(|&v| v as i64))
+            },
+            TypeKind::Long => {
+                let values = primitive_values::<i64>(array, &valid_rows);
+                self.push_ints(column, StreamKind::Data, values.iter().copied());
+                int_min_max(values.iter().copied())
+            },
+            TypeKind::Date => {
+                let values = primitive_values::<i32>(array, &valid_rows);
+                self.push_ints(column, StreamKind::Data, values.iter().map(|&v| v as i64));
+                let min = values.iter().min();
+                let max = values.iter().max();
+                min.zip(max).map(|(&min, &max)| MinMax::Date(min, max))
+            },
+            TypeKind::Float => {
+                let values = primitive_values::<f32>(array, &valid_rows);
+                let data = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+                self.push_stream(column, StreamKind::Data, data);
+                double_min_max(values.iter().map(|&v| v as f64))
+            },
+            TypeKind::Double => {
+                let values = primitive_values::<f64>(array, &valid_rows);
+                let data = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+                self.push_stream(column, StreamKind::Data, data);
+                double_min_max(values.iter().copied())
+            },
+            TypeKind::String => {
+                let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+                let values = valid_rows
+                    .iter()
+                    .map(|&i| array.value(i))
+                    .collect::<Vec<_>>();
+                self.push_binary(column, values.iter().map(|v| v.as_bytes()));
+                let min = values.iter().min();
+                let max = values.iter().max();
+                min.zip(max)
+                    .filter(|(min, max)| min.len().max(max.len()) <= MAX_STRING_STATISTICS_LEN)
+                    .map(|(min, max)| MinMax::String((*min).into(), (*max).into()))
+            },
+            TypeKind::Binary => {
+                let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+                self.push_binary(column, valid_rows.iter().map(|&i| array.value(i)));
+                None
+            },
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let ArrowDataType::Timestamp(tu, _) = array.dtype() else {
+                    unreachable!()
+                };
+                let units_per_second = match tu {
+                    TimeUnit::Second => 1,
+                    TimeUnit::Millisecond => 1_000,
+                    TimeUnit::Microsecond => 1_000_000,
+                    TimeUnit::Nanosecond => 1_000_000_000,
+                };
+                let values = primitive_values::<i64>(array, &valid_rows);
+                let (seconds, nanos): (Vec<_>, Vec<_>) = values
+                    .iter()
+                    .map(|&v| {
+                        let seconds = v.div_euclid(units_per_second);
+                        let nanos =
+                            v.rem_euclid(units_per_second) * (1_000_000_000 / units_per_second);
+                        // Readers expect the seconds of timestamps before the epoch to be
+                        // rounded towards zero, as the Java writer did.
+                        let stored = if seconds < 0 && nanos > 999_999 {
+                            seconds + 1
+                        } else {
+                            seconds
+                        };
+                        (stored - ORC_EPOCH_SECONDS, encode_nanos(nanos) as i64)
+                    })
+                    .unzip();
+                self.push_ints(column, StreamKind::Data, seconds);
+                self.push_uints(column, StreamKind::Secondary, nanos);
+
+                let units_per_milli = (units_per_second / 1_000).max(1);
+                let to_millis = |v: i64| {
+                    if units_per_second == 1 {
+                        v * 1_000
+                    } else {
+                        v.div_euclid(units_per_milli)
+                    }
+                };
+                let min = values.iter().min();
+                let max = values.iter().max();
+                min.zip(max)
+                    .map(|(&min, &max)| MinMax::Timestamp(to_millis(min), to_millis(max)))
+            },
+            TypeKind::Decimal => {
+                let values = primitive_values::<i128>(array, &valid_rows);
+                let mut data = Vec::new();
+                for &v in &values {
+                    write_varint_i128(&mut data, v);
+                }
+                self.push_stream(column, StreamKind::Data, data);
+                let scale = ty.scale as i64;
+                self.push_ints(column, StreamKind::Secondary, values.iter().map(|_| scale));
+                None
+            },
+            TypeKind::List => {
+                let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let offsets = array.offsets();
+                self.push_uints(
+                    column,
+                    StreamKind::Length,
+                    valid_rows.iter().map(|&i| {
+                        let (start, end) = offsets.start_end(i);
+                        (end - start) as i64
+                    }),
+                );
+                let child_rows = valid_rows
+                    .iter()
+                    .flat_map(|&i| {
+                        let (start, end) = offsets.start_end(i);
+                        start..end
+                    })
+                    .collect::<Vec<_>>();
+                self.encode_column(
+                    types,
+                    ty.subtypes[0],
+                    array.values().as_ref(),
+                    Some(&child_rows),
+                )?;
+                None
+            },
+            TypeKind::Struct => {
+                let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+                for (&child, values) in ty.subtypes.iter().zip(array.values()) {
+                    self.encode_column(types, child, values.as_ref(), Some(&valid_rows))?;
+                }
+                None
+            },
+            TypeKind::Varchar | TypeKind::Char | TypeKind::Map | TypeKind::Union => {
+                unreachable!()
+            },
+        };
+
+        self.statistics[column as usize] = ColumnStatistics {
+            number_of_values: Some(valid_rows.len() as u64),
+            has_null: Some(has_null),
+            min_max,
+        };
+        Ok(())
+    }
+
+    fn push_stream(&mut self, column: u32, kind: StreamKind, data: Vec<u8>) {
+        self.streams.push((column, kind, data));
+    }
+
+    fn push_ints(&mut self, column: u32, kind: StreamKind, values: impl IntoIterator<Item = i64>) {
+        let mut data = Vec::new();
+        encode_ints(&values.into_iter().collect::<Vec<_>>(), true, &mut data);
+        self.push_stream(column, kind, data);
+    }
+
+    fn push_uints(&mut self, column: u32, kind: StreamKind, values: impl IntoIterator<Item = i64>) {
+        let mut data = Vec::new();
+        encode_ints(&values.into_iter().collect::<Vec<_>>(), false, &mut data);
+        self.push_stream(column, kind, data);
+    }
+
+    fn push_binary<'a>(&mut self, column: u32, values: impl Iterator<Item = &'a [u8]>) {
+        let mut data = Vec::new();
+        let mut lengths = Vec::new();
+        for value in values {
+            data.extend_from_slice(value);
+            lengths.push(value.len() as i64);
+        }
+        self.push_stream(column, StreamKind::Data, data);
+        self.push_uints(column, StreamKind::Length, lengths);
+    }
+}
+
+fn primitive_values<T: NativeType>(array: &dyn Array, rows: &[usize]) -> Vec<T> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    rows.iter().map(|&i| array.value(i)).collect()
+}
+
+fn int_min_max(values: impl Iterator<Item = i64> + Clone) -> Option<MinMax> {
+    let min = values.clone().min()?;
+    let max = values.max()?;
+    Some(MinMax::Int(min, max))
+}
+
+fn double_min_max(values: impl Iterator<Item = f64>) -> Option<MinMax> {
+    let (min, max) = values
+        .filter(|v| !v.is_nan())
+        .fold(None, |acc: Option<(f64, f64)>, v| match acc {
+            None => Some((v, v)),
+            Some((min, max)) => Some((min.min(v), max.max(v))),
+        })?;
+    Some(MinMax::Double(min, max))
+}
+
+/// Combine the statistics of a column in two stripes.
+pub(super) fn merge_statistics(lhs: &mut ColumnStatistics, rhs: &ColumnStatistics) {
+    // Columns without values have no minimum and maximum.
+    let min_max = match (lhs.min_max.take(), &rhs.min_max) {
+        (min_max, None) if rhs.number_of_values == Some(0) => min_max,
+        (None, min_max) if lhs.number_of_values == Some(0) => min_max.clone(),
+        (Some(MinMax::Int(lmin, lmax)), Some(MinMax::Int(rmin, rmax))) => {
+            Some(MinMax::Int(lmin.min(*rmin), lmax.max(*rmax)))
+        },
+        (Some(MinMax::Double(lmin, lmax)), Some(MinMax::Double(rmin, rmax))) => {
+            Some(MinMax::Double(lmin.min(*rmin), lmax.max(*rmax)))
+        },
+        (Some(MinMax::String(lmin, lmax)), Some(MinMax::String(rmin, rmax))) => Some(
+            MinMax::String(lmin.min(rmin.clone()), lmax.max(rmax.clone())),
+        ),
+        (Some(MinMax::Date(lmin, lmax)), Some(MinMax::Date(rmin, rmax))) => {
+            Some(MinMax::Date(lmin.min(*rmin), lmax.max(*rmax)))
+        },
+        (Some(MinMax::Timestamp(lmin, lmax)), Some(MinMax::Timestamp(rmin, rmax))) => {
+            Some(MinMax::Timestamp(lmin.min(*rmin), lmax.max(*rmax)))
+        },
+        _ => None,
+    };
+    lhs.min_max = min_max;
+    lhs.number_of_values = lhs
+        .number_of_values
+        .zip(rhs.number_of_values)
+        .map(|(l, r)| l + r);
+    lhs.has_null = lhs.has_null.zip(rhs.has_null).map(|(l, r)| l || r);
+}
@@ -0,0 +1,234 @@
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, TimeUnit};
+use polars_core::prelude::*;
+
+use super::codec::decompress;
+use super::proto::{self, Footer, MinMax, OrcType, PostScript, TypeKind};
+use crate::predicates::ColumnStatistics;
+
+const MAGIC: &[u8] = b"ORC";
+
+/// The metadata in the tail of an ORC file.
+#[derive(Debug)]
+pub struct OrcMetadata {
+    pub(super) postscript: PostScript,
+    pub(super) footer: Footer,
+    pub(super) stripe_statistics: Vec<Vec<proto::ColumnStatistics>>,
+    schema: ArrowSchema,
+}
+
+impl OrcMetadata {
+    /// Read the metadata from the bytes of an ORC file.
+    pub fn read(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() > MAGIC.len() && bytes.starts_with(MAGIC),
+            ComputeError: "not an ORC file"
+        );
+        let postscript_len = *bytes.last().unwrap() as usize;
+        let tail = &bytes[..bytes.len() - 1];
+        polars_ensure!(
+            tail.len() >= postscript_len,
+            oos = "truncated ORC postscript"
+        );
+        let (tail, postscript) = tail.split_at(tail.len() - postscript_len);
+        let postscript = PostScript::decode(postscript)?;
+
+        let block_size = postscript.compression_block_size as usize;
+        let footer_len = postscript.footer_length as usize;
+        let metadata_len = postscript.metadata_length as usize;
+        polars_ensure!(
+            tail.len() >= footer_len + metadata_len,
+            oos = "truncated ORC file tail"
+        );
+        let (tail, footer) = tail.split_at(tail.len() - footer_len);
+        let footer = Footer::decode(&decompress(postscript.compression, block_size, footer)?)?;
+        let metadata = &tail[tail.len() - metadata_len..];
+        let stripe_statistics =
+            proto::decode_metadata(&decompress(postscript.compression, block_size, metadata)?)?;
+
+        let root = footer
+            .types
+            .first()
+            .filter(|root| root.kind == TypeKind::Struct)
+            .ok_or_else(|| polars_err!(ComputeError: "ORC file does not hold a struct"))?;
+        let schema = root
+            .field_names
+            .iter()
+            .zip(&root.subtypes)
+            .map(|(name, &column)| {
+                let dtype = to_arrow_dtype(&footer.types, column)?;
+                Ok((name.clone(), Field::new(name.clone(), dtype, true)))
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        Ok(Self {
+            postscript,
+            footer,
+            stripe_statistics,
+            schema,
+        })
+    }
+
+    pub fn arrow_schema(&self) -> &ArrowSchema {
+        &self.schema
+    }
+
+    pub fn schema(&self) -> Schema {
+        Schema::from_arrow_schema(&self.schema)
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.footer.number_of_rows as usize
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.footer.stripes.len()
+    }
+
+    pub fn stripe_num_rows(&self, stripe: usize) -> usize {
+        self.footer.stripes[stripe].number_of_rows as usize
+    }
+
+    /// The id of the ORC column of each top-level field.
+    pub(super) fn field_columns(&self) -> &[u32] {
+        &self.footer.types[0].subtypes
+    }
+
+    /// The statistics of the top-level columns in a stripe, used to skip stripes with a
+    /// predicate.
+    pub fn stripe_statistics(&self, stripe: usize) -> PlIndexMap<PlSmallStr, ColumnStatistics> {
+        let Some(stats) = self.stripe_statistics.get(stripe) else {
+            return PlIndexMap::default();
+        };
+        let num_rows = self.stripe_num_rows(stripe) as u64;
+
+        self.schema
+            .iter_values()
+            .zip(self.field_columns())
+            .filter_map(|(field, &column)| {
+                let stats = stats.get(column as usize)?;
+                let dtype = DataType::from_arrow_field(field);
+                let (min, max) = stats
+                    .min_max
+                    .as_ref()
+                    .and_then(|min_max| min_max_to_any_values(min_max, &dtype))
+                    .unwrap_or((AnyValue::Null, AnyValue::Null));
+                let null_count = stats
+                    .number_of_values
+                    .map(|n| num_rows.saturating_sub(n) as IdxSize);
+                let stats = ColumnStatistics {
+                    dtype,
+                    min,
+                    max,
+                    null_count,
+                };
+                Some((field.name.clone(), stats))
+            })
+            .collect()
+    }
+}
+
+fn to_arrow_dtype(types: &[OrcType], column: u32) -> PolarsResult<ArrowDataType> {
+    let ty = types
+        .get(column as usize)
+        .ok_or_else(|| polars_err!(oos = "ORC column {} does not exist", column))?;
+    let child = |i: usize| {
+        let column = *ty
+            .subtypes
+            .get(i)
+            .ok_or_else(|| polars_err!(oos = "ORC column {} misses a child type", column))?;
+        to_arrow_dtype(types, column)
+    };
+
+    Ok(match ty.kind {
+        TypeKind::Boolean => ArrowDataType::Boolean,
+        TypeKind::Byte => ArrowDataType::Int8,
+        TypeKind::Short => ArrowDataType::Int16,
+        TypeKind::Int => ArrowDataType::Int32,
+        TypeKind::Long => ArrowDataType::Int64,
+        TypeKind::Float => ArrowDataType::Float32,
+        TypeKind::Double => ArrowDataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => ArrowDataType::Utf8View,
+        TypeKind::Binary => ArrowDataType::BinaryView,
+        TypeKind::Date => ArrowDataType::Date32,
+        TypeKind::Timestamp => ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
+        TypeKind::TimestampInstant => {
+            ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some(PlSmallStr::from_static("UTC")))
+        },
+        TypeKind::Decimal => {
+            // Files written before ORC 0.12 could leave out the precision.
+            let precision = if ty.precision == 0 { 38 } else { ty.precision };
+            ArrowDataType::Decimal(precision as usize, ty.scale as usize)
+        },
+        TypeKind::List => ArrowDataType::LargeList(Box::new(Field::new(
+            PlSmallStr::from_static("item"),
+            child(0)?,
+            true,
+        ))),
+        TypeKind::Map => {
+            let entries = ArrowDataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("key"), child(0)?, true),
+                Field::new(PlSmallStr::from_static("value"), child(1)?, true),
+            ]);
+            ArrowDataType::LargeList(Box::new(Field::new(
+                PlSmallStr::from_static("item"),
+                entries,
+                true,
+            )))
+        },
+        TypeKind::Struct => ArrowDataType::Struct(
+            ty.field_names
+                .iter()
+                .zip(&ty.subtypes)
+                .map(|(name, &column)| {
+                    Ok(Field::new(
+                        name.clone(),
+                        to_arrow_dtype(types, column)?,
+                        true,
+                    ))
+                })
+                .collect::<PolarsResult<_>>()?,
+        ),
+        TypeKind::Union => {
+            polars_bail!(ComputeError: "reading ORC union columns is not supported")
+        },
+    })
+}
+
+fn min_max_to_any_values(
+    min_max: &MinMax,
+    dtype: &DataType,
+) -> Option<(AnyValue<'static>, AnyValue<'static>)> {
+    let (min, max) = match (min_max, dtype) {
+        (MinMax::Int(min, max), dtype) if dtype.is_integer() => (
+            AnyValue::Int64(*min).strict_cast(dtype)?,
+            AnyValue::Int64(*max).strict_cast(dtype)?,
+        ),
+        (MinMax::Double(min, max), dtype) if dtype.is_float() => {
+            if min.is_nan() || max.is_nan() {
+                return None;
+            }
+            (
+                AnyValue::Float64(*min).strict_cast(dtype)?,
+                AnyValue::Float64(*max).strict_cast(dtype)?,
+            )
+        },
+        (MinMax::String(min, max), DataType::String) => (
+            AnyValue::StringOwned(min.clone()),
+            AnyValue::StringOwned(max.clone()),
+        ),
+        (MinMax::Date(min, max), DataType::Date) => (AnyValue::Date(*min), AnyValue::Date(*max)),
+        // The statistics are in milliseconds, so widen them to include the sub-millisecond
+        // part of the values.
+        (MinMax::Timestamp(min, max), DataType::Datetime(tu, tz)) => {
+            let tz = tz.clone().map(Arc::new);
+            let min = min.checked_mul(1_000_000)?;
+            let max = max.checked_add(1)?.checked_mul(1_000_000)? - 1;
+            (
+                AnyValue::DatetimeOwned(min, *tu, tz.clone()),
+                AnyValue::DatetimeOwned(max, *tu, tz),
+            )
+        },
+        _ => return None,
+    };
+    Some((min.into_static(), max.into_static()))
+}
@@ -0,0 +1,19 @@
+//! Read and write [Apache ORC] files.
+//!
+//! The decoder supports all column encodings and the zlib, snappy, zstd and lz4 codecs, but no
+//! union columns. The writer uses the direct encodings and writes the statistics of every
+//! stripe, which are used to skip stripes when scanning with a predicate.
+//!
+//! [Apache ORC]: https://orc.apache.org
+mod codec;
+mod decode;
+mod encode;
+mod metadata;
+mod proto;
+mod read;
+mod rle;
+mod write;
+
+pub use metadata::OrcMetadata;
+pub use read::*;
+pub use write::*;
@@ -0,0 +1,781 @@
+//! The protobuf messages of the ORC file tail and stripe footers.
+//!
+//! Only the fields that are needed to read and write the data are (de)serialized, see the
+//! [ORC specification](https://orc.apache.org/specification/ORCv1/) for the full messages.
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+pub(super) fn read_varint(buf: &mut &[u8]) -> PolarsResult<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = buf
+            .split_first()
+            .ok_or_else(|| polars_err!(oos = "unexpected end of ORC varint"))?;
+        *buf = rest;
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    polars_bail!(oos = "ORC varint is longer than 64 bits")
+}
+
+pub(super) fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+pub(super) fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+pub(super) fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl<'a> WireValue<'a> {
+    fn uint(&self) -> PolarsResult<u64> {
+        match self {
+            Self::Varint(v) | Self::Fixed64(v) => Ok(*v),
+            Self::Fixed32(v) => Ok(*v as u64),
+            Self::Bytes(_) => polars_bail!(oos = "expected an integer ORC protobuf field"),
+        }
+    }
+
+    fn sint(&self) -> PolarsResult<i64> {
+        Ok(zigzag_decode(self.uint()?))
+    }
+
+    fn double(&self) -> PolarsResult<f64> {
+        match self {
+            Self::Fixed64(v) => Ok(f64::from_bits(*v)),
+            _ => polars_bail!(oos = "expected a double ORC protobuf field"),
+        }
+    }
+
+    fn bytes(&self) -> PolarsResult<&'a [u8]> {
+        match self {
+            Self::Bytes(v) => Ok(*v),
+            _ => polars_bail!(oos = "expected a length-delimited ORC protobuf field"),
+        }
+    }
+
+    fn string(&self) -> PolarsResult<PlSmallStr> {
+        let s = std::str::from_utf8(self.bytes()?)
+            .map_err(|_| polars_err!(oos = "invalid utf-8 in ORC protobuf string"))?;
+        Ok(PlSmallStr::from_str(s))
+    }
+
+    /// Repeated integers can be written both packed and unpacked.
+    fn extend_uints(&self, out: &mut Vec<u64>) -> PolarsResult<()> {
+        match self {
+            Self::Bytes(buf) => {
+                let mut buf = *buf;
+                while !buf.is_empty() {
+                    out.push(read_varint(&mut buf)?);
+                }
+            },
+            v => out.push(v.uint()?),
+        }
+        Ok(())
+    }
+}
+
+struct ProtoReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn next_field(&mut self) -> PolarsResult<Option<(u64, WireValue<'a>)>> {
+        if self.buf.is_empty() {
+            return Ok(None);
+        }
+        let key = read_varint(&mut self.buf)?;
+        let value = match key & 7 {
+            0 => WireValue::Varint(read_varint(&mut self.buf)?),
+            1 => WireValue::Fixed64(u64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            2 => {
+                let len = read_varint(&mut self.buf)? as usize;
+                WireValue::Bytes(self.take(len)?)
+            },
+            5 => WireValue::Fixed32(u32::from_le_bytes(self.take(4)?.try_into().unwrap())),
+            wire_type => polars_bail!(oos = "unsupported ORC protobuf wire type {}", wire_type),
+        };
+        Ok(Some((key >> 3, value)))
+    }
+
+    fn take(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        if self.buf.len() < len {
+            polars_bail!(oos = "unexpected end of ORC protobuf message");
+        }
+        let (out, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(out)
+    }
+}
+
+#[derive(Default)]
+pub(super) struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn key(&mut self, field: u64, wire_type: u64) {
+        write_varint(&mut self.buf, (field << 3) | wire_type);
+    }
+
+    fn uint(&mut self, field: u64, value: u64) {
+        self.key(field, 0);
+        write_varint(&mut self.buf, value);
+    }
+
+    fn sint(&mut self, field: u64, value: i64) {
+        self.uint(field, zigzag_encode(value));
+    }
+
+    fn double(&mut self, field: u64, value: f64) {
+        self.key(field, 1);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u64, value: &[u8]) {
+        self.key(field, 2);
+        write_varint(&mut self.buf, value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    fn message(&mut self, field: u64, f: impl FnOnce(&mut ProtoWriter)) {
+        let mut inner = ProtoWriter::default();
+        f(&mut inner);
+        self.bytes(field, &inner.buf);
+    }
+
+    fn packed_uints(&mut self, field: u64, values: impl IntoIterator<Item = u64>) {
+        let mut packed = Vec::new();
+        for value in values {
+            write_varint(&mut packed, value);
+        }
+        self.bytes(field, &packed);
+    }
+
+    pub(super) fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum CompressionKind {
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+impl CompressionKind {
+    fn try_from_proto(value: u64) -> PolarsResult<Self> {
+        Ok(match value {
+            0 => Self::None,
+            1 => Self::Zlib,
+            2 => Self::Snappy,
+            3 => Self::Lzo,
+            4 => Self::Lz4,
+            5 => Self::Zstd,
+            v => polars_bail!(oos = "unknown ORC compression kind {}", v),
+        })
+    }
+
+    fn to_proto(self) -> u64 {
+        match self {
+            Self::None => 0,
+            Self::Zlib => 1,
+            Self::Snappy => 2,
+            Self::Lzo => 3,
+            Self::Lz4 => 4,
+            Self::Zstd => 5,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+    pub compression_block_size: u64,
+    pub metadata_length: u64,
+}
+
+impl PostScript {
+    pub(super) fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self {
+            footer_length: 0,
+            compression: CompressionKind::None,
+            compression_block_size: 256 * 1024,
+            metadata_length: 0,
+        };
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => out.footer_length = value.uint()?,
+                2 => out.compression = CompressionKind::try_from_proto(value.uint()?)?,
+                3 => out.compression_block_size = value.uint()?,
+                5 => out.metadata_length = value.uint()?,
+                8000 if value.bytes()? != b"ORC" => {
+                    polars_bail!(oos = "invalid ORC postscript magic")
+                },
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+
+    pub(super) fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.footer_length);
+        w.uint(2, self.compression.to_proto());
+        if self.compression != CompressionKind::None {
+            w.uint(3, self.compression_block_size);
+        }
+        // File format version 0.12.
+        w.packed_uints(4, [0, 12]);
+        w.uint(5, self.metadata_length);
+        // Writer version ORC-135, which writes the statistics of all columns in UTC.
+        w.uint(6, 6);
+        w.bytes(8000, b"ORC");
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Footer {
+    pub header_length: u64,
+    pub content_length: u64,
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<OrcType>,
+    pub number_of_rows: u64,
+    pub statistics: Vec<ColumnStatistics>,
+}
+
+impl Footer {
+    pub(super) fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => out.header_length = value.uint()?,
+                2 => out.content_length = value.uint()?,
+                3 => out.stripes.push(StripeInformation::decode(value.bytes()?)?),
+                4 => out.types.push(OrcType::decode(value.bytes()?)?),
+                6 => out.number_of_rows = value.uint()?,
+                7 => out
+                    .statistics
+                    .push(ColumnStatistics::decode(value.bytes()?)?),
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+
+    pub(super) fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.header_length);
+        w.uint(2, self.content_length);
+        for stripe in &self.stripes {
+            w.message(3, |w| stripe.encode(w));
+        }
+        for ty in &self.types {
+            w.message(4, |w| ty.encode(w));
+        }
+        w.uint(6, self.number_of_rows);
+        for stats in &self.statistics {
+            w.message(7, |w| stats.encode(w));
+        }
+        // No row index is written.
+        w.uint(8, 0);
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => out.offset = value.uint()?,
+                2 => out.index_length = value.uint()?,
+                3 => out.data_length = value.uint()?,
+                4 => out.footer_length = value.uint()?,
+                5 => out.number_of_rows = value.uint()?,
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.offset);
+        w.uint(2, self.index_length);
+        w.uint(3, self.data_length);
+        w.uint(4, self.footer_length);
+        w.uint(5, self.number_of_rows);
+    }
+
+    /// The total length of the stripe in the file.
+    pub(super) fn length(&self) -> u64 {
+        self.index_length + self.data_length + self.footer_length
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl TypeKind {
+    fn try_from_proto(value: u64) -> PolarsResult<Self> {
+        use TypeKind::*;
+        const KINDS: [TypeKind; 19] = [
+            Boolean,
+            Byte,
+            Short,
+            Int,
+            Long,
+            Float,
+            Double,
+            String,
+            Binary,
+            Timestamp,
+            List,
+            Map,
+            Struct,
+            Union,
+            Decimal,
+            Date,
+            Varchar,
+            Char,
+            TimestampInstant,
+        ];
+        KINDS
+            .get(value as usize)
+            .copied()
+            .ok_or_else(|| polars_err!(oos = "unknown ORC type kind {}", value))
+    }
+
+    fn to_proto(self) -> u64 {
+        self as u64
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct OrcType {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<PlSmallStr>,
+    pub precision: u32,
+    pub scale: u32,
+}
+
+impl OrcType {
+    pub(super) fn new(kind: TypeKind) -> Self {
+        Self {
+            kind,
+            subtypes: Vec::new(),
+            field_names: Vec::new(),
+            precision: 0,
+            scale: 0,
+        }
+    }
+
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut kind = None;
+        let mut subtypes = Vec::new();
+        let mut out = Self::new(TypeKind::Struct);
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => kind = Some(TypeKind::try_from_proto(value.uint()?)?),
+                2 => value.extend_uints(&mut subtypes)?,
+                3 => out.field_names.push(value.string()?),
+                5 => out.precision = value.uint()? as u32,
+                6 => out.scale = value.uint()? as u32,
+                _ => {},
+            }
+        }
+        out.kind = kind.ok_or_else(|| polars_err!(oos = "ORC type without a kind"))?;
+        out.subtypes = subtypes.into_iter().map(|v| v as u32).collect();
+        Ok(out)
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.kind.to_proto());
+        if !self.subtypes.is_empty() {
+            w.packed_uints(2, self.subtypes.iter().map(|&v| v as u64));
+        }
+        for name in &self.field_names {
+            w.bytes(3, name.as_bytes());
+        }
+        if self.kind == TypeKind::Decimal {
+            w.uint(5, self.precision as u64);
+            w.uint(6, self.scale as u64);
+        }
+    }
+}
+
+/// The minimum and maximum of the values of a column, for the kinds of statistics that are used
+/// to skip stripes.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum MinMax {
+    Int(i64, i64),
+    Double(f64, f64),
+    String(PlSmallStr, PlSmallStr),
+    Date(i32, i32),
+    /// Milliseconds since the unix epoch in UTC.
+    Timestamp(i64, i64),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct ColumnStatistics {
+    pub number_of_values: Option<u64>,
+    pub has_null: Option<bool>,
+    pub min_max: Option<MinMax>,
+}
+
+impl ColumnStatistics {
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => out.number_of_values = Some(value.uint()?),
+                2 => {
+                    let (min, max) = decode_min_max(value.bytes()?, 1, 2, |v| v.sint())?;
+                    out.min_max = min.zip(max).map(|(min, max)| MinMax::Int(min, max));
+                },
+                3 => {
+                    let (min, max) = decode_min_max(value.bytes()?, 1, 2, |v| v.double())?;
+                    out.min_max = min.zip(max).map(|(min, max)| MinMax::Double(min, max));
+                },
+                4 => {
+                    let (min, max) = decode_min_max(value.bytes()?, 1, 2, |v| v.string())?;
+                    out.min_max = min.zip(max).map(|(min, max)| MinMax::String(min, max));
+                },
+                7 => {
+                    let (min, max) = decode_min_max(value.bytes()?, 1, 2, |v| v.sint())?;
+                    out.min_max = min
+                        .zip(max)
+                        .map(|(min, max)| MinMax::Date(min as i32, max as i32));
+                },
+                9 => {
+                    // Only the UTC statistics can be used for pruning, older writers only wrote
+                    // them in the writer's local time zone.
+                    let (min, max) = decode_min_max(value.bytes()?, 3, 4, |v| v.sint())?;
+                    out.min_max = min.zip(max).map(|(min, max)| MinMax::Timestamp(min, max));
+                },
+                10 => out.has_null = Some(value.uint()? != 0),
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        if let Some(n) = self.number_of_values {
+            w.uint(1, n);
+        }
+        match &self.min_max {
+            None => {},
+            Some(MinMax::Int(min, max)) => w.message(2, |w| {
+                w.sint(1, *min);
+                w.sint(2, *max);
+            }),
+            Some(MinMax::Double(min, max)) => w.message(3, |w| {
+                w.double(1, *min);
+                w.double(2, *max);
+            }),
+            Some(MinMax::String(min, max)) => w.message(4, |w| {
+                w.bytes(1, min.as_bytes());
+                w.bytes(2, max.as_bytes());
+            }),
+            Some(MinMax::Date(min, max)) => w.message(7, |w| {
+                w.sint(1, *min as i64);
+                w.sint(2, *max as i64);
+            }),
+            Some(MinMax::Timestamp(min, max)) => w.message(9, |w| {
+                w.sint(1, *min);
+                w.sint(2, *max);
+                w.sint(3, *min);
+                w.sint(4, *max);
+            }),
+        }
+        if let Some(has_null) = self.has_null {
+            w.uint(10, has_null as u64);
+        }
+    }
+}
+
+fn decode_min_max<T>(
+    buf: &[u8],
+    min_field: u64,
+    max_field: u64,
+    f: impl Fn(&WireValue<'_>) -> PolarsResult<T>,
+) -> PolarsResult<(Option<T>, Option<T>)> {
+    let mut min = None;
+    let mut max = None;
+    let mut reader = ProtoReader::new(buf);
+    while let Some((field, value)) = reader.next_field()? {
+        if field == min_field {
+            min = Some(f(&value)?);
+        } else if field == max_field {
+            max = Some(f(&value)?);
+        }
+    }
+    Ok((min, max))
+}
+
+/// The statistics of every column of every stripe.
+pub(super) fn decode_metadata(buf: &[u8]) -> PolarsResult<Vec<Vec<ColumnStatistics>>> {
+    let mut out = Vec::new();
+    let mut reader = ProtoReader::new(buf);
+    while let Some((field, value)) = reader.next_field()? {
+        if field == 1 {
+            let mut stripe_stats = Vec::new();
+            let mut reader = ProtoReader::new(value.bytes()?);
+            while let Some((field, value)) = reader.next_field()? {
+                if field == 1 {
+                    stripe_stats.push(ColumnStatistics::decode(value.bytes()?)?);
+                }
+            }
+            out.push(stripe_stats);
+        }
+    }
+    Ok(out)
+}
+
+pub(super) fn encode_metadata(stripe_stats: &[Vec<ColumnStatistics>], w: &mut ProtoWriter) {
+    for stats in stripe_stats {
+        w.message(1, |w| {
+            for column_stats in stats {
+                w.message(1, |w| column_stats.encode(w));
+            }
+        });
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(super) enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// Indexes and other streams that aren't needed to decode the values.
+    Other,
+}
+
+impl StreamKind {
+    fn from_proto(value: u64) -> Self {
+        match value {
+            0 => Self::Present,
+            1 => Self::Data,
+            2 => Self::Length,
+            3 => Self::DictionaryData,
+            5 => Self::Secondary,
+            _ => Self::Other,
+        }
+    }
+
+    fn to_proto(self) -> u64 {
+        match self {
+            Self::Present => 0,
+            Self::Data => 1,
+            Self::Length => 2,
+            Self::DictionaryData => 3,
+            Self::Secondary => 5,
+            Self::Other => unreachable!(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct Stream {
+    pub kind: StreamKind,
+    pub column: u32,
+    pub length: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ColumnEncodingKind {
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u32,
+}
+
+impl ColumnEncoding {
+    pub(super) const DIRECT: Self = Self {
+        kind: ColumnEncodingKind::Direct,
+        dictionary_size: 0,
+    };
+
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::DIRECT;
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => {
+                    out.kind = match value.uint()? {
+                        0 => ColumnEncodingKind::Direct,
+                        1 => ColumnEncodingKind::Dictionary,
+                        2 => ColumnEncodingKind::DirectV2,
+                        3 => ColumnEncodingKind::DictionaryV2,
+                        v => polars_bail!(oos = "unknown ORC column encoding {}", v),
+                    }
+                },
+                2 => out.dictionary_size = value.uint()? as u32,
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+
+    /// Whether integers are encoded with the second version of the run length encoding.
+    pub(super) fn is_v2(&self) -> bool {
+        matches!(
+            self.kind,
+            ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2
+        )
+    }
+
+    pub(super) fn is_dictionary(&self) -> bool {
+        matches!(
+            self.kind,
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+}
+
+impl StripeFooter {
+    pub(super) fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => {
+                    let mut stream = Stream {
+                        kind: StreamKind::Other,
+                        column: 0,
+                        length: 0,
+                    };
+                    let mut reader = ProtoReader::new(value.bytes()?);
+                    while let Some((field, value)) = reader.next_field()? {
+                        match field {
+                            1 => stream.kind = StreamKind::from_proto(value.uint()?),
+                            2 => stream.column = value.uint()? as u32,
+                            3 => stream.length = value.uint()?,
+                            _ => {},
+                        }
+                    }
+                    out.streams.push(stream);
+                },
+                2 => out.columns.push(ColumnEncoding::decode(value.bytes()?)?),
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+
+    pub(super) fn encode(&self, w: &mut ProtoWriter) {
+        for stream in &self.streams {
+            w.message(1, |w| {
+                w.uint(1, stream.kind.to_proto());
+                w.uint(2, stream.column as u64);
+                w.uint(3, stream.length);
+            });
+        }
+        for encoding in &self.columns {
+            w.message(2, |w| {
+                w.uint(1, encoding.kind as u64);
+                if encoding.is_dictionary() {
+                    w.uint(2, encoding.dictionary_size as u64);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_statistics_without_utc() {
+        let mut w = ProtoWriter::default();
+        w.message(9, |w| {
+            w.sint(1, 0);
+            w.sint(2, 1000);
+        });
+        let stats = ColumnStatistics::decode(&w.into_inner()).unwrap();
+        assert_eq!(stats.min_max, None);
+
+        let mut w = ProtoWriter::default();
+        w.message(9, |w| {
+            w.sint(1, 0);
+            w.sint(2, 1000);
+            w.sint(3, 3600);
+            w.sint(4, 4600);
+        });
+        let stats = ColumnStatistics::decode(&w.into_inner()).unwrap();
+        assert_eq!(stats.min_max, Some(MinMax::Timestamp(3600, 4600)));
+    }
+}
@@ -0,0 +1,130 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::OrcMetadata;
+use crate::mmap::MmapBytesReader;
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct OrcScanOptions;
+
+/// Read [Apache ORC] format into a [`DataFrame`]
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.orc").expect("file not found");
+///
+///     OrcReader::new(file)
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct OrcReader<R> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+}
+
+impl<R: MmapBytesReader> OrcReader<R> {
+    /// Get schema of the ORC File
+    pub fn schema(&mut self) -> PolarsResult<Schema> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        Ok(OrcMetadata::read(&bytes)?.schema())
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for OrcReader<R> {
+    fn new(reader: R) -> Self {
+        OrcReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let metadata = OrcMetadata::read(&bytes)?;
+        let schema = metadata.arrow_schema();
+
+        if let Some(columns) = &self.columns {
+            self.projection = Some(columns_to_projection(columns, schema)?);
+        }
+        let projected_schema = match &self.projection {
+            Some(projection) => apply_projection(schema, projection),
+            None => schema.clone(),
+        };
+
+        // Only decode the stripes that hold the first `n_rows` rows.
+        let n_rows = self.n_rows.unwrap_or(usize::MAX);
+        let mut num_rows = 0;
+        let stripes = (0..metadata.num_stripes())
+            .take_while(|&i| {
+                let take = num_rows < n_rows;
+                num_rows += metadata.stripe_num_rows(i);
+                take
+            })
+            .collect::<Vec<_>>();
+
+        let dfs = POOL.install(|| {
+            stripes
+                .into_par_iter()
+                .map(|i| metadata.read_stripe(&bytes, i, self.projection.as_deref()))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let mut df = if dfs.is_empty() {
+            DataFrame::empty_with_arrow_schema(&projected_schema)
+        } else {
+            accumulate_dataframes_vertical_unchecked(dfs)
+        };
+        if df.height() > n_rows {
+            df = df.slice(0, n_rows);
+        }
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
@@ -0,0 +1,398 @@
+//! The run length encodings of ORC streams.
+//!
+//! Bytes and booleans use the byte run length encoding, integers use either version 1 or
+//! version 2 of the integer run length encoding, depending on the column encoding. Only the
+//! first version of the integer encoding is written.
+use arrow::bitmap::Bitmap;
+use polars_error::{PolarsResult, polars_ensure, polars_err};
+
+use super::proto::{read_varint, write_varint, zigzag_decode, zigzag_encode};
+
+fn split_first(buf: &mut &[u8]) -> PolarsResult<u8> {
+    let (&byte, rest) = buf
+        .split_first()
+        .ok_or_else(|| polars_err!(oos = "unexpected end of ORC stream"))?;
+    *buf = rest;
+    Ok(byte)
+}
+
+fn split_at<'a>(buf: &mut &'a [u8], len: usize) -> PolarsResult<&'a [u8]> {
+    polars_ensure!(buf.len() >= len, oos = "unexpected end of ORC stream");
+    let (out, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(out)
+}
+
+/// Decode `n` values of a byte run length encoded stream.
+pub(super) fn decode_bytes(mut buf: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        let header = split_first(&mut buf)?;
+        if header < 0x80 {
+            let value = split_first(&mut buf)?;
+            out.extend(std::iter::repeat_n(value, header as usize + 3));
+        } else {
+            out.extend_from_slice(split_at(&mut buf, 256 - header as usize)?);
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decode `n` booleans, which are stored as bytes with the most significant bit first.
+pub(super) fn decode_bools(buf: &[u8], n: usize) -> PolarsResult<Bitmap> {
+    let mut bytes = decode_bytes(buf, n.div_ceil(8))?;
+    for byte in &mut bytes {
+        *byte = byte.reverse_bits();
+    }
+    Ok(Bitmap::from_u8_vec(bytes, n))
+}
+
+fn read_int(buf: &mut &[u8], signed: bool) -> PolarsResult<i64> {
+    let value = read_varint(buf)?;
+    Ok(if signed {
+        zigzag_decode(value)
+    } else {
+        value as i64
+    })
+}
+
+/// Decode `n` values of an integer run length encoded stream.
+///
+/// Unsigned values that don't fit in an `i64` wrap around.
+pub(super) fn decode_ints(buf: &[u8], n: usize, signed: bool, v2: bool) -> PolarsResult<Vec<i64>> {
+    let mut out = Vec::with_capacity(n);
+    let mut buf = buf;
+    while out.len() < n {
+        if v2 {
+            decode_run_v2(&mut buf, signed, &mut out)?;
+        } else {
+            decode_run_v1(&mut buf, signed, &mut out)?;
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+fn decode_run_v1(buf: &mut &[u8], signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = split_first(buf)?;
+    if header < 0x80 {
+        let delta = split_first(buf)? as i8 as i64;
+        let base = read_int(buf, signed)?;
+        out.extend((0..header as i64 + 3).map(|i| base.wrapping_add(i.wrapping_mul(delta))));
+    } else {
+        for _ in 0..256 - header as usize {
+            out.push(read_int(buf, signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// The bit width of the values of a run from its 5 bit encoding.
+fn decode_bit_width(encoded: u8) -> u32 {
+    match encoded {
+        0..=23 => encoded as u32 + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// The smallest bit width that can be encoded and fits `n` bits.
+fn closest_fixed_bits(n: u32) -> u32 {
+    match n {
+        0 => 1,
+        1..=24 => n,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// Unpack `n` big endian values of `width` bits, the last byte is padded.
+fn unpack(buf: &mut &[u8], width: u32, n: usize) -> PolarsResult<Vec<u64>> {
+    let data = split_at(buf, (width as usize * n).div_ceil(8))?;
+    let mut out = Vec::with_capacity(n);
+    let mut bit_pos = 0;
+    for _ in 0..n {
+        let mut value = 0u64;
+        let mut remaining = width;
+        while remaining > 0 {
+            let available = 8 - (bit_pos % 8) as u32;
+            let take = available.min(remaining);
+            let bits = (data[bit_pos / 8] as u32 >> (available - take)) & ((1 << take) - 1);
+            value = (value << take) | bits as u64;
+            remaining -= take;
+            bit_pos += take as usize;
+        }
+        out.push(value);
+    }
+    Ok(out)
+}
+
+fn run_length_v2(header: u8, buf: &mut &[u8]) -> PolarsResult<usize> {
+    Ok((((header as usize & 1) << 8) | split_first(buf)? as usize) + 1)
+}
+
+fn decode_run_v2(buf: &mut &[u8], signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = split_first(buf)?;
+    let decode_sign = |v: u64| if signed { zigzag_decode(v) } else { v as i64 };
+    match header >> 6 {
+        // Short repeat
+        0 => {
+            let width = ((header >> 3) & 7) as usize + 1;
+            let count = (header & 7) as usize + 3;
+            let value = split_at(buf, width)?
+                .iter()
+                .fold(0u64, |acc, &b| (acc << 8) | b as u64);
+            out.extend(std::iter::repeat_n(decode_sign(value), count));
+        },
+        // Direct
+        1 => {
+            let width = decode_bit_width((header >> 1) & 0x1F);
+            let len = run_length_v2(header, buf)?;
+            out.extend(unpack(buf, width, len)?.into_iter().map(decode_sign));
+        },
+        // Patched base
+        2 => {
+            let width = decode_bit_width((header >> 1) & 0x1F);
+            let len = run_length_v2(header, buf)?;
+            let third = split_first(buf)?;
+            let base_width = ((third >> 5) & 7) as usize + 1;
+            let patch_width = decode_bit_width(third & 0x1F);
+            let fourth = split_first(buf)?;
+            let patch_gap_width = ((fourth >> 5) & 7) as u32 + 1;
+            let patch_list_len = (fourth & 0x1F) as usize;
+
+            // The base is stored in sign-magnitude form.
+            let base = split_at(buf, base_width)?
+                .iter()
+                .fold(0u64, |acc, &b| (acc << 8) | b as u64);
+            let sign_mask = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_mask != 0 {
+                -((base & !sign_mask) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = unpack(buf, width, len)?;
+            let patches = unpack(
+                buf,
+                closest_fixed_bits(patch_width + patch_gap_width),
+                patch_list_len,
+            )?;
+            let patch_mask = u64::MAX >> (64 - patch_width);
+            let mut index = 0;
+            for patch in patches {
+                // Gaps that don't fit are split over multiple entries without a patch.
+                index += (patch.checked_shr(patch_width).unwrap_or(0)) as usize;
+                let patch = patch & patch_mask;
+                if patch != 0 {
+                    let value = values
+                        .get_mut(index)
+                        .ok_or_else(|| polars_err!(oos = "invalid ORC patch position"))?;
+                    *value |= patch.checked_shl(width).unwrap_or(0);
+                }
+            }
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        },
+        // Delta
+        _ => {
+            let encoded_width = (header >> 1) & 0x1F;
+            let len = run_length_v2(header, buf)?;
+            let mut value = read_int(buf, signed)?;
+            let delta_base = zigzag_decode(read_varint(buf)?);
+            out.push(value);
+            if len == 1 {
+                return Ok(());
+            }
+            value = value.wrapping_add(delta_base);
+            out.push(value);
+            if encoded_width == 0 {
+                // Fixed delta
+                for _ in 2..len {
+                    value = value.wrapping_add(delta_base);
+                    out.push(value);
+                }
+            } else {
+                let deltas = unpack(buf, decode_bit_width(encoded_width), len - 2)?;
+                for delta in deltas {
+                    // The deltas have the sign of the delta base.
+                    value = if delta_base < 0 {
+                        value.wrapping_sub(delta as i64)
+                    } else {
+                        value.wrapping_add(delta as i64)
+                    };
+                    out.push(value);
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+const MIN_REPEAT: usize = 3;
+const MAX_REPEAT: usize = 127 + MIN_REPEAT;
+const MAX_LITERALS: usize = 128;
+
+pub(super) fn encode_bytes(values: &[u8], out: &mut Vec<u8>) {
+    let flush_literals = |literals: &[u8], out: &mut Vec<u8>| {
+        for chunk in literals.chunks(MAX_LITERALS) {
+            out.push((256 - chunk.len()) as u8);
+            out.extend_from_slice(chunk);
+        }
+    };
+
+    let mut literal_start = 0;
+    let mut i = 0;
+    while i < values.len() {
+        let run = values[i..]
+            .iter()
+            .take(MAX_REPEAT)
+            .take_while(|&&v| v == values[i])
+            .count();
+        if run >= MIN_REPEAT {
+            flush_literals(&values[literal_start..i], out);
+            out.push((run - MIN_REPEAT) as u8);
+            out.push(values[i]);
+            literal_start = i + run;
+        }
+        i += run;
+    }
+    flush_literals(&values[literal_start..], out);
+}
+
+/// Encode booleans as bytes with the most significant bit first.
+pub(super) fn encode_bools(values: impl IntoIterator<Item = bool>, out: &mut Vec<u8>) {
+    let mut bytes = Vec::new();
+    for (i, value) in values.into_iter().enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0u8);
+        }
+        if value {
+            *bytes.last_mut().unwrap() |= 0x80 >> (i % 8);
+        }
+    }
+    encode_bytes(&bytes, out);
+}
+
+fn write_int(value: i64, signed: bool, out: &mut Vec<u8>) {
+    write_varint(
+        out,
+        if signed {
+            zigzag_encode(value)
+        } else {
+            value as u64
+        },
+    );
+}
+
+/// Encode integers with version 1 of the integer run length encoding.
+pub(super) fn encode_ints(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    let flush_literals = |literals: &[i64], out: &mut Vec<u8>| {
+        for chunk in literals.chunks(MAX_LITERALS) {
+            out.push((256 - chunk.len()) as u8);
+            for &value in chunk {
+                write_int(value, signed, out);
+            }
+        }
+    };
+
+    let mut literal_start = 0;
+    let mut i = 0;
+    while i < values.len() {
+        if i + MIN_REPEAT <= values.len() {
+            let delta = values[i + 1].wrapping_sub(values[i]);
+            if (i8::MIN as i64..=i8::MAX as i64).contains(&delta) {
+                let run = 2 + values[i + 1..]
+                    .windows(2)
+                    .take(MAX_REPEAT - 2)
+                    .take_while(|w| w[1].wrapping_sub(w[0]) == delta)
+                    .count();
+                if run >= MIN_REPEAT {
+                    flush_literals(&values[literal_start..i], out);
+                    out.push((run - MIN_REPEAT) as u8);
+                    out.push(delta as i8 as u8);
+                    write_int(values[i], signed, out);
+                    i += run;
+                    literal_start = i;
+                    continue;
+                }
+            }
+        }
+        i += 1;
+    }
+    flush_literals(&values[literal_start..], out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The examples of the ORC specification.
+    #[test]
+    fn test_decode_ints_v2() {
+        let decode = |buf: &[u8], n| decode_ints(buf, n, false, true).unwrap();
+        assert_eq!(decode(&[0x0a, 0x27, 0x10], 5), vec![10000; 5]);
+        assert_eq!(
+            decode(
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                4
+            ),
+            vec![23713, 43806, 57005, 48879]
+        );
+        assert_eq!(
+            decode(&[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46], 10),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+        let patched = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        let mut expected = (0..20).map(|i| 2000 + 10 * i).collect::<Vec<_>>();
+        expected[0] = 2030;
+        expected[1] = 2000;
+        expected[2] = 2020;
+        expected[3] = 1000000;
+        assert_eq!(decode(&patched, 20), expected);
+    }
+
+    #[test]
+    fn test_ints_v1_roundtrip() {
+        let values = [
+            vec![],
+            vec![-1],
+            (0..1000).collect::<Vec<i64>>(),
+            (0..1000).map(|i| i * 1000 - 7).collect(),
+            (0..1000).map(|i| (i * 7919) % 113 - 50).collect(),
+            vec![i64::MIN, i64::MAX, 0, 0, 0, i64::MAX],
+        ];
+        for values in values {
+            let mut buf = Vec::new();
+            encode_ints(&values, true, &mut buf);
+            assert_eq!(
+                decode_ints(&buf, values.len(), true, false).unwrap(),
+                values
+            );
+        }
+    }
+
+    #[test]
+    fn test_bools_roundtrip() {
+        let values = (0..1000).map(|i| i % 3 == 0 || i > 500).collect::<Vec<_>>();
+        let mut buf = Vec::new();
+        encode_bools(values.iter().copied(), &mut buf);
+        let decoded = decode_bools(&buf, values.len()).unwrap();
+        assert_eq!(decoded.iter().collect::<Vec<_>>(), values);
+    }
+}
@@ -0,0 +1,233 @@
+use std::io::Write;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::codec::compress;
+use super::encode::{EncodedStripe, merge_statistics, push_orc_types, to_orc_compatible_dtype};
+use super::proto::{
+    self, ColumnStatistics, CompressionKind, Footer, OrcType, PostScript, ProtoWriter, Stream,
+    StripeFooter, StripeInformation, TypeKind,
+};
+use crate::shared::SerWriter;
+
+const DEFAULT_STRIPE_SIZE: usize = 512 * 512;
+const DEFAULT_COMPRESSION_BLOCK_SIZE: usize = 256 * 1024;
+
+/// Compression codec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum OrcCompression {
+    /// Deflate without a zlib header
+    Zlib,
+    Snappy,
+    /// LZ4 (block)
+    LZ4,
+    /// ZSTD
+    #[default]
+    ZSTD,
+}
+
+impl From<OrcCompression> for CompressionKind {
+    fn from(value: OrcCompression) -> Self {
+        match value {
+            OrcCompression::Zlib => CompressionKind::Zlib,
+            OrcCompression::Snappy => CompressionKind::Snappy,
+            OrcCompression::LZ4 => CompressionKind::Lz4,
+            OrcCompression::ZSTD => CompressionKind::Zstd,
+        }
+    }
+}
+
+/// Write a [`DataFrame`] to [Apache ORC] format
+///
+/// Data types without an ORC counterpart are cast to a wider type: unsigned integers are
+/// written as signed integers and categoricals as strings.
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcWriter;
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut file = File::create("file.orc").expect("could not create file");
+///
+///     OrcWriter::new(&mut file)
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct OrcWriter<W> {
+    writer: W,
+    compression: Option<OrcCompression>,
+    stripe_size: Option<usize>,
+}
+
+impl<W> OrcWriter<W>
+where
+    W: Write,
+{
+    /// Set the compression used. Defaults to `ZSTD`.
+    pub fn with_compression(mut self, compression: Option<OrcCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the number of rows of a stripe. Defaults to 262144.
+    pub fn with_stripe_size(mut self, size: Option<usize>) -> Self {
+        self.stripe_size = size;
+        self
+    }
+}
+
+impl<W> SerWriter<W> for OrcWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            compression: Some(OrcCompression::default()),
+            stripe_size: None,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let compression = self
+            .compression
+            .map_or(CompressionKind::None, CompressionKind::from);
+        let block_size = DEFAULT_COMPRESSION_BLOCK_SIZE;
+        let stripe_size = self.stripe_size.unwrap_or(DEFAULT_STRIPE_SIZE).max(1);
+
+        let mut types = vec![OrcType::new(TypeKind::Struct)];
+        let mut columns = Vec::with_capacity(df.width());
+        for c in df.get_columns() {
+            let dtype = to_orc_compatible_dtype(c.dtype())?;
+            let column = types.len() as u32;
+            types[0].field_names.push(c.name().clone());
+            types[0].subtypes.push(column);
+            push_orc_types(&dtype, &mut types);
+            columns.push(c.as_materialized_series().strict_cast(&dtype)?);
+        }
+
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"ORC");
+        let mut file_offset = buf.len() as u64;
+        let mut stripes = Vec::new();
+        let mut stripe_statistics: Vec<Vec<ColumnStatistics>> = Vec::new();
+        let mut file_statistics: Option<Vec<ColumnStatistics>> = None;
+
+        for offset in (0..df.height()).step_by(stripe_size) {
+            let num_rows = stripe_size.min(df.height() - offset);
+            let mut stripe = EncodedStripe::new(&types, num_rows);
+            for (series, &column) in columns.iter().zip(&types[0].subtypes) {
+                let array = series
+                    .slice(offset as i64, num_rows)
+                    .rechunk()
+                    .to_arrow(0, CompatLevel::newest());
+                stripe.encode_column(&types, column, array.as_ref(), None)?;
+            }
+
+            let stripe_start = buf.len() as u64;
+            let mut footer = StripeFooter {
+                streams: Vec::with_capacity(stripe.streams.len()),
+                columns: stripe.encodings,
+            };
+            for (column, kind, data) in stripe.streams {
+                let start = buf.len();
+                compress(compression, block_size, &data, &mut buf)?;
+                footer.streams.push(Stream {
+                    kind,
+                    column,
+                    length: (buf.len() - start) as u64,
+                });
+            }
+            let data_length = buf.len() as u64 - stripe_start;
+            let footer_length =
+                write_message(&mut buf, compression, block_size, |w| footer.encode(w))?;
+            let info = StripeInformation {
+                offset: file_offset,
+                index_length: 0,
+                data_length,
+                footer_length,
+                number_of_rows: num_rows as u64,
+            };
+            file_offset += info.length();
+            stripes.push(info);
+
+            match &mut file_statistics {
+                None => file_statistics = Some(stripe.statistics.clone()),
+                Some(file_statistics) => {
+                    for (lhs, rhs) in file_statistics.iter_mut().zip(&stripe.statistics) {
+                        merge_statistics(lhs, rhs);
+                    }
+                },
+            }
+            stripe_statistics.push(stripe.statistics);
+
+            // Flush every stripe so that the file doesn't have to be kept in memory.
+            self.writer.write_all(&buf)?;
+            buf.clear();
+        }
+        let content_length = stripes.iter().map(|s| s.length()).sum::<u64>();
+
+        let metadata_length = write_message(&mut buf, compression, block_size, |w| {
+            proto::encode_metadata(&stripe_statistics, w)
+        })?;
+        let footer = Footer {
+            header_length: 3,
+            content_length,
+            stripes,
+            statistics: file_statistics.unwrap_or_else(|| {
+                vec![
+                    ColumnStatistics {
+                        number_of_values: Some(0),
+                        has_null: Some(false),
+                        min_max: None,
+                    };
+                    types.len()
+                ]
+            }),
+            types,
+            number_of_rows: df.height() as u64,
+        };
+        let footer_length = write_message(&mut buf, compression, block_size, |w| footer.encode(w))?;
+
+        let postscript = PostScript {
+            footer_length,
+            compression,
+            compression_block_size: block_size as u64,
+            metadata_length,
+        };
+        let mut w = ProtoWriter::default();
+        postscript.encode(&mut w);
+        let postscript = w.into_inner();
+        buf.extend_from_slice(&postscript);
+        buf.push(postscript.len() as u8);
+
+        self.writer.write_all(&buf)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Write a compressed protobuf message and return its length.
+fn write_message(
+    buf: &mut Vec<u8>,
+    compression: CompressionKind,
+    block_size: usize,
+    f: impl FnOnce(&mut ProtoWriter),
+) -> PolarsResult<u64> {
+    let mut w = ProtoWriter::default();
+    f(&mut w);
+    let start = buf.len();
+    compress(compression, block_size, &w.into_inner(), buf)?;
+    Ok((buf.len() - start) as u64)
+}
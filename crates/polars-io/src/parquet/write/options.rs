@@ -1,7 +1,7 @@
 use polars_error::PolarsResult;
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BloomFilterOptions, BrotliLevel as BrotliLevelParquet, CompressionOptions,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
@@ -17,6 +17,9 @@ pub struct ParquetWriteOptions {
     pub compression: ParquetCompression,
     /// Compute and write column statistics.
     pub statistics: StatisticsOptions,
+    /// Write a bloom filter for every flat column that doesn't get one from the field
+    /// overwrites.
+    pub bloom_filter: Option<BloomFilterOptions>,
     /// If `None` will be all written to a single row group.
     pub row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
@@ -57,6 +60,8 @@ pub struct ParquetFieldOverwrites {
     pub required: Option<bool>,
     pub field_id: Option<i32>,
     pub metadata: Option<Vec<MetadataKeyValue>>,
+    /// Write a bloom filter for the (flat) field.
+    pub bloom_filter: Option<BloomFilterOptions>,
 }
 
 /// The compression strategy to use for writing Parquet files.
@@ -5,9 +5,9 @@ use arrow::datatypes::PhysicalType;
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    ChildWriteOptions, ColumnWriteOptions, CompressionOptions, Encoding, FieldWriteOptions,
-    FileWriter, KeyValue, ListLikeFieldWriteOptions, StatisticsOptions, StructFieldWriteOptions,
-    Version, WriteOptions, to_parquet_schema,
+    BloomFilterOptions, ChildWriteOptions, ColumnWriteOptions, CompressionOptions, Encoding,
+    FieldWriteOptions, FileWriter, KeyValue, ListLikeFieldWriteOptions, StatisticsOptions,
+    StructFieldWriteOptions, Version, WriteOptions, to_parquet_schema,
 };
 
 use super::batched_writer::BatchedWriter;
@@ -24,6 +24,7 @@ impl ParquetWriteOptions {
         ParquetWriter::new(f)
             .with_compression(self.compression)
             .with_statistics(self.statistics)
+            .with_bloom_filter(self.bloom_filter)
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
@@ -38,6 +39,8 @@ pub struct ParquetWriter<W> {
     compression: CompressionOptions,
     /// Compute and write column statistics.
     statistics: StatisticsOptions,
+    /// Write bloom filters for the flat columns.
+    bloom_filter: Option<BloomFilterOptions>,
     /// if `None` will be 512^2 rows
     row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
@@ -64,6 +67,7 @@ where
             writer,
             compression: ParquetCompression::default().into(),
             statistics: StatisticsOptions::default(),
+            bloom_filter: None,
             row_group_size: None,
             data_page_size: None,
             parallel: true,
@@ -88,6 +92,12 @@ where
         self
     }
 
+    /// Write a bloom filter for every flat column that doesn't get one from the field overwrites.
+    pub fn with_bloom_filter(mut self, bloom_filter: Option<BloomFilterOptions>) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
     /// Set the row group size (in number of rows) during writing. This can reduce memory pressure and improve
     /// writing performance.
     pub fn with_row_group_size(mut self, size: Option<usize>) -> Self {
@@ -121,7 +131,8 @@ where
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
-        let column_options = get_column_write_options(&schema, &self.field_overwrites);
+        let column_options =
+            get_column_write_options(&schema, self.bloom_filter, &self.field_overwrites);
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
         let options = self.materialize_options();
         let writer = Mutex::new(FileWriter::try_new(
@@ -186,6 +197,7 @@ fn to_column_write_options_rec(
         // Dummy value.
         children: ChildWriteOptions::Leaf(FieldWriteOptions {
             encoding: Encoding::Plain,
+            bloom_filter: None,
         }),
     };
 
@@ -201,9 +213,10 @@ fn to_column_write_options_rec(
         | Dictionary(_) | LargeUtf8 | BinaryView | Utf8View => {
             column_options.children = ChildWriteOptions::Leaf(FieldWriteOptions {
                 encoding: encoding_map(field.dtype()),
+                bloom_filter: overwrites.and_then(|o| o.bloom_filter),
             });
         },
-        List | FixedSizeList | LargeList => {
+        List | FixedSizeList | LargeList | Map => {
             let child_overwrites = overwrites.and_then(|o| match &o.children {
                 ChildFieldOverwrites::None => None,
                 ChildFieldOverwrites::ListLike(child_overwrites) => Some(child_overwrites.as_ref()),
@@ -215,6 +228,8 @@ fn to_column_write_options_rec(
                 to_column_write_options_rec(inner, child_overwrites)
             } else if let ArrowDataType::LargeList(inner) = a {
                 to_column_write_options_rec(inner, child_overwrites)
+            } else if let ArrowDataType::Map(inner, _) = a {
+                to_column_write_options_rec(inner, child_overwrites)
             } else if let ArrowDataType::FixedSizeList(inner, _) = a {
                 to_column_write_options_rec(inner, child_overwrites)
             } else {
@@ -253,7 +268,7 @@ fn to_column_write_options_rec(
             }
         },
 
-        Map | Union => unreachable!(),
+        Union => unreachable!(),
     }
 
     column_options
@@ -261,6 +276,7 @@ fn to_column_write_options_rec(
 
 pub fn get_column_write_options(
     schema: &ArrowSchema,
+    bloom_filter: Option<BloomFilterOptions>,
     field_overwrites: &[ParquetFieldOverwrites],
 ) -> Vec<ColumnWriteOptions> {
     let field_overwrites = PlHashMap::from(
@@ -271,7 +287,14 @@ pub fn get_column_write_options(
     );
     schema
         .iter_values()
-        .map(|f| to_column_write_options_rec(f, field_overwrites.get(&f.name).copied()))
+        .map(|f| {
+            let mut column_options =
+                to_column_write_options_rec(f, field_overwrites.get(&f.name).copied());
+            if let ChildWriteOptions::Leaf(leaf) = &mut column_options.children {
+                leaf.bloom_filter = leaf.bloom_filter.or(bloom_filter);
+            }
+            column_options
+        })
         .collect()
 }
 
@@ -286,7 +309,7 @@ fn encoding_map(dtype: &ArrowDataType) -> Encoding {
         PhysicalType::Primitive(dt) => {
             use arrow::types::PrimitiveType::*;
             match dt {
-                Float32 | Float64 | Float16 => Encoding::Plain,
+                Float32 | Float64 | Float16 | UInt128 => Encoding::Plain,
                 _ => Encoding::RleDictionary,
             }
         },
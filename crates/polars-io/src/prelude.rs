@@ -13,4 +13,5 @@ pub use crate::parquet::{metadata::*, read::*, write::*};
 pub use crate::partition::write_partitioned_dataset;
 pub use crate::path_utils::*;
 pub use crate::shared::{SerReader, SerWriter};
+pub use crate::utils::compression::{CompressedWriter, ExternalCompression};
 pub use crate::utils::*;
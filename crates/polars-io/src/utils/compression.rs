@@ -1,13 +1,19 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
 use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
+use polars_utils::mmap::MemSlice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
     GZIP,
     ZLIB,
     ZSTD,
+    BZIP2,
+    XZ,
+    LZ4,
 }
 
 impl SupportedCompression {
@@ -19,17 +25,39 @@ impl SupportedCompression {
             // not enough bytes to perform prefix checks
             return None;
         }
-        match bytes[..4] {
-            [0x1f, 0x8b, _, _] => Some(Self::GZIP),
+        match bytes {
+            [0x1f, 0x8b, ..] => Some(Self::GZIP),
             // Different zlib compression levels without preset dictionary.
-            [0x78, 0x01, _, _] => Some(Self::ZLIB),
-            [0x78, 0x5e, _, _] => Some(Self::ZLIB),
-            [0x78, 0x9c, _, _] => Some(Self::ZLIB),
-            [0x78, 0xda, _, _] => Some(Self::ZLIB),
-            [0x28, 0xb5, 0x2f, 0xfd] => Some(Self::ZSTD),
+            [0x78, 0x01, ..] => Some(Self::ZLIB),
+            [0x78, 0x5e, ..] => Some(Self::ZLIB),
+            [0x78, 0x9c, ..] => Some(Self::ZLIB),
+            [0x78, 0xda, ..] => Some(Self::ZLIB),
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(Self::ZSTD),
+            // "BZh" followed by the block size.
+            [b'B', b'Z', b'h', b'1'..=b'9', ..] => Some(Self::BZIP2),
+            [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => Some(Self::XZ),
+            // LZ4 frame format.
+            [0x04, 0x22, 0x4d, 0x18, ..] => Some(Self::LZ4),
             _ => None,
         }
     }
+
+    /// Wrap `reader` in a decoder for this compression. Files consisting of multiple
+    /// concatenated members (or frames) are decoded completely.
+    #[cfg(feature = "decompress")]
+    pub fn decoder<'a, R: std::io::BufRead + Send + 'a>(
+        &self,
+        reader: R,
+    ) -> PolarsResult<Box<dyn Read + Send + 'a>> {
+        Ok(match self {
+            Self::GZIP => Box::new(flate2::bufread::MultiGzDecoder::new(reader)),
+            Self::ZLIB => Box::new(flate2::bufread::ZlibDecoder::new(reader)),
+            Self::ZSTD => Box::new(zstd::Decoder::with_buffer(reader)?),
+            Self::BZIP2 => Box::new(bzip2::bufread::MultiBzDecoder::new(reader)),
+            Self::XZ => Box::new(liblzma::bufread::XzDecoder::new_multi_decoder(reader)),
+            Self::LZ4 => Box::new(lz4_flex::frame::FrameDecoder::new(reader)),
+        })
+    }
 }
 
 /// Decompress `bytes` if compression is detected, otherwise simply return it.
@@ -40,21 +68,9 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
 
     if let Some(algo) = SupportedCompression::check(bytes) {
         feature_gated!("decompress", {
-            match algo {
-                SupportedCompression::GZIP => {
-                    flate2::read::MultiGzDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZLIB => {
-                    flate2::read::ZlibDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZSTD => {
-                    zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
-                },
-            }
+            algo.decoder(bytes)?
+                .read_to_end(out)
+                .map_err(to_compute_err)?;
 
             Ok(out)
         })
@@ -62,3 +78,181 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Decompresses a compressed file incrementally, so that the decompressed contents never have to
+/// be held in memory at once.
+pub struct DecompressingReader {
+    decoder: Box<dyn Read + Send>,
+}
+
+impl DecompressingReader {
+    /// Returns `None` if no compression is detected in `bytes`.
+    pub fn try_new(bytes: MemSlice) -> PolarsResult<Option<Self>> {
+        let Some(algo) = SupportedCompression::check(&bytes) else {
+            return Ok(None);
+        };
+
+        feature_gated!("decompress", {
+            let decoder = algo.decoder(std::io::Cursor::new(bytes))?;
+            Ok(Some(Self { decoder }))
+        })
+    }
+
+    /// Append up to `n` decompressed bytes to `buf`. Returns the number of bytes appended, which
+    /// is only less than `n` at the end of the file.
+    pub fn read_chunk(&mut self, buf: &mut Vec<u8>, n: usize) -> PolarsResult<usize> {
+        (&mut self.decoder)
+            .take(n as u64)
+            .read_to_end(buf)
+            .map_err(to_compute_err)
+    }
+
+    /// Append decompressed bytes to `buf` until it holds at least `n_lines` complete lines, or
+    /// until the end of the file. Returns whether the end of the file was reached.
+    ///
+    /// `count_lines` returns the number of line endings in the given bytes, and the position of
+    /// the last one. The bytes it is called with always start at the beginning of a line.
+    pub fn read_lines(
+        &mut self,
+        buf: &mut Vec<u8>,
+        n_lines: usize,
+        mut count_lines: impl FnMut(&[u8]) -> (usize, usize),
+    ) -> PolarsResult<bool> {
+        const CHUNK_SIZE: usize = 64 * 1024;
+
+        let mut n_lines_found = 0;
+        let mut counted_until = 0;
+
+        loop {
+            let (count, position) = count_lines(&buf[counted_until..]);
+            if count > 0 {
+                n_lines_found += count;
+                counted_until += position + 1;
+            }
+
+            if n_lines_found >= n_lines {
+                return Ok(false);
+            }
+
+            if self.read_chunk(buf, CHUNK_SIZE)? < CHUNK_SIZE {
+                return Ok(true);
+            }
+        }
+    }
+}
+
+impl Read for DecompressingReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.decoder.read(buf)
+    }
+}
+
+/// Compression of an entire file, for formats that have no compression of their own (CSV,
+/// NDJSON).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ExternalCompression {
+    #[default]
+    Uncompressed,
+    /// Gzip with an optional compression level (0-9).
+    Gzip(Option<u32>),
+    /// Zstandard with an optional compression level.
+    Zstd(Option<i32>),
+}
+
+/// Streaming encoder of an [`ExternalCompression`].
+pub enum CompressedWriter<W: Write> {
+    Uncompressed(W),
+    #[cfg(feature = "compress")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "compress")]
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn new(writer: W, compression: ExternalCompression) -> PolarsResult<Self> {
+        if compression == ExternalCompression::Uncompressed {
+            return Ok(Self::Uncompressed(writer));
+        }
+
+        feature_gated!("compress", {
+            match compression {
+                ExternalCompression::Uncompressed => unreachable!(),
+                ExternalCompression::Gzip(level) => {
+                    let level = level.unwrap_or(6);
+                    polars_ensure!(
+                        level <= 9,
+                        InvalidOperation: "gzip compression level must be between 0 and 9, got {}", level
+                    );
+                    Ok(Self::Gzip(flate2::write::GzEncoder::new(
+                        writer,
+                        flate2::Compression::new(level),
+                    )))
+                },
+                ExternalCompression::Zstd(level) => {
+                    let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+                    polars_ensure!(
+                        zstd::compression_level_range().contains(&level),
+                        InvalidOperation: "invalid zstd compression level: {}", level
+                    );
+                    Ok(Self::Zstd(zstd::Encoder::new(writer, level)?))
+                },
+            }
+        })
+    }
+
+    /// Write the remaining compressed data and return the inner writer.
+    pub fn finish(self) -> PolarsResult<W> {
+        match self {
+            Self::Uncompressed(writer) => Ok(writer),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => Ok(encoder.finish()?),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => Ok(encoder.finish()?),
+        }
+    }
+}
+
+impl CompressedWriter<Vec<u8>> {
+    /// Feed `data` into the encoder and take the compressed bytes it has produced so far.
+    ///
+    /// The remaining bytes are returned by [`CompressedWriter::finish`].
+    pub fn write_and_take(&mut self, data: Vec<u8>) -> PolarsResult<Vec<u8>> {
+        match self {
+            Self::Uncompressed(_) => Ok(data),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => {
+                encoder.write_all(&data)?;
+                Ok(std::mem::take(encoder.get_mut()))
+            },
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => {
+                encoder.write_all(&data)?;
+                Ok(std::mem::take(encoder.get_mut()))
+            },
+        }
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Uncompressed(writer) => writer.write(buf),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Uncompressed(writer) => writer.flush(),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
@@ -8,19 +8,26 @@ mod err;
 mod exitable;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+mod recursive_union;
 
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "fixed_width")]
+pub use fixed_width::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_compute::rolling::QuantileMethod;
@@ -951,6 +958,25 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an Avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(
+        self,
+        target: SinkTarget,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::Avro(options),
+            cloud_options,
+        }))
+    }
+
     /// Stream a query result into a parquet file in a partitioned manner. This is useful if the
     /// final result doesn't fit into memory. This methods will return an error if the query cannot
     /// be completely done in a streaming fashion.
@@ -1063,6 +1089,34 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an Avro file in a partitioned manner. This is useful if the
+    /// final result doesn't fit into memory. This methods will return an error if the query cannot
+    /// be completely done in a streaming fashion.
+    #[cfg(feature = "avro")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn sink_avro_partitioned(
+        self,
+        base_path: Arc<PlPath>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        variant: PartitionVariant,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+        per_partition_sort_by: Option<Vec<SortColumn>>,
+        finish_callback: Option<SinkFinishCallback>,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Partition(PartitionSinkType {
+            base_path,
+            file_path_cb,
+            sink_options,
+            variant,
+            file_type: FileType::Avro(options),
+            cloud_options,
+            per_partition_sort_by,
+            finish_callback,
+        }))
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
@@ -1390,6 +1444,113 @@ impl LazyFrame {
         }
     }
 
+    /// Group by each of the `grouping_sets` and vertically concatenate the aggregations, as
+    /// `GROUPING SETS`, `ROLLUP` and `CUBE` do in SQL.
+    ///
+    /// The grouping sets are given as indices into `keys`. The output holds all `keys`
+    /// followed by the `aggs`, where the keys that are not part of the grouping set of a row
+    /// are null. If `grouping_id` is given, a column with that name is added which holds a
+    /// bitmask of the keys that are not part of the grouping set of the row, with the first
+    /// key as the most significant bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// // The equivalent of `GROUP BY ROLLUP (year, country)`.
+    /// fn example(df: DataFrame) -> PolarsResult<LazyFrame> {
+    ///     df.lazy().group_by_grouping_sets(
+    ///         &[col("year"), col("country")],
+    ///         &[vec![0, 1], vec![0], vec![]],
+    ///         &[col("sales").sum()],
+    ///         None,
+    ///     )
+    /// }
+    /// ```
+    pub fn group_by_grouping_sets(
+        mut self,
+        keys: &[Expr],
+        grouping_sets: &[Vec<usize>],
+        aggs: &[Expr],
+        grouping_id: Option<PlSmallStr>,
+    ) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            !grouping_sets.is_empty(),
+            InvalidOperation: "at least one grouping set is required"
+        );
+        polars_ensure!(
+            grouping_id.is_none() || keys.len() <= 64,
+            InvalidOperation: "a grouping id supports at most 64 keys, got {}", keys.len()
+        );
+
+        let schema = self.collect_schema()?;
+        let key_fields = keys
+            .iter()
+            .map(|e| e.to_field(&schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let agg_names = aggs
+            .iter()
+            .map(|e| Ok(e.to_field(&schema)?.name))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let inputs = grouping_sets
+            .iter()
+            .map(|grouping_set| {
+                let mut is_grouped = vec![false; keys.len()];
+                for &i in grouping_set {
+                    polars_ensure!(
+                        i < keys.len(),
+                        OutOfBounds: "grouping set index {} is out of bounds for {} keys", i, keys.len()
+                    );
+                    is_grouped[i] = true;
+                }
+
+                let by = keys
+                    .iter()
+                    .zip(&is_grouped)
+                    .filter(|(_, grouped)| **grouped)
+                    .map(|(key, _)| key.clone())
+                    .collect::<Vec<_>>();
+                let lf = if by.is_empty() {
+                    self.clone().select(aggs)
+                } else {
+                    self.clone().group_by(by).agg(aggs)
+                };
+
+                let mut id = 0u64;
+                let mut projection = Vec::with_capacity(keys.len() + aggs.len() + 1);
+                for (field, grouped) in key_fields.iter().zip(&is_grouped) {
+                    id <<= 1;
+                    if *grouped {
+                        projection.push(col(field.name.clone()));
+                    } else {
+                        id |= 1;
+                        projection.push(
+                            lit(Null {})
+                                .cast(field.dtype.clone())
+                                .alias(field.name.clone()),
+                        );
+                    }
+                }
+                projection.extend(agg_names.iter().map(|name| col(name.clone())));
+                if let Some(name) = &grouping_id {
+                    projection.push(lit(id).alias(name.clone()));
+                }
+                Ok(lf.select(projection))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        concat(
+            inputs,
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )
+    }
+
     /// Left anti join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -0,0 +1,91 @@
+//! Module containing the implementation of the recursive union, the fixpoint operation behind
+//! recursive common table expressions.
+//!
+//! The recursive term reads from a working table. While executing, the rows produced by the
+//! previous iteration are put in that working table and the recursive term is evaluated again,
+//! until it no longer produces any new rows.
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Repeatedly evaluate `recursive_term` on the rows produced by its previous evaluation,
+    /// starting from the rows of `self`, and vertically concatenate all produced rows.
+    ///
+    /// `recursive_term` is called once with a [`LazyFrame`] representing the rows of the
+    /// previous iteration, which has the schema of `self`. The columns it produces are matched
+    /// with those of `self` by position and cast to their data types. The iteration stops once
+    /// an iteration produces no new rows. If `distinct` is set, duplicate rows are discarded
+    /// and rows that were produced before don't count as new rows, as with SQL `UNION`.
+    ///
+    /// An error is raised if the fixpoint is not reached within `max_iterations` iterations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// // All numbers from 1 up to 10.
+    /// fn example() -> PolarsResult<LazyFrame> {
+    ///     df!("n" => [1])?.lazy().recursive_union(
+    ///         |previous| Ok(previous.filter(col("n").lt(lit(10))).select([col("n") + lit(1)])),
+    ///         false,
+    ///         100,
+    ///     )
+    /// }
+    /// ```
+    pub fn recursive_union<F>(
+        mut self,
+        recursive_term: F,
+        distinct: bool,
+        max_iterations: usize,
+    ) -> PolarsResult<LazyFrame>
+    where
+        F: FnOnce(LazyFrame) -> PolarsResult<LazyFrame>,
+    {
+        let schema = self.collect_schema()?;
+
+        let working_table = WorkingTable::new(&schema);
+        let working_lf = LazyFrame::anonymous_scan(
+            Arc::new(working_table.clone()),
+            ScanArgsAnonymous {
+                schema: Some(schema.clone()),
+                name: "WORKING TABLE",
+                ..Default::default()
+            },
+        )?;
+
+        let mut recursive_lf = recursive_term(working_lf)?;
+        let recursive_width = recursive_lf.collect_schema()?.len();
+        polars_ensure!(
+            recursive_width == schema.len(),
+            SchemaMismatch: "recursive term produces {} columns, while the initial term produces {}",
+            recursive_width, schema.len()
+        );
+        let recursive_lf = recursive_lf.select(
+            schema
+                .iter()
+                .enumerate()
+                .map(|(i, (name, dtype))| {
+                    nth(i as i64)
+                        .as_expr()
+                        .strict_cast(dtype.clone())
+                        .alias(name.clone())
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let lp = DslPlan::RecursiveUnion {
+            initial: Arc::new(self.logical_plan),
+            recursive: Arc::new(recursive_lf.logical_plan),
+            options: RecursiveUnionOptions {
+                distinct,
+                max_iterations,
+                working_table,
+            },
+        };
+        Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
+    }
+}
@@ -1,4 +1,6 @@
 pub(crate) use polars_expr::prelude::*;
+#[cfg(feature = "avro")]
+pub use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -7,6 +9,8 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use polars_io::utils::compression::ExternalCompression;
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
@@ -0,0 +1,145 @@
+use polars_core::prelude::*;
+use polars_io::avro::AvroScanOptions;
+use polars_io::cloud::CloudOptions;
+use polars_io::{HiveOptions, RowIndex};
+use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsAvro {
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub hive_options: HiveOptions,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsAvro {
+    fn default() -> Self {
+        Self {
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            row_index: None,
+            cloud_options: Default::default(),
+            hive_options: Default::default(),
+            include_file_paths: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct LazyAvroReader {
+    args: ScanArgsAvro,
+    sources: ScanSources,
+}
+
+impl LazyAvroReader {
+    fn new(args: ScanArgsAvro) -> Self {
+        Self {
+            args,
+            sources: ScanSources::default(),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyAvroReader {
+    fn finish(self) -> PolarsResult<LazyFrame> {
+        let args = self.args;
+
+        let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
+
+        let lf: LazyFrame = DslBuilder::scan_avro(
+            self.sources,
+            AvroScanOptions,
+            UnifiedScanArgs {
+                schema: None,
+                cloud_options: args.cloud_options,
+                hive_options: args.hive_options,
+                rechunk: args.rechunk,
+                cache: args.cache,
+                glob: true,
+                projection: None,
+                column_mapping: None,
+                default_values: None,
+                row_index: args.row_index,
+                pre_slice,
+                cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                missing_columns_policy: MissingColumnsPolicy::Raise,
+                extra_columns_policy: ExtraColumnsPolicy::Raise,
+                include_file_paths: args.include_file_paths,
+                deletion_files: None,
+            },
+        )?
+        .build()
+        .into();
+
+        Ok(lf)
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!()
+    }
+
+    fn sources(&self) -> &ScanSources {
+        &self.sources
+    }
+
+    fn with_sources(mut self, sources: ScanSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+
+    /// [CloudOptions] used to list files.
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.args.cloud_options.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an Avro scan.
+    ///
+    /// The blocks of the files are decoded in parallel by the streaming engine.
+    pub fn scan_avro(path: PlPath, args: ScanArgsAvro) -> PolarsResult<Self> {
+        Self::scan_avro_sources(ScanSources::Paths([path].into()), args)
+    }
+
+    pub fn scan_avro_files(paths: Arc<[PlPath]>, args: ScanArgsAvro) -> PolarsResult<Self> {
+        Self::scan_avro_sources(ScanSources::Paths(paths), args)
+    }
+
+    pub fn scan_avro_sources(sources: ScanSources, args: ScanArgsAvro) -> PolarsResult<Self> {
+        LazyAvroReader::new(args).with_sources(sources).finish()
+    }
+}
@@ -0,0 +1,156 @@
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::fixed_width::FixedWidthReadOptions;
+use polars_io::{HiveOptions, RowIndex};
+use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsFixedWidth {
+    pub options: FixedWidthReadOptions,
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub glob: bool,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsFixedWidth {
+    fn default() -> Self {
+        Self {
+            options: Default::default(),
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            glob: true,
+            row_index: None,
+            cloud_options: Default::default(),
+            include_file_paths: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct LazyFixedWidthReader {
+    args: ScanArgsFixedWidth,
+    sources: ScanSources,
+}
+
+impl LazyFixedWidthReader {
+    fn new(args: ScanArgsFixedWidth) -> Self {
+        Self {
+            args,
+            sources: ScanSources::default(),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyFixedWidthReader {
+    fn finish(self) -> PolarsResult<LazyFrame> {
+        let args = self.args;
+
+        let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
+
+        let lf: LazyFrame = DslBuilder::scan_fixed_width(
+            self.sources,
+            args.options,
+            UnifiedScanArgs {
+                schema: None,
+                cloud_options: args.cloud_options,
+                hive_options: HiveOptions::new_disabled(),
+                rechunk: args.rechunk,
+                cache: args.cache,
+                glob: args.glob,
+                projection: None,
+                column_mapping: None,
+                default_values: None,
+                row_index: args.row_index,
+                pre_slice,
+                cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                missing_columns_policy: MissingColumnsPolicy::Raise,
+                extra_columns_policy: ExtraColumnsPolicy::Raise,
+                include_file_paths: args.include_file_paths,
+                deletion_files: None,
+            },
+        )?
+        .build()
+        .into();
+
+        Ok(lf)
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!()
+    }
+
+    fn sources(&self) -> &ScanSources {
+        &self.sources
+    }
+
+    fn with_sources(mut self, sources: ScanSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+
+    /// [CloudOptions] used to list files.
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.args.cloud_options.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from a scan of fixed-width text files.
+    ///
+    /// The files are split by line, and the lines are parsed in parallel by the streaming
+    /// engine.
+    pub fn scan_fixed_width(path: PlPath, args: ScanArgsFixedWidth) -> PolarsResult<Self> {
+        Self::scan_fixed_width_sources(ScanSources::Paths([path].into()), args)
+    }
+
+    pub fn scan_fixed_width_files(
+        paths: Arc<[PlPath]>,
+        args: ScanArgsFixedWidth,
+    ) -> PolarsResult<Self> {
+        Self::scan_fixed_width_sources(ScanSources::Paths(paths), args)
+    }
+
+    pub fn scan_fixed_width_sources(
+        sources: ScanSources,
+        args: ScanArgsFixedWidth,
+    ) -> PolarsResult<Self> {
+        LazyFixedWidthReader::new(args)
+            .with_sources(sources)
+            .finish()
+    }
+}
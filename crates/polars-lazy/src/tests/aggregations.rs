@@ -568,3 +568,32 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+fn test_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df![
+        "year" => [2018, 2018, 2019],
+        "country" => ["US", "UK", "US"],
+        "sales" => [1i64, 2, 3],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_grouping_sets(
+            &[col("year"), col("country")],
+            &[vec![0, 1], vec![0], vec![]],
+            &[col("sales").sum()],
+            Some("grouping_id".into()),
+        )?
+        .sort(["grouping_id", "year", "country"], Default::default())
+        .collect()?;
+
+    let expected = df![
+        "year" => [Some(2018), Some(2018), Some(2019), Some(2018), Some(2019), None],
+        "country" => [Some("UK"), Some("US"), Some("US"), None, None, None],
+        "sales" => [2i64, 1, 3, 3, 3, 6],
+        "grouping_id" => [0u64, 0, 0, 1, 1, 3],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
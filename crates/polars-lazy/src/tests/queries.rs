@@ -2010,3 +2010,42 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_recursive_union() -> PolarsResult<()> {
+    let edges = df![
+        "src" => [1, 2, 3, 3],
+        "dst" => [2, 3, 1, 4],
+    ]?
+    .lazy();
+    let reachable = |distinct, max_iterations| {
+        df!["node" => [1]]?.lazy().recursive_union(
+            |previous| {
+                Ok(previous
+                    .join(
+                        edges.clone(),
+                        [col("node")],
+                        [col("src")],
+                        JoinArgs::new(JoinType::Inner),
+                    )
+                    .select([col("dst")]))
+            },
+            distinct,
+            max_iterations,
+        )
+    };
+
+    // The cycle 1 -> 2 -> 3 -> 1 only terminates if already found rows are discarded.
+    let out = reachable(true, 10)?
+        .sort(["node"], Default::default())
+        .collect()?;
+    assert_eq!(out, df!["node" => [1, 2, 3, 4]]?);
+
+    let err = reachable(false, 10)?.collect().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("did not terminate within 10 iterations")
+    );
+
+    Ok(())
+}
@@ -13,6 +13,7 @@ mod merge_sorted;
 mod projection;
 mod projection_simple;
 mod projection_utils;
+mod recursive_union;
 mod scan;
 mod sink;
 mod slice;
@@ -45,6 +46,7 @@ pub(super) use self::join::*;
 pub(super) use self::merge_sorted::*;
 pub(super) use self::projection::*;
 pub(super) use self::projection_simple::*;
+pub(super) use self::recursive_union::*;
 pub(super) use self::scan::*;
 pub(super) use self::sink::*;
 pub(super) use self::slice::*;
@@ -0,0 +1,121 @@
+use polars_core::chunked_array::ops::row_encode::encode_rows_unordered;
+use polars_utils::unique_id::UniqueId;
+
+use super::*;
+use crate::{StreamingExecutorBuilder, create_physical_plan};
+
+/// Evaluates the recursive term on the rows produced by its previous evaluation, until it no
+/// longer produces new rows.
+pub(crate) struct RecursiveUnionExec {
+    initial: Box<dyn Executor>,
+    /// Planned anew for every iteration, as executors can only run once.
+    recursive: IRPlan,
+    options: RecursiveUnionOptions,
+    build_streaming_executor: Option<StreamingExecutorBuilder>,
+}
+
+impl RecursiveUnionExec {
+    pub(crate) fn new(
+        initial: Box<dyn Executor>,
+        recursive: IRPlan,
+        options: RecursiveUnionOptions,
+        build_streaming_executor: Option<StreamingExecutorBuilder>,
+    ) -> Self {
+        Self {
+            initial,
+            recursive,
+            options,
+            build_streaming_executor,
+        }
+    }
+
+    fn execute_recursive(&self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let mut lp_arena = self.recursive.lp_arena.clone();
+        let mut expr_arena = self.recursive.expr_arena.clone();
+
+        // Give the caches of every iteration their own ids, so they don't clash with those of
+        // other iterations or of the rest of the query.
+        let mut cache_ids = PlHashMap::new();
+        for i in 0..lp_arena.len() {
+            if let IR::Cache { id, .. } = lp_arena.get_mut(Node(i)) {
+                *id = *cache_ids.entry(*id).or_insert_with(UniqueId::new);
+            }
+        }
+
+        let mut exec = create_physical_plan(
+            self.recursive.lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            self.build_streaming_executor,
+        )?;
+        exec.execute(state)
+    }
+
+    fn iterate(&self, initial: DataFrame, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let working_table = &self.options.working_table;
+
+        // Only the rows of the last iteration are fed to the recursive term. With `distinct`,
+        // the rows seen so far are kept as a set, so that every iteration only has to check
+        // the rows it produced.
+        let mut seen = self.options.distinct.then(PlHashSet::new);
+        let mut new_rows = match &mut seen {
+            Some(seen) => retain_unseen(initial, seen)?,
+            None => initial,
+        };
+        let mut result = new_rows.clone();
+
+        let mut iterations = 0;
+        while new_rows.height() > 0 {
+            state.should_stop()?;
+            polars_ensure!(
+                iterations < self.options.max_iterations,
+                ComputeError: "recursive union did not terminate within {} iterations",
+                self.options.max_iterations
+            );
+            iterations += 1;
+
+            working_table.set(new_rows);
+            let produced = self.execute_recursive(state)?;
+            new_rows = match &mut seen {
+                Some(seen) => retain_unseen(produced, seen)?,
+                None => produced,
+            };
+            result.vstack_mut(&new_rows)?;
+        }
+
+        result.as_single_chunk_par();
+        Ok(result)
+    }
+}
+
+/// Keep the rows of `df` that are not in `seen` and add them to it. Of rows that occur more
+/// than once in `df`, only the first is kept.
+fn retain_unseen(df: DataFrame, seen: &mut PlHashSet<Box<[u8]>>) -> PolarsResult<DataFrame> {
+    let rows = encode_rows_unordered(df.get_columns())?;
+    let mask: BooleanChunked = rows
+        .into_no_null_iter()
+        .map(|row| seen.insert(row.into()))
+        .collect();
+    df.filter(&mask)
+}
+
+impl Executor for RecursiveUnionExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run RecursiveUnionExec")
+            }
+        }
+        let initial = self.initial.execute(state)?;
+
+        let working_table = &self.options.working_table;
+        let _guard = working_table.lock();
+        let out = state
+            .clone()
+            .record(|| self.iterate(initial, state), "recursive_union".into());
+        working_table.clear();
+        out
+    }
+}
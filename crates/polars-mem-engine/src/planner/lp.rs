@@ -2,6 +2,7 @@ use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_expr::state::ExecutionState;
 use polars_plan::plans::expr_ir::ExprIR;
+use polars_plan::plans::prune::prune_plan;
 use polars_utils::format_pl_smallstr;
 use polars_utils::unique_id::UniqueId;
 use recursive::recursive;
@@ -303,7 +304,12 @@ fn create_physical_plan_impl(
                                 FileType::Csv(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::csv::write::CsvWriter;
-                                    CsvWriter::new(BufWriter::new(writer))
+                                    use polars_io::utils::compression::CompressedWriter;
+                                    let mut writer = CompressedWriter::new(
+                                        BufWriter::new(writer),
+                                        options.compression,
+                                    )?;
+                                    CsvWriter::new(&mut writer)
                                         .include_bom(options.include_bom)
                                         .include_header(options.include_header)
                                         .with_separator(options.serialize_options.separator)
@@ -331,15 +337,32 @@ fn create_physical_plan_impl(
                                         .with_null_value(options.serialize_options.null.clone())
                                         .with_quote_style(options.serialize_options.quote_style)
                                         .finish(&mut df)?;
+                                    writer.finish()?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
+                                    use polars_io::utils::compression::CompressedWriter;
 
-                                    JsonWriter::new(BufWriter::new(writer))
+                                    let mut writer = CompressedWriter::new(
+                                        BufWriter::new(writer),
+                                        options.compression,
+                                    )?;
+                                    JsonWriter::new(&mut writer)
                                         .with_json_format(JsonFormat::JsonLines)
                                         .finish(&mut df)?;
+                                    writer.finish()?;
+                                },
+                                #[cfg(feature = "avro")]
+                                FileType::Avro(options) => {
+                                    use polars_io::SerWriter;
+                                    use polars_io::avro::AvroWriter;
+
+                                    AvroWriter::new(BufWriter::new(writer))
+                                        .with_compression(options.compression.map(Into::into))
+                                        .with_name(options.name.to_string())
+                                        .finish(&mut df)?;
                                 },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
@@ -847,6 +870,35 @@ fn create_physical_plan_impl(
             };
             Ok(Box::new(exec))
         },
+        RecursiveUnion {
+            initial,
+            recursive,
+            options,
+        } => {
+            let recursive = prune_plan(IRPlanRef {
+                lp_top: recursive,
+                lp_arena,
+                expr_arena,
+            });
+            let initial = recurse!(initial, state)?;
+            let executor = Box::new(executors::RecursiveUnionExec::new(
+                initial,
+                recursive,
+                options,
+                build_streaming_executor,
+            ));
+
+            // Use cache so that this runs during the cache pre-filling stage and not on the
+            // thread pool, as the scans of the recursive term may run on the streaming engine,
+            // which uses the thread pool internally.
+            let mut prefill = executors::CachePrefill::new_cache(executor, UniqueId::new());
+            let exec = prefill.make_exec();
+            let existing = cache_nodes.insert(prefill.id(), prefill);
+
+            assert!(existing.is_none());
+
+            Ok(Box::new(exec))
+        },
         Invalid => unreachable!(),
     }
 }
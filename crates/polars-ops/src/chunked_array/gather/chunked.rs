@@ -218,6 +218,30 @@ impl TakeChunked for Series {
                     .into_time()
                     .into_series()
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ca = self.interval().unwrap();
+                ca.physical()
+                    .take_chunked_unchecked(by, sorted, avoid_sharing)
+                    .into_interval()
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-f16")]
+            Float16 => {
+                let ca = self.float16().unwrap();
+                ca.physical()
+                    .take_chunked_unchecked(by, sorted, avoid_sharing)
+                    .into_float16()
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, _) => {
+                let ca = self.extension().unwrap();
+                let storage = ca
+                    .storage()
+                    .take_chunked_unchecked(by, sorted, avoid_sharing);
+                ca.with_storage(storage).into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 with_match_categorical_physical_type!(self.dtype().cat_physical().unwrap(), |$C| {
@@ -318,6 +342,28 @@ impl TakeChunked for Series {
                     .into_time()
                     .into_series()
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ca = self.interval().unwrap();
+                ca.physical()
+                    .take_opt_chunked_unchecked(by, avoid_sharing)
+                    .into_interval()
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-f16")]
+            Float16 => {
+                let ca = self.float16().unwrap();
+                ca.physical()
+                    .take_opt_chunked_unchecked(by, avoid_sharing)
+                    .into_float16()
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, _) => {
+                let ca = self.extension().unwrap();
+                let storage = ca.storage().take_opt_chunked_unchecked(by, avoid_sharing);
+                ca.with_storage(storage).into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 with_match_categorical_physical_type!(self.dtype().cat_physical().unwrap(), |$C| {
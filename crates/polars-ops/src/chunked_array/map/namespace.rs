@@ -0,0 +1,186 @@
+use arrow::array::{Array, LargeListArray};
+use arrow::offset::Offsets;
+use polars_core::utils::try_get_supertype;
+
+use super::*;
+
+/// Broadcast a map of length 1 to the length of `key`.
+fn broadcast_map(ca: &MapChunked, key_len: usize) -> PolarsResult<MapChunked> {
+    if ca.len() == 1 && key_len != 1 {
+        return Ok(ca
+            .clone()
+            .into_series()
+            .new_from_index(0, key_len)
+            .map()?
+            .clone());
+    }
+    polars_ensure!(
+        key_len == 1 || key_len == ca.len(),
+        length_mismatch = "map.get",
+        ca.len(),
+        key_len
+    );
+    Ok(ca.clone())
+}
+
+/// For every map, find the entry of which the key equals `key`.
+///
+/// Returns the values of all entries and, for every map, the position of the first matching
+/// entry in those values. Null maps get a null position.
+fn find_key(ca: &MapChunked, key: &Series) -> PolarsResult<(Series, IdxCa)> {
+    let key = key.cast(ca.key_dtype())?;
+    let ca = broadcast_map(ca, key.len())?;
+
+    let entries = ca.entries().rechunk();
+    let arr = entries.downcast_as_array();
+    let offsets = arr.offsets();
+    let start = *offsets.first() as usize;
+    let end = *offsets.last() as usize;
+
+    let inner = entries.get_inner().slice(start as i64, end - start);
+    let inner = inner.struct_()?;
+    let fields = inner.fields_as_series();
+    let (keys, values) = (&fields[0], &fields[1]);
+
+    let needles = if key.len() == 1 {
+        key.new_from_index(0, keys.len())
+    } else {
+        let rows = offsets
+            .lengths()
+            .enumerate()
+            .flat_map(|(i, len)| std::iter::repeat_n(i as IdxSize, len))
+            .collect::<Vec<_>>();
+        key.take_slice(&rows)?
+    };
+    let mask = keys.equal(&needles)?.rechunk().into_owned();
+    let mask = mask.downcast_as_array();
+
+    let validity = arr.validity();
+    let positions = offsets.buffer().windows(2).enumerate().map(|(i, w)| {
+        if validity.is_some_and(|v| !v.get_bit(i)) {
+            return None;
+        }
+        let (lo, hi) = (w[0] as usize - start, w[1] as usize - start);
+        (lo..hi)
+            .find(|&j| mask.is_valid(j) && mask.value(j))
+            .map(|j| j as IdxSize)
+    });
+    let positions = IdxCa::from_iter_options(ca.name().clone(), positions);
+
+    Ok((values.clone(), positions))
+}
+
+/// Get the keys or values of every map.
+fn map_field(ca: &MapChunked, index: usize) -> PolarsResult<ListChunked> {
+    let mut out = ca
+        .entries()
+        .apply_to_inner(&|entries| Ok(entries.struct_()?.fields_as_series()[index].clone()))?;
+    out.rename(ca.name().clone());
+    Ok(out)
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the value belonging to `key` in every map.
+    ///
+    /// Maps without such a key, or that are null themselves, give a null.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let (values, positions) = find_key(ca, key)?;
+        Ok(values.take(&positions)?.with_name(ca.name().clone()))
+    }
+
+    /// Check for every map whether it contains `key`.
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let (_, positions) = find_key(ca, key)?;
+        let ca = broadcast_map(ca, key.len())?;
+        let out: BooleanChunked = positions
+            .iter()
+            .zip(ca.is_not_null().iter())
+            .map(|(pos, valid)| valid.unwrap_or(false).then_some(pos.is_some()))
+            .collect();
+        Ok(out.with_name(ca.name().clone()))
+    }
+
+    fn map_keys(&self) -> PolarsResult<ListChunked> {
+        map_field(self.as_map(), 0)
+    }
+
+    fn map_values(&self) -> PolarsResult<ListChunked> {
+        map_field(self.as_map(), 1)
+    }
+
+    /// Get the `{key, value}` entries of every map as a list of structs.
+    fn map_to_list(&self) -> ListChunked {
+        self.as_map().entries().clone()
+    }
+
+    /// Get the values belonging to `fields` as the fields of a struct.
+    fn map_to_struct(&self, fields: &[PlSmallStr]) -> PolarsResult<StructChunked> {
+        let ca = self.as_map();
+        let fields = fields
+            .iter()
+            .map(|name| {
+                let key = Series::new(PlSmallStr::EMPTY, [name.as_str()]);
+                Ok(self.map_get(&key)?.with_name(name.clone()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())
+    }
+}
+
+impl MapNameSpace for MapChunked {}
+
+/// Convert a struct into a map from the field names to the field values.
+///
+/// The values are cast to the supertype of all fields.
+pub fn struct_to_map(ca: &StructChunked) -> PolarsResult<MapChunked> {
+    let fields = ca.fields_as_series();
+    let n_fields = fields.len();
+    let len = ca.len();
+
+    let value_dtype = fields
+        .iter()
+        .try_fold(DataType::Null, |acc, s| try_get_supertype(&acc, s.dtype()))?;
+    let mut values = Series::new_empty(MAP_VALUE_NAME, &value_dtype);
+    for s in fields.iter() {
+        values.append(&s.cast(&value_dtype)?)?;
+    }
+    let idx = (0..len)
+        .flat_map(|i| (0..n_fields).map(move |j| (j * len + i) as IdxSize))
+        .collect::<Vec<_>>();
+    let values = values.take_slice(&idx)?;
+    let keys = StringChunked::from_iter_values(
+        MAP_KEY_NAME,
+        (0..len).flat_map(|_| fields.iter().map(|s| s.name().as_str())),
+    );
+    let entries = StructChunked::from_series(
+        PlSmallStr::EMPTY,
+        len * n_fields,
+        [keys.into_series(), values].iter(),
+    )?
+    .into_series()
+    .rechunk();
+
+    let offsets = (0..=len).map(|i| (i * n_fields) as i64).collect::<Vec<_>>();
+    // SAFETY: the offsets are monotonically increasing.
+    let offsets = unsafe { Offsets::new_unchecked(offsets) };
+    let validity = ca.has_nulls().then(|| {
+        ca.is_not_null()
+            .rechunk()
+            .downcast_as_array()
+            .values()
+            .clone()
+    });
+    let dtype = LargeListArray::default_datatype(
+        entries
+            .dtype()
+            .to_physical()
+            .to_arrow(CompatLevel::newest()),
+    );
+    let arr = LargeListArray::new(dtype, offsets.into(), entries.chunks()[0].clone(), validity);
+
+    let mut out = ListChunked::with_chunk(ca.name().clone(), arr);
+    out.set_inner_dtype(entries.dtype().clone());
+    out.to_map()
+}
@@ -4,6 +4,8 @@ mod binary;
 #[cfg(feature = "timezones")]
 pub mod datetime;
 pub mod list;
+#[cfg(feature = "dtype-map")]
+pub mod map;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
 #[cfg(feature = "peaks")]
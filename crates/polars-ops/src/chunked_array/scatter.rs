@@ -42,6 +42,8 @@ impl PolarsOpsNumericType for Int32Type {}
 impl PolarsOpsNumericType for Int64Type {}
 #[cfg(feature = "dtype-i128")]
 impl PolarsOpsNumericType for Int128Type {}
+#[cfg(feature = "dtype-u128")]
+impl PolarsOpsNumericType for UInt128Type {}
 impl PolarsOpsNumericType for Float32Type {}
 impl PolarsOpsNumericType for Float64Type {}
 
@@ -74,6 +74,10 @@ pub(super) fn par_sorted_merge_left(
         DataType::Int128 => {
             par_sorted_merge_left_impl(s_left.i128().unwrap(), s_right.i128().unwrap())
         },
+        #[cfg(feature = "dtype-u128")]
+        DataType::UInt128 => {
+            par_sorted_merge_left_impl(s_left.u128().unwrap(), s_right.u128().unwrap())
+        },
         DataType::Float32 => {
             par_sorted_merge_left_impl(s_left.f32().unwrap(), s_right.f32().unwrap())
         },
@@ -150,6 +154,10 @@ pub(super) fn par_sorted_merge_inner_no_nulls(
         DataType::Int128 => {
             par_sorted_merge_inner_impl(s_left.i128().unwrap(), s_right.i128().unwrap())
         },
+        #[cfg(feature = "dtype-u128")]
+        DataType::UInt128 => {
+            par_sorted_merge_inner_impl(s_left.u128().unwrap(), s_right.u128().unwrap())
+        },
         DataType::Float32 => {
             par_sorted_merge_inner_impl(s_left.f32().unwrap(), s_right.f32().unwrap())
         },
@@ -209,7 +217,9 @@ pub(crate) fn _sort_or_hash_inner(
     let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
         .map(|s| s.parse::<f32>().unwrap())
         .unwrap_or(1.0);
-    let is_numeric = s_left.dtype().to_physical().is_primitive_numeric();
+    // The bits of half precision floats are not ordered like their values.
+    let is_numeric =
+        s_left.dtype().to_physical().is_primitive_numeric() && !s_left.dtype().is_float16();
 
     if validate.needs_checks() {
         return s_left.hash_join_inner(s_right, validate, nulls_equal);
@@ -311,7 +321,9 @@ pub(crate) fn sort_or_hash_left(
     let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
         .map(|s| s.parse::<f32>().unwrap())
         .unwrap_or(1.0);
-    let is_numeric = s_left.dtype().to_physical().is_primitive_numeric();
+    // The bits of half precision floats are not ordered like their values.
+    let is_numeric =
+        s_left.dtype().to_physical().is_primitive_numeric() && !s_left.dtype().is_float16();
 
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
 
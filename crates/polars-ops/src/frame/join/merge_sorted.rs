@@ -72,6 +72,11 @@ fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> PolarsR
             let rhs = rhs.binary().unwrap();
             merge_ca(lhs, rhs, merge_indicator).into_series()
         },
+        BinaryOffset => {
+            let lhs = lhs.binary_offset().unwrap();
+            let rhs = rhs.binary_offset().unwrap();
+            merge_ca(lhs, rhs, merge_indicator).into_series()
+        },
         #[cfg(feature = "dtype-struct")]
         Struct(_) => {
             let lhs = lhs.struct_().unwrap();
@@ -162,6 +167,11 @@ fn series_to_merge_indicator(lhs: &Series, rhs: &Series) -> PolarsResult<Vec<boo
             let rhs = rhs_s.binary().unwrap();
             get_merge_indicator(lhs.into_iter(), rhs.into_iter())
         },
+        DataType::BinaryOffset => {
+            let lhs = lhs_s.binary_offset().unwrap();
+            let rhs = rhs_s.binary_offset().unwrap();
+            get_merge_indicator(lhs.into_iter(), rhs.into_iter())
+        },
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(_) => {
             let options = SortOptions::default();
@@ -30,6 +30,11 @@ pub fn abs(s: &Series) -> PolarsResult<Series> {
             let out = ca.wrapping_abs().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => {
+            let ca = s.float16().unwrap().to_float32();
+            ca.wrapping_abs().to_float16().into_series()
+        },
         dt if dt.is_unsigned_integer() => s.clone(),
         dt => polars_bail!(opq = abs, dt),
     };
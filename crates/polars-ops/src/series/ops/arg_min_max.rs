@@ -62,6 +62,10 @@ impl ArgAgg for Series {
             #[cfg(feature = "dtype-categorical")]
             Enum(_, _) => phys_s.arg_min(),
             Date | Datetime(_, _) | Duration(_) | Time => phys_s.arg_min(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => phys_s.arg_min(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => self.float16().unwrap().to_float32().into_series().arg_min(),
             String => {
                 let ca = self.str().unwrap();
                 arg_min_str(ca)
@@ -101,6 +105,10 @@ impl ArgAgg for Series {
             #[cfg(feature = "dtype-categorical")]
             Enum(_, _) => phys_s.arg_max(),
             Date | Datetime(_, _) | Duration(_) | Time => phys_s.arg_max(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => phys_s.arg_max(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => self.float16().unwrap().to_float32().into_series().arg_max(),
             String => {
                 let ca = self.str().unwrap();
                 arg_max_str(ca)
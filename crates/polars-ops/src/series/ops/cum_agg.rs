@@ -4,6 +4,8 @@ use arity::unary_elementwise_values;
 use arrow::array::{Array, BooleanArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder};
 use num_traits::{Bounded, One, Zero};
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::_get_decimal_precision_sum;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{CustomIterTools, NoNull};
@@ -81,6 +83,41 @@ where
     out.with_name(ca.name().clone())
 }
 
+/// Like [`cum_scan_numeric`] for physical decimal values, raising an error if `update` overflows.
+#[cfg(feature = "dtype-decimal")]
+fn cum_scan_decimal<F>(
+    ca: &Int128Chunked,
+    reverse: bool,
+    init: i128,
+    update: F,
+) -> PolarsResult<Int128Chunked>
+where
+    F: Fn(i128, i128) -> Option<i128>,
+{
+    let mut state = init;
+    let mut det = |v: Option<i128>| -> PolarsResult<Option<i128>> {
+        let Some(v) = v else {
+            return Ok(None);
+        };
+        state = update(state, v)
+            .ok_or_else(|| polars_err!(ComputeError: "decimal arithmetic overflowed"))?;
+        Ok(Some(state))
+    };
+    let out = match reverse {
+        false => ca.iter().map(&mut det).collect::<PolarsResult<Vec<_>>>()?,
+        true => {
+            let mut out = ca
+                .iter()
+                .rev()
+                .map(&mut det)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            out.reverse();
+            out
+        },
+    };
+    Ok(out.into_iter().collect_ca(ca.name().clone()))
+}
+
 fn cum_max_numeric<T>(
     ca: &ChunkedArray<T>,
     reverse: bool,
@@ -242,8 +279,29 @@ pub fn cum_prod_with_init(
         UInt64 => cum_prod_numeric(s.u64()?, reverse, init.extract()).into_series(),
         #[cfg(feature = "dtype-i128")]
         Int128 => cum_prod_numeric(s.i128()?, reverse, init.extract()).into_series(),
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => cum_prod_numeric(s.u128()?, reverse, init.extract()).into_series(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => {
+            let ca = s.float16()?.to_float32();
+            cum_prod_numeric(&ca, reverse, init.extract())
+                .to_float16()
+                .into_series()
+        },
         Float32 => cum_prod_numeric(s.f32()?, reverse, init.extract()).into_series(),
         Float64 => cum_prod_numeric(s.f64()?, reverse, init.extract()).into_series(),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(precision, scale) => {
+            let ca = s.decimal().unwrap().physical();
+            let scale = scale.unwrap();
+            // The product keeps the scale of the input, truncating every step like decimal
+            // division.
+            let one = 10_i128.pow(scale as u32);
+            let init = init.clone().to_physical().extract().unwrap_or(one);
+            cum_scan_decimal(ca, reverse, init, |a, b| a.checked_mul(b).map(|p| p / one))?
+                .into_decimal(_get_decimal_precision_sum(*precision), scale)?
+                .into_series()
+        },
         dt => polars_bail!(opq = cum_prod, dt),
     };
     Ok(out)
@@ -278,13 +336,23 @@ pub fn cum_sum_with_init(
         UInt64 => cum_sum_numeric(s.u64()?, reverse, init.extract()).into_series(),
         #[cfg(feature = "dtype-i128")]
         Int128 => cum_sum_numeric(s.i128()?, reverse, init.extract()).into_series(),
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => cum_sum_numeric(s.u128()?, reverse, init.extract()).into_series(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => {
+            let ca = s.float16()?.to_float32();
+            cum_sum_numeric(&ca, reverse, init.extract())
+                .to_float16()
+                .into_series()
+        },
         Float32 => cum_sum_numeric(s.f32()?, reverse, init.extract()).into_series(),
         Float64 => cum_sum_numeric(s.f64()?, reverse, init.extract()).into_series(),
         #[cfg(feature = "dtype-decimal")]
         Decimal(precision, scale) => {
             let ca = s.decimal().unwrap().physical();
-            cum_sum_numeric(ca, reverse, init.clone().to_physical().extract())
-                .into_decimal_unchecked(*precision, scale.unwrap())
+            let init = init.clone().to_physical().extract().unwrap_or(0);
+            cum_scan_decimal(ca, reverse, init, i128::checked_add)?
+                .into_decimal(_get_decimal_precision_sum(*precision), scale.unwrap())?
                 .into_series()
         },
         #[cfg(feature = "dtype-duration")]
@@ -95,6 +95,14 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         return Ok(None);
     }
 
+    // Half precision floats are searched by value rather than by their bits.
+    #[cfg(feature = "dtype-f16")]
+    if let DataType::Float16 = series.dtype() {
+        let series = series.float16()?.to_float32().into_series();
+        let needle = Scalar::new(DataType::Float32, needle.value().clone());
+        return index_of(&series, needle);
+    }
+
     use DataType as DT;
     match series.dtype().to_physical() {
         DT::Null => unreachable!("handled above"),
@@ -145,6 +153,7 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         | DT::Int32
         | DT::Int64
         | DT::Int128
+        | DT::UInt128
         | DT::Float32
         | DT::Float64 => unreachable!("primitive numeric"),
 
@@ -153,6 +162,14 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         DT::Decimal(..) => unreachable!(),
         #[cfg(feature = "dtype-categorical")]
         DT::Categorical(..) | DT::Enum(..) => unreachable!(),
+        #[cfg(feature = "dtype-map")]
+        DT::Map(..) => unreachable!(),
+        #[cfg(feature = "dtype-interval")]
+        DT::Interval => unreachable!(),
+        #[cfg(feature = "dtype-f16")]
+        DT::Float16 => unreachable!(),
+        #[cfg(feature = "dtype-extension")]
+        DT::Extension(..) => unreachable!(),
         DT::Date | DT::Datetime(..) | DT::Duration(..) | DT::Time => unreachable!(),
 
         DT::Object(_) | DT::Unknown(_) => polars_bail!(op = "index_of", series.dtype()),
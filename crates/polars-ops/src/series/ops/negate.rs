@@ -27,6 +27,13 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
             let out = ca.wrapping_neg().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-interval")]
+        Interval => s.interval().unwrap().negate().into_series(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => {
+            let ca = s.float16().unwrap().to_float32();
+            ca.wrapping_neg().to_float16().into_series()
+        },
         dt => polars_bail!(opq = neg, dt),
     };
     Ok(out)
@@ -166,49 +166,39 @@ pub trait RoundSeries: SeriesSealed {
         }
         #[cfg(feature = "dtype-decimal")]
         if let Some(ca) = s.try_decimal() {
+            use polars_core::chunked_array::arithmetic::{
+                _get_decimal_precision_round, _get_decimal_scale_round,
+            };
+
             let scale = ca.scale() as u32;
 
             if scale <= decimals {
                 return Ok(ca.clone().into_series());
             }
 
+            // The result has `decimals` decimal places, so we divide the values by the
+            // multiplier and round the quotient. This can't overflow, unlike rounding the values
+            // to a multiple of the multiplier.
             let decimal_delta = scale - decimals;
             let multiplier = 10i128.pow(decimal_delta);
-            let threshold = multiplier / 2;
-
-            let res = match mode {
-                RoundMode::HalfToEven => ca.physical().apply_values(|v| {
-                    let rem_big = v % (2 * multiplier);
-                    let is_v_floor_even = rem_big.abs() < multiplier;
-                    let rem = if is_v_floor_even {
-                        rem_big
-                    } else if rem_big > 0 {
-                        rem_big - multiplier
-                    } else {
-                        rem_big + multiplier
-                    };
+            let half = multiplier.unsigned_abs() / 2;
 
-                    let threshold = threshold + i128::from(is_v_floor_even);
-                    let round_offset = if rem.abs() >= threshold {
-                        if v < 0 { -multiplier } else { multiplier }
-                    } else {
-                        0
-                    };
-                    v - rem + round_offset
-                }),
-                RoundMode::HalfAwayFromZero => ca.physical().apply_values(|v| {
-                    let rem = v % multiplier;
-                    let round_offset = if rem.abs() >= threshold {
-                        if v < 0 { -multiplier } else { multiplier }
-                    } else {
-                        0
-                    };
-                    v - rem + round_offset
-                }),
-            };
-            return Ok(res
-                .into_decimal_unchecked(ca.precision(), scale as usize)
-                .into_series());
+            let res = ca.physical().apply_values(|v| {
+                let quotient = v / multiplier;
+                let rem = (v % multiplier).unsigned_abs();
+                let round_away = match mode {
+                    RoundMode::HalfToEven => rem > half || (rem == half && quotient % 2 != 0),
+                    RoundMode::HalfAwayFromZero => rem >= half,
+                };
+                if round_away {
+                    quotient + v.signum()
+                } else {
+                    quotient
+                }
+            });
+            let precision = _get_decimal_precision_round(ca.precision(), scale as usize, decimals);
+            let new_scale = _get_decimal_scale_round(scale as usize, decimals);
+            return Ok(res.into_decimal(precision, new_scale)?.into_series());
         }
 
         polars_ensure!(s.dtype().is_integer(), InvalidOperation: "round can only be used on numeric types" );
@@ -4,7 +4,7 @@ use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
     DTYPE_ENUM_VALUES_NEW, Field, IntegerType, IntervalUnit, TimeUnit,
 };
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, i256, months_days_ns};
 use ethnum::I256;
 use polars_compute::cast::CastOptionsImpl;
 
@@ -182,6 +182,31 @@ pub fn page_iter_to_array(
                 ptm,
             )
         },
+        (PhysicalType::FixedLenByteArray(16), Interval(IntervalUnit::MonthDayNano)) => {
+            let n = 16;
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let values = array
+                .values()
+                .chunks_exact(n)
+                .map(|value: &[u8]| months_days_ns::from_le_bytes(value.try_into().unwrap()))
+                .collect::<Vec<_>>();
+            let validity = array.validity().cloned();
+
+            (
+                nested,
+                PrimitiveArray::<months_days_ns>::try_new(dtype.clone(), values.into(), validity)?
+                    .to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::FixedLenByteArray(16), Int128) => {
             let n = 16;
             let (nested, array, ptm) = PageDecoder::new(
@@ -204,6 +229,28 @@ pub fn page_iter_to_array(
                 ptm,
             )
         },
+        (PhysicalType::FixedLenByteArray(16), UInt128) => {
+            let n = 16;
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let (_, values, validity) = array.into_inner();
+            let values = values
+                .try_transmute()
+                .expect("this should work since the parquet decoder has alignment constraints");
+
+            (
+                nested,
+                PrimitiveArray::<u128>::try_new(dtype.clone(), values, validity)?.to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::Int32, Decimal(_, _)) => PageDecoder::new(
             &field.name,
             pages,
@@ -362,6 +409,27 @@ pub fn page_iter_to_array(
         .collect_boxed(filter)?,
 
         // Float16
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(2),
+                fixed_size_binary::BinaryDecoder { size: 2 },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let (_, values, validity) = array.into_inner();
+            let values = values
+                .try_transmute()
+                .expect("this should work since the parquet decoder has alignment constraints");
+
+            (
+                nested,
+                PrimitiveArray::<arrow::types::f16>::try_new(dtype, values, validity)?.to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::FixedLenByteArray(2), Float32) => {
             // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder.
 
@@ -7,12 +7,14 @@ use arrow::array::{
 use arrow::datatypes::{ArrowDataType, Field, IntegerType, IntervalUnit, TimeUnit};
 use arrow::types::{NativeType, days_ms, f16, i256};
 use ethnum::I256;
+use polars_parquet_format::Statistics as ThriftStatistics;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::{ParquetTimeUnit, RowGroupMetadata};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+use crate::parquet::read::ColumnIndex;
+use crate::parquet::schema::types::{PhysicalType as ParquetPhysicalType, PrimitiveType};
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
@@ -217,7 +219,10 @@ impl ColumnStatistics {
                 })
             },
 
-            // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+            (D::Float16, PPT::FixedLenByteArray(2)) => {
+                rmap!(expect_fixedlen, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+            },
+            // Float16 that is read as a Float32.
             (_, PPT::FixedLenByteArray(2))
                 if matches!(
                     self.logical_type.as_ref(),
@@ -299,6 +304,68 @@ pub fn deserialize_all(
     field_idx: usize,
 ) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     assert!(!row_groups.is_empty());
+
+    let primitive_type = &row_groups[0].parquet_columns()[field_idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+    let statistics = row_groups
+        .iter()
+        .map(|rg| rg.parquet_columns()[field_idx].statistics().transpose())
+        .collect::<ParquetResult<Vec<_>>>()?;
+
+    deserialize_statistics_arrays(field, primitive_type, statistics)
+}
+
+/// Deserializes the statistics of the pages in the [`ColumnIndex`] of a column chunk into
+/// arrays with one entry per data page.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_column_index(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    column_index: &ColumnIndex,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    let num_pages = column_index.null_pages.len();
+    if column_index.min_values.len() != num_pages
+        || column_index.max_values.len() != num_pages
+        || column_index
+            .null_counts
+            .as_ref()
+            .is_some_and(|nc| nc.len() != num_pages)
+    {
+        return Err(ParquetError::oos(
+            "The column index must have the same number of entries for every page",
+        ));
+    }
+
+    let statistics = (0..num_pages)
+        .map(|i| {
+            // The min and max values of pages with only nulls are meaningless.
+            let is_null_page = column_index.null_pages[i];
+            let statistics = ThriftStatistics {
+                null_count: column_index.null_counts.as_ref().map(|nc| nc[i]),
+                distinct_count: None,
+                max_value: (!is_null_page).then(|| column_index.max_values[i].clone()),
+                min_value: (!is_null_page).then(|| column_index.min_values[i].clone()),
+                max: None,
+                min: None,
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+            ParquetStatistics::deserialize(&statistics, primitive_type.clone()).map(Some)
+        })
+        .collect::<ParquetResult<Vec<_>>>()?;
+
+    deserialize_statistics_arrays(field, primitive_type, statistics)
+}
+
+fn deserialize_statistics_arrays(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    statistics: Vec<Option<ParquetStatistics>>,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     use ArrowDataType as D;
     match field.dtype() {
         // @TODO: These are all a bit more complex, skip for now.
@@ -308,27 +375,19 @@ pub fn deserialize_all(
         D::Struct(..) => Ok(None),
 
         _ => {
-            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-            let mut distinct_count =
-                MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-
-            let primitive_type = &row_groups[0].parquet_columns()[field_idx]
-                .descriptor()
-                .descriptor
-                .primitive_type;
+            let num_entries = statistics.len();
+            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(num_entries);
+            let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(num_entries);
 
             let logical_type = &primitive_type.logical_type;
             let physical_type = &primitive_type.physical_type;
 
             macro_rules! rmap {
                 ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
-                    let mut min_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-                    let mut max_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-
-                    for rg in row_groups {
-                        let column = &rg.parquet_columns()[field_idx];
-                        let s = column.statistics().transpose()?;
+                    let mut min_arr = <$arr>::with_capacity(num_entries$(, $arg)?);
+                    let mut max_arr = <$arr>::with_capacity(num_entries$(, $arg)?);
 
+                    for s in statistics {
                         let (v_min, v_max, v_null_count, v_distinct_count) = match s {
                             None => (None, None, None, None),
                             Some(s) => {
@@ -401,8 +460,8 @@ pub fn deserialize_all(
             use {ArrowDataType as D, ParquetPhysicalType as PPT};
             let (min_value, max_value) = match (field.dtype(), physical_type) {
                 (D::Null, _) => (
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, num_entries).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, num_entries).to_boxed(),
                 ),
 
                 (D::Boolean, _) => rmap!(
@@ -468,7 +527,10 @@ pub fn deserialize_all(
                     })
                 },
 
-                // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+                (D::Float16, PPT::FixedLenByteArray(2)) => {
+                    rmap!(expect_fixedlen, MutablePrimitiveArray::<f16>, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+                },
+                // Float16 that is read as a Float32.
                 (_, PPT::FixedLenByteArray(2))
                     if matches!(logical_type.as_ref(), Some(PrimitiveLogicalType::Float16)) =>
                 {
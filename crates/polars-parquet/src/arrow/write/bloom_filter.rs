@@ -0,0 +1,101 @@
+use arrow::array::{Array, BinaryArray, BinaryViewArray, PrimitiveArray, Utf8Array, Utf8ViewArray};
+use arrow::datatypes::ArrowDataType;
+use arrow::types::NativeType;
+
+use super::{BloomFilterOptions, ColumnWriteOptions, to_leaves};
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+/// Builds the bloom filters of the leaf columns of `array`, in the same order as the columns
+/// returned by [`array_to_columns`](super::array_to_columns).
+///
+/// Leaves without a bloom filter in their options, or with a type for which we don't support
+/// bloom filters, yield `None`.
+pub fn array_to_bloom_filters(
+    array: &dyn Array,
+    column_options: &ColumnWriteOptions,
+) -> Vec<Option<Vec<u8>>> {
+    let mut field_options = Vec::new();
+    column_options.to_leaves(&mut field_options);
+
+    if field_options.iter().all(|o| o.bloom_filter.is_none()) {
+        return vec![None; field_options.len()];
+    }
+
+    let mut values = Vec::new();
+    to_leaves(array, &mut values);
+    assert_eq!(field_options.len(), values.len());
+
+    values
+        .iter()
+        .zip(field_options)
+        .map(|(values, field_options)| {
+            let options = field_options.bloom_filter?;
+            leaf_to_bloom_filter(values.as_ref(), options)
+        })
+        .collect()
+}
+
+fn leaf_to_bloom_filter(array: &dyn Array, options: BloomFilterOptions) -> Option<Vec<u8>> {
+    let ndv = options
+        .ndv
+        .unwrap_or((array.len() - array.null_count()) as u64);
+    let mut bitset = vec![0; optimal_num_bytes(ndv, options.fpp)];
+
+    // The values are hashed in their physical parquet representation, these casts MUST match
+    // the casts done when writing the pages.
+    match array.dtype().to_logical_type() {
+        ArrowDataType::UInt8 => insert_primitive::<u8, i32>(array, &mut bitset),
+        ArrowDataType::UInt16 => insert_primitive::<u16, i32>(array, &mut bitset),
+        ArrowDataType::UInt32 => insert_primitive::<u32, i32>(array, &mut bitset),
+        ArrowDataType::UInt64 => insert_primitive::<u64, i64>(array, &mut bitset),
+        ArrowDataType::Int8 => insert_primitive::<i8, i32>(array, &mut bitset),
+        ArrowDataType::Int16 => insert_primitive::<i16, i32>(array, &mut bitset),
+        ArrowDataType::Int32 | ArrowDataType::Date32 | ArrowDataType::Time32(_) => {
+            insert_primitive::<i32, i32>(array, &mut bitset)
+        },
+        ArrowDataType::Int64
+        | ArrowDataType::Date64
+        | ArrowDataType::Time64(_)
+        | ArrowDataType::Timestamp(_, _)
+        | ArrowDataType::Duration(_) => insert_primitive::<i64, i64>(array, &mut bitset),
+        ArrowDataType::Float32 => insert_primitive::<f32, f32>(array, &mut bitset),
+        ArrowDataType::Float64 => insert_primitive::<f64, f64>(array, &mut bitset),
+        ArrowDataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            insert_bytes(array.non_null_values_iter(), &mut bitset)
+        },
+        ArrowDataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            insert_bytes(array.non_null_values_iter(), &mut bitset)
+        },
+        ArrowDataType::Utf8View => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            insert_bytes(array.non_null_values_iter(), &mut bitset)
+        },
+        ArrowDataType::BinaryView => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            insert_bytes(array.non_null_values_iter(), &mut bitset)
+        },
+        _ => return None,
+    }
+
+    Some(bitset)
+}
+
+fn insert_primitive<T, P>(array: &dyn Array, bitset: &mut [u8])
+where
+    T: NativeType + num_traits::AsPrimitive<P>,
+    P: ParquetNativeType,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    for value in array.non_null_values_iter() {
+        insert(bitset, hash_native::<P>(value.as_()));
+    }
+}
+
+fn insert_bytes<V: AsRef<[u8]>>(values: impl Iterator<Item = V>, bitset: &mut [u8]) {
+    for value in values {
+        insert(bitset, hash_byte(value));
+    }
+}
@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -27,7 +29,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, f16, i256, months_days_ns};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -124,6 +126,38 @@ impl ColumnWriteOptions {
 #[derive(Clone)]
 pub struct FieldWriteOptions {
     pub encoding: Encoding,
+    /// Whether to write a bloom filter for this field
+    pub bloom_filter: Option<BloomFilterOptions>,
+}
+
+/// The bloom filter to write for a field
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct BloomFilterOptions {
+    /// The false positive probability of the filter
+    pub fpp: f64,
+    /// The number of distinct values to size the filter for, defaults to the number of
+    /// non-null values in the row group
+    pub ndv: Option<u64>,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            fpp: 0.05,
+            ndv: None,
+        }
+    }
+}
+
+impl Eq for BloomFilterOptions {}
+
+impl std::hash::Hash for BloomFilterOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.fpp.to_bits().hash(state);
+        self.ndv.hash(state);
+    }
 }
 
 impl ColumnWriteOptions {
@@ -139,7 +173,10 @@ impl ColumnWriteOptions {
 
 impl FieldWriteOptions {
     pub fn default_with_encoding(encoding: Encoding) -> Self {
-        Self { encoding }
+        Self {
+            encoding,
+            bloom_filter: None,
+        }
     }
 
     pub fn into_default_column_write_options(self) -> ColumnWriteOptions {
@@ -159,6 +196,8 @@ pub struct StructFieldWriteOptions {
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::array_to_bloom_filters;
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
 use polars_error::{PolarsResult, polars_bail};
@@ -637,6 +676,10 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = months_days_ns_to_fixed_size_binary(array);
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -831,6 +874,10 @@ pub fn array_to_page_simple(
             );
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::UInt128 | ArrowDataType::Float16 => {
+            let array = primitive_to_fixed_size_binary(array);
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         other => polars_bail!(nyi = "Writing parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
@@ -1090,11 +1137,57 @@ fn array_to_page_nested(
             );
             fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
         },
+        UInt128 | Float16 => {
+            let array = primitive_to_fixed_size_binary(array);
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, None)
+        },
+        Interval(IntervalUnit::MonthDayNano) => {
+            let array = months_days_ns_to_fixed_size_binary(array);
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, None)
+        },
         other => polars_bail!(nyi = "Writing nested parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
 }
 
+/// Parquet's `INTERVAL` only has millisecond precision, so month-day-nano intervals are stored
+/// as their 16 little-endian bytes (months, days, nanoseconds) without a converted type.
+fn months_days_ns_to_fixed_size_binary(array: &dyn Array) -> FixedSizeBinaryArray {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap();
+    let mut values = Vec::<u8>::with_capacity(16 * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(16),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// `UInt128` and `Float16` values are stored as their little-endian bytes. Their statistics are
+/// not written, as fixed size binary statistics don't order these values correctly.
+fn primitive_to_fixed_size_binary(array: &dyn Array) -> FixedSizeBinaryArray {
+    fn to_fixed_size_binary<T: NativeType>(array: &dyn Array) -> FixedSizeBinaryArray {
+        let array: &PrimitiveArray<T> = array.as_any().downcast_ref().unwrap();
+        FixedSizeBinaryArray::new(
+            ArrowDataType::FixedSizeBinary(size_of::<T>()),
+            array.values().clone().try_transmute().unwrap(),
+            array.validity().cloned(),
+        )
+    }
+
+    match array.dtype() {
+        ArrowDataType::UInt128 => to_fixed_size_binary::<u128>(array),
+        ArrowDataType::Float16 => to_fixed_size_binary::<f16>(array),
+        _ => unreachable!(),
+    }
+}
+
 fn transverse_recursive<T, F: Fn(&ArrowDataType) -> T + Clone>(
     dtype: &ArrowDataType,
     map: F,
@@ -239,9 +239,9 @@ fn to_nested_recursive(
         },
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let type_ = if let ParquetType::GroupType { fields, .. } = type_ {
+            let kv_types = if let ParquetType::GroupType { fields, .. } = type_ {
                 if let ParquetType::GroupType { fields, .. } = &fields[0] {
-                    &fields[0]
+                    fields
                 } else {
                     polars_bail!(InvalidOperation:
                         "Parquet type must be a group for a map array",
@@ -252,13 +252,27 @@ fn to_nested_recursive(
                     "Parquet type must be a group for a map array",
                 )
             };
+            let entries = array
+                .field()
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap();
 
             parents.push(Nested::List(ListNested::new(
                 array.offsets().clone(),
                 array.validity().cloned(),
                 is_optional,
             )));
-            to_nested_recursive(array.field().as_ref(), type_, nested, parents)?;
+            // The repeated `key_value` group is the entries struct itself, so it adds no
+            // definition level of its own.
+            parents.push(Nested::Struct(StructNested {
+                is_optional: false,
+                validity: None,
+                length: entries.len(),
+            }));
+            for (type_, array) in kv_types.iter().zip(entries.values()) {
+                to_nested_recursive(array.as_ref(), type_, nested, parents.clone())?;
+            }
         },
         _ => {
             parents.push(Nested::Primitive(PrimitiveNested {
@@ -892,37 +906,28 @@ mod tests {
 
         let array = MapArray::try_new(map_type, offsets, kv_array, None).unwrap();
 
-        let type_ = ParquetType::GroupType {
-            field_info: FieldInfo {
-                name: "kv".into(),
-                repetition: Repetition::Optional,
-                id: None,
-            },
-            logical_type: None,
-            converted_type: None,
-            fields: vec![
-                ParquetType::PrimitiveType(ParquetPrimitiveType {
-                    field_info: FieldInfo {
-                        name: "k".into(),
-                        repetition: Repetition::Required,
-                        id: None,
-                    },
-                    logical_type: Some(PrimitiveLogicalType::String),
-                    converted_type: Some(PrimitiveConvertedType::Utf8),
-                    physical_type: ParquetPhysicalType::ByteArray,
-                }),
-                ParquetType::PrimitiveType(ParquetPrimitiveType {
-                    field_info: FieldInfo {
-                        name: "v".into(),
-                        repetition: Repetition::Required,
-                        id: None,
-                    },
-                    logical_type: None,
-                    converted_type: None,
-                    physical_type: ParquetPhysicalType::Int32,
-                }),
-            ],
-        };
+        let kv_fields = vec![
+            ParquetType::PrimitiveType(ParquetPrimitiveType {
+                field_info: FieldInfo {
+                    name: "k".into(),
+                    repetition: Repetition::Required,
+                    id: None,
+                },
+                logical_type: Some(PrimitiveLogicalType::String),
+                converted_type: Some(PrimitiveConvertedType::Utf8),
+                physical_type: ParquetPhysicalType::ByteArray,
+            }),
+            ParquetType::PrimitiveType(ParquetPrimitiveType {
+                field_info: FieldInfo {
+                    name: "v".into(),
+                    repetition: Repetition::Required,
+                    id: None,
+                },
+                logical_type: None,
+                converted_type: None,
+                physical_type: ParquetPhysicalType::Int32,
+            }),
+        ];
 
         let type_ = ParquetType::GroupType {
             field_info: FieldInfo {
@@ -934,13 +939,13 @@ mod tests {
             converted_type: None,
             fields: vec![ParquetType::GroupType {
                 field_info: FieldInfo {
-                    name: "map".into(),
+                    name: "key_value".into(),
                     repetition: Repetition::Repeated,
                     id: None,
                 },
                 logical_type: None,
                 converted_type: None,
-                fields: vec![type_],
+                fields: kv_fields,
             }],
         };
 
@@ -955,7 +960,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
                 vec![
@@ -964,7 +969,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
             ]
@@ -1,11 +1,11 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, TimeUnit};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
 use base64::engine::general_purpose;
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_utils::pl_str::PlSmallStr;
 
 use super::super::ARROW_SCHEMA_META_KEY;
@@ -32,6 +32,7 @@ fn convert_dtype(dtype: ArrowDataType) -> ArrowDataType {
     use ArrowDataType as D;
     match dtype {
         D::LargeList(field) => D::LargeList(Box::new(convert_field(*field))),
+        D::Map(field, sorted) => D::Map(Box::new(convert_field(*field)), sorted),
         D::Struct(mut fields) => {
             for field in &mut fields {
                 *field = convert_field(std::mem::take(field))
@@ -135,6 +136,21 @@ fn insert_field_metadata(field: &mut Cow<Field>, options: &ColumnWriteOptions) {
                 });
             }
         },
+        D::Map(f, sorted) => {
+            let ChildWriteOptions::ListLike(o) = &options.children else {
+                unreachable!();
+            };
+
+            let mut child_field = Cow::Borrowed(f.as_ref());
+            insert_field_metadata(&mut child_field, &o.child);
+
+            if let Cow::Owned(child_field) = child_field {
+                let sorted = *sorted;
+                field
+                    .to_mut()
+                    .map_dtype_mut(|dtype| *dtype = D::Map(Box::new(child_field), sorted));
+            }
+        },
         _ => {},
     }
 }
@@ -378,12 +394,22 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                 (PhysicalType::FixedLenByteArray(32), None, None)
             }
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            (PhysicalType::FixedLenByteArray(16), None, None)
+        },
         ArrowDataType::Interval(_) => (
             PhysicalType::FixedLenByteArray(12),
             Some(PrimitiveConvertedType::Interval),
             None,
         ),
-        ArrowDataType::Int128 => (PhysicalType::FixedLenByteArray(16), None, None),
+        ArrowDataType::Int128 | ArrowDataType::UInt128 => {
+            (PhysicalType::FixedLenByteArray(16), None, None)
+        },
+        ArrowDataType::Float16 => (
+            PhysicalType::FixedLenByteArray(2),
+            None,
+            Some(PrimitiveLogicalType::Float16),
+        ),
         ArrowDataType::List(f)
         | ArrowDataType::FixedSizeList(f, _)
         | ArrowDataType::LargeList(f) => {
@@ -410,6 +436,47 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                 field_id,
             ));
         },
+        ArrowDataType::Map(f, _) => {
+            let ChildWriteOptions::ListLike(map_write_options) = &options.children else {
+                unreachable!();
+            };
+            let (ArrowDataType::Struct(kv_fields), ChildWriteOptions::Struct(kv_options)) = (
+                f.dtype().to_logical_type(),
+                &map_write_options.child.children,
+            ) else {
+                polars_bail!(InvalidOperation: "the entries of a map must be a struct");
+            };
+            polars_ensure!(
+                kv_fields.len() == 2,
+                InvalidOperation: "the entries of a map must have exactly 2 fields"
+            );
+
+            // The Parquet MAP layout puts the key and value directly under the repeated
+            // `key_value` group, and the key must always be required.
+            let mut key_field = kv_fields[0].clone();
+            key_field.is_nullable = false;
+            let mut key_options = kv_options.children[0].clone();
+            key_options.required = Some(true);
+
+            return Ok(ParquetType::from_group(
+                name,
+                repetition,
+                Some(GroupConvertedType::Map),
+                Some(GroupLogicalType::Map),
+                vec![ParquetType::from_group(
+                    PlSmallStr::from_static("key_value"),
+                    Repetition::Repeated,
+                    None,
+                    None,
+                    vec![
+                        to_parquet_type(&key_field, &key_options)?,
+                        to_parquet_type(&kv_fields[1], &kv_options.children[1])?,
+                    ],
+                    None,
+                )],
+                field_id,
+            ));
+        },
         other => polars_bail!(nyi = "Writing the data type {other:?} is not yet implemented"),
     };
 
@@ -2,10 +2,12 @@
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
+pub use read::{read, read_from_slice};
 pub use split_block::{insert, is_in_set};
+pub use write::{optimal_num_bytes, write};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,32 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn num_bytes() {
+        // Always at least a single block.
+        assert_eq!(optimal_num_bytes(0, 0.05), 32);
+        assert_eq!(optimal_num_bytes(1, 0.05), 32);
+
+        let num_bytes = optimal_num_bytes(1_000_000, 0.01);
+        assert!(num_bytes.is_power_of_two());
+        assert!(num_bytes >= 1_000_000 * 9 / 8);
+
+        // A higher false positive probability needs less space.
+        assert!(optimal_num_bytes(1_000_000, 0.5) < num_bytes);
+    }
+
+    #[test]
+    fn write_read() {
+        let mut bitset = vec![0; optimal_num_bytes(10, 0.01)];
+        for a in 0..10i64 {
+            insert(&mut bitset, hash_native(a));
+        }
+
+        let mut bytes = vec![];
+        let length = write(&mut bytes, &bitset).unwrap();
+        assert_eq!(length as usize, bytes.len());
+        assert_eq!(read_from_slice(&bytes).unwrap(), Some(bitset.as_slice()));
+        assert!(read_from_slice(&bytes[..bytes.len() - 1]).is_err());
+    }
 }
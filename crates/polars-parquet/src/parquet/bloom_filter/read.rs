@@ -6,7 +6,7 @@ use polars_parquet_format::{
     Uncompressed,
 };
 
-use crate::parquet::error::ParquetResult;
+use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnChunkMetadata;
 
 /// Reads the bloom filter associated to [`ColumnChunkMetadata`] into `bitset`.
@@ -49,3 +49,29 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Returns the bitset of a bloom filter from `bytes`, which hold the filter including its
+/// header, i.e. the `bloom_filter_length` bytes at the `bloom_filter_offset` of a column.
+/// Returns `None` if the algorithm or compression is not supported.
+/// # Error
+/// Errors if the header can't be deserialized or `bytes` don't hold the entire bitset.
+pub fn read_from_slice(mut bytes: &[u8]) -> ParquetResult<Option<&[u8]>> {
+    let mut prot = TCompactInputProtocol::new(&mut bytes, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+    drop(prot);
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}) {
+        return Ok(None);
+    }
+    if header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {}) {
+        return Ok(None);
+    }
+
+    let length: usize = header.num_bytes.try_into()?;
+    if bytes.len() < length {
+        return Err(ParquetError::oos(
+            "The bloom filter is larger than its column chunk metadata states",
+        ));
+    }
+    Ok(Some(&bytes[..length]))
+}
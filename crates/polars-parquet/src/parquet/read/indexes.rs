@@ -0,0 +1,67 @@
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::ParquetResult;
+
+/// Deserializes a [`ColumnIndex`] from `bytes`, i.e. the `column_index_length` bytes at the
+/// `column_index_offset` of a column chunk.
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    // The index can't hold more data than it is encoded with.
+    let max_size = bytes.len() * 2 + 1024;
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes an [`OffsetIndex`] from `bytes`, i.e. the `offset_index_length` bytes at the
+/// `offset_index_offset` of a column chunk.
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = bytes.len() * 2 + 1024;
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+    use polars_parquet_format::{BoundaryOrder, PageLocation};
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() -> ParquetResult<()> {
+        let column_index = ColumnIndex {
+            null_pages: vec![false, true],
+            min_values: vec![1i32.to_le_bytes().to_vec(), vec![0]],
+            max_values: vec![5i32.to_le_bytes().to_vec(), vec![0]],
+            boundary_order: BoundaryOrder::UNORDERED,
+            null_counts: Some(vec![0, 3]),
+            repetition_level_histograms: None,
+            definition_level_histograms: None,
+        };
+        let offset_index = OffsetIndex {
+            page_locations: vec![
+                PageLocation {
+                    offset: 4,
+                    compressed_page_size: 10,
+                    first_row_index: 0,
+                },
+                PageLocation {
+                    offset: 14,
+                    compressed_page_size: 8,
+                    first_row_index: 5,
+                },
+            ],
+            unencoded_byte_array_data_bytes: None,
+        };
+
+        let mut bytes = vec![];
+        column_index.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut bytes))?;
+        assert_eq!(deserialize_column_index(&bytes)?, column_index);
+
+        let mut bytes = vec![];
+        offset_index.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut bytes))?;
+        assert_eq!(deserialize_offset_index(&bytes)?, offset_index);
+
+        Ok(())
+    }
+}
@@ -173,6 +173,35 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes a row group to the file, followed by the bloom filters of its columns.
+    ///
+    /// `bloom_filters` holds the bitset for every leaf column of the row group, or `None` for
+    /// columns without a bloom filter.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: &[Option<Vec<u8>>],
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write(row_group)?;
+
+        let group = self.row_groups.last_mut().unwrap();
+        for (column, bitset) in group.columns.iter_mut().zip(bloom_filters) {
+            let (Some(bitset), Some(meta_data)) = (bitset, column.meta_data.as_mut()) else {
+                continue;
+            };
+            let length = crate::parquet::bloom_filter::write(&mut self.writer, bitset)?;
+            meta_data.bloom_filter_offset = Some(self.offset as i64);
+            meta_data.bloom_filter_length = Some(length.try_into()?);
+            self.offset += length;
+        }
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> ParquetResult<u64> {
@@ -102,6 +102,12 @@ impl DslPlan {
                 scratch.push(input);
                 scratch.extend(contexts);
             },
+            RecursiveUnion {
+                initial, recursive, ..
+            } => {
+                scratch.push(initial);
+                scratch.push(recursive);
+            },
             IR { dsl, .. } => scratch.push(dsl),
             Scan { .. } | DataFrameScan { .. } => (),
             #[cfg(feature = "python")]
@@ -4,11 +4,17 @@ use std::sync::Mutex;
 use deletion::DeletionFilesList;
 use polars_core::schema::iceberg::IcebergSchemaRef;
 use polars_core::utils::get_numeric_upcast_supertype_lossless;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "fixed_width")]
+use polars_io::fixed_width::FixedWidthReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
@@ -53,6 +59,15 @@ pub enum FileScanDsl {
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
+    #[cfg(feature = "orc")]
+    Orc { options: OrcScanOptions },
+
+    #[cfg(feature = "fixed_width")]
+    FixedWidth { options: FixedWidthReadOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -91,6 +106,15 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
+    #[cfg(feature = "orc")]
+    Orc { options: OrcScanOptions },
+
+    #[cfg(feature = "fixed_width")]
+    FixedWidth { options: FixedWidthReadOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -112,6 +136,12 @@ impl FileScanIR {
             Self::Csv { .. } => ScanFlags::empty(),
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => ScanFlags::empty(),
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => ScanFlags::empty(),
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => ScanFlags::empty(),
+            #[cfg(feature = "fixed_width")]
+            Self::FixedWidth { .. } => ScanFlags::empty(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => ScanFlags::SPECIALIZED_PREDICATE_FILTER,
             #[cfg(feature = "json")]
@@ -326,6 +356,21 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "avro")]
+        Avro {
+            options: &'a polars_io::avro::AvroScanOptions,
+        },
+
+        #[cfg(feature = "orc")]
+        Orc {
+            options: &'a polars_io::orc::OrcScanOptions,
+        },
+
+        #[cfg(feature = "fixed_width")]
+        FixedWidth {
+            options: &'a polars_io::fixed_width::FixedWidthReadOptions,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -363,6 +408,15 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { options } => FileScanEqHashWrap::Avro { options },
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc { options } => FileScanEqHashWrap::Orc { options },
+
+                #[cfg(feature = "fixed_width")]
+                FileScanIR::FixedWidth { options } => FileScanEqHashWrap::FixedWidth { options },
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
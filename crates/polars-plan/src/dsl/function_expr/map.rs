@@ -0,0 +1,38 @@
+use super::*;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum MapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    ToList,
+    ToStruct(Arc<[PlSmallStr]>),
+    FromList,
+    FromStruct,
+}
+
+impl Display for MapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use MapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            ToList => "to_list",
+            ToStruct(_) => "to_struct",
+            FromList => "from_list",
+            FromStruct => "from_struct",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<MapFunction> for FunctionExpr {
+    fn from(value: MapFunction) -> Self {
+        Self::MapExpr(value)
+    }
+}
@@ -247,6 +247,12 @@ impl ListNameSpace {
         self.0.map_unary(ListFunction::ToStruct(names))
     }
 
+    /// Interpret every list of `{key, value}` structs as a map.
+    #[cfg(feature = "dtype-map")]
+    pub fn to_map(self) -> Expr {
+        self.0.map_unary(MapFunction::FromList)
+    }
+
     #[cfg(feature = "is_in")]
     /// Check if the list array contain an element
     pub fn contains<E: Into<Expr>>(self, other: E, nulls_equal: bool) -> Expr {
@@ -0,0 +1,41 @@
+use super::*;
+
+/// Specialized expressions for Map dtypes.
+pub struct MapNameSpace(pub(crate) Expr);
+
+impl MapNameSpace {
+    /// Get the value belonging to `key` in every map.
+    ///
+    /// Maps that do not contain the key give a null.
+    pub fn get<E: Into<Expr>>(self, key: E) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::MapExpr(MapFunction::Get), key.into())
+    }
+
+    /// Check if every map contains `key`.
+    pub fn contains_key<E: Into<Expr>>(self, key: E) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::MapExpr(MapFunction::ContainsKey), key.into())
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::Keys))
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::Values))
+    }
+
+    /// Get the entries of every map as a list of `{key, value}` structs.
+    pub fn to_list(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::ToList))
+    }
+
+    /// Get the values belonging to `names` as the fields of a struct.
+    pub fn to_struct(self, names: Arc<[PlSmallStr]>) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::MapExpr(MapFunction::ToStruct(names)))
+    }
+}
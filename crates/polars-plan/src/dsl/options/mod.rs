@@ -1,13 +1,17 @@
-use std::hash::Hash;
+use std::any::Any;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 #[cfg(feature = "json")]
 use std::num::NonZeroUsize;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
 
 mod sink;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -16,6 +20,8 @@ use polars_io::ipc::IpcWriterOptions;
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars_io::utils::compression::ExternalCompression;
 #[cfg(feature = "iejoin")]
 use polars_ops::frame::IEJoinOptions;
 use polars_ops::frame::{CrossJoinFilter, CrossJoinOptions, JoinTypeOptions};
@@ -33,6 +39,7 @@ use strum_macros::IntoStaticStr;
 
 use super::ExprIR;
 use crate::dsl::Selector;
+use crate::plans::{AnonymousScan, AnonymousScanArgs};
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -281,6 +288,77 @@ pub struct HConcatOptions {
     pub parallel: bool,
 }
 
+/// The rows produced by the previous iteration of a recursive union. Its recursive term reads
+/// them through an anonymous scan.
+#[derive(Clone)]
+pub struct WorkingTable {
+    rows: Arc<RwLock<DataFrame>>,
+    execution_lock: Arc<Mutex<()>>,
+}
+
+impl WorkingTable {
+    pub fn new(schema: &Schema) -> Self {
+        Self {
+            rows: Arc::new(RwLock::new(DataFrame::empty_with_schema(schema))),
+            execution_lock: Default::default(),
+        }
+    }
+
+    /// Claim the working table for one execution of the recursive union. The table is shared
+    /// by all executions of the plan, so these must not overlap.
+    pub fn lock(&self) -> MutexGuard<'_, ()> {
+        self.execution_lock.lock().unwrap()
+    }
+
+    pub fn set(&self, df: DataFrame) {
+        *self.rows.write().unwrap() = df;
+    }
+
+    pub fn clear(&self) {
+        let mut rows = self.rows.write().unwrap();
+        *rows = rows.clear();
+    }
+}
+
+impl AnonymousScan for WorkingTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        Ok(self.rows.read().unwrap().clone())
+    }
+}
+
+impl fmt::Debug for WorkingTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "working_table")
+    }
+}
+
+impl PartialEq for WorkingTable {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.rows, &other.rows)
+    }
+}
+
+impl Eq for WorkingTable {}
+
+impl Hash for WorkingTable {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.rows).hash(state)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveUnionOptions {
+    /// Discard duplicate rows, as SQL `UNION` does.
+    pub distinct: bool,
+    /// Raise an error if no fixpoint is reached within this many iterations.
+    pub max_iterations: usize,
+    pub working_table: WorkingTable,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -361,6 +439,8 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 impl FileType {
@@ -371,9 +451,19 @@ impl FileType {
             #[cfg(feature = "ipc")]
             Self::Ipc(_) => "ipc",
             #[cfg(feature = "csv")]
-            Self::Csv(_) => "csv",
+            Self::Csv(options) => match options.compression {
+                ExternalCompression::Uncompressed => "csv",
+                ExternalCompression::Gzip(_) => "csv.gz",
+                ExternalCompression::Zstd(_) => "csv.zst",
+            },
             #[cfg(feature = "json")]
-            Self::Json(_) => "jsonl",
+            Self::Json(options) => match options.compression {
+                ExternalCompression::Uncompressed => "jsonl",
+                ExternalCompression::Gzip(_) => "jsonl.gz",
+                ExternalCompression::Zstd(_) => "jsonl.zst",
+            },
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
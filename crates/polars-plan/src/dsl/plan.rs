@@ -148,6 +148,14 @@ pub enum DslPlan {
         input_right: Arc<DslPlan>,
         key: PlSmallStr,
     },
+    /// Repeatedly evaluate `recursive` on the rows produced by its previous evaluation,
+    /// starting from the rows of `initial`, until it produces no new rows
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    RecursiveUnion {
+        initial: Arc<DslPlan>,
+        recursive: Arc<DslPlan>,
+        options: RecursiveUnionOptions,
+    },
     IR {
         // Keep the original Dsl around as we need that for serialization.
         dsl: Arc<DslPlan>,
@@ -187,6 +195,7 @@ impl Clone for DslPlan {
             Self::SinkMultiple { inputs } => Self::SinkMultiple { inputs: inputs.clone() },
             #[cfg(feature = "merge_sorted")]
             Self::MergeSorted { input_left, input_right, key } => Self::MergeSorted { input_left: input_left.clone(), input_right: input_right.clone(), key: key.clone() },
+            Self::RecursiveUnion { initial, recursive, options } => Self::RecursiveUnion { initial: initial.clone(), recursive: recursive.clone(), options: options.clone() },
             Self::IR {node, dsl, version} => Self::IR {node: *node, dsl: dsl.clone(), version: *version},
         }
     }
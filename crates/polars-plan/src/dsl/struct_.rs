@@ -83,6 +83,13 @@ impl StructNameSpace {
             .map_unary(FunctionExpr::StructExpr(StructFunction::JsonEncode))
     }
 
+    /// Convert every struct into a map from the field names to the field values.
+    #[cfg(feature = "dtype-map")]
+    pub fn to_map(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::MapExpr(MapFunction::FromStruct))
+    }
+
     pub fn with_fields(self, fields: Vec<Expr>) -> Expr {
         self.0
             .map_n_ary(FunctionExpr::StructExpr(StructFunction::WithFields), fields)
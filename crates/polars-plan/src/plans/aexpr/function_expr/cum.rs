@@ -27,11 +27,17 @@ pub(super) fn cum_max(s: &Column, reverse: bool) -> PolarsResult<Column> {
 
 pub(super) mod dtypes {
     use DataType::*;
+    #[cfg(feature = "dtype-decimal")]
+    use polars_core::chunked_array::arithmetic::_get_decimal_precision_sum;
     use polars_core::utils::materialize_dyn_int;
 
     use super::*;
 
     pub fn cum_sum(dt: &DataType) -> DataType {
+        #[cfg(feature = "dtype-decimal")]
+        if let Decimal(precision, scale) = dt {
+            return Decimal(_get_decimal_precision_sum(*precision), *scale);
+        }
         if dt.is_logical() {
             dt.clone()
         } else {
@@ -40,6 +46,7 @@ pub(super) mod dtypes {
                 Int32 => Int32,
                 Int128 => Int128,
                 UInt32 => UInt32,
+                UInt128 => UInt128,
                 UInt64 => UInt64,
                 Float32 => Float32,
                 Float64 => Float64,
@@ -58,8 +65,13 @@ pub(super) mod dtypes {
             Boolean => Int64,
             UInt64 => UInt64,
             Int128 => Int128,
+            UInt128 => UInt128,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Float16,
             Float32 => Float32,
             Float64 => Float64,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, scale) => Decimal(_get_decimal_precision_sum(*precision), *scale),
             _ => Int64,
         }
     }
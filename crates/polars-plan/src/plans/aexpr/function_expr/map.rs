@@ -0,0 +1,167 @@
+use polars_core::utils::try_get_supertype;
+use polars_ops::chunked_array::map::*;
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRMapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    ToList,
+    ToStruct(Arc<[PlSmallStr]>),
+    FromList,
+    FromStruct,
+}
+
+impl IRMapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRMapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| Ok(map_key_value_dtypes(dt)?.1.clone())),
+            ContainsKey => mapper.with_dtype(DataType::Boolean),
+            Keys => mapper.try_map_dtype(|dt| {
+                Ok(DataType::List(Box::new(map_key_value_dtypes(dt)?.0.clone())))
+            }),
+            Values => mapper.try_map_dtype(|dt| {
+                Ok(DataType::List(Box::new(map_key_value_dtypes(dt)?.1.clone())))
+            }),
+            ToList => mapper.try_map_dtype(|dt| {
+                let (key, value) = map_key_value_dtypes(dt)?;
+                Ok(DataType::List(Box::new(DataType::map_entries(
+                    key.clone(),
+                    value.clone(),
+                ))))
+            }),
+            ToStruct(names) => mapper.try_map_dtype(|dt| {
+                let (_, value) = map_key_value_dtypes(dt)?;
+                Ok(DataType::Struct(
+                    names
+                        .iter()
+                        .map(|name| Field::new(name.clone(), value.clone()))
+                        .collect(),
+                ))
+            }),
+            FromList => mapper.try_map_dtype(|dt| match dt {
+                DataType::List(inner) => match inner.as_ref() {
+                    DataType::Struct(fields) if fields.len() == 2 => Ok(DataType::Map(
+                        Box::new(fields[0].dtype().clone()),
+                        Box::new(fields[1].dtype().clone()),
+                        false,
+                    )),
+                    _ => polars_bail!(
+                        InvalidOperation: "cannot convert a list of '{}' to a map, expected a list of structs with 2 fields",
+                        inner
+                    ),
+                },
+                _ => polars_bail!(op = "list.to_map", got = dt, expected = "List"),
+            }),
+            FromStruct => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) => {
+                    let value = fields.iter().try_fold(DataType::Null, |acc, fld| {
+                        try_get_supertype(&acc, fld.dtype())
+                    })?;
+                    Ok(DataType::Map(
+                        Box::new(DataType::String),
+                        Box::new(value),
+                        false,
+                    ))
+                },
+                _ => polars_bail!(op = "struct.to_map", got = dt, expected = "Struct"),
+            }),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRMapFunction as M;
+        match self {
+            M::Get
+            | M::ContainsKey
+            | M::Keys
+            | M::Values
+            | M::ToList
+            | M::ToStruct(_)
+            | M::FromList
+            | M::FromStruct => FunctionOptions::elementwise(),
+        }
+    }
+}
+
+fn map_key_value_dtypes(dtype: &DataType) -> PolarsResult<(&DataType, &DataType)> {
+    match dtype {
+        DataType::Map(key, value, _) => Ok((key.as_ref(), value.as_ref())),
+        dt => polars_bail!(InvalidOperation: "expected Map type, got: {dt}"),
+    }
+}
+
+impl Display for IRMapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRMapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            ToList => "to_list",
+            ToStruct(_) => "to_struct",
+            FromList => "from_list",
+            FromStruct => "from_struct",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<IRMapFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRMapFunction) -> Self {
+        use IRMapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            ContainsKey => map_as_slice!(contains_key),
+            Keys => map!(keys),
+            Values => map!(values),
+            ToList => map!(to_list),
+            ToStruct(names) => map!(to_struct, &names),
+            FromList => map!(from_list),
+            FromStruct => map!(from_struct),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_get(key).map(Column::from)
+}
+
+pub(super) fn contains_key(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_contains_key(key).map(|ca| ca.into_column())
+}
+
+pub(super) fn keys(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_keys().map(|ca| ca.into_column())
+}
+
+pub(super) fn values(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_values().map(|ca| ca.into_column())
+}
+
+pub(super) fn to_list(s: &Column) -> PolarsResult<Column> {
+    Ok(s.map()?.map_to_list().into_column())
+}
+
+pub(super) fn to_struct(s: &Column, names: &[PlSmallStr]) -> PolarsResult<Column> {
+    s.map()?.map_to_struct(names).map(|ca| ca.into_column())
+}
+
+pub(super) fn from_list(s: &Column) -> PolarsResult<Column> {
+    s.list()?.to_map().map(|ca| ca.into_column())
+}
+
+pub(super) fn from_struct(s: &Column) -> PolarsResult<Column> {
+    struct_to_map(s.struct_()?).map(|ca| ca.into_column())
+}
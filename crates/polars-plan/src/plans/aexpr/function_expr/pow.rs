@@ -1,5 +1,9 @@
 use num_traits::pow::Pow;
 use num_traits::{Float, One, ToPrimitive, Zero};
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::_get_decimal_precision_sum;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::prelude::arity::broadcast_try_binary_elementwise;
 use polars_core::prelude::arity::{broadcast_binary_elementwise, unary_elementwise_values};
 use polars_core::with_match_physical_integer_type;
 
@@ -136,10 +140,53 @@ where
     }
 }
 
+/// Raise decimals to a non-negative integer power. The result keeps the scale of the base and
+/// every multiplication is truncated like decimal division.
+#[cfg(feature = "dtype-decimal")]
+fn pow_on_decimal(base: &DecimalChunked, exponent: &Column) -> PolarsResult<Column> {
+    let exponent = exponent.strict_cast(&DataType::UInt32).map_err(|err| polars_err!(
+        InvalidOperation:
+        "{}\n\nHint: decimals can only be raised to non-negative integer powers, cast to float for other exponents.",
+        err
+    ))?;
+    let scale = base.scale();
+    let one = 10_i128.pow(scale as u32);
+    let out = broadcast_try_binary_elementwise(
+        base.physical(),
+        exponent.u32().unwrap(),
+        |b: Option<i128>, e: Option<u32>| {
+            let (Some(b), Some(e)) = (b, e) else {
+                return Ok(None);
+            };
+            let mut acc = one;
+            for _ in 0..e {
+                if acc == 0 || b == one {
+                    break;
+                }
+                acc = acc
+                    .checked_mul(b)
+                    .ok_or_else(|| polars_err!(ComputeError: "decimal arithmetic overflowed"))?
+                    / one;
+            }
+            Ok(Some(acc))
+        },
+    )?;
+    let precision = _get_decimal_precision_sum(base.precision());
+    Ok(out.into_decimal(precision, scale)?.into_column())
+}
+
 fn pow_on_series(base: &Column, exponent: &Column) -> PolarsResult<Column> {
     use DataType::*;
 
     let base_dtype = base.dtype();
+    #[cfg(feature = "dtype-decimal")]
+    if base_dtype.is_decimal() {
+        return if exponent.dtype().is_float() {
+            pow_on_series(&base.cast(&Float64)?, exponent)
+        } else {
+            pow_on_decimal(base.as_materialized_series().decimal()?, exponent)
+        };
+    }
     polars_ensure!(
         base_dtype.is_primitive_numeric(),
         InvalidOperation: "`pow` operation not supported for dtype `{}` as base", base_dtype
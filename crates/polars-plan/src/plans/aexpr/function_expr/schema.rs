@@ -1,3 +1,9 @@
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _get_decimal_precision_quantile, _get_decimal_precision_round, _get_decimal_precision_sum,
+    _get_decimal_scale_mean, _get_decimal_scale_quantile, _get_decimal_scale_round,
+    _get_decimal_scale_std, _get_decimal_scale_var,
+};
 use polars_core::utils::materialize_dyn_int;
 
 use super::*;
@@ -19,6 +25,8 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -61,10 +69,20 @@ impl IRFunctionExpr {
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
                 use IRRollingFunction::*;
+                #[cfg(feature = "dtype-decimal")]
+                if options.weights.is_none() {
+                    let dtype = mapper.args()[0].dtype();
+                    if let Some(dtype) = exact_rolling_decimal_dtype(function, dtype) {
+                        return mapper.with_dtype(dtype);
+                    }
+                }
                 match function {
+                    Min | Max if options.weights.is_some() => mapper.map_to_float_dtype(),
                     Min | Max => mapper.with_same_dtype(),
+                    Mean if options.weights.is_none() => mapper.mean_dtype(),
                     Mean | Quantile | Std => mapper.moment_dtype(),
                     Var => mapper.var_dtype(),
+                    Sum if options.weights.is_some() => mapper.map_to_float_dtype(),
                     Sum => mapper.sum_dtype(),
                     #[cfg(feature = "cov")]
                     CorrCov {..} => mapper.map_to_float_dtype(),
@@ -118,6 +136,8 @@ impl IRFunctionExpr {
                     T::UInt64 => T::UInt64,
                     #[cfg(feature = "dtype-i128")]
                     T::Int128 => T::Int128,
+                    #[cfg(feature = "dtype-u128")]
+                    T::UInt128 => T::UInt128,
                     _ => T::Int64,
                 }
             }),
@@ -230,7 +250,16 @@ impl IRFunctionExpr {
             Entropy { .. } | Log { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
-            Round { .. } | RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
+            Round { decimals, .. } => mapper.map_dtype(|dtype| match dtype {
+                #[cfg(feature = "dtype-decimal")]
+                DataType::Decimal(precision, Some(scale)) => DataType::Decimal(
+                    _get_decimal_precision_round(*precision, *scale, *decimals),
+                    Some(_get_decimal_scale_round(*scale, *decimals)),
+                ),
+                dtype => dtype.clone(),
+            }),
+            #[cfg(feature = "round_series")]
+            RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
             UpperBound | LowerBound => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
@@ -420,6 +449,32 @@ impl IRFunctionExpr {
     }
 }
 
+/// The dtype of a rolling standard deviation, variance or quantile of decimals, which are computed
+/// exactly for windows without weights.
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+fn exact_rolling_decimal_dtype(function: &IRRollingFunction, dtype: &DataType) -> Option<DataType> {
+    let DataType::Decimal(precision, scale) = dtype else {
+        return None;
+    };
+    let scale = scale.unwrap_or(0);
+    let (precision, scale) = match function {
+        IRRollingFunction::Std => (
+            _get_decimal_precision_sum(*precision),
+            _get_decimal_scale_std(scale),
+        ),
+        IRRollingFunction::Var => (
+            _get_decimal_precision_sum(*precision),
+            _get_decimal_scale_var(scale),
+        ),
+        IRRollingFunction::Quantile => (
+            _get_decimal_precision_quantile(*precision, scale),
+            _get_decimal_scale_quantile(scale),
+        ),
+        _ => return None,
+    };
+    Some(DataType::Decimal(precision, Some(scale)))
+}
+
 pub struct FieldsMapper<'a> {
     fields: &'a [Field],
 }
@@ -481,6 +536,18 @@ impl<'a> FieldsMapper<'a> {
         }
     }
 
+    /// Map to the dtype of a mean, which is exact for decimals.
+    pub fn mean_dtype(&self) -> PolarsResult<Field> {
+        #[cfg(feature = "dtype-decimal")]
+        if let DataType::Decimal(precision, scale) = self.fields[0].dtype() {
+            return self.with_dtype(DataType::Decimal(
+                _get_decimal_precision_sum(*precision),
+                Some(_get_decimal_scale_mean(scale.unwrap_or(0))),
+            ));
+        }
+        self.moment_dtype()
+    }
+
     pub fn moment_dtype(&self) -> PolarsResult<Field> {
         let map_inner = |dt: &DataType| match dt {
             DataType::Boolean => DataType::Float64,
@@ -636,6 +703,8 @@ impl<'a> FieldsMapper<'a> {
         use DataType::*;
         self.map_dtype(|dtype| match dtype {
             Int8 | UInt8 | Int16 | UInt16 => Int64,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, scale) => Decimal(_get_decimal_precision_sum(*precision), *scale),
             dt => dt.clone(),
         })
     }
@@ -680,6 +749,15 @@ impl<'a> FieldsMapper<'a> {
     pub(super) fn pow_dtype(&self) -> PolarsResult<Field> {
         let base_dtype = self.fields[0].dtype();
         let exponent_dtype = self.fields[1].dtype();
+        #[cfg(feature = "dtype-decimal")]
+        if let DataType::Decimal(precision, scale) = base_dtype {
+            let dtype = if exponent_dtype.is_float() {
+                DataType::Float64
+            } else {
+                DataType::Decimal(_get_decimal_precision_sum(*precision), *scale)
+            };
+            return Ok(Field::new(self.fields[0].name().clone(), dtype));
+        }
         if base_dtype.is_integer() {
             if exponent_dtype.is_float() {
                 Ok(Field::new(
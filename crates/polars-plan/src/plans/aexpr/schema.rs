@@ -1,6 +1,9 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_core::chunked_array::arithmetic::{
-    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul,
+    _get_decimal_precision_add_sub, _get_decimal_precision_div, _get_decimal_precision_mul,
+    _get_decimal_precision_quantile, _get_decimal_precision_sum, _get_decimal_scale_add_sub,
+    _get_decimal_scale_div, _get_decimal_scale_mean, _get_decimal_scale_mul,
+    _get_decimal_scale_quantile, _get_decimal_scale_std, _get_decimal_scale_var,
 };
 use polars_utils::format_pl_smallstr;
 use recursive::recursive;
@@ -184,6 +187,10 @@ impl AExpr {
                         let dt = match field.dtype() {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(precision, scale) => {
+                                Some(Decimal(_get_decimal_precision_sum(*precision), *scale))
+                            },
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -195,6 +202,14 @@ impl AExpr {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Microseconds, None)),
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(precision, scale) => {
+                                let scale = scale.unwrap_or(0);
+                                field.coerce(Decimal(
+                                    _get_decimal_precision_quantile(precision, scale),
+                                    Some(_get_decimal_scale_quantile(scale)),
+                                ))
+                            },
                             _ => {
                                 let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
                                 let mapper = FieldsMapper::new(&field);
@@ -207,6 +222,11 @@ impl AExpr {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Microseconds, None)),
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(precision, scale) => field.coerce(Decimal(
+                                _get_decimal_precision_sum(precision),
+                                Some(_get_decimal_scale_mean(scale.unwrap_or(0))),
+                            )),
                             _ => {
                                 let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
                                 let mapper = FieldsMapper::new(&field);
@@ -221,14 +241,36 @@ impl AExpr {
                         Ok(field)
                     },
                     Std(expr, _) => {
-                        let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
-                        let mapper = FieldsMapper::new(&field);
-                        mapper.moment_dtype()
+                        let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
+                        match field.dtype {
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(precision, scale) => field.coerce(Decimal(
+                                _get_decimal_precision_sum(precision),
+                                Some(_get_decimal_scale_std(scale.unwrap_or(0))),
+                            )),
+                            _ => {
+                                let field = [field];
+                                let mapper = FieldsMapper::new(&field);
+                                return mapper.moment_dtype();
+                            },
+                        }
+                        Ok(field)
                     },
                     Var(expr, _) => {
-                        let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
-                        let mapper = FieldsMapper::new(&field);
-                        mapper.var_dtype()
+                        let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
+                        match field.dtype {
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(precision, scale) => field.coerce(Decimal(
+                                _get_decimal_precision_sum(precision),
+                                Some(_get_decimal_scale_var(scale.unwrap_or(0))),
+                            )),
+                            _ => {
+                                let field = [field];
+                                let mapper = FieldsMapper::new(&field);
+                                return mapper.var_dtype();
+                            },
+                        }
+                        Ok(field)
                     },
                     NUnique(expr) => {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
@@ -246,9 +288,23 @@ impl AExpr {
                         Ok(field)
                     },
                     Quantile { expr, .. } => {
-                        let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
-                        let mapper = FieldsMapper::new(&field);
-                        mapper.map_numeric_to_float_dtype(true)
+                        let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
+                        match field.dtype {
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(precision, scale) => {
+                                let scale = scale.unwrap_or(0);
+                                field.coerce(Decimal(
+                                    _get_decimal_precision_quantile(precision, scale),
+                                    Some(_get_decimal_scale_quantile(scale)),
+                                ))
+                            },
+                            _ => {
+                                let field = [field];
+                                let mapper = FieldsMapper::new(&field);
+                                return mapper.map_numeric_to_float_dtype(true);
+                            },
+                        }
+                        Ok(field)
                     },
                 }
             },
@@ -433,6 +489,12 @@ fn get_arithmetic_field(
                 (Struct(_), Struct(_)) => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => return Ok(left_field),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, _) | (_, Interval) => {
+                    polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -487,9 +549,18 @@ fn get_arithmetic_field(
                     )?)
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
-                    Decimal(None, Some(scale))
+                    let precision = _get_decimal_precision_add_sub(
+                        *precision_left,
+                        *scale_left,
+                        *precision_right,
+                        *scale_right,
+                    );
+                    Decimal(precision, Some(scale))
                 },
                 (left, right) => try_get_supertype(left, right)?,
             }
@@ -497,6 +568,14 @@ fn get_arithmetic_field(
         Operator::Plus => {
             let right_type = right_ae.to_field_impl(ctx)?.dtype;
             match (&left_field.dtype, &right_type) {
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => return Ok(left_field),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Datetime(_, _) | Date) => right_type,
+                #[cfg(feature = "dtype-interval")]
+                (Interval, _) | (_, Interval) => {
+                    polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -541,9 +620,18 @@ fn get_arithmetic_field(
                     )?)
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
-                    Decimal(None, Some(scale))
+                    let precision = _get_decimal_precision_add_sub(
+                        *precision_left,
+                        *scale_left,
+                        *precision_right,
+                        *scale_right,
+                    );
+                    Decimal(precision, Some(scale))
                 },
                 (left, right) => try_get_supertype(left, right)?,
             }
@@ -586,18 +674,25 @@ fn get_arithmetic_field(
                     },
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
-                    let scale = match op {
-                        Operator::Multiply => _get_decimal_scale_mul(*scale_left, *scale_right),
-                        Operator::Divide | Operator::TrueDivide => {
-                            _get_decimal_scale_div(*scale_left)
-                        },
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
+                    let (precision, scale) = match op {
+                        Operator::Multiply => (
+                            _get_decimal_precision_mul(*precision_left, *precision_right),
+                            _get_decimal_scale_mul(*scale_left, *scale_right),
+                        ),
+                        Operator::Divide | Operator::TrueDivide => (
+                            _get_decimal_precision_div(*precision_left, *scale_left, *scale_right),
+                            _get_decimal_scale_div(*scale_left),
+                        ),
                         _ => {
                             debug_assert!(false);
-                            *scale_left
+                            (*precision_left, *scale_left)
                         },
                     };
-                    let dtype = Decimal(None, Some(scale));
+                    let dtype = Decimal(precision, Some(scale));
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
@@ -697,6 +792,10 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
             let dtype = get_truediv_dtype(list_dtype.leaf_dtype(), other_dtype.leaf_dtype())?;
             list_dtype.cast_leaf(dtype)
         },
+        #[cfg(feature = "dtype-f16")]
+        (Float16, Float16 | Boolean) => Float16,
+        #[cfg(feature = "dtype-f16")]
+        (Float16, other) | (other, Float16) => get_truediv_dtype(&Float32, other)?,
         #[cfg(feature = "dtype-u8")]
         (Float32, UInt8 | Int8) => Float32,
         #[cfg(feature = "dtype-u16")]
@@ -705,9 +804,11 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
         (Float32, Float64) => Float64,
         (Float32, _) => Float32,
         #[cfg(feature = "dtype-decimal")]
-        (Decimal(_, Some(scale_left)), Decimal(_, _)) => {
+        (Decimal(precision_left, Some(scale_left)), Decimal(_, scale_right)) => {
             let scale = _get_decimal_scale_div(*scale_left);
-            Decimal(None, Some(scale))
+            let precision =
+                _get_decimal_precision_div(*precision_left, *scale_left, scale_right.unwrap_or(0));
+            Decimal(precision, Some(scale))
         },
         #[cfg(feature = "dtype-u8")]
         (UInt8 | Int8, Float32) => Float32,
@@ -144,6 +144,20 @@ pub(super) fn convert_functions(
                 C::Slice(s, e) => IC::Slice(s, e),
             })
         },
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(map_function) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            I::MapExpr(match map_function {
+                M::Get => IM::Get,
+                M::ContainsKey => IM::ContainsKey,
+                M::Keys => IM::Keys,
+                M::Values => IM::Values,
+                M::ToList => IM::ToList,
+                M::ToStruct(names) => IM::ToStruct(names),
+                M::FromList => IM::FromList,
+                M::FromStruct => IM::FromStruct,
+            })
+        },
         F::ListExpr(list_function) => {
             use {IRListFunction as IL, ListFunction as L};
             I::ListExpr(match list_function {
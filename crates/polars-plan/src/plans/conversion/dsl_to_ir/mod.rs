@@ -1026,6 +1026,30 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 key,
             }
         },
+        DslPlan::RecursiveUnion {
+            initial,
+            recursive,
+            options,
+        } => {
+            let initial = to_alp_impl(owned(initial), ctxt)
+                .map_err(|e| e.context(failed_here!(recursive_union)))?;
+            let recursive = to_alp_impl(owned(recursive), ctxt)
+                .map_err(|e| e.context(failed_here!(recursive_union)))?;
+
+            let schema = ctxt.lp_arena.get(initial).schema(ctxt.lp_arena);
+            let recursive_schema = ctxt.lp_arena.get(recursive).schema(ctxt.lp_arena);
+            recursive_schema
+                .matches_schema(schema.as_ref())
+                .map_err(|_| polars_err!(SchemaMismatch: "the recursive term of a recursive union should have the schema of the initial term, \
+                    got\n{:?} and \n{:?}", schema, recursive_schema)
+                )?;
+
+            IR::RecursiveUnion {
+                initial,
+                recursive,
+                options,
+            }
+        },
         DslPlan::IR { node, dsl, version } => {
             return if node.is_some()
                 && version == ctxt.lp_arena.version()
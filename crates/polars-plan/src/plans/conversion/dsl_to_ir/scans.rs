@@ -4,7 +4,7 @@ use polars_io::cloud::CloudOptions;
 #[cfg(feature = "cloud")]
 use polars_io::pl_async::get_runtime;
 use polars_io::prelude::*;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::DecompressingReader;
 
 use super::*;
 
@@ -49,8 +49,20 @@ pub(super) fn dsl_to_ir(
             FileScanDsl::Ipc { .. } => {
                 sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
+            #[cfg(feature = "fixed_width")]
+            FileScanDsl::FixedWidth { .. } => {
+                sources.expand_paths(unified_scan_args, cloud_options)?
+            },
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
             #[cfg(feature = "python")]
@@ -188,7 +200,12 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "avro",
+    feature = "orc"
+))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -199,7 +216,7 @@ fn prepare_output_schema(
     Ok(Arc::new(schema))
 }
 
-#[cfg(any(feature = "json", feature = "csv"))]
+#[cfg(any(feature = "json", feature = "csv", feature = "fixed_width"))]
 fn prepare_schemas(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -314,6 +331,92 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use arrow::io::avro::avro_schema::read::read_metadata;
+    use arrow::io::avro::read::infer_schema;
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(first.to_include_path_name())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = read_metadata(&mut std::io::Cursor::new(&memslice[..]))?;
+    let reader_schema = Arc::new(infer_schema(&metadata.record)?);
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(reader_schema.as_ref()), row_index)?,
+        Some(Either::Left(reader_schema)),
+        (None, 0),
+    );
+
+    Ok(file_info)
+}
+
+#[cfg(feature = "orc")]
+pub(super) fn orc_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+    use polars_io::orc::OrcMetadata;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(first.to_include_path_name())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = OrcMetadata::read(&memslice)?;
+    let reader_schema = Arc::new(metadata.arrow_schema().clone());
+    let num_rows = metadata.num_rows();
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(metadata.schema(), row_index)?,
+        Some(Either::Left(reader_schema)),
+        (None, num_rows),
+    );
+
+    Ok(file_info)
+}
+
 #[cfg(feature = "csv")]
 pub fn csv_file_info(
     sources: &ScanSources,
@@ -361,7 +464,20 @@ pub fn csv_file_info(
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        // Only the start of a compressed file is needed to infer the schema.
+        let bytes: &[u8] =
+            if let Some(mut decompressor) = DecompressingReader::try_new(memslice.clone())? {
+                decompress_schema_inference_prefix(
+                    &mut decompressor,
+                    csv_options,
+                    csv_options.infer_schema_length,
+                    owned,
+                )?;
+                owned
+            } else {
+                &memslice
+            };
+        let mut reader = std::io::Cursor::new(bytes);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -473,18 +589,26 @@ pub fn ndjson_file_info(
         }
     };
 
-    let owned = &mut vec![];
-
     let mut schema = if let Some(schema) = ndjson_options.schema.clone() {
         schema
     } else {
         let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
 
-        Arc::new(polars_io::ndjson::infer_schema(
-            &mut reader,
-            ndjson_options.infer_schema_length,
-        )?)
+        // A compressed file is decompressed while reading, so that only the lines that are
+        // needed to infer the schema are decompressed.
+        let schema = if let Some(decompressor) = DecompressingReader::try_new(memslice.clone())? {
+            polars_io::ndjson::infer_schema(
+                &mut std::io::BufReader::new(decompressor),
+                ndjson_options.infer_schema_length,
+            )?
+        } else {
+            polars_io::ndjson::infer_schema(
+                &mut std::io::Cursor::new(&memslice[..]),
+                ndjson_options.infer_schema_length,
+            )?
+        };
+
+        Arc::new(schema)
     };
 
     if let Some(overwriting_schema) = &ndjson_options.schema_overwrite {
@@ -504,6 +628,61 @@ pub fn ndjson_file_info(
     ))
 }
 
+#[cfg(feature = "fixed_width")]
+pub(super) fn fixed_width_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    options: &polars_io::fixed_width::FixedWidthReadOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+    use polars_io::fixed_width::{
+        decompress_schema_inference_prefix, find_starting_point, infer_fixed_width_schema,
+    };
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(first.to_include_path_name())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+
+    // A compressed file is decompressed while reading, so that only the lines that are
+    // needed to infer the schema are decompressed.
+    let mut prefix = vec![];
+    let bytes = if let Some(mut decompressor) = DecompressingReader::try_new(memslice.clone())? {
+        decompress_schema_inference_prefix(&mut decompressor, options, &mut prefix)?;
+        &prefix[..]
+    } else {
+        &memslice[..]
+    };
+    let bytes = &bytes[find_starting_point(bytes, options)..];
+    let schema = infer_fixed_width_schema(bytes, options)?;
+
+    let (reader_schema, schema) = prepare_schemas(schema, row_index)?;
+
+    Ok(FileInfo::new(
+        schema,
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    ))
+}
+
 // Add flags that influence metadata/schema here
 #[derive(Eq, Hash, PartialEq)]
 enum CachedSourceKey {
@@ -577,6 +756,18 @@ impl SourcesToFileInfo {
                     },
                 )
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options } => (
+                scans::avro_file_info(sources, unified_scan_args.row_index.as_ref(), cloud_options)
+                    .map_err(|e| e.context(failed_here!(avro scan)))?,
+                FileScanIR::Avro { options },
+            ),
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { options } => (
+                scans::orc_file_info(sources, unified_scan_args.row_index.as_ref(), cloud_options)
+                    .map_err(|e| e.context(failed_here!(orc scan)))?,
+                FileScanIR::Orc { options },
+            ),
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
                 // TODO: This is a hack. We conditionally set `allow_missing_columns` to
@@ -597,6 +788,17 @@ impl SourcesToFileInfo {
                     FileScanIR::Csv { options },
                 )
             },
+            #[cfg(feature = "fixed_width")]
+            FileScanDsl::FixedWidth { options } => (
+                scans::fixed_width_file_info(
+                    sources,
+                    unified_scan_args.row_index.as_ref(),
+                    &options,
+                    cloud_options,
+                )
+                .map_err(|e| e.context(failed_here!(fixed_width scan)))?,
+                FileScanIR::FixedWidth { options },
+            ),
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { options } => (
                 scans::ndjson_file_info(
@@ -314,6 +314,20 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IC::Slice(s, l) => C::Slice(s, l),
             })
         },
+        #[cfg(feature = "dtype-map")]
+        IF::MapExpr(f) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            F::MapExpr(match f {
+                IM::Get => M::Get,
+                IM::ContainsKey => M::ContainsKey,
+                IM::Keys => M::Keys,
+                IM::Values => M::Values,
+                IM::ToList => M::ToList,
+                IM::ToStruct(names) => M::ToStruct(names),
+                IM::FromList => M::FromList,
+                IM::FromStruct => M::FromStruct,
+            })
+        },
         IF::ListExpr(f) => {
             use {IRListFunction as IL, ListFunction as L};
             F::ListExpr(match f {
@@ -47,6 +47,54 @@ fn is_cat_str_binary(type_left: &DataType, type_right: &DataType) -> bool {
     }
 }
 
+#[cfg(feature = "dtype-interval")]
+// Calendar intervals can't be added with plain arithmetic, so we rewrite
+// `temporal +/- interval` into an `offset_by`.
+fn process_interval_arithmetic(
+    type_left: &DataType,
+    type_right: &DataType,
+    node_left: Node,
+    node_right: Node,
+    op: Operator,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<AExpr>> {
+    use DataType::*;
+
+    let (node_temporal, node_interval) = match (type_left, type_right, op) {
+        (Date | Datetime(_, _), Interval, Operator::Plus | Operator::Minus) => {
+            (node_left, node_right)
+        },
+        (Interval, Date | Datetime(_, _), Operator::Plus) => (node_right, node_left),
+        _ => polars_bail!(
+            InvalidOperation: "{} operation not supported for dtypes `{}` and `{}`",
+            op, type_left, type_right
+        ),
+    };
+
+    let node_interval = if op == Operator::Minus {
+        let function = IRFunctionExpr::Negate;
+        let options = function.function_options();
+        expr_arena.add(AExpr::Function {
+            input: vec![ExprIR::from_node(node_interval, expr_arena)],
+            function,
+            options,
+        })
+    } else {
+        node_interval
+    };
+
+    let function = IRFunctionExpr::TemporalExpr(IRTemporalFunction::OffsetBy);
+    let options = function.function_options();
+    Ok(Some(AExpr::Function {
+        input: vec![
+            ExprIR::from_node(node_temporal, expr_arena),
+            ExprIR::from_node(node_interval, expr_arena),
+        ],
+        function,
+        options,
+    }))
+}
+
 #[cfg(feature = "dtype-struct")]
 // Ensure we don't cast to supertype
 // otherwise we will fill a struct with null fields
@@ -212,6 +260,17 @@ pub(super) fn process_binary(
 
     if op.is_arithmetic() {
         match (&type_left, &type_right) {
+            #[cfg(feature = "dtype-interval")]
+            (Interval, _) | (_, Interval) => {
+                return process_interval_arithmetic(
+                    &type_left,
+                    &type_right,
+                    node_left,
+                    node_right,
+                    op,
+                    expr_arena,
+                );
+            },
             (Duration(_), Duration(_)) => return Ok(None),
             (Duration(_), r) if r.is_primitive_numeric() => return Ok(None),
             (String, a) | (a, String) if a.is_primitive_numeric() => {
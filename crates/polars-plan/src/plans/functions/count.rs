@@ -4,12 +4,20 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "avro",
+    feature = "orc"
 ))]
 use polars_core::error::feature_gated;
 #[cfg(any(feature = "json", feature = "parquet"))]
 use polars_io::SerReader;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "json",
+    feature = "avro",
+    feature = "orc",
+    feature = "fixed_width"
+))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetReader;
@@ -30,7 +38,9 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro",
+        feature = "orc"
     )))]
     {
         unreachable!()
@@ -40,7 +50,9 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro",
+        feature = "orc"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
@@ -57,6 +69,14 @@ pub fn count_rows(
             ),
             #[cfg(feature = "json")]
             FileScanIR::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            #[cfg(feature = "avro")]
+            FileScanIR::Avro { .. } => count_rows_avro(sources, cloud_options),
+            #[cfg(feature = "orc")]
+            FileScanIR::Orc { .. } => count_rows_orc(sources, cloud_options),
+            #[cfg(feature = "fixed_width")]
+            FileScanIR::FixedWidth { options } => {
+                count_rows_fixed_width(sources, options, cloud_options)
+            },
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset { .. } => unreachable!(),
             FileScanIR::Anonymous { .. } => {
@@ -254,3 +274,149 @@ pub(super) fn count_rows_ndjson(
         })
         .sum()
 }
+
+#[cfg(feature = "avro")]
+pub(super) fn count_rows_avro(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use arrow::io::avro::avro_schema::read::read_metadata;
+    use arrow::io::avro::read::read_block_locations;
+    use polars_core::config;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+
+            // Only the block headers are read, the blocks are neither decompressed nor decoded.
+            let mut reader = std::io::Cursor::new(&memslice[..]);
+            let metadata = read_metadata(&mut reader)?;
+            let blocks =
+                read_block_locations(&memslice, reader.position() as usize, metadata.marker)?;
+            Ok(blocks.iter().map(|block| block.num_rows).sum::<usize>())
+        })
+        .sum()
+}
+
+#[cfg(feature = "orc")]
+pub(super) fn count_rows_orc(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::orc::OrcMetadata;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+
+            // The row count is stored in the file footer.
+            Ok(OrcMetadata::read(&memslice)?.num_rows())
+        })
+        .sum()
+}
+
+#[cfg(feature = "fixed_width")]
+pub(super) fn count_rows_fixed_width(
+    sources: &ScanSources,
+    options: &polars_io::fixed_width::FixedWidthReadOptions,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::utils::compression::maybe_decompress_bytes;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            let mut out = vec![];
+            let bytes = maybe_decompress_bytes(&memslice, &mut out)?;
+
+            // The lines are counted without parsing the fields.
+            Ok(polars_io::fixed_width::count_rows(bytes, options))
+        })
+        .sum()
+}
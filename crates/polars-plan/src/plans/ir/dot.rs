@@ -324,6 +324,16 @@ impl<'a> IRDotDisplay<'a> {
 
                 write_label(f, id, |f| write!(f, "MERGE_SORTED ON '{key}'",))?;
             },
+            RecursiveUnion {
+                initial,
+                recursive,
+                options: _,
+            } => {
+                recurse!(*initial);
+                recurse!(*recursive);
+
+                write_label(f, id, |f| f.write_str("RECURSIVE UNION"))?;
+            },
             Invalid => write_label(f, id, |f| f.write_str("INVALID"))?,
         }
 
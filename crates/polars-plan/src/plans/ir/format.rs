@@ -252,6 +252,20 @@ impl<'a> IRDisplay<'a> {
                 self.with_root(*input_right)._format(f, sub_indent)?;
                 write!(f, "\n{:indent$}END MERGE_SORTED", "")
             },
+            RecursiveUnion {
+                initial,
+                recursive,
+                options: _,
+            } => {
+                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                write!(f, ":")?;
+
+                write!(f, "\n{:indent$}INITIAL PLAN:", "")?;
+                self.with_root(*initial)._format(f, sub_indent)?;
+                write!(f, "\n{:indent$}RECURSIVE PLAN:", "")?;
+                self.with_root(*recursive)._format(f, sub_indent)?;
+                write!(f, "\n{:indent$}END RECURSIVE UNION", "")
+            },
             ir_node => {
                 write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
                 for input in ir_node.inputs() {
@@ -893,6 +907,18 @@ pub fn write_ir_non_recursive(
             input_right: _,
             key,
         } => write!(f, "{:indent$}MERGE SORTED ON '{key}'", ""),
+        IR::RecursiveUnion {
+            initial: _,
+            recursive: _,
+            options,
+        } => {
+            let name = if options.distinct {
+                "RECURSIVE UNION"
+            } else {
+                "RECURSIVE UNION ALL"
+            };
+            write!(f, "{:indent$}{name}", "")
+        },
         IR::Invalid => write!(f, "{:indent$}INVALID", ""),
     }
 }
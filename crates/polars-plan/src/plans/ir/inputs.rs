@@ -64,6 +64,7 @@ impl IR {
             SinkMultiple { .. } => Exprs::Empty,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => Exprs::Empty,
+            RecursiveUnion { .. } => Exprs::Empty,
 
             #[cfg(feature = "python")]
             PythonScan { options } => match &options.predicate {
@@ -136,6 +137,7 @@ impl IR {
             SinkMultiple { .. } => ExprsMut::Empty,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => ExprsMut::Empty,
+            RecursiveUnion { .. } => ExprsMut::Empty,
 
             #[cfg(feature = "python")]
             PythonScan { options } => match &mut options.predicate {
@@ -236,6 +238,9 @@ impl IR {
                 input_right,
                 ..
             } => Inputs::double(*input_left, *input_right),
+            RecursiveUnion {
+                initial, recursive, ..
+            } => Inputs::double(*initial, *recursive),
             Invalid => unreachable!(),
         }
     }
@@ -275,6 +280,9 @@ impl IR {
                 input_right,
                 ..
             } => InputsMut::double(input_left, input_right),
+            RecursiveUnion {
+                initial, recursive, ..
+            } => InputsMut::double(initial, recursive),
             Invalid => unreachable!(),
         }
     }
@@ -156,6 +156,13 @@ pub enum IR {
         input_right: Node,
         key: PlSmallStr,
     },
+    #[cfg_attr(feature = "ir_serde", serde(skip))]
+    RecursiveUnion {
+        initial: Node,
+        /// Reads the rows of the previous iteration from `options.working_table`.
+        recursive: Node,
+        options: RecursiveUnionOptions,
+    },
     #[default]
     Invalid,
 }
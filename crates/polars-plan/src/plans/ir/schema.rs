@@ -44,6 +44,7 @@ impl IR {
             SimpleProjection { .. } => "simple_projection",
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => "merge_sorted",
+            RecursiveUnion { .. } => "recursive_union",
             Invalid => "invalid",
         }
     }
@@ -110,6 +111,7 @@ impl IR {
             ExtContext { schema, .. } => schema,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { input_left, .. } => return arena.get(*input_left).schema(arena),
+            RecursiveUnion { initial, .. } => return arena.get(*initial).schema(arena),
             Invalid => unreachable!(),
         };
         Cow::Borrowed(schema)
@@ -170,6 +172,7 @@ impl IR {
             },
             #[cfg(feature = "merge_sorted")]
             MergeSorted { input_left, .. } => IR::schema_with_cache(*input_left, arena, cache),
+            RecursiveUnion { initial, .. } => IR::schema_with_cache(*initial, arena, cache),
             Invalid => unreachable!(),
         };
         cache.insert(node, schema.clone());
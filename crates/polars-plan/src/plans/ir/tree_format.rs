@@ -378,6 +378,17 @@ impl<'a> TreeFmtNode<'a> {
                             .chain([self.lp_node(Some("RIGHT PLAN:".to_string()), *input_right)])
                             .collect(),
                     ),
+                    RecursiveUnion {
+                        initial,
+                        recursive,
+                        options: _,
+                    } => ND(
+                        wh(h, "RECURSIVE UNION"),
+                        vec![
+                            self.lp_node(Some("INITIAL PLAN:".to_string()), *initial),
+                            self.lp_node(Some("RECURSIVE PLAN:".to_string()), *recursive),
+                        ],
+                    ),
                     Invalid => ND(wh(h, "INVALID"), vec![]),
                 }
             },
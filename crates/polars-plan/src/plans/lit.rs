@@ -242,6 +242,7 @@ impl LiteralValue {
                 AnyValue::Int32(v) => cast_usize!(v),
                 AnyValue::Int64(v) => cast_usize!(v),
                 AnyValue::Int128(v) => cast_usize!(v),
+                AnyValue::UInt128(v) => cast_usize!(v),
                 _ => {
                     polars_bail!(InvalidOperation: "expression must be constant literal to extract integer")
                 },
@@ -272,6 +273,7 @@ impl LiteralValue {
                 AnyValue::Int32(v) => cast_i64!(v),
                 AnyValue::Int64(v) => Ok(v),
                 AnyValue::Int128(v) => cast_i64!(v),
+                AnyValue::UInt128(v) => cast_i64!(v),
                 _ => {
                     polars_bail!(InvalidOperation: "expression must be constant literal to extract integer")
                 },
@@ -502,6 +504,7 @@ make_literal_typed!(u8, UInt8);
 make_literal_typed!(u16, UInt16);
 make_literal_typed!(u32, UInt32);
 make_literal_typed!(u64, UInt64);
+make_literal_typed!(u128, UInt128);
 
 make_dyn_lit!(f32, Float);
 make_dyn_lit!(f64, Float);
@@ -33,6 +33,7 @@ pub(super) struct MemberCollector {
     pub(crate) has_distinct: bool,
     pub(crate) has_sort: bool,
     pub(crate) has_group_by: bool,
+    pub(crate) has_recursive_union: bool,
     #[cfg(feature = "cse")]
     scans: UniqueScans,
 }
@@ -48,6 +49,7 @@ impl MemberCollector {
             has_distinct: false,
             has_sort: false,
             has_group_by: false,
+            has_recursive_union: false,
             #[cfg(feature = "cse")]
             scans: UniqueScans::default(),
         }
@@ -79,6 +81,9 @@ impl MemberCollector {
                 HConcat { .. } => {
                     self.has_joins_or_unions = true;
                 },
+                RecursiveUnion { .. } => {
+                    self.has_recursive_union = true;
+                },
                 #[cfg(feature = "cse")]
                 DataFrameScan { .. } => {
                     self.scans.insert(_node, lp_arena, _expr_arena);
@@ -168,6 +168,8 @@ fn visit_logical_plan_for_scan_paths(
             let use_fast_file_count = use_fast_file_count.unwrap_or(match scan_type.as_ref() {
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
+                #[cfg(feature = "fixed_width")]
+                FileScanIR::FixedWidth { .. } => true,
                 _ => false,
             });
 
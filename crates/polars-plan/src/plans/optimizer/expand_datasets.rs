@@ -167,6 +167,17 @@ impl OptimizationRule for ExpandDatasets {
                                     metadata: None,
                                 },
 
+                                #[cfg(feature = "avro")]
+                                FileScanDsl::Avro { options } => FileScanIR::Avro { options },
+
+                                #[cfg(feature = "orc")]
+                                FileScanDsl::Orc { options } => FileScanIR::Orc { options },
+
+                                #[cfg(feature = "fixed_width")]
+                                FileScanDsl::FixedWidth { options } => {
+                                    FileScanIR::FixedWidth { options }
+                                },
+
                                 #[cfg(feature = "parquet")]
                                 FileScanDsl::Parquet { options } => FileScanIR::Parquet {
                                     options,
@@ -371,6 +371,12 @@ impl PredicatePushDown<'_> {
                     FileScanIR::Parquet { .. } => {},
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => {},
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { .. } => {},
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc { .. } => {},
+                    #[cfg(feature = "fixed_width")]
+                    FileScanIR::FixedWidth { .. } => {},
                     _ => {
                         // Disallow row index pushdown of other scans as they may
                         // not update the row index properly before applying the
@@ -628,6 +634,10 @@ impl PredicatePushDown<'_> {
             lp @ HConcat { .. } => {
                 self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
             },
+            // Predicates would change the rows the recursive term sees.
+            lp @ RecursiveUnion { .. } => {
+                self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
+            },
             // Caches will run predicate push-down in the `cache_states` run.
             Cache { .. } => {
                 if self.block_at_cache {
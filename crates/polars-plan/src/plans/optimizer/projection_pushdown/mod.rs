@@ -453,6 +453,12 @@ impl ProjectionPushDown {
                     FileScanIR::NDJson { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => true,
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { .. } => true,
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc { .. } => true,
+                    #[cfg(feature = "fixed_width")]
+                    FileScanIR::FixedWidth { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
@@ -772,6 +778,10 @@ impl ProjectionPushDown {
                     key,
                 })
             },
+            // Every column can be used by the next iteration of the recursive term.
+            lp @ RecursiveUnion { .. } => {
+                self.no_pushdown_restart_opt(lp, ctx, lp_arena, expr_arena)
+            },
             Invalid => unreachable!(),
         }
     }
@@ -227,6 +227,15 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScanIR::Ipc { .. } => true,
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { .. } => true,
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc { .. } => true,
+
+                #[cfg(feature = "fixed_width")]
+                FileScanIR::FixedWidth { .. } => true,
+
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
 
@@ -434,6 +443,7 @@ impl SlicePushDown {
             | m @ (Cache {..}, _)
             | m @ (Distinct {..}, _)
             | m @ (GroupBy{..},_)
+            | m @ (RecursiveUnion{..},_)
             // blocking in streaming
             | m @ (Join{..},_)
             => {
@@ -193,6 +193,13 @@ impl Hash for HashableEqLP<'_> {
             } => {
                 key.hash(state);
             },
+            IR::RecursiveUnion {
+                initial: _,
+                recursive: _,
+                options,
+            } => {
+                options.hash(state);
+            },
             IR::Invalid => unreachable!(),
         }
     }
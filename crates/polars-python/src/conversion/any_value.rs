@@ -73,6 +73,7 @@ pub(crate) fn any_value_into_py_object<'py>(
         AnyValue::Int32(v) => v.into_bound_py_any(py),
         AnyValue::Int64(v) => v.into_bound_py_any(py),
         AnyValue::Int128(v) => v.into_bound_py_any(py),
+        AnyValue::UInt128(v) => v.into_bound_py_any(py),
         AnyValue::Float32(v) => v.into_bound_py_any(py),
         AnyValue::Float64(v) => v.into_bound_py_any(py),
         AnyValue::Null => py.None().into_bound_py_any(py),
@@ -100,6 +101,7 @@ pub(crate) fn any_value_into_py_object<'py>(
             time_delta.into_bound_py_any(py)
         },
         AnyValue::Time(v) => nanos_since_midnight_to_naivetime(v).into_bound_py_any(py),
+        AnyValue::Interval(v) => (v.months(), v.days(), v.ns()).into_bound_py_any(py),
         AnyValue::Array(v, _) | AnyValue::List(v) => PySeries::new(v).to_list(py),
         ref av @ AnyValue::Struct(_, _, flds) => {
             Ok(struct_dict(py, av._iter_struct_av(), flds)?.into_any())
@@ -203,6 +205,8 @@ pub(crate) fn py_object_to_any_value(
             Ok(AnyValue::Int64(v))
         } else if let Ok(v) = ob.extract::<i128>() {
             Ok(AnyValue::Int128(v))
+        } else if let Ok(v) = ob.extract::<u128>() {
+            Ok(AnyValue::UInt128(v))
         } else if !strict {
             let f = ob.extract::<f64>()?;
             Ok(AnyValue::Float64(f))
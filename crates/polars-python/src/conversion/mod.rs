@@ -16,6 +16,10 @@ use polars::frame::row::Row;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(feature = "fixed_width")]
+use polars::io::fixed_width::FixedWidthSpanUnit;
+#[cfg(feature = "orc")]
+use polars::io::orc::OrcCompression;
 use polars::prelude::ColumnMapping;
 use polars::prelude::default_values::{
     DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
@@ -236,6 +240,14 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Int128"))?;
                 class.call0()
             },
+            DataType::UInt128 => {
+                let class = pl.getattr(intern!(py, "UInt128"))?;
+                class.call0()
+            },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16"))?;
+                class.call0()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32"))?;
                 class.call0()
@@ -309,6 +321,7 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 class.call1((series,))
             },
             DataType::Time => pl.getattr(intern!(py, "Time")).and_then(|x| x.call0()),
+            DataType::Interval => pl.getattr(intern!(py, "Interval")).and_then(|x| x.call0()),
             DataType::Struct(fields) => {
                 let field_class = pl.getattr(intern!(py, "Field"))?;
                 let iter = fields.iter().map(|fld| {
@@ -320,6 +333,18 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let struct_class = pl.getattr(intern!(py, "Struct"))?;
                 struct_class.call1((fields,))
             },
+            DataType::Map(key, value, keys_sorted) => {
+                let class = pl.getattr(intern!(py, "Map"))?;
+                let key = Wrap(*key.clone());
+                let value = Wrap(*value.clone());
+                let kwargs = [("keys_sorted", *keys_sorted)];
+                class.call((&key, &value), Some(&kwargs.into_py_dict(py)?))
+            },
+            DataType::Extension(ext, storage) => {
+                let class = pl.getattr(intern!(py, "Extension"))?;
+                let storage = Wrap(*storage.clone());
+                class.call1((ext.name.as_str(), &storage, ext.metadata.as_deref()))
+            },
             DataType::Null => {
                 let class = pl.getattr(intern!(py, "Null"))?;
                 class.call0()
@@ -374,6 +399,8 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "UInt128" => DataType::UInt128,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
@@ -383,12 +410,16 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Enum" => DataType::from_frozen_categories(FrozenCategories::new([]).unwrap()),
                     "Date" => DataType::Date,
                     "Time" => DataType::Time,
+                    "Interval" => DataType::Interval,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Duration" => DataType::Duration(TimeUnit::Microseconds),
                     "Decimal" => DataType::Decimal(None, None), // "none" scale => "infer"
                     "List" => DataType::List(Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
                     "Struct" => DataType::Struct(vec![]),
+                    "Map" => {
+                        DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null), false)
+                    },
                     "Null" => DataType::Null,
                     #[cfg(feature = "object")]
                     "Object" => DataType::Object(OBJECT_NAME),
@@ -409,6 +440,8 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "UInt128" => DataType::UInt128,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
@@ -432,6 +465,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             },
             "Date" => DataType::Date,
             "Time" => DataType::Time,
+            "Interval" => DataType::Interval,
             "Datetime" => {
                 let time_unit = ob.getattr(intern!(py, "time_unit")).unwrap();
                 let time_unit = time_unit.extract::<Wrap<TimeUnit>>()?.0;
@@ -473,6 +507,26 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     .collect::<Vec<Field>>();
                 DataType::Struct(fields)
             },
+            "Map" => {
+                let key = ob.getattr(intern!(py, "key")).unwrap();
+                let value = ob.getattr(intern!(py, "value")).unwrap();
+                let key = key.extract::<Wrap<DataType>>()?;
+                let value = value.extract::<Wrap<DataType>>()?;
+                let keys_sorted = ob.getattr(intern!(py, "keys_sorted"))?.extract::<bool>()?;
+                DataType::Map(Box::new(key.0), Box::new(value.0), keys_sorted)
+            },
+            "Extension" => {
+                let name = ob.getattr(intern!(py, "name"))?.extract::<PyBackedStr>()?;
+                let storage = ob.getattr(intern!(py, "storage"))?;
+                let storage = storage.extract::<Wrap<DataType>>()?;
+                let metadata = ob
+                    .getattr(intern!(py, "metadata"))?
+                    .extract::<Option<PyBackedStr>>()?;
+                DataType::Extension(
+                    ExtensionType::new((&*name).into(), metadata.as_deref().map(Into::into)),
+                    Box::new(storage.0),
+                )
+            },
             "Null" => DataType::Null,
             #[cfg(feature = "object")]
             "Object" => DataType::Object(OBJECT_NAME),
@@ -848,6 +902,25 @@ impl<'py> FromPyObject<'py> for Wrap<Option<AvroCompression>> {
     }
 }
 
+#[cfg(feature = "orc")]
+impl<'py> FromPyObject<'py> for Wrap<Option<OrcCompression>> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "uncompressed" => None,
+            "zlib" => Some(OrcCompression::Zlib),
+            "snappy" => Some(OrcCompression::Snappy),
+            "lz4" => Some(OrcCompression::LZ4),
+            "zstd" => Some(OrcCompression::ZSTD),
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "orc `compression` must be one of {{'uncompressed', 'zlib', 'snappy', 'lz4', 'zstd'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<CategoricalOrdering> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -939,6 +1012,22 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
     }
 }
 
+#[cfg(feature = "fixed_width")]
+impl<'py> FromPyObject<'py> for Wrap<FixedWidthSpanUnit> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "bytes" => FixedWidthSpanUnit::Bytes,
+            "chars" => FixedWidthSpanUnit::Chars,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`span_unit` must be one of {{'bytes', 'chars'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "ipc")]
 impl<'py> FromPyObject<'py> for Wrap<Option<IpcCompression>> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -1508,6 +1597,31 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+pub(crate) fn parse_external_compression(
+    compression: &str,
+    compression_level: Option<i32>,
+) -> PyResult<ExternalCompression> {
+    let parsed = match compression {
+        "uncompressed" => ExternalCompression::Uncompressed,
+        "gzip" => ExternalCompression::Gzip(
+            compression_level
+                .map(|lvl| {
+                    u32::try_from(lvl).map_err(|_| {
+                        PyValueError::new_err(format!("invalid gzip compression level: {lvl}"))
+                    })
+                })
+                .transpose()?,
+        ),
+        "zstd" => ExternalCompression::Zstd(compression_level),
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`compression` must be one of {{'uncompressed', 'gzip', 'zstd'}}, got {e}",
+            )));
+        },
+    };
+    Ok(parsed)
+}
+
 pub(crate) fn strings_to_pl_smallstr<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
@@ -5,6 +5,8 @@ use std::sync::Arc;
 use polars::io::RowIndex;
 #[cfg(feature = "avro")]
 use polars::io::avro::AvroCompression;
+#[cfg(feature = "orc")]
+use polars::io::orc::OrcCompression;
 use polars::prelude::*;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
@@ -234,6 +236,28 @@ impl PyDataFrame {
         })
     }
 
+    #[staticmethod]
+    #[cfg(feature = "orc")]
+    #[pyo3(signature = (py_f, columns, projection, n_rows))]
+    pub fn read_orc(
+        py: Python<'_>,
+        py_f: Bound<PyAny>,
+        columns: Option<Vec<String>>,
+        projection: Option<Vec<usize>>,
+        n_rows: Option<usize>,
+    ) -> PyResult<Self> {
+        use polars::io::orc::OrcReader;
+
+        let mmap_bytes_r = get_mmap_bytes_reader(&py_f)?;
+        py.enter_polars_df(move || {
+            OrcReader::new(mmap_bytes_r)
+                .with_projection(projection)
+                .with_columns(columns)
+                .with_n_rows(n_rows)
+                .finish()
+        })
+    }
+
     #[cfg(feature = "json")]
     pub fn write_json(&mut self, py: Python<'_>, py_f: PyObject) -> PyResult<()> {
         let file = BufWriter::new(get_file_like(py_f, true)?);
@@ -281,4 +305,23 @@ impl PyDataFrame {
                 .finish(&mut self.df)
         })
     }
+
+    #[cfg(feature = "orc")]
+    #[pyo3(signature = (py_f, compression, stripe_size))]
+    pub fn write_orc(
+        &mut self,
+        py: Python<'_>,
+        py_f: PyObject,
+        compression: Wrap<Option<OrcCompression>>,
+        stripe_size: Option<usize>,
+    ) -> PyResult<()> {
+        use polars::io::orc::OrcWriter;
+        let mut buf = get_file_like(py_f, true)?;
+        py.enter_polars(|| {
+            OrcWriter::new(&mut buf)
+                .with_compression(compression.0)
+                .with_stripe_size(stripe_size)
+                .finish(&mut self.df)
+        })
+    }
 }
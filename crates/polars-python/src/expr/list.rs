@@ -198,6 +198,10 @@ impl PyExpr {
         self.inner.clone().list().to_array(width).into()
     }
 
+    fn list_to_map(&self) -> Self {
+        self.inner.clone().list().to_map().into()
+    }
+
     #[pyo3(signature = (names))]
     fn list_to_struct(&self, names: Bound<'_, PySequence>) -> PyResult<Self> {
         Ok(self
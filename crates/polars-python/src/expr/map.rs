@@ -0,0 +1,45 @@
+use polars::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+use pyo3::prelude::*;
+use pyo3::types::PySequence;
+
+use crate::PyExpr;
+use crate::conversion::Wrap;
+
+#[pymethods]
+impl PyExpr {
+    fn map_get(&self, key: PyExpr) -> Self {
+        self.inner.clone().map_().get(key.inner).into()
+    }
+
+    fn map_contains_key(&self, key: PyExpr) -> Self {
+        self.inner.clone().map_().contains_key(key.inner).into()
+    }
+
+    fn map_keys(&self) -> Self {
+        self.inner.clone().map_().keys().into()
+    }
+
+    fn map_values(&self) -> Self {
+        self.inner.clone().map_().values().into()
+    }
+
+    fn map_to_list(&self) -> Self {
+        self.inner.clone().map_().to_list().into()
+    }
+
+    #[pyo3(signature = (names))]
+    fn map_to_struct(&self, names: Bound<'_, PySequence>) -> PyResult<Self> {
+        Ok(self
+            .inner
+            .clone()
+            .map_()
+            .to_struct(
+                names
+                    .try_iter()?
+                    .map(|x| Ok(x?.extract::<Wrap<PlSmallStr>>()?.0))
+                    .collect::<PyResult<Arc<[_]>>>()?,
+            )
+            .into())
+    }
+}
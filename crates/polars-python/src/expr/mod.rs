@@ -13,6 +13,8 @@ mod datetime;
 mod general;
 #[cfg(feature = "pymethods")]
 mod list;
+#[cfg(feature = "pymethods")]
+mod map;
 #[cfg(all(feature = "meta", feature = "pymethods"))]
 mod meta;
 #[cfg(feature = "pymethods")]
@@ -26,6 +26,10 @@ impl PyExpr {
         self.inner.clone().struct_().json_encode().into()
     }
 
+    fn struct_to_map(&self) -> Self {
+        self.inner.clone().struct_().to_map().into()
+    }
+
     fn struct_with_fields(&self, fields: Vec<PyExpr>) -> Self {
         let fields = fields.to_exprs();
         let e = self.inner.clone().struct_().with_fields(fields);
@@ -2,6 +2,10 @@ use std::collections::HashMap;
 use std::num::NonZeroUsize;
 
 use either::Either;
+#[cfg(feature = "avro")]
+use polars::io::avro::AvroCompression;
+#[cfg(feature = "fixed_width")]
+use polars::io::fixed_width::{FixedWidthColumn, FixedWidthReadOptions, FixedWidthSpanUnit};
 use polars::io::{HiveOptions, RowIndex};
 use polars::time::*;
 use polars_core::prelude::*;
@@ -413,6 +417,268 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "avro")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        source, sources, n_rows, cache, rechunk, row_index, cloud_options,credential_provider,
+        hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl,
+        include_file_paths
+    ))]
+    fn new_from_avro(
+        source: Option<PyObject>,
+        sources: Wrap<ScanSources>,
+        n_rows: Option<usize>,
+        cache: bool,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        hive_partitioning: Option<bool>,
+        hive_schema: Option<Wrap<Schema>>,
+        try_parse_hive_dates: bool,
+        retries: usize,
+        file_cache_ttl: Option<u64>,
+        include_file_paths: Option<String>,
+    ) -> PyResult<Self> {
+        #[cfg(feature = "cloud")]
+        use cloud::credential_provider::PlCredentialProvider;
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+
+        let hive_options = HiveOptions {
+            enabled: hive_partitioning,
+            hive_start_idx: 0,
+            schema: hive_schema.map(|x| Arc::new(x.0)),
+            try_parse_dates: try_parse_hive_dates,
+        };
+
+        let mut args = ScanArgsAvro {
+            n_rows,
+            cache,
+            rechunk,
+            row_index,
+            cloud_options: None,
+            hive_options,
+            include_file_paths: include_file_paths.map(|x| x.into()),
+        };
+
+        let sources = sources.0;
+        let (first_path, sources) = match source {
+            None => (sources.first_path().map(|p| p.into_owned()), sources),
+            Some(source) => pyobject_to_first_path_and_scan_sources(source)?,
+        };
+
+        #[cfg(feature = "cloud")]
+        if let Some(first_path) = first_path {
+            let first_path_url = first_path.to_str();
+
+            let mut cloud_options =
+                parse_cloud_options(first_path_url, cloud_options.unwrap_or_default())?;
+            if let Some(file_cache_ttl) = file_cache_ttl {
+                cloud_options.file_cache_ttl = file_cache_ttl;
+            }
+            args.cloud_options = Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(PlCredentialProvider::from_python_builder),
+                    ),
+            );
+        }
+
+        let lf = LazyFrame::scan_avro_sources(sources, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
+    #[cfg(feature = "orc")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        source, sources, n_rows, cache, rechunk, row_index, cloud_options,credential_provider,
+        hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl,
+        include_file_paths
+    ))]
+    fn new_from_orc(
+        source: Option<PyObject>,
+        sources: Wrap<ScanSources>,
+        n_rows: Option<usize>,
+        cache: bool,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        hive_partitioning: Option<bool>,
+        hive_schema: Option<Wrap<Schema>>,
+        try_parse_hive_dates: bool,
+        retries: usize,
+        file_cache_ttl: Option<u64>,
+        include_file_paths: Option<String>,
+    ) -> PyResult<Self> {
+        #[cfg(feature = "cloud")]
+        use cloud::credential_provider::PlCredentialProvider;
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+
+        let hive_options = HiveOptions {
+            enabled: hive_partitioning,
+            hive_start_idx: 0,
+            schema: hive_schema.map(|x| Arc::new(x.0)),
+            try_parse_dates: try_parse_hive_dates,
+        };
+
+        let mut args = ScanArgsOrc {
+            n_rows,
+            cache,
+            rechunk,
+            row_index,
+            cloud_options: None,
+            hive_options,
+            include_file_paths: include_file_paths.map(|x| x.into()),
+        };
+
+        let sources = sources.0;
+        let (first_path, sources) = match source {
+            None => (sources.first_path().map(|p| p.into_owned()), sources),
+            Some(source) => pyobject_to_first_path_and_scan_sources(source)?,
+        };
+
+        #[cfg(feature = "cloud")]
+        if let Some(first_path) = first_path {
+            let first_path_url = first_path.to_str();
+
+            let mut cloud_options =
+                parse_cloud_options(first_path_url, cloud_options.unwrap_or_default())?;
+            if let Some(file_cache_ttl) = file_cache_ttl {
+                cloud_options.file_cache_ttl = file_cache_ttl;
+            }
+            args.cloud_options = Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(PlCredentialProvider::from_python_builder),
+                    ),
+            );
+        }
+
+        let lf = LazyFrame::scan_orc_sources(sources, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
+    #[cfg(feature = "fixed_width")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        source, sources, columns, span_unit, overwrite_dtype, skip_rows, n_rows,
+        infer_schema_length, null_values, missing_utf8_is_empty_string, ignore_errors,
+        try_parse_dates, decimal_comma, encoding, eol_char, cache, rechunk, row_index, glob,
+        cloud_options, credential_provider, retries, file_cache_ttl, include_file_paths
+    ))]
+    fn new_from_fixed_width(
+        source: Option<PyObject>,
+        sources: Wrap<ScanSources>,
+        columns: Vec<(PyBackedStr, usize, usize)>,
+        span_unit: Wrap<FixedWidthSpanUnit>,
+        overwrite_dtype: Option<Vec<(PyBackedStr, Wrap<DataType>)>>,
+        skip_rows: usize,
+        n_rows: Option<usize>,
+        infer_schema_length: Option<usize>,
+        null_values: Option<Wrap<NullValues>>,
+        missing_utf8_is_empty_string: bool,
+        ignore_errors: bool,
+        try_parse_dates: bool,
+        decimal_comma: bool,
+        encoding: Wrap<CsvEncoding>,
+        eol_char: &str,
+        cache: bool,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        glob: bool,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        retries: usize,
+        file_cache_ttl: Option<u64>,
+        include_file_paths: Option<String>,
+    ) -> PyResult<Self> {
+        #[cfg(feature = "cloud")]
+        use cloud::credential_provider::PlCredentialProvider;
+
+        let eol_char = eol_char
+            .as_bytes()
+            .first()
+            .ok_or_else(|| polars_err!(InvalidOperation: "`eol_char` cannot be empty"))
+            .copied()
+            .map_err(PyPolarsErr::from)?;
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+
+        let columns = columns
+            .into_iter()
+            .map(|(name, start, end)| FixedWidthColumn::new((&*name).into(), start, end))
+            .collect();
+        let overwrite_dtype = overwrite_dtype.map(|overwrite_dtype| {
+            overwrite_dtype
+                .into_iter()
+                .map(|(name, dtype)| Field::new((&*name).into(), dtype.0))
+                .collect::<Schema>()
+        });
+
+        let options = FixedWidthReadOptions::default()
+            .with_columns(columns)
+            .with_span_unit(span_unit.0)
+            .with_schema_overwrite(overwrite_dtype.map(Arc::new))
+            .with_skip_rows(skip_rows)
+            .with_infer_schema_length(infer_schema_length)
+            .with_null_values(null_values.map(|w| w.0))
+            .with_missing_is_null(!missing_utf8_is_empty_string)
+            .with_ignore_errors(ignore_errors)
+            .with_try_parse_dates(try_parse_dates)
+            .with_decimal_comma(decimal_comma)
+            .with_encoding(encoding.0)
+            .with_eol_char(eol_char);
+
+        let mut args = ScanArgsFixedWidth {
+            options,
+            n_rows,
+            cache,
+            rechunk,
+            glob,
+            row_index,
+            cloud_options: None,
+            include_file_paths: include_file_paths.map(|x| x.into()),
+        };
+
+        let sources = sources.0;
+        let (first_path, sources) = match source {
+            None => (sources.first_path().map(|p| p.into_owned()), sources),
+            Some(source) => pyobject_to_first_path_and_scan_sources(source)?,
+        };
+
+        #[cfg(feature = "cloud")]
+        if let Some(first_path) = first_path {
+            let first_path_url = first_path.to_str();
+
+            let mut cloud_options =
+                parse_cloud_options(first_path_url, cloud_options.unwrap_or_default())?;
+            if let Some(file_cache_ttl) = file_cache_ttl {
+                cloud_options.file_cache_ttl = file_cache_ttl;
+            }
+            args.cloud_options = Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(PlCredentialProvider::from_python_builder),
+                    ),
+            );
+        }
+
+        let lf = LazyFrame::scan_fixed_width_sources(sources, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
     #[staticmethod]
     #[pyo3(signature = (
         dataset_object
@@ -705,7 +971,8 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[pyo3(signature = (
         target, compression, compression_level, statistics, row_group_size, data_page_size,
-        cloud_options, credential_provider, retries, sink_options, metadata, field_overwrites,
+        bloom_filter, cloud_options, credential_provider, retries, sink_options, metadata,
+        field_overwrites,
     ))]
     fn sink_parquet(
         &self,
@@ -716,6 +983,7 @@ impl PyLazyFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        bloom_filter: Option<(f64, Option<u64>)>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
@@ -723,11 +991,14 @@ impl PyLazyFrame {
         metadata: Wrap<Option<KeyValueMetadata>>,
         field_overwrites: Vec<Wrap<ParquetFieldOverwrites>>,
     ) -> PyResult<PyLazyFrame> {
+        use polars_parquet::write::BloomFilterOptions;
+
         let compression = parse_parquet_compression(compression, compression_level)?;
 
         let options = ParquetWriteOptions {
             compression,
             statistics: statistics.0,
+            bloom_filter: bloom_filter.map(|(fpp, ndv)| BloomFilterOptions { fpp, ndv }),
             row_group_size,
             data_page_size,
             key_value_metadata: metadata.0,
@@ -839,7 +1110,7 @@ impl PyLazyFrame {
     #[pyo3(signature = (
         target, include_bom, include_header, separator, line_terminator, quote_char, batch_size,
         datetime_format, date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style, cloud_options, credential_provider, retries, sink_options
+        quote_style, compression, compression_level, cloud_options, credential_provider, retries, sink_options
     ))]
     fn sink_csv(
         &self,
@@ -859,6 +1130,8 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
@@ -886,6 +1159,7 @@ impl PyLazyFrame {
             include_header,
             batch_size,
             serialize_options,
+            compression: parse_external_compression(compression, compression_level)?,
         };
 
         #[cfg(feature = "cloud")]
@@ -931,17 +1205,24 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "json")]
-    #[pyo3(signature = (target, cloud_options, credential_provider, retries, sink_options))]
+    #[pyo3(signature = (
+        target, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
+    ))]
     fn sink_json(
         &self,
         py: Python<'_>,
         target: SinkTarget,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions {
+            compression: parse_external_compression(compression, compression_level)?,
+        };
 
         let cloud_options = match target.base_path() {
             None => None,
@@ -980,6 +1261,67 @@ impl PyLazyFrame {
         .map_err(Into::into)
     }
 
+    #[cfg(feature = "avro")]
+    #[pyo3(signature = (
+        target, compression, name, cloud_options, credential_provider, retries, sink_options
+    ))]
+    fn sink_avro(
+        &self,
+        py: Python<'_>,
+        target: SinkTarget,
+        compression: Wrap<Option<AvroCompression>>,
+        name: String,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        retries: usize,
+        sink_options: Wrap<SinkOptions>,
+    ) -> PyResult<PyLazyFrame> {
+        let options = AvroWriterOptions {
+            compression: compression.0.map(Into::into),
+            name: name.into(),
+        };
+
+        #[cfg(feature = "cloud")]
+        let cloud_options = match target.base_path() {
+            None => None,
+            Some(base_path) => {
+                let cloud_options =
+                    parse_cloud_options(base_path.to_str(), cloud_options.unwrap_or_default())?;
+                Some(
+                    cloud_options
+                        .with_max_retries(retries)
+                        .with_credential_provider(
+                            credential_provider.map(polars::prelude::cloud::credential_provider::PlCredentialProvider::from_python_builder),
+                        ),
+                )
+            },
+        };
+
+        #[cfg(not(feature = "cloud"))]
+        let cloud_options = None;
+
+        py.enter_polars(|| {
+            let ldf = self.ldf.clone();
+            match target {
+                SinkTarget::File(target) => {
+                    ldf.sink_avro(target, options, cloud_options, sink_options.0)
+                },
+                SinkTarget::Partition(partition) => ldf.sink_avro_partitioned(
+                    Arc::new(partition.base_path.0),
+                    partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.variant,
+                    options,
+                    cloud_options,
+                    sink_options.0,
+                    partition.per_partition_sort_by,
+                    partition.finish_callback,
+                ),
+            }
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
     fn filter(&mut self, predicate: PyExpr) -> Self {
         let ldf = self.ldf.clone();
         ldf.filter(predicate.inner).into()
@@ -1555,6 +1897,7 @@ impl PyLazyFrame {
 impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOverwrites> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         use polars_io::parquet::write::ParquetFieldOverwrites;
+        use polars_parquet::write::BloomFilterOptions;
 
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
@@ -1596,12 +1939,18 @@ impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOver
             .map(|v| v.extract::<bool>())
             .transpose()?;
 
+        let bloom_filter = PyDictMethods::get_item(&parsed, "bloom_filter")?
+            .map(|v| v.extract::<(f64, Option<u64>)>())
+            .transpose()?
+            .map(|(fpp, ndv)| BloomFilterOptions { fpp, ndv });
+
         Ok(Wrap(ParquetFieldOverwrites {
             name,
             children,
             field_id,
             metadata,
             required,
+            bloom_filter,
         }))
     }
 }
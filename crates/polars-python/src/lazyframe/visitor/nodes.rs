@@ -41,6 +41,12 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro { .. } => Err(PyNotImplementedError::new_err("avro scan")),
+        #[cfg(feature = "orc")]
+        FileScanIR::Orc { .. } => Err(PyNotImplementedError::new_err("orc scan")),
+        #[cfg(feature = "fixed_width")]
+        FileScanIR::FixedWidth { .. } => Err(PyNotImplementedError::new_err("fixed_width scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
@@ -710,6 +716,7 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
             key: key.to_string(),
         }
         .into_py_any(py),
+        IR::RecursiveUnion { .. } => Err(PyNotImplementedError::new_err("recursive union")),
         IR::Invalid => Err(PyNotImplementedError::new_err("Invalid")),
     }
 }
@@ -53,12 +53,12 @@ impl PySeries {
     fn mean<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         match self.series.dtype() {
             Boolean => scalar_to_py(
-                py.enter_polars_ok(|| self.series.cast(&DataType::UInt8).unwrap().mean_reduce()),
+                py.enter_polars(|| self.series.cast(&DataType::UInt8).unwrap().mean_reduce()),
                 py,
             ),
             // For non-numeric output types we require mean_reduce.
-            dt if dt.is_temporal() => {
-                scalar_to_py(py.enter_polars_ok(|| self.series.mean_reduce()), py)
+            dt if dt.is_temporal() || dt.is_decimal() => {
+                scalar_to_py(py.enter_polars(|| self.series.mean_reduce()), py)
             },
             _ => Ok(self.series.mean().into_pyobject(py)?),
         }
@@ -71,7 +71,7 @@ impl PySeries {
                 py,
             ),
             // For non-numeric output types we require median_reduce.
-            dt if dt.is_temporal() => {
+            dt if dt.is_temporal() || dt.is_decimal() => {
                 scalar_to_py(py.enter_polars(|| self.series.median_reduce()), py)
             },
             _ => Ok(self.series.median().into_pyobject(py)?),
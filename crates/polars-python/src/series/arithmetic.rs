@@ -38,6 +38,7 @@ impl_arithmetic!(add_u8, u8, +);
 impl_arithmetic!(add_u16, u16, +);
 impl_arithmetic!(add_u32, u32, +);
 impl_arithmetic!(add_u64, u64, +);
+impl_arithmetic!(add_u128, u128, +);
 impl_arithmetic!(add_i8, i8, +);
 impl_arithmetic!(add_i16, i16, +);
 impl_arithmetic!(add_i32, i32, +);
@@ -50,6 +51,7 @@ impl_arithmetic!(sub_u8, u8, -);
 impl_arithmetic!(sub_u16, u16, -);
 impl_arithmetic!(sub_u32, u32, -);
 impl_arithmetic!(sub_u64, u64, -);
+impl_arithmetic!(sub_u128, u128, -);
 impl_arithmetic!(sub_i8, i8, -);
 impl_arithmetic!(sub_i16, i16, -);
 impl_arithmetic!(sub_i32, i32, -);
@@ -62,6 +64,7 @@ impl_arithmetic!(div_u8, u8, /);
 impl_arithmetic!(div_u16, u16, /);
 impl_arithmetic!(div_u32, u32, /);
 impl_arithmetic!(div_u64, u64, /);
+impl_arithmetic!(div_u128, u128, /);
 impl_arithmetic!(div_i8, i8, /);
 impl_arithmetic!(div_i16, i16, /);
 impl_arithmetic!(div_i32, i32, /);
@@ -72,6 +75,7 @@ impl_arithmetic!(mul_u8, u8, *);
 impl_arithmetic!(mul_u16, u16, *);
 impl_arithmetic!(mul_u32, u32, *);
 impl_arithmetic!(mul_u64, u64, *);
+impl_arithmetic!(mul_u128, u128, *);
 impl_arithmetic!(mul_i8, i8, *);
 impl_arithmetic!(mul_i16, i16, *);
 impl_arithmetic!(mul_i32, i32, *);
@@ -82,6 +86,7 @@ impl_arithmetic!(rem_u8, u8, %);
 impl_arithmetic!(rem_u16, u16, %);
 impl_arithmetic!(rem_u32, u32, %);
 impl_arithmetic!(rem_u64, u64, %);
+impl_arithmetic!(rem_u128, u128, %);
 impl_arithmetic!(rem_i8, i8, %);
 impl_arithmetic!(rem_i16, i16, %);
 impl_arithmetic!(rem_i32, i32, %);
@@ -104,6 +109,7 @@ impl_rhs_arithmetic!(add_u8_rhs, u8, add);
 impl_rhs_arithmetic!(add_u16_rhs, u16, add);
 impl_rhs_arithmetic!(add_u32_rhs, u32, add);
 impl_rhs_arithmetic!(add_u64_rhs, u64, add);
+impl_rhs_arithmetic!(add_u128_rhs, u128, add);
 impl_rhs_arithmetic!(add_i8_rhs, i8, add);
 impl_rhs_arithmetic!(add_i16_rhs, i16, add);
 impl_rhs_arithmetic!(add_i32_rhs, i32, add);
@@ -114,6 +120,7 @@ impl_rhs_arithmetic!(sub_u8_rhs, u8, sub);
 impl_rhs_arithmetic!(sub_u16_rhs, u16, sub);
 impl_rhs_arithmetic!(sub_u32_rhs, u32, sub);
 impl_rhs_arithmetic!(sub_u64_rhs, u64, sub);
+impl_rhs_arithmetic!(sub_u128_rhs, u128, sub);
 impl_rhs_arithmetic!(sub_i8_rhs, i8, sub);
 impl_rhs_arithmetic!(sub_i16_rhs, i16, sub);
 impl_rhs_arithmetic!(sub_i32_rhs, i32, sub);
@@ -124,6 +131,7 @@ impl_rhs_arithmetic!(div_u8_rhs, u8, div);
 impl_rhs_arithmetic!(div_u16_rhs, u16, div);
 impl_rhs_arithmetic!(div_u32_rhs, u32, div);
 impl_rhs_arithmetic!(div_u64_rhs, u64, div);
+impl_rhs_arithmetic!(div_u128_rhs, u128, div);
 impl_rhs_arithmetic!(div_i8_rhs, i8, div);
 impl_rhs_arithmetic!(div_i16_rhs, i16, div);
 impl_rhs_arithmetic!(div_i32_rhs, i32, div);
@@ -134,6 +142,7 @@ impl_rhs_arithmetic!(mul_u8_rhs, u8, mul);
 impl_rhs_arithmetic!(mul_u16_rhs, u16, mul);
 impl_rhs_arithmetic!(mul_u32_rhs, u32, mul);
 impl_rhs_arithmetic!(mul_u64_rhs, u64, mul);
+impl_rhs_arithmetic!(mul_u128_rhs, u128, mul);
 impl_rhs_arithmetic!(mul_i8_rhs, i8, mul);
 impl_rhs_arithmetic!(mul_i16_rhs, i16, mul);
 impl_rhs_arithmetic!(mul_i32_rhs, i32, mul);
@@ -144,6 +153,7 @@ impl_rhs_arithmetic!(rem_u8_rhs, u8, rem);
 impl_rhs_arithmetic!(rem_u16_rhs, u16, rem);
 impl_rhs_arithmetic!(rem_u32_rhs, u32, rem);
 impl_rhs_arithmetic!(rem_u64_rhs, u64, rem);
+impl_rhs_arithmetic!(rem_u128_rhs, u128, rem);
 impl_rhs_arithmetic!(rem_i8_rhs, i8, rem);
 impl_rhs_arithmetic!(rem_i16_rhs, i16, rem);
 impl_rhs_arithmetic!(rem_i32_rhs, i32, rem);
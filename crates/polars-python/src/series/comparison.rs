@@ -47,6 +47,7 @@ impl_op!(equal, eq_u8, u8);
 impl_op!(equal, eq_u16, u16);
 impl_op!(equal, eq_u32, u32);
 impl_op!(equal, eq_u64, u64);
+impl_op!(equal, eq_u128, u128);
 impl_op!(equal, eq_i8, i8);
 impl_op!(equal, eq_i16, i16);
 impl_op!(equal, eq_i32, i32);
@@ -60,6 +61,7 @@ impl_op!(not_equal, neq_u8, u8);
 impl_op!(not_equal, neq_u16, u16);
 impl_op!(not_equal, neq_u32, u32);
 impl_op!(not_equal, neq_u64, u64);
+impl_op!(not_equal, neq_u128, u128);
 impl_op!(not_equal, neq_i8, i8);
 impl_op!(not_equal, neq_i16, i16);
 impl_op!(not_equal, neq_i32, i32);
@@ -73,6 +75,7 @@ impl_op!(gt, gt_u8, u8);
 impl_op!(gt, gt_u16, u16);
 impl_op!(gt, gt_u32, u32);
 impl_op!(gt, gt_u64, u64);
+impl_op!(gt, gt_u128, u128);
 impl_op!(gt, gt_i8, i8);
 impl_op!(gt, gt_i16, i16);
 impl_op!(gt, gt_i32, i32);
@@ -86,6 +89,7 @@ impl_op!(gt_eq, gt_eq_u8, u8);
 impl_op!(gt_eq, gt_eq_u16, u16);
 impl_op!(gt_eq, gt_eq_u32, u32);
 impl_op!(gt_eq, gt_eq_u64, u64);
+impl_op!(gt_eq, gt_eq_u128, u128);
 impl_op!(gt_eq, gt_eq_i8, i8);
 impl_op!(gt_eq, gt_eq_i16, i16);
 impl_op!(gt_eq, gt_eq_i32, i32);
@@ -99,6 +103,7 @@ impl_op!(lt, lt_u8, u8);
 impl_op!(lt, lt_u16, u16);
 impl_op!(lt, lt_u32, u32);
 impl_op!(lt, lt_u64, u64);
+impl_op!(lt, lt_u128, u128);
 impl_op!(lt, lt_i8, i8);
 impl_op!(lt, lt_i16, i16);
 impl_op!(lt, lt_i32, i32);
@@ -112,6 +117,7 @@ impl_op!(lt_eq, lt_eq_u8, u8);
 impl_op!(lt_eq, lt_eq_u16, u16);
 impl_op!(lt_eq, lt_eq_u32, u32);
 impl_op!(lt_eq, lt_eq_u64, u64);
+impl_op!(lt_eq, lt_eq_u128, u128);
 impl_op!(lt_eq, lt_eq_i8, i8);
 impl_op!(lt_eq, lt_eq_i16, i16);
 impl_op!(lt_eq, lt_eq_i32, i32);
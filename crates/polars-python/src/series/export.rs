@@ -29,6 +29,11 @@ impl PySeries {
                 DataType::Int32 => PyList::new(py, series.i32().map_err(PyPolarsErr::from)?)?,
                 DataType::Int64 => PyList::new(py, series.i64().map_err(PyPolarsErr::from)?)?,
                 DataType::Int128 => PyList::new(py, series.i128().map_err(PyPolarsErr::from)?)?,
+                DataType::UInt128 => PyList::new(py, series.u128().map_err(PyPolarsErr::from)?)?,
+                DataType::Float16 => {
+                    let ca = series.float16().map_err(PyPolarsErr::from)?;
+                    PyList::new(py, &ca.to_float32())?
+                },
                 DataType::Float32 => PyList::new(py, series.f32().map_err(PyPolarsErr::from)?)?,
                 DataType::Float64 => PyList::new(py, series.f64().map_err(PyPolarsErr::from)?)?,
                 DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -61,6 +66,14 @@ impl PySeries {
                     }
                     v
                 },
+                DataType::Map(..) => {
+                    let ca = series.map().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, &ca.entries().clone().into_series());
+                },
+                DataType::Extension(_, _) => {
+                    let ca = series.extension().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, ca.storage());
+                },
                 DataType::Array(_, _) => {
                     let v = PyList::empty(py);
                     let ca = series.array().map_err(PyPolarsErr::from)?;
@@ -85,6 +98,16 @@ impl PySeries {
                     let ca = series.time().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::Interval => {
+                    let ca = series.interval().map_err(PyPolarsErr::from)?;
+                    let iter = ca.physical().iter().map(|opt_v| {
+                        opt_v.map(|v| {
+                            let v = i128_to_interval(v);
+                            (v.months(), v.days(), v.ns())
+                        })
+                    });
+                    PyList::new(py, iter)?
+                },
                 DataType::Datetime(_, _) => {
                     let ca = series.datetime().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
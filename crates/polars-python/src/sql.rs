@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use polars::sql::SQLContext;
 use pyo3::prelude::*;
 
@@ -19,11 +21,13 @@ pub struct PySQLContext {
 )]
 impl PySQLContext {
     #[staticmethod]
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> PySQLContext {
-        PySQLContext {
-            context: SQLContext::new(),
+    #[pyo3(signature = (catalog=None))]
+    pub fn new(catalog: Option<PathBuf>) -> PyResult<PySQLContext> {
+        let mut context = SQLContext::new();
+        if let Some(catalog) = catalog {
+            context = context.with_catalog(catalog).map_err(PyPolarsErr::from)?;
         }
+        Ok(PySQLContext { context })
     }
 
     pub fn execute(&mut self, query: &str) -> PyResult<PyLazyFrame> {
@@ -3,7 +3,7 @@ use arrow::bitmap::{Bitmap, BitmapBuilder};
 use arrow::buffer::Buffer;
 use arrow::datatypes::ArrowDataType;
 use arrow::offset::OffsetsBuffer;
-use arrow::types::NativeType;
+use arrow::types::{NativeType, f16};
 use polars_dtype::categorical::CatNative;
 
 use self::encode::fixed_size;
@@ -151,7 +151,7 @@ fn dtype_and_data_to_encoded_item_len(
         },
 
         D::Union(_) => todo!(),
-        D::Map(_, _) => todo!(),
+        D::Map(..) => todo!(),
         D::Decimal32(_, _) => todo!(),
         D::Decimal64(_, _) => todo!(),
         D::Decimal256(_, _) => todo!(),
@@ -361,6 +361,13 @@ unsafe fn decode(
                     };
                 }
             }
+            if matches!(dt, D::UInt16) {
+                if let Some(RowEncodingContext::Float16) = dict {
+                    return numeric::decode_primitive::<f16>(rows, opt)
+                        .transmute::<u16>()
+                        .to_boxed();
+                }
+            }
 
             with_match_arrow_primitive_type!(dt, |$T| {
                 numeric::decode_primitive::<$T>(rows, opt).to_boxed()
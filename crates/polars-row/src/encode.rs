@@ -7,7 +7,7 @@ use arrow::array::{
 };
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowDataType;
-use arrow::types::{NativeType, Offset};
+use arrow::types::{NativeType, Offset, f16};
 use polars_dtype::categorical::CatNative;
 
 use crate::fixed::numeric::FixedLengthEncoding;
@@ -517,7 +517,7 @@ fn get_encoder(
         },
 
         D::Union(_) => unreachable!(),
-        D::Map(_, _) => unreachable!(),
+        D::Map(..) => unreachable!(),
         D::Extension(_) => unreachable!(),
         D::Unknown => unreachable!(),
 
@@ -661,6 +661,13 @@ unsafe fn encode_flat_array(
                     return;
                 }
             }
+            if matches!(dt, D::UInt16) {
+                if let Some(RowEncodingContext::Float16) = dict {
+                    let array = array.as_any().downcast_ref::<UInt16Array>().unwrap();
+                    numeric::encode(buffer, &array.clone().transmute::<f16>(), opt, offsets);
+                    return;
+                }
+            }
 
             with_match_arrow_primitive_type!(dt, |$T| {
                 let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
@@ -703,7 +710,7 @@ unsafe fn encode_flat_array(
         D::Decimal256(_, _) => todo!(),
 
         D::Union(_) => todo!(),
-        D::Map(_, _) => todo!(),
+        D::Map(..) => todo!(),
         D::Extension(_) => todo!(),
         D::Unknown => todo!(),
 
@@ -947,6 +954,7 @@ pub fn fixed_size(
         D::UInt16 => u16::ENCODED_LEN,
         D::UInt32 => u32::ENCODED_LEN,
         D::UInt64 => u64::ENCODED_LEN,
+        D::UInt128 => u128::ENCODED_LEN,
 
         D::Int8 => i8::ENCODED_LEN,
         D::Int16 => i16::ENCODED_LEN,
@@ -958,6 +966,7 @@ pub fn fixed_size(
             _ => unreachable!(),
         },
 
+        D::Float16 => f16::ENCODED_LEN,
         D::Float32 => f32::ENCODED_LEN,
         D::Float64 => f64::ENCODED_LEN,
         D::FixedSizeList(f, width) => 1 + width * fixed_size(f.dtype(), opt, dict)?,
@@ -22,6 +22,8 @@ pub enum RowEncodingContext {
     Categorical(RowEncodingCategoricalContext),
     /// Decimal with given precision
     Decimal(usize),
+    /// Half precision float stored as the bits in an `UInt16` array
+    Float16,
 }
 
 #[derive(Debug, Clone)]
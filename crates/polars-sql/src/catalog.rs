@@ -0,0 +1,146 @@
+//! On-disk catalog of the tables and views of a [`SQLContext`](crate::SQLContext), allowing
+//! them to be shared across sessions.
+//!
+//! A catalog is a directory holding the (versioned) serialized DSL plan of every table in
+//! `tables/<name>.plan`, and the `CREATE VIEW` statement of every view in `views/<name>.sql`.
+//! The plan of a table that is read from files only refers to these files, so the catalog
+//! stores the definition of such a table rather than its data.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+const TABLES_DIR: &str = "tables";
+const VIEWS_DIR: &str = "views";
+const TABLE_EXTENSION: &str = "plan";
+const VIEW_EXTENSION: &str = "sql";
+
+#[derive(Clone, Debug)]
+pub(crate) struct Catalog {
+    path: PathBuf,
+}
+
+impl Catalog {
+    /// Open the catalog in the given directory, creating it if it doesn't exist yet.
+    pub(crate) fn open(path: &Path) -> PolarsResult<Self> {
+        fs::create_dir_all(path.join(TABLES_DIR))?;
+        fs::create_dir_all(path.join(VIEWS_DIR))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Read all tables stored in the catalog.
+    pub(crate) fn read_tables(&self) -> PolarsResult<Vec<(String, LazyFrame)>> {
+        self.entries(TABLES_DIR, TABLE_EXTENSION)?
+            .into_iter()
+            .map(|(name, path)| Ok((name, deserialize_plan(&path)?)))
+            .collect()
+    }
+
+    /// Read the definitions of all views stored in the catalog.
+    pub(crate) fn read_views(&self) -> PolarsResult<Vec<(String, String)>> {
+        self.entries(VIEWS_DIR, VIEW_EXTENSION)?
+            .into_iter()
+            .map(|(name, path)| Ok((name, fs::read_to_string(path)?)))
+            .collect()
+    }
+
+    pub(crate) fn contains_table(&self, name: &str) -> bool {
+        self.table_path(name).is_ok_and(|path| path.is_file())
+    }
+
+    pub(crate) fn write_table(&self, name: &str, lf: &LazyFrame) -> PolarsResult<()> {
+        let path = self.table_path(name)?;
+        let mut buf = Vec::new();
+        serialize_plan(lf, &mut buf)?;
+        write_atomic(&path, &buf)
+    }
+
+    pub(crate) fn write_view(&self, name: &str, definition: &str) -> PolarsResult<()> {
+        let path = self.view_path(name)?;
+        write_atomic(&path, definition.as_bytes())
+    }
+
+    pub(crate) fn remove_table(&self, name: &str) -> PolarsResult<()> {
+        remove_if_exists(&self.table_path(name)?)
+    }
+
+    pub(crate) fn remove_view(&self, name: &str) -> PolarsResult<()> {
+        remove_if_exists(&self.view_path(name)?)
+    }
+
+    fn table_path(&self, name: &str) -> PolarsResult<PathBuf> {
+        Ok(self
+            .path
+            .join(TABLES_DIR)
+            .join(format!("{}.{TABLE_EXTENSION}", valid_name(name)?)))
+    }
+
+    fn view_path(&self, name: &str) -> PolarsResult<PathBuf> {
+        Ok(self
+            .path
+            .join(VIEWS_DIR)
+            .join(format!("{}.{VIEW_EXTENSION}", valid_name(name)?)))
+    }
+
+    /// The names and paths of the files with the given extension in a catalog directory.
+    fn entries(&self, dir: &str, extension: &str) -> PolarsResult<Vec<(String, PathBuf)>> {
+        let mut entries = fs::read_dir(self.path.join(dir))?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<PolarsResult<Vec<_>>>()?
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+            .filter_map(|path| Some((path.file_stem()?.to_str()?.to_string(), path)))
+            .collect::<Vec<_>>();
+        entries.sort_unstable();
+        Ok(entries)
+    }
+}
+
+/// Table and view names are used as file names, so they can't refer to other directories.
+fn valid_name(name: &str) -> PolarsResult<&str> {
+    polars_ensure!(
+        !name.is_empty() && !name.starts_with('.') && !name.contains(['/', '\\']),
+        SQLInterface: "'{}' is not a valid name for a catalog table or view", name
+    );
+    Ok(name)
+}
+
+/// Write to a temporary file first, so that other sessions never read a partial file.
+fn write_atomic(path: &Path, contents: &[u8]) -> PolarsResult<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+fn remove_if_exists(path: &Path) -> PolarsResult<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_plan(lf: &LazyFrame, writer: &mut Vec<u8>) -> PolarsResult<()> {
+    lf.logical_plan
+        .serialize_versioned(writer, Default::default())
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_plan(path: &Path) -> PolarsResult<LazyFrame> {
+    let reader = std::io::BufReader::new(fs::File::open(path)?);
+    Ok(polars_plan::dsl::DslPlan::deserialize_versioned(reader)?.into())
+}
+
+#[cfg(not(feature = "serde"))]
+fn serialize_plan(_lf: &LazyFrame, _writer: &mut Vec<u8>) -> PolarsResult<()> {
+    polars_bail!(SQLInterface: "storing tables in a catalog requires the 'serde' feature")
+}
+
+#[cfg(not(feature = "serde"))]
+fn deserialize_plan(_path: &Path) -> PolarsResult<LazyFrame> {
+    polars_bail!(SQLInterface: "reading tables from a catalog requires the 'serde' feature")
+}
@@ -1,28 +1,45 @@
 use std::cell::RefCell;
 use std::ops::Deref;
+use std::path::Path;
 
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
-use polars_ops::frame::JoinCoalesce;
+use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    AlterTableOperation, AssignmentTarget, BinaryOperator, ColumnOption, CreateTable, Cte, Delete,
+    Distinct, ExcludeSelectItem, Expr as SQLExpr, ExprWithAlias, FromTable, FunctionArg,
+    GroupByExpr, GroupByWithModifier, Ident, Insert, JoinConstraint, JoinOperator, MergeAction,
+    MergeClause, MergeClauseKind, MergeInsertExpr, MergeInsertKind, ObjectName, ObjectType, Offset,
+    OrderBy, PivotValueSource, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator,
+    SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
+use crate::catalog::Catalog;
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
+use crate::types::map_sql_dtype_to_polars;
+
+/// Prefix of the (internal) columns flagging whether a group key is part of the grouping set
+/// of a row, as returned by the GROUPING function.
+pub(crate) const GROUPING_FLAG_PREFIX: &str = "__POLARS_GROUPING_FLAG_";
+const GROUPING_ID_NAME: &str = "__POLARS_GROUPING_ID";
+/// Flags marking the rows coming from the target and source tables of a MERGE statement.
+const MERGE_TARGET_FLAG: &str = "__POLARS_MERGE_TARGET";
+const MERGE_SOURCE_FLAG: &str = "__POLARS_MERGE_SOURCE";
+/// Row index of the MERGE target, used to detect rows matched by several source rows.
+const MERGE_TARGET_ROW_IDX: &str = "__POLARS_MERGE_TARGET_IDX";
+/// Maximum number of times the recursive term of a recursive CTE is evaluated.
+const MAX_RECURSIVE_CTE_ITERATIONS: usize = 10_000;
 
 #[derive(Clone)]
 pub struct TableInfo {
@@ -63,6 +80,62 @@ pub struct SQLContext {
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+
+    view_map: PlHashMap<String, View>,
+    catalog: Option<Catalog>,
+    // the views whose query is being planned, to detect views referring to themselves
+    expanding_views: Vec<String>,
+}
+
+/// A view, whose query is planned again every time the view is referred to.
+#[derive(Clone)]
+struct View {
+    query: Query,
+    columns: Vec<Ident>,
+}
+
+impl View {
+    /// Parse the `CREATE VIEW` statement defining a view.
+    fn parse(definition: &str) -> PolarsResult<(String, Self)> {
+        let ast = Parser::new(&GenericDialect)
+            .try_with_sql(definition)
+            .map_err(to_sql_interface_err)?
+            .parse_statements()
+            .map_err(to_sql_interface_err)?;
+        match ast.as_slice() {
+            [
+                Statement::CreateView {
+                    name,
+                    columns,
+                    query,
+                    ..
+                },
+            ] => Ok((
+                name.0.first().unwrap().value.clone(),
+                Self {
+                    query: (**query).clone(),
+                    columns: columns.iter().map(|c| c.name.clone()).collect(),
+                },
+            )),
+            _ => polars_bail!(SQLInterface: "invalid view definition: {}", definition),
+        }
+    }
+
+    /// The `CREATE VIEW` statement defining the view.
+    fn definition(&self, name: &str) -> String {
+        let name = Ident::with_quote('"', name);
+        if self.columns.is_empty() {
+            format!("CREATE VIEW {} AS {}", name, self.query)
+        } else {
+            let columns: Vec<String> = self.columns.iter().map(|c| c.to_string()).collect();
+            format!(
+                "CREATE VIEW {} ({}) AS {}",
+                name,
+                columns.join(", "),
+                self.query
+            )
+        }
+    }
 }
 
 impl Default for SQLContext {
@@ -75,6 +148,9 @@ impl Default for SQLContext {
             joined_aliases: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            view_map: Default::default(),
+            catalog: None,
+            expanding_views: Default::default(),
         }
     }
 }
@@ -91,9 +167,35 @@ impl SQLContext {
         Self::default()
     }
 
-    /// Get the names of all registered tables, in sorted order.
+    /// Attach the on-disk catalog stored in the given directory, creating it if needed.
+    ///
+    /// The tables and views stored in the catalog are registered, and the tables and views
+    /// created with `CREATE TABLE` and `CREATE VIEW` (unless `TEMPORARY`) are stored in it,
+    /// so that they can be used by other sessions. Storing tables requires the `serde` feature.
+    /// ```rust,no_run
+    /// # use polars_sql::SQLContext;
+    /// # fn main() -> polars_core::error::PolarsResult<()> {
+    /// let mut ctx = SQLContext::new().with_catalog("/path/to/catalog")?;
+    /// ctx.execute("CREATE VIEW recent AS SELECT * FROM events WHERE year >= 2024")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_catalog(mut self, path: impl AsRef<Path>) -> PolarsResult<Self> {
+        let catalog = Catalog::open(path.as_ref())?;
+        for (name, lf) in catalog.read_tables()? {
+            self.table_map.insert(name, lf);
+        }
+        for (name, definition) in catalog.read_views()? {
+            let (_, view) = View::parse(&definition)?;
+            self.view_map.insert(name, view);
+        }
+        self.catalog = Some(catalog);
+        Ok(self)
+    }
+
+    /// Get the names of all registered tables and views, in sorted order.
     pub fn get_tables(&self) -> Vec<String> {
-        let mut tables = Vec::from_iter(self.table_map.keys().cloned());
+        let mut tables = Vec::from_iter(self.table_map.keys().chain(self.view_map.keys()).cloned());
         tables.sort_unstable();
         tables
     }
@@ -117,9 +219,12 @@ impl SQLContext {
         self.table_map.insert(name.to_owned(), lf);
     }
 
-    /// Unregister a [`LazyFrame`] table from the [`SQLContext`].
+    /// Unregister a [`LazyFrame`] table (or view) from the [`SQLContext`].
+    ///
+    /// Tables and views stored in an attached catalog are not removed from the catalog.
     pub fn unregister(&mut self, name: &str) {
         self.table_map.remove(&name.to_owned());
+        self.view_map.remove(name);
     }
 
     /// Execute a SQL query, returning a [`LazyFrame`].
@@ -195,13 +300,23 @@ impl SQLContext {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
+            stmt @ Statement::CreateView { .. } => self.execute_create_view(stmt)?,
+            stmt @ Statement::AlterTable { .. } => self.execute_alter_table(stmt)?,
+            stmt @ Statement::ExplainTable { .. } => self.execute_describe_table(stmt)?,
             stmt @ Statement::Drop {
                 object_type: ObjectType::Table,
                 ..
             } => self.execute_drop_table(stmt)?,
+            stmt @ Statement::Drop {
+                object_type: ObjectType::View,
+                ..
+            } => self.execute_drop_view(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert_into_table(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update_table(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge_into_table(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -227,13 +342,74 @@ impl SQLContext {
         table
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.table_aliases.borrow().get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .or_else(|| self.cte_map.borrow().get(alias))
+                        .cloned()
+                })
             })
     }
 
+    /// Get a table or view in the current scope; the query of a view is planned on demand.
+    fn get_relation(&mut self, name: &str) -> PolarsResult<Option<LazyFrame>> {
+        if let Some(lf) = self.get_table_from_current_scope(name) {
+            return Ok(Some(lf));
+        }
+        let Some(view) = self.view_map.get(name).cloned() else {
+            return Ok(None);
+        };
+        let lf = self.execute_view(name, &view)?;
+        // further references to the view in the statement (eg: `view.column`) use this plan
+        self.cte_map
+            .borrow_mut()
+            .insert(name.to_string(), lf.clone());
+        Ok(Some(lf))
+    }
+
+    /// Plan the query of a view, independently of the statement referring to the view.
+    fn execute_view(&mut self, name: &str, view: &View) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            !self.expanding_views.iter().any(|v| v == name),
+            SQLInterface: "view '{}' refers to itself", name
+        );
+        let cte_map = self.cte_map.take();
+        let table_aliases = self.table_aliases.take();
+        let joined_aliases = self.joined_aliases.take();
+        self.expanding_views.push(name.to_string());
+
+        let lf = self.execute_query(&view.query).and_then(|mut lf| {
+            if view.columns.is_empty() {
+                return Ok(lf);
+            }
+            let schema = self.get_frame_schema(&mut lf)?;
+            polars_ensure!(
+                view.columns.len() == schema.len(),
+                SQLSyntax: "number of columns ({}) of view '{}' does not match the number of columns in its query ({})",
+                view.columns.len(), name, schema.len()
+            );
+            let new_columns: Vec<&str> = view.columns.iter().map(|c| c.value.as_str()).collect();
+            Ok(lf.rename(schema.iter_names(), new_columns, true))
+        });
+
+        self.expanding_views.pop();
+        self.cte_map.replace(cte_map);
+        self.table_aliases.replace(table_aliases);
+        self.joined_aliases.replace(joined_aliases);
+        lf
+    }
+
+    /// Replace the plan of a registered table, also in the catalog if it is stored there.
+    fn replace_table(&mut self, name: String, lf: LazyFrame) -> PolarsResult<()> {
+        if let Some(catalog) = &self.catalog {
+            if catalog.contains_table(&name) {
+                catalog.write_table(&name, &lf)?;
+            }
+        }
+        self.table_map.insert(name, lf);
+        Ok(())
+    }
+
     fn expr_or_ordinal(
         &mut self,
         e: &SQLExpr,
@@ -487,15 +663,68 @@ impl SQLContext {
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
             Statement::Drop { names, .. } => {
-                names.iter().for_each(|name| {
-                    self.table_map.remove(&name.to_string());
-                });
+                for name in names {
+                    let name = name.to_string();
+                    if let Some(catalog) = &self.catalog {
+                        catalog.remove_table(&name)?;
+                    }
+                    self.table_map.remove(&name);
+                }
                 Ok(DataFrame::empty().lazy())
             },
             _ => polars_bail!(SQLInterface: "unexpected statement type; expected DROP"),
         }
     }
 
+    // DROP VIEW [IF EXISTS] <view>
+    fn execute_drop_view(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        match stmt {
+            Statement::Drop {
+                names, if_exists, ..
+            } => {
+                for name in names {
+                    let name = name.0.first().unwrap().value.as_str();
+                    if self.view_map.remove(name).is_none() && !*if_exists {
+                        polars_bail!(SQLInterface: "view '{}' does not exist", name);
+                    }
+                    if let Some(catalog) = &self.catalog {
+                        catalog.remove_view(name)?;
+                    }
+                }
+                Ok(DataFrame::empty().lazy())
+            },
+            _ => polars_bail!(SQLInterface: "unexpected statement type; expected DROP"),
+        }
+    }
+
+    // DESCRIBE <tbl>
+    fn execute_describe_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        match stmt {
+            Statement::ExplainTable { table_name, .. } => {
+                let tbl_name = table_name.0.first().unwrap().value.as_str();
+                let Some(mut lf) = self.get_relation(tbl_name)? else {
+                    polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
+                };
+                let schema = self.get_frame_schema(&mut lf)?;
+                let df = DataFrame::new(vec![
+                    Column::new(
+                        PlSmallStr::from_static("column_name"),
+                        schema.iter_names().cloned().collect::<Vec<_>>(),
+                    ),
+                    Column::new(
+                        PlSmallStr::from_static("data_type"),
+                        schema
+                            .iter_values()
+                            .map(|dtype| dtype.to_string())
+                            .collect::<Vec<_>>(),
+                    ),
+                ])?;
+                Ok(df.lazy())
+            },
+            _ => polars_bail!(SQLInterface: "unexpected statement type; expected DESCRIBE"),
+        }
+    }
+
     // DELETE FROM <tbl> [WHERE ...]
     fn execute_delete_from_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::Delete(Delete {
@@ -553,6 +782,354 @@ impl SQLContext {
         }
     }
 
+    // INSERT INTO <tbl> [(<col>, ...)] {SELECT ... | VALUES ...}
+    fn execute_insert_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            on,
+            returning,
+            ..
+        }) = stmt
+        {
+            if on.is_some() || returning.is_some() || partitioned.is_some() {
+                let error_message = match () {
+                    _ if on.is_some() => "INSERT does not support the ON CONFLICT clause",
+                    _ if returning.is_some() => "INSERT does not support the RETURNING clause",
+                    _ if partitioned.is_some() => "INSERT does not support the PARTITION clause",
+                    _ => unreachable!(),
+                };
+                polars_bail!(SQLInterface: error_message);
+            }
+            let Some(source) = source else {
+                polars_bail!(SQLInterface: "INSERT expects a SELECT or VALUES clause")
+            };
+            let tbl_name = table_name.0.first().unwrap().value.clone();
+            let Some(mut lf) = self.table_map.get(&tbl_name).cloned() else {
+                polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+            };
+            let schema = self.get_frame_schema(&mut lf)?;
+            let mut rows = self.execute_query(source)?;
+            let rows_schema = self.get_frame_schema(&mut rows)?;
+
+            // the columns that are given a value, in the order of the source columns
+            let target_columns: Vec<&str> = if columns.is_empty() {
+                schema.iter_names().map(|name| name.as_str()).collect()
+            } else {
+                columns.iter().map(|c| c.value.as_str()).collect()
+            };
+            if let Some(name) = target_columns.iter().find(|name| !schema.contains(name)) {
+                polars_bail!(SQLInterface: "column '{}' does not exist in table '{}'", name, tbl_name)
+            }
+            if target_columns.len() != rows_schema.len() {
+                polars_bail!(
+                    SQLSyntax: "INSERT expects {} values per row; found {}",
+                    target_columns.len(), rows_schema.len()
+                )
+            }
+
+            // align the new rows with the table; columns without a value are null
+            let projection = schema
+                .iter()
+                .map(|(name, dtype)| {
+                    match target_columns.iter().position(|c| c == name) {
+                        Some(idx) => {
+                            let (source_name, _) = rows_schema.get_at_index(idx).unwrap();
+                            col(source_name.clone()).strict_cast(dtype.clone())
+                        },
+                        None => lit(Null {}).cast(dtype.clone()),
+                    }
+                    .alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            let rows = rows.select(projection);
+
+            // INSERT OVERWRITE replaces the existing rows
+            let lf = if *overwrite {
+                rows
+            } else {
+                concat([lf, rows], UnionArgs::default())?
+            };
+            self.replace_table(tbl_name, lf.clone())?;
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        }
+    }
+
+    // UPDATE <tbl> SET <col> = <expr>[, ...] [WHERE ...]
+    fn execute_update_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+            ..
+        } = stmt
+        {
+            if !table.joins.is_empty() || from.is_some() || returning.is_some() {
+                let error_message = match () {
+                    _ if !table.joins.is_empty() => "UPDATE does not support table JOINs",
+                    _ if from.is_some() => "UPDATE does not support the FROM clause",
+                    _ if returning.is_some() => "UPDATE does not support the RETURNING clause",
+                    _ => unreachable!(),
+                };
+                polars_bail!(SQLInterface: error_message);
+            }
+            let tbl_name = self.get_registered_table_name(&table.relation, "UPDATE")?;
+            let (_, mut lf) = self.get_table(&table.relation)?;
+            let schema = self.get_frame_schema(&mut lf)?;
+
+            // rows not matching the WHERE clause (or where it is null) keep their values
+            let condition = selection
+                .as_ref()
+                .map(|expr| parse_sql_expr(expr, self, Some(schema.as_ref())))
+                .transpose()?;
+            let mut exprs = Vec::with_capacity(assignments.len());
+            for assignment in assignments {
+                let (name, dtype) = resolve_assignment_target(&assignment.target, &schema)?;
+                let value = parse_sql_expr(&assignment.value, self, Some(schema.as_ref()))?
+                    .strict_cast(dtype.clone());
+                let value = match &condition {
+                    Some(condition) => when(condition.clone())
+                        .then(value)
+                        .otherwise(col(name.clone())),
+                    None => value,
+                };
+                exprs.push(value.alias(name.clone()));
+            }
+            let lf = lf.with_columns(exprs);
+            self.replace_table(tbl_name, lf.clone())?;
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected UPDATE")
+        }
+    }
+
+    // MERGE INTO <tbl> USING <source> ON <constraint>
+    // WHEN [NOT] MATCHED [AND ...] THEN {UPDATE SET ... | DELETE | INSERT ...} [...]
+    fn execute_merge_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        {
+            let tbl_name = self.get_registered_table_name(table, "MERGE")?;
+            let (t_name, mut target) = self.get_table(table)?;
+            let (s_name, mut source) = self.get_table(source)?;
+            let target_schema = self.get_frame_schema(&mut target)?;
+            let source_schema = self.get_frame_schema(&mut source)?;
+
+            // full join the source onto the table, flagging which side(s) each row came from
+            let (left_on, right_on) = process_join_constraint(
+                &JoinConstraint::On(*on.clone()),
+                &TableInfo {
+                    frame: target.clone(),
+                    name: (&t_name).into(),
+                    schema: target_schema.clone(),
+                },
+                &TableInfo {
+                    frame: source.clone(),
+                    name: (&s_name).into(),
+                    schema: source_schema.clone(),
+                },
+            )?;
+            let mut joined = target
+                .with_row_index(MERGE_TARGET_ROW_IDX, None)
+                .with_column(lit(true).alias(MERGE_TARGET_FLAG))
+                .join_builder()
+                .with(source.with_column(lit(true).alias(MERGE_SOURCE_FLAG)))
+                .left_on(left_on)
+                .right_on(right_on)
+                .how(JoinType::Full)
+                .suffix(format!(":{s_name}"))
+                .coalesce(JoinCoalesce::KeepColumns)
+                .maintain_order(MaintainOrderJoin::LeftRight)
+                .finish();
+            let joined_schema = self.get_frame_schema(&mut joined)?;
+            self.register_joined_aliases(&s_name, &target_schema, &source_schema, &joined_schema);
+
+            // the source aliases only apply within this statement
+            let res =
+                self.apply_merge_clauses(tbl_name, joined, &target_schema, &joined_schema, clauses);
+            self.joined_aliases.borrow_mut().remove(&s_name);
+            res
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected MERGE")
+        }
+    }
+
+    /// Apply the WHEN clauses of a MERGE statement to the full join of its target and source.
+    fn apply_merge_clauses(
+        &mut self,
+        tbl_name: String,
+        joined: LazyFrame,
+        target_schema: &SchemaRef,
+        joined_schema: &SchemaRef,
+        clauses: &[MergeClause],
+    ) -> PolarsResult<LazyFrame> {
+        let in_target = col(MERGE_TARGET_FLAG).is_not_null();
+        let in_source = col(MERGE_SOURCE_FLAG).is_not_null();
+        let mut updated = target_schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        let mut inserted = target_schema
+            .iter_values()
+            .map(|dtype| lit(Null {}).cast(dtype.clone()))
+            .collect::<Vec<_>>();
+        let mut is_deleted = lit(false);
+        let mut is_inserted = lit(false);
+
+        // each row is handled by the first clause that applies to it
+        let mut is_handled = lit(false);
+        for clause in clauses {
+            let is_not_matched = matches!(
+                clause.clause_kind,
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget
+            );
+            let mut condition = match clause.clause_kind {
+                MergeClauseKind::Matched => in_target.clone().and(in_source.clone()),
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    in_target.clone().not()
+                },
+                MergeClauseKind::NotMatchedBySource => in_source.clone().not(),
+            };
+            if let Some(predicate) = &clause.predicate {
+                let predicate = parse_sql_expr(predicate, self, Some(joined_schema.as_ref()))?;
+                condition = condition.and(predicate.fill_null(lit(false)));
+            }
+            let condition = condition.and(is_handled.clone().not());
+            is_handled = is_handled.or(condition.clone());
+
+            match &clause.action {
+                MergeAction::Update { assignments } if !is_not_matched => {
+                    for assignment in assignments {
+                        let (name, dtype) =
+                            resolve_assignment_target(&assignment.target, target_schema)?;
+                        let idx = target_schema.index_of(name).unwrap();
+                        let value =
+                            parse_sql_expr(&assignment.value, self, Some(joined_schema.as_ref()))?
+                                .strict_cast(dtype.clone());
+                        updated[idx] = when(condition.clone())
+                            .then(value)
+                            .otherwise(updated[idx].clone());
+                    }
+                },
+                MergeAction::Delete if !is_not_matched => {
+                    is_deleted = is_deleted.or(condition);
+                },
+                MergeAction::Insert(MergeInsertExpr { columns, kind }) if is_not_matched => {
+                    let MergeInsertKind::Values(Values { rows, .. }) = kind else {
+                        polars_bail!(SQLInterface: "MERGE does not support INSERT ROW")
+                    };
+                    polars_ensure!(
+                        rows.len() == 1,
+                        SQLSyntax: "MERGE INSERT expects exactly one row of values; found {}", rows.len()
+                    );
+                    let target_columns: Vec<&str> = if columns.is_empty() {
+                        target_schema
+                            .iter_names()
+                            .map(|name| name.as_str())
+                            .collect()
+                    } else {
+                        columns.iter().map(|c| c.value.as_str()).collect()
+                    };
+                    if target_columns.len() != rows[0].len() {
+                        polars_bail!(
+                            SQLSyntax: "MERGE INSERT expects {} values; found {}",
+                            target_columns.len(), rows[0].len()
+                        )
+                    }
+                    for (name, value) in target_columns.into_iter().zip(&rows[0]) {
+                        let Some((idx, _, dtype)) = target_schema.get_full(name) else {
+                            polars_bail!(SQLInterface: "column '{}' does not exist in table '{}'", name, tbl_name)
+                        };
+                        let value = parse_sql_expr(value, self, Some(joined_schema.as_ref()))?
+                            .strict_cast(dtype.clone());
+                        inserted[idx] = when(condition.clone())
+                            .then(value)
+                            .otherwise(inserted[idx].clone());
+                    }
+                    is_inserted = is_inserted.or(condition);
+                },
+                action => {
+                    let clause_kind = if is_not_matched {
+                        "NOT MATCHED"
+                    } else {
+                        "MATCHED"
+                    };
+                    polars_bail!(SQLSyntax: "MERGE does not allow {} in WHEN {} clauses", action, clause_kind)
+                },
+            }
+        }
+
+        // each target row may match at most one source row, which is checked when the
+        // plan is executed
+        let msg = format!(
+            "MERGE found a row of table '{tbl_name}' that matches more than one source row"
+        );
+        let matched_rows = col(MERGE_TARGET_ROW_IDX).filter(in_target.clone().and(in_source));
+        let has_single_matches = matched_rows.clone().n_unique().eq(matched_rows.len()).map(
+            move |c| {
+                polars_ensure!(c.bool()?.all(), SQLInterface: "{}", msg);
+                Ok(c)
+            },
+            |_, field| Ok(field.clone()),
+        );
+        let joined = joined.filter(has_single_matches);
+
+        let names = target_schema.iter_names().cloned().collect::<Vec<_>>();
+        let kept = joined
+            .clone()
+            .filter(in_target.and(is_deleted.not()))
+            .select(
+                updated
+                    .into_iter()
+                    .zip(&names)
+                    .map(|(e, name)| e.alias(name.clone()))
+                    .collect::<Vec<_>>(),
+            );
+        let inserted = joined.filter(is_inserted).select(
+            inserted
+                .into_iter()
+                .zip(&names)
+                .map(|(e, name)| e.alias(name.clone()))
+                .collect::<Vec<_>>(),
+        );
+        let lf = concat([kept, inserted], UnionArgs::default())?;
+        self.replace_table(tbl_name, lf.clone())?;
+        Ok(lf)
+    }
+
+    /// Get the name of the registered table that a data-modifying statement writes to.
+    fn get_registered_table_name(
+        &self,
+        relation: &TableFactor,
+        stmt: &str,
+    ) -> PolarsResult<String> {
+        match relation {
+            TableFactor::Table {
+                name, args: None, ..
+            } => {
+                let tbl_name = name.0.first().unwrap().value.clone();
+                polars_ensure!(
+                    self.table_map.contains_key(&tbl_name),
+                    SQLInterface: "table '{}' does not exist", tbl_name
+                );
+                Ok(tbl_name)
+            },
+            _ => polars_bail!(SQLInterface: "{} expects a registered table name", stmt),
+        }
+    }
+
     // TRUNCATE <tbl>
     fn execute_truncate_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::Truncate {
@@ -567,14 +1144,12 @@ impl SQLContext {
                         polars_bail!(SQLInterface: "TRUNCATE expects exactly one table name; found {}", table_names.len())
                     }
                     let tbl = table_names[0].to_string();
-                    if let Some(lf) = self.table_map.get_mut(&tbl) {
-                        *lf = DataFrame::empty_with_schema(
-                            lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)
-                                .unwrap()
-                                .as_ref(),
-                        )
-                        .lazy();
-                        Ok(lf.clone())
+                    if let Some(mut lf) = self.table_map.get(&tbl).cloned() {
+                        let lf =
+                            DataFrame::empty_with_schema(self.get_frame_schema(&mut lf)?.as_ref())
+                                .lazy();
+                        self.replace_table(tbl, lf.clone())?;
+                        Ok(lf)
                     } else {
                         polars_bail!(SQLInterface: "table '{}' does not exist", tbl);
                     }
@@ -594,19 +1169,65 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = match &*cte.query.body {
+                    SetExpr::SetOperation {
+                        op: SetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    } if with.recursive && references_table(right, &cte_name) => {
+                        self.execute_recursive_cte(cte, left, right, set_quantifier)?
+                    },
+                    _ => {
+                        let lf = self.execute_query(&cte.query)?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    },
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Execute a recursive CTE of the form `initial UNION [ALL] recursive`, where the recursive
+    /// term refers to the CTE itself, by evaluating the recursive term on the rows produced by
+    /// its previous evaluation until no new rows are produced.
+    fn execute_recursive_cte(
+        &mut self,
+        cte: &Cte,
+        left: &SetExpr,
+        right: &SetExpr,
+        quantifier: &SetQuantifier,
+    ) -> PolarsResult<LazyFrame> {
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => {
+                polars_bail!(SQLInterface: "'UNION {}' is not supported in recursive CTEs", quantifier)
+            },
+        };
+        polars_ensure!(
+            !references_table(left, &cte.alias.name.value),
+            SQLInterface: "the initial term of recursive CTE '{}' cannot refer to itself",
+            cte.alias.name.value
+        );
+
+        self.register_ctes(&cte.query)?;
+        let initial = self.process_query(left, &cte.query)?;
+        let initial = self.rename_columns_from_table_alias(initial, &cte.alias)?;
+        let lf = initial.recursive_union(
+            |working| {
+                self.register_cte(&cte.alias.name.value, working);
+                self.process_query(right, &cte.query)
+            },
+            distinct,
+            MAX_RECURSIVE_CTE_ITERATIONS,
+        )?;
+        self.process_limit_offset(lf, &cte.query.limit, &cte.query.offset)
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
@@ -681,41 +1302,74 @@ impl SQLContext {
 
                 // track join-aliased columns so we can resolve them later
                 let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{name}:{r_name}");
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
             }
         };
         Ok(lf)
     }
 
+    fn register_joined_aliases(
+        &self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        joined_schema: &Schema,
+    ) {
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{name}:{r_name}");
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+    }
+
+    /// Execute the 'FROM' part of the query; multiple (comma-separated) tables are cross
+    /// joined, leaving it to the WHERE clause to constrain the join.
+    fn execute_from(&mut self, from: &[TableWithJoins]) -> PolarsResult<LazyFrame> {
+        let mut lf = self.execute_from_statement(&from[0])?;
+        for tbl_expr in &from[1..] {
+            let r_name = match &tbl_expr.relation {
+                TableFactor::Table {
+                    alias: Some(alias), ..
+                }
+                | TableFactor::Derived {
+                    alias: Some(alias), ..
+                }
+                | TableFactor::UNNEST {
+                    alias: Some(alias), ..
+                } => alias.name.value.clone(),
+                TableFactor::Table { name, .. } => name.0.first().unwrap().value.clone(),
+                _ => polars_bail!(
+                    SQLInterface:
+                    "cannot join on unnamed relation; please provide an alias"
+                ),
+            };
+            let mut rf = self.execute_from_statement(tbl_expr)?;
+            let left_schema = self.get_frame_schema(&mut lf)?;
+            let right_schema = self.get_frame_schema(&mut rf)?;
+            lf = lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name)));
+
+            let joined_schema = self.get_frame_schema(&mut lf)?;
+            self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
+        }
+        Ok(lf)
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
         let mut lf = if select_stmt.from.is_empty() {
             DataFrame::empty().lazy()
         } else {
-            // Note: implicit joins need more work to support properly,
-            // explicit joins are preferred for now (ref: #16662)
-            let from = select_stmt.clone().from;
-            if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
-            }
-            self.execute_from_statement(from.first().unwrap())?
+            self.execute_from(&select_stmt.from)?
         };
 
         // Filter expression (WHERE clause)
@@ -734,31 +1388,19 @@ impl SQLContext {
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets: Option<Vec<Vec<usize>>> = None;
         match &select_stmt.group_by {
-            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
+            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values),
+            // optionally with ROLLUP, CUBE, or GROUPING SETS
             GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
-                // translate the group expressions, allowing ordinal values
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| {
-                        self.expr_or_ordinal(
-                            e,
-                            &projections,
-                            None,
-                            Some(schema.deref()),
-                            "GROUP BY",
-                        )
-                    })
-                    .collect::<PolarsResult<_>>()?
+                (group_by_keys, grouping_sets) =
+                    self.process_group_by_exprs(group_by_exprs, modifiers, &projections, &schema)?;
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
             GroupByExpr::All(modifiers) => {
                 if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
+                    polars_bail!(SQLInterface: "GROUP BY ALL does not support CUBE, ROLLUP, or TOTALS modifiers")
                 }
                 projections.iter().for_each(|expr| match expr {
                     // immediately match the most common cases (col|agg|len|lit, optionally aliased).
@@ -785,11 +1427,12 @@ impl SQLContext {
             },
         };
 
-        lf = if group_by_keys.is_empty() {
+        lf = if group_by_keys.is_empty() && grouping_sets.is_none() {
             // The 'having' clause is only valid inside 'group by'
             if select_stmt.having.is_some() {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
             };
+            lf = self.process_qualify(lf, &select_stmt.qualify, &projections)?;
 
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
@@ -888,15 +1531,17 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf =
+                self.process_group_by(lf, &group_by_keys, grouping_sets.as_deref(), &projections)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
-            }
+            };
+            self.process_qualify(lf, &select_stmt.qualify, &[])?
         };
 
         // Apply optional DISTINCT clause.
@@ -981,6 +1626,31 @@ impl SQLContext {
         Ok(flattened_exprs)
     }
 
+    /// Apply the QUALIFY clause, which filters on the result of window functions. It may refer
+    /// to the (aliased) projections, which are evaluated in the filter if not yet available.
+    fn process_qualify(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &Option<SQLExpr>,
+        projections: &[Expr],
+    ) -> PolarsResult<LazyFrame> {
+        let Some(expr) = expr else {
+            return Ok(lf);
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let predicate = parse_sql_expr(expr, self, Some(schema.as_ref()))?.map_expr(|e| match e {
+            Expr::Column(name) if !schema.contains(&name) => projections
+                .iter()
+                .find_map(|p| match p {
+                    Expr::Alias(inner, alias) if *alias == name => Some((**inner).clone()),
+                    _ => None,
+                })
+                .unwrap_or(Expr::Column(name)),
+            e => e,
+        });
+        Ok(lf.filter(predicate))
+    }
+
     fn process_where(
         &mut self,
         mut lf: LazyFrame,
@@ -1030,6 +1700,11 @@ impl SQLContext {
         constraint: &JoinConstraint,
         join_type: JoinType,
     ) -> PolarsResult<LazyFrame> {
+        if let JoinConstraint::On(expr) = constraint {
+            if !is_equi_join_on(expr) {
+                return self.process_join_where(tbl_left, tbl_right, expr, join_type);
+            }
+        }
         let (left_on, right_on) = process_join_constraint(constraint, tbl_left, tbl_right)?;
 
         let joined = tbl_left
@@ -1047,6 +1722,140 @@ impl SQLContext {
         Ok(joined)
     }
 
+    /// Join on an arbitrary `ON` predicate.
+    ///
+    /// The matching pairs of rows are found with `join_where` (a filtered cross join, from which
+    /// the optimizer recovers equi/inequality join keys), after which the unmatched rows that
+    /// outer, semi and anti joins need are recovered using row indices.
+    fn process_join_where(
+        &mut self,
+        tbl_left: &TableInfo,
+        tbl_right: &TableInfo,
+        expr: &SQLExpr,
+        join_type: JoinType,
+    ) -> PolarsResult<LazyFrame> {
+        const LEFT_INDEX: &str = "__POLARS_JOIN_LEFT_IDX";
+        const RIGHT_INDEX: &str = "__POLARS_JOIN_RIGHT_IDX";
+        const MATCHED: &str = "__POLARS_JOIN_MATCHED";
+
+        // resolve the predicate against the joined columns, where the right columns that
+        // clash with a left column are suffixed with the name of the right table
+        let suffix = format_pl_smallstr!(":{}", tbl_right.name);
+        let mut cross = tbl_left
+            .frame
+            .clone()
+            .cross_join(tbl_right.frame.clone(), Some(suffix.clone()));
+        let joined_schema = self.get_frame_schema(&mut cross)?;
+        self.register_joined_aliases(
+            &tbl_right.name,
+            &tbl_left.schema,
+            &tbl_right.schema,
+            &joined_schema,
+        );
+        let predicate = parse_sql_expr(expr, self, Some(joined_schema.as_ref()))?;
+
+        let join_where = |left: LazyFrame, right: LazyFrame| {
+            left.join_builder()
+                .with(right)
+                .how(JoinType::Inner)
+                .suffix(suffix.clone())
+                .join_where(vec![predicate.clone()])
+        };
+        if join_type == JoinType::Inner {
+            return Ok(join_where(tbl_left.frame.clone(), tbl_right.frame.clone()));
+        }
+
+        let left = tbl_left.frame.clone().with_row_index(LEFT_INDEX, None);
+        let right = tbl_right.frame.clone().with_row_index(RIGHT_INDEX, None);
+        let matches = join_where(left.clone(), right.clone());
+
+        // the rows of `lf` whose row index does (not) occur in the matches
+        let filter_matched = |lf: LazyFrame, index: &str, matched: bool| {
+            let matched_rows = matches
+                .clone()
+                .select([col(index)])
+                .unique(None, UniqueKeepStrategy::Any)
+                .with_column(lit(true).alias(MATCHED));
+            let is_matched = col(MATCHED).is_not_null();
+            lf.left_join(matched_rows, col(index), col(index))
+                .filter(if matched {
+                    is_matched
+                } else {
+                    is_matched.not()
+                })
+        };
+        let (left_names, right_names) = joined_schema
+            .iter_names()
+            .cloned()
+            .partition::<Vec<_>, _>(|name| tbl_left.schema.contains(name));
+        let pad = |lf: LazyFrame, names: &[PlSmallStr], null_names: &[PlSmallStr]| {
+            lf.select(
+                names
+                    .iter()
+                    .map(|name| col(name.clone()))
+                    .chain(null_names.iter().map(|name| {
+                        lit(Null {})
+                            .cast(joined_schema.get(name).unwrap().clone())
+                            .alias(name.clone())
+                    }))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let joined_names = || {
+            joined_schema
+                .iter_names()
+                .map(|name| col(name.clone()))
+                .collect::<Vec<_>>()
+        };
+        let unmatched_left = || {
+            pad(
+                filter_matched(left.clone(), LEFT_INDEX, false),
+                &left_names,
+                &right_names,
+            )
+        };
+        let unmatched_right = || {
+            // the right columns are renamed as they would have been in the joined result
+            let renamed = tbl_right
+                .schema
+                .iter_names()
+                .zip(&right_names)
+                .map(|(name, joined_name)| col(name.clone()).alias(joined_name.clone()))
+                .collect::<Vec<_>>();
+            let lf = filter_matched(right.clone(), RIGHT_INDEX, false).select(renamed);
+            pad(lf, &right_names, &left_names).select(joined_names())
+        };
+        let matched = || matches.clone().select(joined_names());
+
+        let inputs = match join_type {
+            JoinType::Left => vec![matched(), unmatched_left()],
+            JoinType::Right => vec![matched(), unmatched_right()],
+            JoinType::Full => vec![matched(), unmatched_left(), unmatched_right()],
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi => {
+                return Ok(filter_matched(left.clone(), LEFT_INDEX, true).select(
+                    left_names
+                        .iter()
+                        .map(|name| col(name.clone()))
+                        .collect::<Vec<_>>(),
+                ));
+            },
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Anti => {
+                return Ok(filter_matched(left.clone(), LEFT_INDEX, false).select(
+                    left_names
+                        .iter()
+                        .map(|name| col(name.clone()))
+                        .collect::<Vec<_>>(),
+                ));
+            },
+            join_type => {
+                polars_bail!(SQLInterface: "join type '{:?}' not currently supported with non-equi join constraints", join_type)
+            },
+        };
+        concat(inputs, UnionArgs::default())
+    }
+
     fn process_subqueries(&self, lf: LazyFrame, exprs: Vec<&mut Expr>) -> LazyFrame {
         let mut contexts = vec![];
         for expr in exprs {
@@ -1073,34 +1882,217 @@ impl SQLContext {
     fn execute_create_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::CreateTable(CreateTable {
             if_not_exists,
+            temporary,
             name,
+            columns,
             query,
             ..
         }) = stmt
         {
             let tbl_name = name.0.first().unwrap().value.as_str();
+            polars_ensure!(
+                !self.view_map.contains_key(tbl_name),
+                SQLInterface: "relation '{}' already exists as a view", tbl_name
+            );
             // CREATE TABLE IF NOT EXISTS
             if *if_not_exists && self.table_map.contains_key(tbl_name) {
-                polars_bail!(SQLInterface: "relation '{}' already exists", tbl_name);
-                // CREATE OR REPLACE TABLE
+                return Ok(statement_response("CREATE TABLE"));
             }
-            if let Some(query) = query {
-                let lf = self.execute_query(query)?;
-                self.register(tbl_name, lf);
-                let out = df! {
-                    "Response" => ["CREATE TABLE"]
-                }
-                .unwrap()
-                .lazy();
-                Ok(out)
-            } else {
-                polars_bail!(SQLInterface: "only `CREATE TABLE AS SELECT ...` is currently supported");
+            let lf = match query {
+                // CREATE TABLE <tbl> AS SELECT ...
+                Some(query) => self.execute_query(query)?,
+                // CREATE TABLE <tbl> (<column> <type>, ...)
+                None if !columns.is_empty() => {
+                    let schema = columns
+                        .iter()
+                        .map(|c| {
+                            Ok(Field::new(
+                                c.name.value.as_str().into(),
+                                map_sql_dtype_to_polars(&c.data_type)?,
+                            ))
+                        })
+                        .collect::<PolarsResult<Schema>>()?;
+                    DataFrame::empty_with_schema(&schema).lazy()
+                },
+                None => {
+                    polars_bail!(SQLInterface: "CREATE TABLE expects a query or column definitions")
+                },
+            };
+            // CREATE TEMPORARY TABLE is only registered in this context
+            if let (Some(catalog), false) = (&self.catalog, *temporary) {
+                catalog.write_table(tbl_name, &lf)?;
             }
+            self.register(tbl_name, lf);
+            Ok(statement_response("CREATE TABLE"))
         } else {
             unreachable!()
         }
     }
 
+    // CREATE [OR REPLACE] [TEMPORARY] VIEW [IF NOT EXISTS] <view> [(<column>, ...)] AS <query>
+    fn execute_create_view(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            columns,
+            query,
+            if_not_exists,
+            temporary,
+            ..
+        } = stmt
+        else {
+            unreachable!()
+        };
+        polars_ensure!(
+            !*materialized,
+            SQLInterface: "CREATE MATERIALIZED VIEW is not supported; use CREATE TABLE ... AS instead"
+        );
+        let view_name = name.0.first().unwrap().value.clone();
+        polars_ensure!(
+            !self.table_map.contains_key(&view_name),
+            SQLInterface: "relation '{}' already exists as a table", view_name
+        );
+        if self.view_map.contains_key(&view_name) {
+            if *if_not_exists {
+                return Ok(statement_response("CREATE VIEW"));
+            }
+            polars_ensure!(
+                *or_replace,
+                SQLInterface: "view '{}' already exists; use CREATE OR REPLACE VIEW to replace it",
+                view_name
+            );
+        }
+        let view = View {
+            query: (**query).clone(),
+            columns: columns.iter().map(|c| c.name.clone()).collect(),
+        };
+
+        // validate the view by planning its query, as it would be planned when referred to
+        let previous = self.view_map.insert(view_name.clone(), view.clone());
+        let validated = self
+            .execute_view(&view_name, &view)
+            .and_then(|mut lf| self.get_frame_schema(&mut lf).map(|_| ()))
+            .and_then(|_| match (&self.catalog, *temporary) {
+                (Some(catalog), false) => {
+                    catalog.write_view(&view_name, &view.definition(&view_name))
+                },
+                _ => Ok(()),
+            });
+        if let Err(e) = validated {
+            match previous {
+                Some(previous) => self.view_map.insert(view_name, previous),
+                None => self.view_map.remove(&view_name),
+            };
+            return Err(e);
+        }
+        Ok(statement_response("CREATE VIEW"))
+    }
+
+    // ALTER TABLE [IF EXISTS] <tbl> <operation>, ...
+    fn execute_alter_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::AlterTable {
+            name,
+            if_exists,
+            operations,
+            ..
+        } = stmt
+        else {
+            unreachable!()
+        };
+        let mut tbl_name = name.0.first().unwrap().value.clone();
+        let Some(mut lf) = self.table_map.get(&tbl_name).cloned() else {
+            if *if_exists {
+                return Ok(statement_response("ALTER TABLE"));
+            }
+            polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+        };
+        let old_name = tbl_name.clone();
+
+        for op in operations {
+            let schema = self.get_frame_schema(&mut lf)?;
+            lf = match op {
+                AlterTableOperation::RenameTable { table_name } => {
+                    tbl_name = table_name.0.first().unwrap().value.clone();
+                    polars_ensure!(
+                        tbl_name == old_name
+                            || !(self.table_map.contains_key(&tbl_name)
+                                || self.view_map.contains_key(&tbl_name)),
+                        SQLInterface: "relation '{}' already exists", tbl_name
+                    );
+                    lf
+                },
+                AlterTableOperation::RenameColumn {
+                    old_column_name,
+                    new_column_name,
+                } => {
+                    let (old, new) = (&old_column_name.value, &new_column_name.value);
+                    polars_ensure!(
+                        schema.contains(old),
+                        ColumnNotFound: "column '{}' does not exist in table '{}'", old, tbl_name
+                    );
+                    polars_ensure!(
+                        old == new || !schema.contains(new),
+                        Duplicate: "column '{}' already exists in table '{}'", new, tbl_name
+                    );
+                    lf.rename([old.as_str()], [new.as_str()], true)
+                },
+                AlterTableOperation::AddColumn {
+                    if_not_exists,
+                    column_def,
+                    ..
+                } => {
+                    let column_name = column_def.name.value.as_str();
+                    if schema.contains(column_name) {
+                        polars_ensure!(
+                            *if_not_exists,
+                            Duplicate: "column '{}' already exists in table '{}'", column_name, tbl_name
+                        );
+                        continue;
+                    }
+                    let dtype = map_sql_dtype_to_polars(&column_def.data_type)?;
+                    let default = column_def.options.iter().find_map(|opt| match &opt.option {
+                        ColumnOption::Default(expr) => Some(expr),
+                        _ => None,
+                    });
+                    let value = match default {
+                        Some(expr) => parse_sql_expr(expr, self, Some(schema.as_ref()))?,
+                        None => lit(LiteralValue::untyped_null()),
+                    };
+                    lf.with_column(value.strict_cast(dtype).alias(column_name))
+                },
+                AlterTableOperation::DropColumn {
+                    column_name,
+                    if_exists,
+                    ..
+                } => {
+                    let column_name = column_name.value.as_str();
+                    if !schema.contains(column_name) {
+                        polars_ensure!(
+                            *if_exists,
+                            ColumnNotFound: "column '{}' does not exist in table '{}'", column_name, tbl_name
+                        );
+                        continue;
+                    }
+                    lf.drop(cols([column_name]))
+                },
+                op => polars_bail!(SQLInterface: "ALTER TABLE operation is not supported: {}", op),
+            };
+        }
+
+        if let Some(catalog) = &self.catalog {
+            if catalog.contains_table(&old_name) {
+                catalog.write_table(&tbl_name, &lf)?;
+                if tbl_name != old_name {
+                    catalog.remove_table(&old_name)?;
+                }
+            }
+        }
+        self.table_map.remove(&old_name);
+        self.table_map.insert(tbl_name, lf);
+        Ok(statement_response("ALTER TABLE"))
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1110,7 +2102,7 @@ impl SQLContext {
                     return self.execute_table_function(name, alias, &args.args);
                 }
                 let tbl_name = name.0.first().unwrap().value.as_str();
-                if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                if let Some(lf) = self.get_relation(tbl_name)? {
                     match alias {
                         Some(alias) => {
                             self.table_aliases
@@ -1212,11 +2204,170 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let values = match value_source {
+                    PivotValueSource::List(values) => values,
+                    _ => polars_bail!(
+                        SQLInterface: "PIVOT requires an explicit list of values; found {}",
+                        value_source
+                    ),
+                };
+                let [on] = value_column.as_slice() else {
+                    polars_bail!(SQLInterface: "PIVOT currently supports a single FOR column; found {}", relation)
+                };
+                let lf = self.execute_pivot(
+                    lf,
+                    aggregate_functions,
+                    on,
+                    values,
+                    default_on_null.as_ref(),
+                )?;
+                self.register_relation_alias(tbl_name, lf, alias)
+            },
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+                ..
+            } => {
+                let (tbl_name, mut lf) = self.get_table(table)?;
+                let schema = self.get_frame_schema(&mut lf)?;
+                let on: Vec<PlSmallStr> = columns
+                    .iter()
+                    .map(|c| PlSmallStr::from_str(c.value.as_str()))
+                    .collect();
+                let index: Vec<PlSmallStr> = schema
+                    .iter_names()
+                    .filter(|c| !on.contains(c))
+                    .cloned()
+                    .collect();
+                // as in other SQL dialects, rows with a NULL value are excluded
+                let lf = lf
+                    .unpivot(UnpivotArgsDSL {
+                        on: cols(on),
+                        index: cols(index),
+                        variable_name: Some(PlSmallStr::from_str(name.value.as_str())),
+                        value_name: Some(PlSmallStr::from_str(value.value.as_str())),
+                    })
+                    .filter(col(value.value.as_str()).is_not_null());
+                self.register_relation_alias(tbl_name, lf, alias)
+            },
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    /// Pivot the values of the `on` column into columns, one for each of the given values
+    /// (and aggregate); the remaining columns that are not aggregated form the index.
+    /// As the values are known up front, this is planned as a (lazy) stable group-by. The
+    /// `pivot` machinery is not used, as it works on an eager `DataFrame` and discovers the
+    /// output columns from the data.
+    fn execute_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        on: &Ident,
+        values: &[ExprWithAlias],
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on_name = PlSmallStr::from_str(on.value.as_str());
+        polars_ensure!(
+            schema.contains(&on_name),
+            ColumnNotFound: "PIVOT column '{}' was not found", on_name
+        );
+        let aggregates = aggregate_functions
+            .iter()
+            .map(|agg| {
+                let expr = parse_sql_expr(&agg.expr, self, Some(schema.as_ref()))?;
+                let name = agg
+                    .alias
+                    .as_ref()
+                    .map_or_else(|| agg.expr.to_string(), |a| a.value.clone());
+                Ok((expr, name))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let default_on_null = default_on_null
+            .map(|e| parse_sql_expr(e, self, Some(schema.as_ref())))
+            .transpose()?;
+
+        // every column that is neither pivoted nor aggregated is part of the index
+        let mut aggregated: PlHashSet<PlSmallStr> = PlHashSet::new();
+        aggregated.insert(on_name.clone());
+        for (expr, _) in &aggregates {
+            aggregated.extend(expr_to_leaf_column_names(expr));
+        }
+        let index: Vec<Expr> = schema
+            .iter_names()
+            .filter(|c| !aggregated.contains(*c))
+            .map(|c| col(c.clone()))
+            .collect();
+
+        let mut pivoted = Vec::with_capacity(values.len() * aggregates.len());
+        for value in values {
+            let value_name = match &value.alias {
+                Some(alias) => alias.value.clone(),
+                None => match &value.expr {
+                    SQLExpr::Value(
+                        SQLValue::SingleQuotedString(s) | SQLValue::DoubleQuotedString(s),
+                    ) => s.clone(),
+                    e => e.to_string(),
+                },
+            };
+            let cond =
+                col(on_name.clone()).eq(parse_sql_expr(&value.expr, self, Some(schema.as_ref()))?);
+            for (expr, agg_name) in &aggregates {
+                // aggregate only the rows having this value
+                let expr = expr.clone().map_expr(|e| match e {
+                    Expr::Column(name) => col(name).filter(cond.clone()),
+                    Expr::Len => col(on_name.clone()).filter(cond.clone()).len(),
+                    e => e,
+                });
+                // groups without any such rows have no value
+                let mut expr = when(cond.clone().any(true))
+                    .then(expr)
+                    .otherwise(lit(Null {}));
+                if let Some(default) = &default_on_null {
+                    expr = expr.fill_null(default.clone());
+                }
+                let name = if aggregates.len() == 1 {
+                    value_name.clone()
+                } else {
+                    format!("{value_name}_{agg_name}")
+                };
+                pivoted.push(expr.alias(name));
+            }
+        }
+        Ok(lf.group_by_stable(index).agg(pivoted))
+    }
+
+    /// Register a relation derived from the given table under its alias, if any.
+    fn register_relation_alias(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -1292,14 +2443,126 @@ impl SQLContext {
         ))
     }
 
+    /// Translate the GROUP BY expressions into the group keys and, if ROLLUP, CUBE, or
+    /// GROUPING SETS are used, the grouping sets (as indices into the group keys).
+    fn process_group_by_exprs(
+        &mut self,
+        group_by_exprs: &[SQLExpr],
+        modifiers: &[GroupByWithModifier],
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<(Vec<Expr>, Option<Vec<Vec<usize>>>)> {
+        let mut keys = Vec::new();
+        let mut key_index = |ctx: &mut Self, e: &SQLExpr| -> PolarsResult<usize> {
+            let key = ctx.expr_or_ordinal(e, projections, None, Some(schema), "GROUP BY")?;
+            Ok(match keys.iter().position(|k| *k == key) {
+                Some(idx) => idx,
+                None => {
+                    keys.push(key);
+                    keys.len() - 1
+                },
+            })
+        };
+
+        // every GROUP BY element contributes a list of (partial) grouping sets; the
+        // grouping sets of the query are their cross product
+        let mut elements = Vec::with_capacity(group_by_exprs.len());
+        let mut has_grouping_sets = false;
+        for e in group_by_exprs {
+            let sets = match e {
+                SQLExpr::Rollup(sets) | SQLExpr::Cube(sets) | SQLExpr::GroupingSets(sets) => {
+                    has_grouping_sets = true;
+                    let sets = sets
+                        .iter()
+                        .map(|set| {
+                            set.iter()
+                                .map(|e| key_index(self, e))
+                                .collect::<PolarsResult<Vec<_>>>()
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    match e {
+                        SQLExpr::Rollup(_) => rollup_grouping_sets(&sets),
+                        SQLExpr::Cube(_) => cube_grouping_sets(&sets)?,
+                        _ => sets,
+                    }
+                },
+                _ => vec![vec![key_index(self, e)?]],
+            };
+            elements.push(sets);
+        }
+
+        // "GROUP BY x, y WITH ROLLUP" (etc) modifiers apply to all of the keys
+        let mut with_totals = false;
+        if !modifiers.is_empty() {
+            if has_grouping_sets {
+                polars_bail!(SQLSyntax: "GROUP BY modifiers cannot be combined with ROLLUP, CUBE, or GROUPING SETS")
+            }
+            has_grouping_sets = true;
+            for modifier in modifiers {
+                match modifier {
+                    GroupByWithModifier::Rollup => {
+                        elements = vec![rollup_grouping_sets(&elements.concat())]
+                    },
+                    GroupByWithModifier::Cube => {
+                        elements = vec![cube_grouping_sets(&elements.concat())?]
+                    },
+                    GroupByWithModifier::Totals => with_totals = true,
+                }
+            }
+        }
+        if !has_grouping_sets {
+            return Ok((keys, None));
+        }
+
+        let mut grouping_sets = elements.iter().fold(vec![Vec::new()], |acc, sets| {
+            acc.iter()
+                .flat_map(|prefix| {
+                    sets.iter().map(move |set| {
+                        let mut grouping_set = prefix.clone();
+                        grouping_set.extend(set);
+                        grouping_set.sort_unstable();
+                        grouping_set.dedup();
+                        grouping_set
+                    })
+                })
+                .collect::<Vec<Vec<usize>>>()
+        });
+        if with_totals && !grouping_sets.iter().any(|set| set.is_empty()) {
+            grouping_sets.push(vec![]);
+        }
+        Ok((keys, Some(grouping_sets)))
+    }
+
     fn process_group_by(
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<&[Vec<usize>]>,
         projections: &[Expr],
     ) -> PolarsResult<LazyFrame> {
-        let schema_before = self.get_frame_schema(&mut lf)?;
-        let group_by_keys_schema = expressions_to_schema(group_by_keys, &schema_before)?;
+        let mut schema_before = self.get_frame_schema(&mut lf)?;
+        let mut group_by_keys_schema = expressions_to_schema(group_by_keys, &schema_before)?;
+
+        // GROUPING(...) refers to a flag per group key, which we treat as an additional
+        // key; it requires aggregating by grouping sets (a single one if not given).
+        let uses_grouping = projections.iter().any(|e| {
+            has_expr(
+                e,
+                |e| matches!(e, Expr::Column(name) if name.starts_with(GROUPING_FLAG_PREFIX)),
+            )
+        });
+        let all_keys = [(0..group_by_keys.len()).collect::<Vec<_>>()];
+        let grouping_sets = grouping_sets.or(uses_grouping.then_some(all_keys.as_slice()));
+        let key_names = group_by_keys_schema.iter_names_cloned().collect::<Vec<_>>();
+        if grouping_sets.is_some() {
+            let mut schema = schema_before.as_ref().clone();
+            for name in &key_names {
+                let flag = format_pl_smallstr!("{GROUPING_FLAG_PREFIX}{name}");
+                schema.insert(flag.clone(), DataType::Int32);
+                group_by_keys_schema.insert(flag, DataType::Int32);
+            }
+            schema_before = Arc::new(schema);
+        }
 
         // Remove the group_by keys as polars adds those implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
@@ -1365,7 +2628,26 @@ impl SQLContext {
                 }
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let aggregated = match grouping_sets {
+            Some(grouping_sets) => {
+                // each group key is flagged if it is not part of the grouping set of the row
+                let grouping_id = PlSmallStr::from_static(GROUPING_ID_NAME);
+                let flags = key_names.iter().enumerate().map(|(i, name)| {
+                    let bit = 1u64 << (key_names.len() - 1 - i);
+                    (col(grouping_id.clone()).floor_div(lit(bit)) % lit(2u64))
+                        .cast(DataType::Int32)
+                        .alias(format_pl_smallstr!("{GROUPING_FLAG_PREFIX}{name}"))
+                });
+                lf.group_by_grouping_sets(
+                    group_by_keys,
+                    grouping_sets,
+                    &aggregation_projection,
+                    Some(grouping_id.clone()),
+                )?
+                .with_columns(flags.collect::<Vec<_>>())
+            },
+            None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+        };
         let projection_schema = expressions_to_schema(projections, &schema_before)?;
 
         // A final projection to get the proper order and any deferred transforms/aliases.
@@ -1560,6 +2842,83 @@ impl SQLContext {
     }
 }
 
+/// The grouping sets of `ROLLUP (a, b, c)`: `(a, b, c)`, `(a, b)`, `(a)` and `()`.
+fn rollup_grouping_sets(elements: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    (0..=elements.len())
+        .rev()
+        .map(|n| elements[..n].concat())
+        .collect()
+}
+
+/// The grouping sets of `CUBE (a, b, c)`: all subsets of `(a, b, c)`.
+fn cube_grouping_sets(elements: &[Vec<usize>]) -> PolarsResult<Vec<Vec<usize>>> {
+    // the same limit as PostgreSQL, the number of grouping sets grows exponentially
+    polars_ensure!(
+        elements.len() <= 12,
+        SQLInterface: "CUBE is limited to 12 elements; found {}", elements.len()
+    );
+    let n = elements.len();
+    Ok((0..1usize << n)
+        .rev()
+        .map(|mask| {
+            elements
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << (n - 1 - i)) != 0)
+                .flat_map(|(_, element)| element.iter().copied())
+                .collect()
+        })
+        .collect())
+}
+
+/// Get the column (and its data type) that an UPDATE assignment writes to.
+fn resolve_assignment_target<'a>(
+    target: &AssignmentTarget,
+    schema: &'a Schema,
+) -> PolarsResult<(&'a PlSmallStr, &'a DataType)> {
+    let AssignmentTarget::ColumnName(name) = target else {
+        polars_bail!(SQLInterface: "tuple assignments are not supported; found {}", target)
+    };
+    // the column may be qualified with the table name
+    let column = &name.0.last().unwrap().value;
+    match schema.get_full(column) {
+        Some((_, name, dtype)) => Ok((name, dtype)),
+        None => polars_bail!(SQLInterface: "column '{}' does not exist", column),
+    }
+}
+
+/// Whether the query reads from a table named `name`, which is how a recursive CTE is told
+/// apart from a regular CTE in a `WITH RECURSIVE` clause.
+fn references_table(query: &SetExpr, name: &str) -> bool {
+    match query {
+        SetExpr::Select(select) => select
+            .from
+            .iter()
+            .any(|tbl_expr| table_with_joins_references_table(tbl_expr, name)),
+        SetExpr::Query(query) => references_table(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            references_table(left, name) || references_table(right, name)
+        },
+        SetExpr::Table(table) => table.table_name.as_deref() == Some(name),
+        _ => false,
+    }
+}
+
+fn table_with_joins_references_table(tbl_expr: &TableWithJoins, name: &str) -> bool {
+    std::iter::once(&tbl_expr.relation)
+        .chain(tbl_expr.joins.iter().map(|join| &join.relation))
+        .any(|relation| match relation {
+            TableFactor::Table { name: tbl_name, .. } => {
+                tbl_name.0.last().is_some_and(|ident| ident.value == name)
+            },
+            TableFactor::Derived { subquery, .. } => references_table(&subquery.body, name),
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => table_with_joins_references_table(table_with_joins, name),
+            _ => false,
+        })
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -1638,6 +2997,28 @@ fn process_join_on(
     }
 }
 
+/// Whether the join constraint only consists of equalities between columns of the two tables
+/// (combined with 'AND'), such that the tables can be joined on these columns as keys.
+fn is_equi_join_on(expression: &SQLExpr) -> bool {
+    match expression {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => is_equi_join_on(left) && is_equi_join_on(right),
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => matches!(
+            (left.as_ref(), right.as_ref()),
+            (SQLExpr::CompoundIdentifier(l), SQLExpr::CompoundIdentifier(r)) if l.len() == 2 && r.len() == 2
+        ),
+        SQLExpr::Nested(expr) => is_equi_join_on(expr),
+        _ => false,
+    }
+}
+
 fn process_join_constraint(
     constraint: &JoinConstraint,
     tbl_left: &TableInfo,
@@ -1721,3 +3102,12 @@ impl ExprSqlProjectionHeightBehavior {
         }
     }
 }
+
+/// The single-row frame returned by statements that don't produce rows.
+fn statement_response(statement: &str) -> LazyFrame {
+    df! {
+        "Response" => [statement]
+    }
+    .unwrap()
+    .lazy()
+}
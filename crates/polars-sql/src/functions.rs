@@ -2,25 +2,36 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, FillNullStrategy, IDX_DTYPE, PolarsResult, QuantileMethod, RollingOptionsFixedWindow,
+    Schema, TimeUnit, polars_bail, polars_err,
 };
 use polars_lazy::dsl::Expr;
 use polars_ops::chunked_array::UnicodeForm;
 use polars_ops::series::RoundMode;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_plan::dsl::{
+    WindowMapping, coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, when,
+};
 use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
 use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
+use polars_plan::utils::expr_output_name;
+use polars_time::chunkedarray::RollingOptionsDynamicWindow;
+use polars_time::{ClosedWindow, Duration};
+use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    NullTreatment, OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits,
+    WindowSpec, WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::context::GROUPING_FLAG_PREFIX;
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -555,6 +566,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function.
+    /// Returns a bitmask of the given GROUP BY keys that are not part of the grouping set
+    /// of the row, with the first key as the most significant bit.
+    /// ```sql
+    /// SELECT column_1, column_2, GROUPING(column_1, column_2) FROM df GROUP BY ROLLUP (column_1, column_2);
+    /// ```
+    Grouping,
     /// SQL 'last' function.
     /// Returns the last element of the grouping.
     /// ```sql
@@ -593,12 +611,32 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT MIN(column_1) FROM df;
     /// ```
     Min,
+    /// SQL 'percentile_cont' function.
+    /// Returns the continuous percentile of the column given in the WITHIN GROUP clause
+    /// (interpolated value between two closest values).
+    /// ```sql
+    /// SELECT PERCENTILE_CONT(0.9) WITHIN GROUP (ORDER BY column_1) FROM df;
+    /// ```
+    PercentileCont,
+    /// SQL 'percentile_disc' function.
+    /// Returns the discrete percentile of the column given in the WITHIN GROUP clause.
+    /// ```sql
+    /// SELECT PERCENTILE_DISC(0.9) WITHIN GROUP (ORDER BY column_1) FROM df;
+    /// ```
+    PercentileDisc,
     /// SQL 'stddev' function.
     /// Returns the standard deviation of all the elements in the grouping.
     /// ```sql
     /// SELECT STDDEV(column_1) FROM df;
     /// ```
     StdDev,
+    /// SQL 'string_agg' function.
+    /// Concatenates the string values of the grouping, separated by the given separator
+    /// (or a comma), optionally in the order of an ORDER BY or WITHIN GROUP clause.
+    /// ```sql
+    /// SELECT STRING_AGG(column_1, ', ') WITHIN GROUP (ORDER BY column_2) FROM df;
+    /// ```
+    StringAgg,
     /// SQL 'sum' function.
     /// Returns the sum of all the elements in the grouping.
     /// ```sql
@@ -611,6 +649,41 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT VARIANCE(column_1) FROM df;
     /// ```
     Variance,
+
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'dense_rank' function.
+    /// Returns the rank of the row within its window partition, without gaps between ranks.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'first_value' function.
+    /// Returns the first value of the window frame; with IGNORE NULLS, the first non-null value.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'last_value' function.
+    /// Returns the last value of the window frame; with IGNORE NULLS, the last non-null value.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1 IGNORE NULLS) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    LastValue,
+    /// SQL 'rank' function.
+    /// Returns the rank of the row within its window partition, with gaps for tied rows.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'row_number' function.
+    /// Returns the number of the row within its window partition, starting at 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+
     // ----
     // Array functions
     // ----
@@ -748,18 +821,22 @@ impl PolarsSQLFunctions {
             "date",
             "date_part",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
             "greatest",
             "if",
             "ifnull",
             "initcap",
             "last",
+            "last_value",
             "least",
             "left",
             "length",
+            "listagg",
             "ln",
             "log",
             "log10",
@@ -774,17 +851,21 @@ impl PolarsSQLFunctions {
             "mod",
             "nullif",
             "octet_length",
+            "percentile_cont",
+            "percentile_disc",
             "pi",
             "pow",
             "power",
             "quantile_cont",
             "quantile_disc",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -796,6 +877,7 @@ impl PolarsSQLFunctions {
             "stdev",
             "stdev_samp",
             "strftime",
+            "string_agg",
             "strpos",
             "strptime",
             "substr",
@@ -923,16 +1005,29 @@ impl PolarsSQLFunctions {
             "covar_pop" => Self::CovarPop,
             "covar" | "covar_samp" => Self::CovarSamp,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
+            "percentile_cont" => Self::PercentileCont,
+            "percentile_disc" => Self::PercentileDisc,
             "quantile_cont" => Self::QuantileCont,
             "quantile_disc" => Self::QuantileDisc,
             "min" => Self::Min,
             "stdev" | "stddev" | "stdev_samp" | "stddev_samp" => Self::StdDev,
+            "string_agg" | "listagg" => Self::StringAgg,
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "dense_rank" => Self::DenseRank,
+            "first_value" => Self::FirstValue,
+            "last_value" => Self::LastValue,
+            "rank" => Self::Rank,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -971,15 +1066,51 @@ impl SQLFunctionVisitor<'_> {
         let function_name = PolarsSQLFunctions::try_from_sql(self.func, self.ctx)?;
         let function = self.func;
 
-        // TODO: implement the following functions where possible
-        if !function.within_group.is_empty() {
-            polars_bail!(SQLInterface: "'WITHIN GROUP' is not currently supported")
+        if let Some(func) = move_null_treatment_clause(function) {
+            return SQLFunctionVisitor {
+                func: &func,
+                ctx: self.ctx,
+                active_schema: self.active_schema,
+            }
+            .visit_function();
+        }
+        if function.null_treatment.is_some()
+            && !matches!(function_name, First | FirstValue | Last | LastValue)
+        {
+            polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not supported for {}", function.name)
+        }
+        let ignore_nulls = matches!(function.null_treatment, Some(NullTreatment::IgnoreNulls));
+        if !function.within_group.is_empty()
+            && !matches!(
+                function_name,
+                ArrayAgg | PercentileCont | PercentileDisc | StringAgg
+            )
+        {
+            polars_bail!(SQLInterface: "'WITHIN GROUP' is not supported for {}", function.name)
         }
-        if function.filter.is_some() {
-            polars_bail!(SQLInterface: "'FILTER' is not currently supported")
+        if let Some(filter) = &function.filter {
+            return self.visit_filtered_aggregate(filter);
         }
-        if function.null_treatment.is_some() {
-            polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
+
+        // aggregates with an explicit frame, e.g. ROWS BETWEEN 2 PRECEDING AND CURRENT ROW
+        if let Some(WindowType::WindowSpec(
+            spec @ WindowSpec {
+                window_frame: Some(frame),
+                ..
+            },
+        )) = &function.over
+        {
+            let agg = match function_name {
+                Avg => Some(FrameAggregate::Mean),
+                Count => Some(FrameAggregate::Count),
+                Max => Some(FrameAggregate::Max),
+                Min => Some(FrameAggregate::Min),
+                Sum => Some(FrameAggregate::Sum),
+                _ => None,
+            };
+            if let Some(agg) = agg {
+                return self.visit_window_frame(agg, spec, frame);
+            }
         }
 
         match function_name {
@@ -1433,8 +1564,21 @@ impl SQLFunctionVisitor<'_> {
             Count => self.visit_count(),
             CovarPop => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 0)),
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
-            First => self.visit_unary(Expr::first),
-            Last => self.visit_unary(Expr::last),
+            First => self.visit_unary(move |e| {
+                if ignore_nulls {
+                    e.drop_nulls().first()
+                } else {
+                    e.first()
+                }
+            }),
+            Grouping => self.visit_grouping(),
+            Last => self.visit_unary(move |e| {
+                if ignore_nulls {
+                    e.drop_nulls().last()
+                } else {
+                    e.last()
+                }
+            }),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
             QuantileCont => {
@@ -1490,10 +1634,22 @@ impl SQLFunctionVisitor<'_> {
                 }
             },
             Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            PercentileCont => self.visit_percentile(QuantileMethod::Linear),
+            PercentileDisc => self.visit_percentile(QuantileMethod::Equiprobable),
             StdDev => self.visit_unary(|e| e.std(1)),
+            StringAgg => self.visit_string_agg(),
             Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            DenseRank => self.visit_ranking(RankingFunction::DenseRank),
+            FirstValue => self.visit_first_last_value(false, ignore_nulls),
+            LastValue => self.visit_first_last_value(true, ignore_nulls),
+            Rank => self.visit_ranking(RankingFunction::Rank),
+            RowNumber => self.visit_ranking(RankingFunction::RowNumber),
+
             // ----
             // Array functions
             // ----
@@ -1780,6 +1936,7 @@ impl SQLFunctionVisitor<'_> {
                         _ => {},
                     }
                 }
+                base = self.apply_within_group(base)?;
                 Ok(base.implode())
             },
             _ => {
@@ -1841,6 +1998,81 @@ impl SQLFunctionVisitor<'_> {
         self.apply_window_spec(count_expr, &self.func.over)
     }
 
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        if args.is_empty() || args.len() > 31 {
+            polars_bail!(SQLSyntax: "GROUPING expects 1-31 arguments (found {})", args.len())
+        }
+        // sum the flags of the given keys, as set by the (grouping sets) aggregation
+        let mut grouping = lit(0i32);
+        for (i, arg) in args.iter().enumerate() {
+            let FunctionArgExpr::Expr(sql_expr) = arg else {
+                return self.not_supported_error();
+            };
+            let key = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
+            let flag = col(format_pl_smallstr!(
+                "{GROUPING_FLAG_PREFIX}{}",
+                expr_output_name(&key)?
+            ));
+            grouping = grouping + flag * lit(1i32 << (args.len() - 1 - i));
+        }
+        Ok(grouping.alias("grouping"))
+    }
+
+    /// Visit STRING_AGG (or LISTAGG), concatenating the string values of the grouping with
+    /// an optional separator, in the order of an optional ORDER BY or WITHIN GROUP clause.
+    fn visit_string_agg(&mut self) -> PolarsResult<Expr> {
+        let (args, is_distinct, clauses) = extract_args_and_clauses(self.func)?;
+        let (sql_expr, separator) = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => (sql_expr, None),
+            [
+                FunctionArgExpr::Expr(sql_expr),
+                FunctionArgExpr::Expr(separator),
+            ] => (sql_expr, Some(separator)),
+            _ => {
+                polars_bail!(SQLSyntax: "{} expects 1 or 2 arguments (found {})", self.func.name, args.len())
+            },
+        };
+        let separator = match separator {
+            Some(separator) => match parse_sql_expr(separator, self.ctx, self.active_schema)? {
+                Expr::Literal(lv) if lv.extract_str().is_some() => {
+                    lv.extract_str().unwrap().to_string()
+                },
+                _ => {
+                    polars_bail!(SQLSyntax: "{} separator must be a string literal; found {}", self.func.name, separator)
+                },
+            },
+            None => ",".to_string(),
+        };
+        let mut base = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
+        if is_distinct {
+            base = base.unique_stable();
+        }
+        for clause in clauses {
+            if let FunctionArgumentClause::OrderBy(order_exprs) = clause {
+                base = self.apply_order_by(base, order_exprs.as_slice())?;
+            }
+        }
+        base = self.apply_within_group(base)?;
+        Ok(base.cast(DataType::String).str().join(&separator, true))
+    }
+
+    /// Sort the values of an aggregate by its WITHIN GROUP (ORDER BY ...) clause, if any.
+    fn apply_within_group(&mut self, expr: Expr) -> PolarsResult<Expr> {
+        let within_group = &self.func.within_group;
+        if within_group.is_empty() {
+            return Ok(expr);
+        }
+        let (_, _, clauses) = extract_args_and_clauses(self.func)?;
+        if clauses
+            .iter()
+            .any(|clause| matches!(clause, FunctionArgumentClause::OrderBy(_)))
+        {
+            polars_bail!(SQLSyntax: "{} cannot have both an ORDER BY and a WITHIN GROUP clause", self.func.name)
+        }
+        self.apply_order_by(expr, within_group)
+    }
+
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
         let mut by = Vec::with_capacity(order_by.len());
         let mut descending = Vec::with_capacity(order_by.len());
@@ -1900,6 +2132,319 @@ impl SQLFunctionVisitor<'_> {
         })
     }
 
+    /// Evaluate an aggregate over an explicit window frame; the frame is computed on the
+    /// ordered rows of each partition, using cumulative or rolling functions
+    /// e.g. SUM(a) OVER (ORDER BY b ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)
+    ///   -> ROLLING_SUM(a, 3) OVER (ORDER BY b)
+    fn visit_window_frame(
+        &mut self,
+        agg: FrameAggregate,
+        spec: &WindowSpec,
+        frame: &WindowFrame,
+    ) -> PolarsResult<Expr> {
+        let (args, is_distinct) = extract_args_distinct(self.func)?;
+        if is_distinct {
+            polars_bail!(SQLInterface: "DISTINCT is not supported for aggregates with a window frame; found {}", self.func)
+        }
+        // COUNT is evaluated as the sum of an indicator of the rows it counts
+        let values = match (agg, args.as_slice()) {
+            (FrameAggregate::Count, [FunctionArgExpr::Wildcard] | []) => {
+                int_range(lit(0), len(), 1, IDX_DTYPE)
+                    .is_not_null()
+                    .cast(IDX_DTYPE)
+            },
+            (FrameAggregate::Count, [FunctionArgExpr::Expr(sql_expr)]) => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+                    .is_not_null()
+                    .cast(IDX_DTYPE)
+            },
+            (_, [FunctionArgExpr::Expr(sql_expr)]) => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            _ => return self.not_supported_error(),
+        };
+
+        let Some((order_by, sort_options)) = self.parse_window_order_by(spec)? else {
+            polars_bail!(SQLInterface: "window frame requires an ORDER BY clause; found {}", self.func)
+        };
+
+        let start_bound = &frame.start_bound;
+        let end_bound = frame
+            .end_bound
+            .as_ref()
+            .unwrap_or(&WindowFrameBound::CurrentRow);
+        if matches!(start_bound, WindowFrameBound::Following(None))
+            || matches!(end_bound, WindowFrameBound::Preceding(None))
+        {
+            polars_bail!(SQLSyntax: "invalid window frame; found {}", frame)
+        }
+
+        let expr = match frame.units {
+            WindowFrameUnits::Rows => {
+                let start = rows_frame_offset(start_bound)?;
+                let end = rows_frame_offset(end_bound)?;
+                if start.zip(end).is_some_and(|(start, end)| start > end) {
+                    polars_bail!(SQLSyntax: "window frame cannot start after it ends; found {}", frame)
+                }
+                rows_frame(agg, values, start, end)
+            },
+            WindowFrameUnits::Range => match (start_bound, end_bound) {
+                (WindowFrameBound::Preceding(None), WindowFrameBound::Following(None)) => {
+                    agg.aggregate(values)
+                },
+                (WindowFrameBound::Preceding(None), WindowFrameBound::CurrentRow) => {
+                    // rows with equal ORDER BY values (peers) share the frame ending at
+                    // the last of them
+                    agg.cumulative(values, false)
+                        .gather(last_peer_index(&order_by))
+                },
+                (WindowFrameBound::Preceding(Some(offset)), WindowFrameBound::CurrentRow) => {
+                    if order_by.len() != 1 || sort_options.descending {
+                        polars_bail!(SQLInterface: "RANGE frame with an offset requires a single ascending ORDER BY column; found {}", self.func)
+                    }
+                    let window_size = match offset.as_ref() {
+                        SQLExpr::Interval(interval) => interval_to_duration(interval)?,
+                        SQLExpr::Value(SQLValue::Number(s, _)) => match s.parse::<i64>() {
+                            Ok(n) if n >= 0 => Duration::new(n),
+                            _ => {
+                                polars_bail!(SQLSyntax: "invalid RANGE frame offset; found {}", offset)
+                            },
+                        },
+                        _ => {
+                            polars_bail!(SQLSyntax: "invalid RANGE frame offset; found {}", offset)
+                        },
+                    };
+                    agg.rolling_by(values, order_by[0].clone(), window_size)
+                },
+                _ => polars_bail!(
+                    SQLInterface: "RANGE frames are only supported from UNBOUNDED PRECEDING or an offset PRECEDING to CURRENT ROW; found {}",
+                    frame
+                ),
+            },
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS window frames are not currently supported")
+            },
+        };
+        // frames without any rows count zero rows
+        let expr = match agg {
+            FrameAggregate::Count => expr.fill_null(lit(0)),
+            _ => expr,
+        };
+
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        expr.over_with_options(
+            (!partition_by.is_empty()).then_some(partition_by),
+            Some((order_by, sort_options)),
+            WindowMapping::GroupsToRows,
+        )
+    }
+
+    /// Number or rank the rows of each window partition, in the order of the window's
+    /// ORDER BY clause; rows with equal ORDER BY values (peers) share the same rank.
+    /// e.g. RANK() OVER (PARTITION BY a ORDER BY b)
+    fn visit_ranking(&mut self, ranking: RankingFunction) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        if !args.is_empty() {
+            return self.not_supported_error();
+        }
+        let spec = self.required_window_spec()?;
+        let order = self.parse_window_order_by(spec)?;
+
+        let row = int_range(lit(0), len(), 1, IDX_DTYPE);
+        let is_new_peer = || {
+            order
+                .iter()
+                .flat_map(|(by, _)| by)
+                .fold(row.clone().shift(lit(1)).is_null(), |acc, e| {
+                    acc.or(e.clone().neq_missing(e.clone().shift(lit(1))))
+                })
+        };
+        let expr = match ranking {
+            RankingFunction::DenseRank => is_new_peer().cast(IDX_DTYPE).cum_sum(false),
+            RankingFunction::Rank => when(is_new_peer())
+                .then(row.clone() + lit(1))
+                .otherwise(lit(LiteralValue::untyped_null()))
+                .fill_null_with_strategy(FillNullStrategy::Forward(None)),
+            RankingFunction::RowNumber => row + lit(1),
+        }
+        .strict_cast(IDX_DTYPE);
+
+        Ok(self
+            .apply_window_partition(expr, spec, order)?
+            .alias(ranking.name()))
+    }
+
+    /// Return the first or last value of the window frame, which is the whole partition if
+    /// the window is not ordered, and ends at the current row (and its peers) otherwise.
+    /// e.g. LAST_VALUE(a IGNORE NULLS) OVER (PARTITION BY b ORDER BY c)
+    fn visit_first_last_value(&mut self, last: bool, ignore_nulls: bool) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let [FunctionArgExpr::Expr(sql_expr)] = args.as_slice() else {
+            return self.not_supported_error();
+        };
+        let spec = self.required_window_spec()?;
+        if let Some(frame) = &spec.window_frame {
+            polars_bail!(SQLInterface: "window frames are not supported for {}; found {}", self.func.name, frame)
+        }
+        let order = self.parse_window_order_by(spec)?;
+        let value = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
+
+        let expr = match (&order, last) {
+            (Some((order_by, _)), true) => {
+                // the frame ends at the last peer of the current row
+                let value = if ignore_nulls {
+                    value.fill_null_with_strategy(FillNullStrategy::Forward(None))
+                } else {
+                    value
+                };
+                value.gather(last_peer_index(order_by))
+            },
+            (Some((order_by, _)), false) if ignore_nulls => {
+                // the frame starts at the first row, so it has a value from the first
+                // non-null one on, up to the last peer of the current row
+                let seen = value
+                    .clone()
+                    .is_not_null()
+                    .cast(IDX_DTYPE)
+                    .cum_sum(false)
+                    .gt(lit(0));
+                when(seen)
+                    .then(value.drop_nulls().first())
+                    .otherwise(lit(LiteralValue::untyped_null()))
+                    .gather(last_peer_index(order_by))
+            },
+            _ => {
+                let value = if ignore_nulls {
+                    value.drop_nulls()
+                } else {
+                    value
+                };
+                if last { value.last() } else { value.first() }
+            },
+        };
+        self.apply_window_partition(expr, spec, order)
+    }
+
+    /// Evaluate an aggregate on the rows satisfying its FILTER clause, by filtering the
+    /// columns it refers to; e.g. SUM(a) FILTER (WHERE b > 0) -> SUM(a.filter(b > 0))
+    fn visit_filtered_aggregate(&mut self, filter: &SQLExpr) -> PolarsResult<Expr> {
+        if let Some(WindowType::WindowSpec(spec)) = &self.func.over {
+            if !spec.order_by.is_empty() || spec.window_frame.is_some() {
+                polars_bail!(SQLInterface: "FILTER is not supported with an ordered window; found {}", self.func)
+            }
+        }
+        let cond = parse_sql_expr(filter, self.ctx, self.active_schema)?;
+        let func = SQLFunction {
+            filter: None,
+            over: None,
+            ..self.func.clone()
+        };
+        let expr = SQLFunctionVisitor {
+            func: &func,
+            ctx: self.ctx,
+            active_schema: self.active_schema,
+        }
+        .visit_function()?
+        .map_expr(|e| match e {
+            Expr::Column(name) => col(name).filter(cond.clone()),
+            Expr::Len => cond.clone().filter(cond.clone()).len(),
+            e => e,
+        });
+        self.apply_window_spec(expr, &self.func.over)
+    }
+
+    /// Visit the PERCENTILE_CONT and PERCENTILE_DISC ordered-set aggregates, which take the
+    /// column from their WITHIN GROUP clause.
+    /// e.g. PERCENTILE_CONT(0.9) WITHIN GROUP (ORDER BY a) -> QUANTILE(a, 0.9)
+    fn visit_percentile(&mut self, method: QuantileMethod) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let [FunctionArgExpr::Expr(sql_expr)] = args.as_slice() else {
+            polars_bail!(SQLSyntax: "{} expects 1 argument (found {})", self.func.name, args.len())
+        };
+        let [order_by] = self.func.within_group.as_slice() else {
+            polars_bail!(SQLSyntax: "{} requires a WITHIN GROUP (ORDER BY ...) clause with a single column; found {}", self.func.name, self.func)
+        };
+        let fraction = match parse_sql_expr(sql_expr, self.ctx, self.active_schema)? {
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Float(f))) => f,
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) => n as f64,
+            _ => polars_bail!(SQLSyntax: "invalid value for {} ({})", self.func.name, sql_expr),
+        };
+        if !(0.0..=1.0).contains(&fraction) {
+            polars_bail!(SQLSyntax: "{} value must be between 0 and 1 ({})", self.func.name, sql_expr)
+        }
+        let fraction = if order_by.asc.unwrap_or(true) {
+            fraction
+        } else if matches!(method, QuantileMethod::Linear) {
+            1.0 - fraction
+        } else {
+            polars_bail!(SQLInterface: "{} does not support a descending order; found {}", self.func.name, self.func)
+        };
+        let expr = parse_sql_expr(&order_by.expr, self.ctx, self.active_schema)?;
+        self.apply_window_spec(expr.quantile(fraction.into(), method), &self.func.over)
+    }
+
+    /// Get the specification of the window of a function that requires an OVER clause.
+    fn required_window_spec(&self) -> PolarsResult<&'a WindowSpec> {
+        match self.func.over.as_ref() {
+            Some(WindowType::WindowSpec(spec)) => Ok(spec),
+            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
+                SQLInterface: "Named windows are not currently supported; found {:?}",
+                named_window
+            ),
+            None => {
+                polars_bail!(SQLSyntax: "{} requires an OVER clause; found {}", self.func.name, self.func)
+            },
+        }
+    }
+
+    /// Evaluate the expression over the partitions of the window, in the window's order.
+    fn apply_window_partition(
+        &mut self,
+        expr: Expr,
+        spec: &WindowSpec,
+        order: Option<(Vec<Expr>, SortOptions)>,
+    ) -> PolarsResult<Expr> {
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(if partition_by.is_empty() && order.is_none() {
+            expr
+        } else {
+            expr.over_with_options(
+                (!partition_by.is_empty()).then_some(partition_by),
+                order,
+                WindowMapping::GroupsToRows,
+            )
+        })
+    }
+
+    /// Parse the ORDER BY clause of a window, which must have the same sort order for all
+    /// of its columns; returns `None` if the window is not ordered.
+    fn parse_window_order_by(
+        &mut self,
+        spec: &WindowSpec,
+    ) -> PolarsResult<Option<(Vec<Expr>, SortOptions)>> {
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        let mut sort_options: Option<SortOptions> = None;
+        for ob in &spec.order_by {
+            let desc_order = !ob.asc.unwrap_or(true);
+            let options = SortOptions::default()
+                .with_order_descending(desc_order)
+                .with_nulls_last(!ob.nulls_first.unwrap_or(desc_order));
+            if sort_options.replace(options).is_some_and(|o| o != options) {
+                polars_bail!(SQLInterface: "window ORDER BY requires all columns to have the same sort order; found {}", self.func)
+            }
+            order_by.push(parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?);
+        }
+        Ok(sort_options.map(|options| (order_by, options)))
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
@@ -1909,6 +2454,198 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Index of the last row having the same ORDER BY values (the last peer) as each row.
+fn last_peer_index(order_by: &[Expr]) -> Expr {
+    let row = int_range(lit(0), len(), 1, IDX_DTYPE);
+    let is_last_peer = order_by
+        .iter()
+        .fold(row.clone().shift(lit(-1)).is_null(), |acc, e| {
+            acc.or(e.clone().neq_missing(e.clone().shift(lit(-1))))
+        });
+    when(is_last_peer)
+        .then(row)
+        .otherwise(lit(LiteralValue::untyped_null()))
+        .fill_null_with_strategy(FillNullStrategy::Backward(None))
+}
+
+/// Move an IGNORE|RESPECT NULLS clause given with the arguments of a function, as in
+/// `LAST_VALUE(a IGNORE NULLS)`, after the arguments; returns `None` if there is none.
+fn move_null_treatment_clause(func: &SQLFunction) -> Option<SQLFunction> {
+    let FunctionArguments::List(args) = &func.args else {
+        return None;
+    };
+    let null_treatment = args.clauses.iter().find_map(|clause| match clause {
+        FunctionArgumentClause::IgnoreOrRespectNulls(NullTreatment::IgnoreNulls) => {
+            Some(NullTreatment::IgnoreNulls)
+        },
+        FunctionArgumentClause::IgnoreOrRespectNulls(NullTreatment::RespectNulls) => {
+            Some(NullTreatment::RespectNulls)
+        },
+        _ => None,
+    })?;
+    let mut args = args.clone();
+    args.clauses
+        .retain(|clause| !matches!(clause, FunctionArgumentClause::IgnoreOrRespectNulls(_)));
+    Some(SQLFunction {
+        args: FunctionArguments::List(args),
+        null_treatment: Some(null_treatment),
+        ..func.clone()
+    })
+}
+
+/// Functions numbering or ranking the rows of a window partition.
+#[derive(Clone, Copy)]
+enum RankingFunction {
+    DenseRank,
+    Rank,
+    RowNumber,
+}
+
+impl RankingFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::DenseRank => "dense_rank",
+            Self::Rank => "rank",
+            Self::RowNumber => "row_number",
+        }
+    }
+}
+
+/// Aggregates that can be evaluated over an explicit window frame.
+#[derive(Clone, Copy)]
+enum FrameAggregate {
+    /// Sum of the (non-null indicator of the) counted rows.
+    Count,
+    Max,
+    Mean,
+    Min,
+    Sum,
+}
+
+impl FrameAggregate {
+    fn aggregate(self, e: Expr) -> Expr {
+        match self {
+            Self::Count | Self::Sum => e.sum(),
+            Self::Max => e.max(),
+            Self::Mean => e.mean(),
+            Self::Min => e.min(),
+        }
+    }
+
+    /// Aggregate of all rows up to the current row, or from the current row onwards
+    /// if `reverse` is set.
+    fn cumulative(self, e: Expr, reverse: bool) -> Expr {
+        // cumulative functions leave nulls in place, the aggregate ignores them
+        let fill = if reverse {
+            FillNullStrategy::Backward(None)
+        } else {
+            FillNullStrategy::Forward(None)
+        };
+        match self {
+            Self::Count | Self::Sum => e.cum_sum(reverse).fill_null_with_strategy(fill),
+            Self::Max => e.cum_max(reverse).fill_null_with_strategy(fill),
+            Self::Mean => {
+                e.clone().cum_sum(reverse).fill_null_with_strategy(fill) / e.cum_count(reverse)
+            },
+            Self::Min => e.cum_min(reverse).fill_null_with_strategy(fill),
+        }
+    }
+
+    /// Aggregate of the `window_size` rows ending at the current row.
+    fn rolling(self, e: Expr, window_size: usize) -> Expr {
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods: 1,
+            ..Default::default()
+        };
+        match self {
+            Self::Count | Self::Sum => e.rolling_sum(options),
+            Self::Max => e.rolling_max(options),
+            Self::Mean => e.rolling_mean(options),
+            Self::Min => e.rolling_min(options),
+        }
+    }
+
+    /// Aggregate of the rows whose `by` value is at most `window_size` before the value of
+    /// the current row, including its peers.
+    fn rolling_by(self, e: Expr, by: Expr, window_size: Duration) -> Expr {
+        let options = RollingOptionsDynamicWindow {
+            window_size,
+            min_periods: 1,
+            closed_window: ClosedWindow::Both,
+            fn_params: None,
+        };
+        match self {
+            Self::Count | Self::Sum => e.rolling_sum_by(by, options),
+            Self::Max => e.rolling_max_by(by, options),
+            Self::Mean => e.rolling_mean_by(by, options),
+            Self::Min => e.rolling_min_by(by, options),
+        }
+    }
+}
+
+/// Returns the offset of a ROWS frame bound relative to the current row, or `None` if the
+/// bound is unbounded.
+fn rows_frame_offset(bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+    let (offset, sign) = match bound {
+        WindowFrameBound::CurrentRow => return Ok(Some(0)),
+        WindowFrameBound::Preceding(None) | WindowFrameBound::Following(None) => {
+            return Ok(None);
+        },
+        WindowFrameBound::Preceding(Some(offset)) => (offset, -1),
+        WindowFrameBound::Following(Some(offset)) => (offset, 1),
+    };
+    match offset.as_ref() {
+        SQLExpr::Value(SQLValue::Number(s, _)) => match s.parse::<i64>() {
+            Ok(n) if n >= 0 => Ok(Some(sign * n)),
+            _ => polars_bail!(SQLSyntax: "invalid ROWS frame offset; found {}", offset),
+        },
+        _ => polars_bail!(SQLSyntax: "invalid ROWS frame offset; found {}", offset),
+    }
+}
+
+/// Aggregates the (ordered) rows from `start` to `end` relative to the current row, where
+/// `None` is unbounded.
+fn rows_frame(agg: FrameAggregate, e: Expr, start: Option<i64>, end: Option<i64>) -> Expr {
+    // the cumulative aggregate of row `i + offset`; rows past the last one repeat the
+    // aggregate of all rows, rows before the first one have an empty frame
+    let shift_cumulative = |e: Expr, offset: i64, fill: FillNullStrategy| {
+        if offset == 0 {
+            e
+        } else {
+            e.shift(lit(-offset)).fill_null_with_strategy(fill)
+        }
+    };
+    match (start, end) {
+        (None, None) => agg.aggregate(e),
+        (None, Some(end)) => shift_cumulative(
+            agg.cumulative(e, false),
+            end,
+            FillNullStrategy::Forward(None),
+        ),
+        (Some(start), None) => shift_cumulative(
+            agg.cumulative(e, true),
+            start,
+            FillNullStrategy::Backward(None),
+        ),
+        (Some(start), Some(end)) => {
+            let window_size = (end - start + 1) as usize;
+            if end > 0 {
+                // pad with nulls, such that the frames of the last rows are truncated, and
+                // take the window ending at row `i + end` for row `i`
+                let len = e.clone().len();
+                agg.rolling(
+                    e.extend_constant(lit(LiteralValue::untyped_null()), lit(end)),
+                    window_size,
+                )
+                .slice(lit(end), len)
+            } else {
+                agg.rolling(e, window_size).shift(lit(-end))
+            }
+        },
+    }
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
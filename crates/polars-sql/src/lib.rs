@@ -1,6 +1,7 @@
 //! Polars SQL
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
+mod catalog;
 mod context;
 pub mod function_registry;
 mod functions;
@@ -131,7 +131,16 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
-            SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
+            SQLExpr::Interval(interval) => {
+                let duration = interval_to_duration(interval)?;
+                // years, quarters, and months do not have a fixed duration; such
+                // intervals become calendar-aware `Interval` values instead
+                if duration.months() != 0 {
+                    Ok(lit(Scalar::new_interval(duration_to_interval(&duration)?)))
+                } else {
+                    Ok(lit(duration))
+                }
+            },
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
             },
@@ -469,14 +478,14 @@ impl SQLExprVisitor<'_> {
         // need special handling for interval offsets and comparisons
         let (lhs, mut rhs) = match (left, op, right) {
             (_, SQLBinaryOperator::Minus, SQLExpr::Interval(v)) => {
-                let duration = interval_to_duration(v, false)?;
+                let duration = interval_to_duration(v)?;
                 return Ok(self
                     .visit_expr(left)?
                     .dt()
                     .offset_by(lit(format!("-{duration}"))));
             },
             (_, SQLBinaryOperator::Plus, SQLExpr::Interval(v)) => {
-                let duration = interval_to_duration(v, false)?;
+                let duration = interval_to_duration(v)?;
                 return Ok(self
                     .visit_expr(left)?
                     .dt()
@@ -484,8 +493,8 @@ impl SQLExprVisitor<'_> {
             },
             (SQLExpr::Interval(v1), _, SQLExpr::Interval(v2)) => {
                 // shortcut interval comparison evaluation (-> bool)
-                let d1 = interval_to_duration(v1, false)?;
-                let d2 = interval_to_duration(v2, false)?;
+                let d1 = interval_to_duration(v1)?;
+                let d2 = interval_to_duration(v2)?;
                 let res = match op {
                     SQLBinaryOperator::Gt => Ok(lit(d1 > d2)),
                     SQLBinaryOperator::Lt => Ok(lit(d1 < d2)),
@@ -1080,7 +1089,7 @@ pub fn sql_expr<S: AsRef<str>>(s: S) -> PolarsResult<Expr> {
     })
 }
 
-pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsResult<Duration> {
+pub(crate) fn interval_to_duration(interval: &Interval) -> PolarsResult<Duration> {
     if interval.last_field.is_some()
         || interval.leading_field.is_some()
         || interval.leading_precision.is_some()
@@ -1099,19 +1108,29 @@ pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsRe
         Some(s) if s.contains('-') => {
             polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
         },
-        Some(s) => {
-            // years, quarters, and months do not have a fixed duration; these
-            // interval parts can only be used with respect to a reference point
-            let duration = Duration::parse_interval(s);
-            if fixed && duration.months() != 0 {
-                polars_bail!(SQLSyntax: "fixed-duration interval cannot contain years, quarters, or months; found {}", s)
-            };
-            Ok(duration)
-        },
+        Some(s) => Ok(Duration::parse_interval(s)),
         None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
     }
 }
 
+fn duration_to_interval(duration: &Duration) -> PolarsResult<months_days_ns> {
+    let months = i32::try_from(duration.months());
+    let days = duration
+        .weeks()
+        .checked_mul(7)
+        .and_then(|d| d.checked_add(duration.days()))
+        .and_then(|d| i32::try_from(d).ok());
+    let (Ok(months), Some(days)) = (months, days) else {
+        polars_bail!(SQLSyntax: "interval out of range; found {}", duration)
+    };
+    let interval = months_days_ns::new(months, days, duration.nanoseconds());
+    Ok(if duration.negative() {
+        -interval
+    } else {
+        interval
+    })
+}
+
 pub(crate) fn parse_sql_expr(
     expr: &SQLExpr,
     ctx: &mut SQLContext,
@@ -99,6 +99,7 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         SQLDataType::UInt16 => DataType::UInt16,
         SQLDataType::UInt32 => DataType::UInt32,
         SQLDataType::UInt64 => DataType::UInt64,
+        SQLDataType::UInt128 => DataType::UInt128,
         SQLDataType::UnsignedTinyInt(_) => DataType::UInt8,
         SQLDataType::UnsignedInt(_) | SQLDataType::UnsignedInteger(_) => DataType::UInt32,
         SQLDataType::UnsignedInt2(_) | SQLDataType::UnsignedSmallInt(_) => DataType::UInt16,
@@ -184,6 +185,7 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
                 "uint2" | "usmallint" => DataType::UInt16,
                 "uint4" | "uinteger" | "uint" => DataType::UInt32,
                 "uint8" | "ubigint" => DataType::UInt64,
+                "uhugeint" => DataType::UInt128,
                 _ => {
                     polars_bail!(SQLInterface: "datatype {:?} is not currently supported", value)
                 },
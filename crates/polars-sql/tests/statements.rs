@@ -601,3 +601,28 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+#[test]
+fn test_nested_views() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE VIEW v1 AS SELECT b FROM df WHERE b > 3")
+        .unwrap();
+    ctx.execute("CREATE VIEW v2 (total) AS SELECT SUM(v1.b) FROM v1")
+        .unwrap();
+    assert_eq!(ctx.get_tables(), ["df", "v1", "v2"]);
+
+    // the views are planned again when they are used, so they see the renamed table
+    ctx.execute("ALTER TABLE df RENAME TO tmp").unwrap();
+    assert!(ctx.execute("SELECT * FROM v2").is_err());
+    ctx.execute("ALTER TABLE tmp RENAME TO df").unwrap();
+
+    let actual = ctx.execute("SELECT * FROM v2").unwrap().collect().unwrap();
+    let expected = df! {
+        "total" => [39i64],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use polars_core::frame::UniqueKeepStrategy;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+/// The rows we buffer, at most one per key after compaction.
+struct DistinctBuffer {
+    df: DataFrame,
+    compacted_height: usize,
+}
+
+enum DistinctState {
+    Sink(DistinctBuffer),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+/// Keeps the last row of every distinct value of `keys`, in the order those rows occurred.
+///
+/// The order-preserving group by orders groups by their first occurrence, so it can't be used
+/// for this.
+pub struct DistinctNode {
+    keys: Vec<PlSmallStr>,
+    state: DistinctState,
+}
+
+impl DistinctNode {
+    pub fn new(input_schema: Arc<Schema>, keys: Vec<PlSmallStr>) -> Self {
+        Self {
+            keys,
+            state: DistinctState::Sink(DistinctBuffer {
+                df: DataFrame::empty_with_schema(&input_schema),
+                compacted_height: 0,
+            }),
+        }
+    }
+}
+
+/// Reduces the buffer to the last row per key, in order of occurrence of that row.
+fn compact(df: &DataFrame, keys: &[PlSmallStr]) -> PolarsResult<DataFrame> {
+    df.unique_impl(true, Some(keys.to_vec()), UniqueKeepStrategy::Last, None)
+}
+
+impl ComputeNode for DistinctNode {
+    fn name(&self) -> &str {
+        "distinct"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = DistinctState::Done;
+            },
+            // Input is done, transition to being a source.
+            DistinctState::Sink(buffer) if recv[0] == PortState::Done => {
+                let df = compact(&buffer.df, &self.keys)?;
+                self.state = DistinctState::Source(InMemorySourceNode::new(
+                    Arc::new(df),
+                    MorselSeq::default(),
+                ));
+            },
+            // Nothing to change.
+            _ => {},
+        }
+
+        // Communicate our state.
+        match &mut self.state {
+            DistinctState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            DistinctState::Source(src) => {
+                src.update_state(&mut [], send, state)?;
+                recv[0] = PortState::Done;
+            },
+            DistinctState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, DistinctState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+
+        let keys = &self.keys;
+        match &mut self.state {
+            DistinctState::Sink(buffer) => {
+                assert!(send_ports[0].is_none());
+                // The last row of a key depends on the order of the input, so we always need
+                // it in order.
+                let mut recv = recv_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let morsel_size = get_ideal_morsel_size();
+                    while let Ok(morsel) = recv.recv().await {
+                        buffer.df.vstack_mut_owned(morsel.into_df())?;
+
+                        // Compact once the buffer doubled in size, such that we keep at most
+                        // about twice the number of distinct keys in memory.
+                        if buffer.df.height() >= 2 * buffer.compacted_height.max(morsel_size) {
+                            buffer.df = compact(&buffer.df, keys)?;
+                            buffer.compacted_height = buffer.df.height();
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+            DistinctState::Source(src) => {
+                assert!(recv_ports[0].is_none());
+                src.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            DistinctState::Done => unreachable!(),
+        }
+    }
+}
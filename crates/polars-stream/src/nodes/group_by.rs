@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use polars_core::POOL;
@@ -22,12 +23,23 @@ use crate::async_primitives::connector::Receiver;
 use crate::expression::StreamExpr;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    MemoryBudget, MemoryReservation, SpillFile, get_spill_memory_limit, hstack_spill_keys,
+    split_spill_keys,
+};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
 #[cfg(not(debug_assertions))]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4096;
 
+/// Cold morsels of a single partition which were spilled to disk, each spilled frame consists
+/// of the key columns followed by the reduction columns.
+struct SpilledMorsels {
+    seqs: Vec<u64>,
+    file: SpillFile,
+}
+
 struct LocalGroupBySinkState {
     hot_grouper: Box<dyn HotGrouper>,
     hot_grouped_reductions: Vec<Box<dyn GroupedReduction>>,
@@ -43,6 +55,11 @@ struct LocalGroupBySinkState {
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
 
+    // The key columns of cold_morsels, only kept if we might have to spill them.
+    cold_key_dfs: Vec<DataFrame>,
+    cold_morsels_reservation: MemoryReservation,
+    spilled_per_p: Vec<Vec<SpilledMorsels>>,
+
     // Similar to the above, but for (evicted) pre-aggregates.
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
@@ -55,6 +72,7 @@ impl LocalGroupBySinkState {
         reductions: Vec<Box<dyn GroupedReduction>>,
         hot_table_size: usize,
         num_partitions: usize,
+        spill_budget: &MemoryBudget,
     ) -> Self {
         let hot_grouper = new_hash_hot_grouper(key_schema, hot_table_size);
         Self {
@@ -67,6 +85,10 @@ impl LocalGroupBySinkState {
             morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
             morsel_idxs_offsets_per_p: vec![0; num_partitions],
 
+            cold_key_dfs: Vec::new(),
+            cold_morsels_reservation: spill_budget.empty_reservation(),
+            spilled_per_p: (0..num_partitions).map(|_| Vec::new()).collect(),
+
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
@@ -99,6 +121,49 @@ impl LocalGroupBySinkState {
             .extend(self.pre_agg_idxs_values_per_p.iter().map(|vp| vp.len()));
         self.pre_aggs.push((hash_keys, reductions));
     }
+
+    /// Writes the cold morsels to disk, with a separate spill file per partition.
+    fn spill_cold_morsels(&mut self) -> PolarsResult<()> {
+        let num_partitions = self.morsel_idxs_values_per_p.len();
+        for p in 0..num_partitions {
+            let mut seqs = Vec::new();
+            let mut dfs = Vec::new();
+            for (i, ((seq, _keys, cols), key_df)) in
+                self.cold_morsels.iter().zip(&self.cold_key_dfs).enumerate()
+            {
+                let p_morsel_idxs_start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let p_morsel_idxs_stop =
+                    self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                if p_morsel_idxs_start == p_morsel_idxs_stop {
+                    continue;
+                }
+                let p_morsel_idxs =
+                    &self.morsel_idxs_values_per_p[p][p_morsel_idxs_start..p_morsel_idxs_stop];
+                unsafe {
+                    dfs.push(hstack_spill_keys(
+                        key_df.take_slice_unchecked_impl(p_morsel_idxs, false),
+                        cols.take_slice_unchecked_impl(p_morsel_idxs, false),
+                    ));
+                }
+                seqs.push(*seq);
+            }
+
+            if let Some(df) = dfs.first() {
+                let file = SpillFile::write(df.schema().clone(), dfs)?;
+                self.spilled_per_p[p].push(SpilledMorsels { seqs, file });
+            }
+        }
+
+        self.cold_morsels.clear();
+        self.cold_key_dfs.clear();
+        for idxs in &mut self.morsel_idxs_values_per_p {
+            idxs.clear();
+        }
+        self.morsel_idxs_offsets_per_p.clear();
+        self.morsel_idxs_offsets_per_p.resize(num_partitions, 0);
+        self.cold_morsels_reservation.release();
+        Ok(())
+    }
 }
 
 struct GroupBySinkState {
@@ -110,6 +175,7 @@ struct GroupBySinkState {
     locals: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
     partitioner: HashPartitioner,
+    spill_budget: MemoryBudget,
 }
 
 impl GroupBySinkState {
@@ -126,6 +192,7 @@ impl GroupBySinkState {
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
+            let spill_budget = &self.spill_budget;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -192,6 +259,18 @@ impl GroupBySinkState {
                                 .morsel_idxs_offsets_per_p
                                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
                             local.cold_morsels.push((seq, cold_keys, cold_df));
+
+                            // If we may have to spill we need the original keys to rebuild the
+                            // hash keys when reading them back.
+                            if spill_budget.limit().is_some() {
+                                let cold_key_df = keys.take_slice_unchecked_impl(&cold_idxs, false);
+                                let (_, _, cold_df) = local.cold_morsels.last().unwrap();
+                                let size = cold_df.estimated_size() + cold_key_df.estimated_size();
+                                local.cold_key_dfs.push(cold_key_df);
+                                if !local.cold_morsels_reservation.try_grow(size) {
+                                    local.spill_cold_morsels()?;
+                                }
+                            }
                         }
                     }
 
@@ -205,7 +284,16 @@ impl GroupBySinkState {
         }
     }
 
-    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
+    /// Combine the local states into the final groups per partition.
+    ///
+    /// Partitions of which data was spilled are combined one at a time, and their output is
+    /// spilled as well, such that memory usage stays bounded. Returns the in-memory partitions
+    /// and the spilled output.
+    fn combine_locals(
+        &mut self,
+        key_schema: &Schema,
+        output_schema: &Arc<Schema>,
+    ) -> PolarsResult<(Vec<GroupByPartition>, Vec<SpillFile>)> {
         // Finalize pre-aggregations.
         POOL.install(|| {
             self.locals
@@ -242,12 +330,15 @@ impl GroupBySinkState {
         }
         let (drop_q_send, drop_q_recv) = async_channel::bounded(self.locals.len());
         let num_partitions = self.locals[0].sketch_per_p.len();
-        let output_per_partition: SparseInitVec<GroupByPartition> =
+        let output_per_partition: SparseInitVec<PartitionOutput> =
             SparseInitVec::with_capacity(num_partitions);
+        let spill_permit = tokio::sync::Semaphore::new(1);
         let locals = &self.locals;
         let grouper_template = &self.grouper;
         let grouped_reductions_template = &self.grouped_reductions;
         let grouped_reduction_cols = &self.grouped_reduction_cols;
+        let random_state = &self.random_state;
+        let num_keys = self.key_selectors.len();
 
         async_executor::task_scope(|s| {
             // Wrap in outer Arc to move to each thread, performing the
@@ -261,7 +352,16 @@ impl GroupBySinkState {
                 let drop_q_send = drop_q_send.clone();
                 let drop_q_recv = drop_q_recv.clone();
                 let output_per_partition = &output_per_partition;
+                let spill_permit = &spill_permit;
                 join_handles.push(s.spawn_task(TaskPriority::High, async move {
+                    // Only keep a single spilled partition in memory at a time.
+                    let is_spilled = locals.iter().any(|l| !l.spilled_per_p[p].is_empty());
+                    let permit = if is_spilled {
+                        Some(spill_permit.acquire().await.unwrap())
+                    } else {
+                        None
+                    };
+
                     // Extract from outer arc and drop outer arc.
                     let morsels_per_local = Arc::unwrap_or_clone(arc_morsels_per_local);
                     let pre_aggs_per_local = Arc::unwrap_or_clone(arc_pre_aggs_per_local);
@@ -334,6 +434,41 @@ impl GroupBySinkState {
                         }
                     }
 
+                    // Insert spilled morsels.
+                    let mut all_idxs: Vec<IdxSize> = Vec::new();
+                    for l in locals {
+                        for spilled in &l.spilled_per_p[p] {
+                            let mut reader = spilled.file.reader()?;
+                            for seq_id in &spilled.seqs {
+                                let df = reader.next_df()?.unwrap();
+                                let (key_df, cols) = split_spill_keys(df, num_keys);
+                                let keys = HashKeys::from_df(&key_df, *random_state, true, false);
+                                all_idxs.clear();
+                                all_idxs.extend(0..keys.len() as IdxSize);
+                                unsafe {
+                                    group_idxs.clear();
+                                    p_grouper.insert_keys_subset(
+                                        &keys,
+                                        &all_idxs,
+                                        Some(&mut group_idxs),
+                                    );
+                                    for (c, r) in
+                                        grouped_reduction_cols.iter().zip(&mut p_reductions)
+                                    {
+                                        let values = cols.column(c.as_str()).unwrap();
+                                        r.resize(p_grouper.num_groups());
+                                        r.update_groups_subset(
+                                            values,
+                                            &all_idxs,
+                                            &group_idxs,
+                                            *seq_id,
+                                        )?;
+                                    }
+                                }
+                            }
+                        }
+                    }
+
                     // Insert pre-aggregates.
                     for (l, l_pre_aggs) in locals.iter().zip(pre_aggs_per_local) {
                         // Try to help with dropping.
@@ -375,22 +510,28 @@ impl GroupBySinkState {
                         }
                     }
 
+                    let partition = GroupByPartition {
+                        grouper: p_grouper,
+                        grouped_reductions: p_reductions,
+                    };
+                    let output = if is_spilled {
+                        let df = partition.into_df(key_schema, output_schema)?;
+                        let morsel_size = get_ideal_morsel_size();
+                        let dfs = (0..df.height().div_ceil(morsel_size))
+                            .map(|i| df.slice((i * morsel_size) as i64, morsel_size));
+                        PartitionOutput::Spilled(SpillFile::write(output_schema.clone(), dfs)?)
+                    } else {
+                        PartitionOutput::InMemory(partition)
+                    };
+                    drop(permit);
+
                     // We're done, help others out by doing drops.
                     drop(drop_q_send); // So we don't deadlock trying to receive from ourselves.
                     while let Ok(to_drop) = drop_q_recv.recv().await {
                         drop(to_drop);
                     }
 
-                    output_per_partition
-                        .try_set(
-                            p,
-                            GroupByPartition {
-                                grouper: p_grouper,
-                                grouped_reductions: p_reductions,
-                            },
-                        )
-                        .ok()
-                        .unwrap();
+                    output_per_partition.try_set(p, output).ok().unwrap();
 
                     PolarsResult::Ok(())
                 }));
@@ -421,10 +562,23 @@ impl GroupBySinkState {
                 .for_each(drop);
         });
 
-        Ok(output_per_partition.try_assume_init().ok().unwrap())
+        let mut partitions = Vec::new();
+        let mut spilled = Vec::new();
+        for output in output_per_partition.try_assume_init().ok().unwrap() {
+            match output {
+                PartitionOutput::InMemory(partition) => partitions.push(partition),
+                PartitionOutput::Spilled(file) => spilled.push(file),
+            }
+        }
+        Ok((partitions, spilled))
     }
 }
 
+enum PartitionOutput {
+    InMemory(GroupByPartition),
+    Spilled(SpillFile),
+}
+
 struct GroupByPartition {
     grouper: Box<dyn Grouper>,
     grouped_reductions: Vec<Box<dyn GroupedReduction>>,
@@ -453,6 +607,9 @@ pub struct GroupByNode {
     state: GroupByState,
     key_schema: Arc<Schema>,
     output_schema: Arc<Schema>,
+    // The output of spilled partitions, emitted one at a time after the in-memory output.
+    spilled_output: VecDeque<SpillFile>,
+    next_source_seq: u64,
 }
 
 impl GroupByNode {
@@ -477,6 +634,7 @@ impl GroupByNode {
             .collect::<PlHashSet<_>>()
             .into_iter()
             .collect_vec();
+        let spill_budget = MemoryBudget::new(get_spill_memory_limit());
         let locals = (0..num_pipelines)
             .map(|_| {
                 let reductions = grouped_reductions.iter().map(|gr| gr.new_empty()).collect();
@@ -485,6 +643,7 @@ impl GroupByNode {
                     reductions,
                     hot_table_size,
                     num_partitions,
+                    &spill_budget,
                 )
             })
             .collect();
@@ -499,9 +658,12 @@ impl GroupByNode {
                 grouped_reduction_cols,
                 locals,
                 partitioner,
+                spill_budget,
             }),
             key_schema,
             output_schema,
+            spilled_output: VecDeque::new(),
+            next_source_seq: 0,
         }
     }
 }
@@ -532,7 +694,8 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
-                let partitions = sink.combine_locals()?;
+                let (partitions, spilled_output) =
+                    sink.combine_locals(&self.key_schema, &self.output_schema)?;
                 let dfs = POOL.install(|| {
                     partitions
                         .into_par_iter()
@@ -541,14 +704,27 @@ impl ComputeNode for GroupByNode {
                 })?;
 
                 let df = accumulate_dataframes_vertical_unchecked(dfs);
+                // The source sends at most one morsel per row, but at least one.
+                self.next_source_seq = df.height() as u64 + 1;
+                self.spilled_output = spilled_output.into();
                 let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
                 self.state = GroupByState::Source(source);
             },
-            // Defer to source node implementation.
+            // Defer to source node implementation, moving on to the next spilled output once
+            // the current one is exhausted.
             GroupByState::Source(src) => {
                 src.update_state(&mut [], send, state)?;
-                if send[0] == PortState::Done {
-                    self.state = GroupByState::Done;
+                while send[0] == PortState::Done {
+                    let Some(file) = self.spilled_output.pop_front() else {
+                        self.state = GroupByState::Done;
+                        break;
+                    };
+                    let df = file.read_df()?;
+                    let seq = MorselSeq::new(self.next_source_seq);
+                    self.next_source_seq += df.height() as u64 + 1;
+                    *src = InMemorySourceNode::new(Arc::new(df), seq);
+                    send[0] = PortState::Ready;
+                    src.update_state(&mut [], send, state)?;
                 }
             },
             // Nothing to change.
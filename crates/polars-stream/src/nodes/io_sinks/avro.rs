@@ -0,0 +1,184 @@
+use std::cmp::Reverse;
+use std::pin::Pin;
+
+use arrow::io::avro::avro_schema::schema::Record;
+use arrow::io::avro::write::to_record;
+use polars_core::prelude::CompatLevel;
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_io::avro::{AvroBlockEncoder, AvroCompression, AvroWriterOptions, write_avro_header};
+use polars_io::cloud::CloudOptions;
+use polars_io::schema_to_arrow_checked;
+use polars_plan::dsl::{SinkOptions, SinkTarget};
+use polars_utils::priority::Priority;
+
+use super::{SinkInputPort, SinkNode};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::{Receiver, Sender, connector};
+use crate::async_primitives::linearizer::Linearizer;
+use crate::execute::StreamingExecutionState;
+use crate::morsel::MorselSeq;
+use crate::nodes::io_sinks::parallelize_receive_task;
+use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::{JoinHandle, TaskPriority};
+
+type IOSend = Linearizer<Priority<Reverse<MorselSeq>, Vec<u8>>>;
+
+pub struct AvroSinkNode {
+    target: SinkTarget,
+    sink_options: SinkOptions,
+    record: Record,
+    compression: Option<AvroCompression>,
+    cloud_options: Option<CloudOptions>,
+
+    io_tx: Option<Sender<IOSend>>,
+    io_task: Option<tokio_util::task::AbortOnDropHandle<PolarsResult<()>>>,
+}
+impl AvroSinkNode {
+    pub fn new(
+        target: SinkTarget,
+        schema: SchemaRef,
+        sink_options: SinkOptions,
+        write_options: AvroWriterOptions,
+        cloud_options: Option<CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let arrow_schema = schema_to_arrow_checked(&schema, CompatLevel::oldest(), "avro")?;
+        let record = to_record(&arrow_schema, write_options.name.to_string())?;
+
+        Ok(Self {
+            target,
+            sink_options,
+            record,
+            compression: write_options.compression.map(AvroCompression::from),
+            cloud_options,
+
+            io_tx: None,
+            io_task: None,
+        })
+    }
+}
+
+impl SinkNode for AvroSinkNode {
+    fn name(&self) -> &str {
+        "avro-sink"
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        true
+    }
+    fn do_maintain_order(&self) -> bool {
+        self.sink_options.maintain_order
+    }
+
+    fn initialize(&mut self, _state: &StreamingExecutionState) -> PolarsResult<()> {
+        let (io_tx, mut io_rx) = connector::<Linearizer<Priority<Reverse<MorselSeq>, Vec<u8>>>>();
+
+        // IO task.
+        //
+        // Task that will actually do write to the target file.
+        let target = self.target.clone();
+        let sink_options = self.sink_options.clone();
+        let record = self.record.clone();
+        let compression = self.compression;
+        let cloud_options = self.cloud_options.clone();
+        let io_task = polars_io::pl_async::get_runtime().spawn(async move {
+            use tokio::io::AsyncWriteExt;
+
+            let mut file = target
+                .open_into_writeable_async(&sink_options, cloud_options.as_ref())
+                .await?;
+
+            write_avro_header(&mut *file, record, compression)?;
+
+            let mut file = file.try_into_async_writeable()?;
+
+            while let Ok(mut lin_rx) = io_rx.recv().await {
+                while let Some(Priority(_, buffer)) = lin_rx.get().await {
+                    file.write_all(&buffer).await?;
+                }
+            }
+
+            file.sync_on_close(sink_options.sync_on_close).await?;
+            file.close().await?;
+
+            PolarsResult::Ok(())
+        });
+
+        self.io_tx = Some(io_tx);
+        self.io_task = Some(tokio_util::task::AbortOnDropHandle::new(io_task));
+
+        Ok(())
+    }
+
+    fn spawn_sink(
+        &mut self,
+        recv_port_rx: Receiver<(PhaseOutcome, SinkInputPort)>,
+        state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let io_tx = self
+            .io_tx
+            .take()
+            .expect("not initialized / spawn called more than once");
+        let pass_rxs = parallelize_receive_task(
+            join_handles,
+            recv_port_rx,
+            state.num_pipelines,
+            self.sink_options.maintain_order,
+            io_tx,
+        );
+
+        // Encode task.
+        //
+        // Task encodes every morsel into a (compressed) Avro block. Blocks don't depend on each
+        // other, so they are encoded in parallel.
+        join_handles.extend(pass_rxs.into_iter().map(|mut pass_rx| {
+            let mut encoder = AvroBlockEncoder::new(self.record.clone(), self.compression);
+
+            spawn(TaskPriority::High, async move {
+                while let Ok((mut rx, mut lin_tx)) = pass_rx.recv().await {
+                    while let Ok(morsel) = rx.recv().await {
+                        let (mut df, seq, _, consume_token) = morsel.into_inner();
+
+                        let mut buffer = Vec::new();
+                        if df.height() > 0 {
+                            df.rechunk_mut();
+                            for chunk in df.iter_chunks(CompatLevel::oldest(), false) {
+                                encoder.write_block(&mut buffer, &chunk)?;
+                            }
+                        }
+
+                        if lin_tx.insert(Priority(Reverse(seq), buffer)).await.is_err() {
+                            return Ok(());
+                        }
+                        drop(consume_token); // Keep the consume_token until here to increase the
+                        // backpressure.
+                    }
+                }
+
+                PolarsResult::Ok(())
+            })
+        }));
+    }
+
+    fn finalize(
+        &mut self,
+        _state: &StreamingExecutionState,
+    ) -> Option<Pin<Box<dyn Future<Output = PolarsResult<()>> + Send>>> {
+        // If we were never spawned, we need to make sure that the `tx` is taken. This signals to
+        // the IO task that it is done and prevents deadlocks.
+        drop(self.io_tx.take());
+
+        let io_task = self
+            .io_task
+            .take()
+            .expect("not initialized / finish called more than once");
+
+        // Wait for the IO task to complete.
+        Some(Box::pin(async move {
+            io_task
+                .await
+                .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))
+        }))
+    }
+}
@@ -7,6 +7,7 @@ use polars_error::PolarsResult;
 use polars_io::SerWriter;
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::{CsvWriter, CsvWriterOptions};
+use polars_io::utils::compression::CompressedWriter;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -78,11 +79,17 @@ impl SinkNode for CsvSinkNode {
 
             let mut file = target
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
-                .await?;
+                .await?
+                .try_into_async_writeable()?;
+
+            // All buffers go through a single encoder, so that a compressed file is a single
+            // gzip member or zstd frame.
+            let mut compressed = CompressedWriter::new(Vec::new(), options.compression)?;
 
             // Write the header
             if options.include_header || options.include_bom {
-                let mut writer = CsvWriter::new(&mut *file)
+                let mut header = Vec::new();
+                let mut writer = CsvWriter::new(&mut header)
                     .include_bom(options.include_bom)
                     .include_header(options.include_header)
                     .with_separator(options.serialize_options.separator)
@@ -99,15 +106,16 @@ impl SinkNode for CsvSinkNode {
                     .n_threads(1) // Disable rayon parallelism
                     .batched(&schema)?;
                 writer.write_batch(&DataFrame::empty_with_schema(&schema))?;
+                drop(writer);
+                file.write_all(&compressed.write_and_take(header)?).await?;
             }
 
-            let mut file = file.try_into_async_writeable()?;
-
             while let Ok(mut lin_rx) = io_rx.recv().await {
                 while let Some(Priority(_, buffer)) = lin_rx.get().await {
-                    file.write_all(&buffer).await?;
+                    file.write_all(&compressed.write_and_take(buffer)?).await?;
                 }
             }
+            file.write_all(&compressed.finish()?).await?;
 
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
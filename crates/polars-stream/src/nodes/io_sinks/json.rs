@@ -3,7 +3,8 @@ use std::pin::Pin;
 
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::json::BatchedWriter;
+use polars_io::json::{BatchedWriter, JsonWriterOptions};
+use polars_io::utils::compression::CompressedWriter;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -22,6 +23,7 @@ type IOSend = Linearizer<Priority<Reverse<MorselSeq>, Vec<u8>>>;
 pub struct NDJsonSinkNode {
     target: SinkTarget,
     sink_options: SinkOptions,
+    write_options: JsonWriterOptions,
     cloud_options: Option<CloudOptions>,
 
     io_tx: Option<Sender<IOSend>>,
@@ -31,11 +33,13 @@ impl NDJsonSinkNode {
     pub fn new(
         target: SinkTarget,
         sink_options: SinkOptions,
+        write_options: JsonWriterOptions,
         cloud_options: Option<CloudOptions>,
     ) -> Self {
         Self {
             target,
             sink_options,
+            write_options,
             cloud_options,
 
             io_tx: None,
@@ -65,6 +69,7 @@ impl SinkNode for NDJsonSinkNode {
         let sink_options = self.sink_options.clone();
         let cloud_options = self.cloud_options.clone();
         let target = self.target.clone();
+        let compression = self.write_options.compression;
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
             use tokio::io::AsyncWriteExt;
 
@@ -73,11 +78,16 @@ impl SinkNode for NDJsonSinkNode {
                 .await?
                 .try_into_async_writeable()?;
 
+            // All buffers go through a single encoder, so that a compressed file is a single
+            // gzip member or zstd frame.
+            let mut compressed = CompressedWriter::new(Vec::new(), compression)?;
+
             while let Ok(mut lin_rx) = io_rx.recv().await {
                 while let Some(Priority(_, buffer)) = lin_rx.get().await {
-                    file.write_all(&buffer).await?;
+                    file.write_all(&compressed.write_and_take(buffer)?).await?;
                 }
             }
+            file.write_all(&compressed.finish()?).await?;
 
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
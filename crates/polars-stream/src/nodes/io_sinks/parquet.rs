@@ -14,7 +14,7 @@ use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, Version,
-    WriteOptions, array_to_columns, to_parquet_schema,
+    WriteOptions, array_to_bloom_filters, array_to_columns, to_parquet_schema,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
@@ -33,6 +33,9 @@ use crate::execute::StreamingExecutionState;
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::{JoinHandle, TaskPriority};
 
+/// The compressed pages and the bloom filter (if any) of every leaf column of a row group.
+type EncodedRowGroup = (Vec<Vec<CompressedPage>>, Vec<Option<Vec<u8>>>);
+
 pub struct ParquetSinkNode {
     target: SinkTarget,
 
@@ -48,7 +51,7 @@ pub struct ParquetSinkNode {
     file_size: Arc<RelaxedCell<u64>>,
     metrics: Arc<Mutex<Option<WriteMetrics>>>,
 
-    io_tx: Option<crate::async_primitives::connector::Sender<Vec<Vec<CompressedPage>>>>,
+    io_tx: Option<crate::async_primitives::connector::Sender<EncodedRowGroup>>,
     io_task: Option<tokio_util::task::AbortOnDropHandle<PolarsResult<()>>>,
 }
 
@@ -62,8 +65,11 @@ impl ParquetSinkNode {
         collect_metrics: bool,
     ) -> PolarsResult<Self> {
         let schema = schema_to_arrow_checked(&input_schema, CompatLevel::newest(), "parquet")?;
-        let column_options: Vec<ColumnWriteOptions> =
-            get_column_write_options(&schema, &write_options.field_overwrites);
+        let column_options: Vec<ColumnWriteOptions> = get_column_write_options(
+            &schema,
+            write_options.bloom_filter,
+            &write_options.field_overwrites,
+        );
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
         let metrics =
             Arc::new(Mutex::new(collect_metrics.then(|| {
@@ -108,7 +114,7 @@ impl SinkNode for ParquetSinkNode {
 
     fn initialize(&mut self, _state: &StreamingExecutionState) -> PolarsResult<()> {
         // Collect task -> IO task
-        let (io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (io_tx, mut io_rx) = connector::<EncodedRowGroup>();
 
         // IO task.
         //
@@ -150,11 +156,11 @@ impl SinkNode for ParquetSinkNode {
             );
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
-            while let Ok(current_row_group) = io_rx.recv().await {
+            while let Ok((current_row_group, bloom_filters)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 assert_eq!(current_row_group.len(), num_parquet_columns);
-                writer.write_row_group(&current_row_group)?;
+                writer.write_row_group(&current_row_group, &bloom_filters)?;
             }
 
             let file_size = writer.finish()?;
@@ -236,6 +242,10 @@ impl SinkNode for ParquetSinkNode {
                             // @NOTE: Since one Polars column might contain multiple Parquet columns (when
                             // it has a struct datatype), we return a Vec<Vec<CompressedPage>>.
 
+                            // Array -> Parquet bloom filters.
+                            let bloom_filters =
+                                array_to_bloom_filters(array.as_ref(), column_options);
+
                             // Array -> Parquet pages.
                             let encoded_columns =
                                 array_to_columns(array, type_.clone(), column_options, options)?;
@@ -260,7 +270,10 @@ impl SinkNode for ParquetSinkNode {
                                 .collect::<ParquetResult<Vec<_>>>()?;
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, (compressed_pages, bloom_filters)),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -282,7 +295,7 @@ impl SinkNode for ParquetSinkNode {
             struct Current {
                 seq: usize,
                 num_columns_seen: usize,
-                columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                columns: Vec<Option<EncodedRowGroup>>,
             }
 
             let mut current = Current {
@@ -292,14 +305,14 @@ impl SinkNode for ParquetSinkNode {
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, encoded_column))) = lin_rx.get().await {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
 
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
-                current.columns[i] = Some(compressed_pages);
+                current.columns[i] = Some(encoded_column);
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -307,11 +320,18 @@ impl SinkNode for ParquetSinkNode {
                     // them.
                     let mut current_row_group: Vec<Vec<CompressedPage>> =
                         Vec::with_capacity(num_parquet_columns);
+                    let mut bloom_filters = Vec::with_capacity(num_parquet_columns);
                     for column in current.columns.iter_mut() {
-                        current_row_group.extend(column.take().unwrap());
+                        let (compressed_pages, column_bloom_filters) = column.take().unwrap();
+                        current_row_group.extend(compressed_pages);
+                        bloom_filters.extend(column_bloom_filters);
                     }
 
-                    if io_tx.send(current_row_group).await.is_err() {
+                    if io_tx
+                        .send((current_row_group, bloom_filters))
+                        .await
+                        .is_err()
+                    {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
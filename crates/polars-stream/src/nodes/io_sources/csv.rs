@@ -14,8 +14,9 @@ use polars_io::prelude::_csv_read_internal::{
 use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice,
+    decompress_schema_inference_prefix,
 };
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::DecompressingReader;
 use polars_io::utils::slice::SplitSlicePosition;
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
@@ -91,8 +92,7 @@ const NO_SLICE: (usize, usize) = (0, usize::MAX);
 const SLICE_ENDED: (usize, usize) = (usize::MAX, 0);
 
 struct LineBatch {
-    // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-    bytes: &'static [u8],
+    bytes: MemSlice,
     n_lines: usize,
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
@@ -105,7 +105,8 @@ struct CsvFileReader {
     #[expect(unused)] // Will be used when implementing cloud streaming.
     cloud_options: Option<Arc<CloudOptions>>,
     options: Arc<CsvReadOptions>,
-    // Cached on first access - we may be called multiple times e.g. on negative slice.
+    // Cached on first access - we may be called multiple times e.g. on negative slice. Note that
+    // these bytes may be compressed.
     cached_bytes: Option<MemSlice>,
     verbose: bool,
 }
@@ -130,8 +131,6 @@ impl FileReader for CsvFileReader {
     ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
         let verbose = self.verbose;
 
-        let memslice = self.get_bytes_maybe_decompress()?;
-
         let BeginReadArgs {
             projection: Projection::Plain(projected_schema),
             // Because we currently only support PRE_SLICE we don't need to handle row index here.
@@ -175,6 +174,8 @@ impl FileReader for CsvFileReader {
             self.options.infer_schema_length
         };
 
+        let (memslice, decompressor) = self.get_bytes_and_decompressor(infer_schema_length)?;
+
         let (mut inferred_schema, ..) = polars_io::csv::read::infer_file_schema(
             &polars_io::mmap::ReaderBytes::Owned(memslice.clone()),
             &self.options.parse_options,
@@ -269,7 +270,8 @@ impl FileReader for CsvFileReader {
         let line_batch_source_handle = AbortOnDropHandle::new(spawn(
             TaskPriority::Low,
             LineBatchSource {
-                memslice: memslice.clone(),
+                memslice,
+                decompressor,
                 line_counter: CountLines::new(
                     self.options.parse_options.quote_char,
                     self.options.parse_options.eol_char,
@@ -294,8 +296,6 @@ impl FileReader for CsvFileReader {
             .zip(morsel_senders)
             .enumerate()
             .map(|(worker_idx, (mut line_batch_rx, mut morsel_tx))| {
-                // Hold a ref as we are receiving `&'static [u8]`s pointing to this.
-                let global_memslice = memslice.clone();
                 // Only verbose log from the last worker to avoid flooding output.
                 let verbose = verbose && worker_idx == n_workers - 1;
                 let mut n_rows_processed: usize = 0;
@@ -313,19 +313,13 @@ impl FileReader for CsvFileReader {
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
-                        debug_assert!(bytes.as_ptr() as usize >= global_memslice.as_ptr() as usize);
-                        debug_assert!(
-                            bytes.as_ptr() as usize + bytes.len()
-                                <= global_memslice.as_ptr() as usize + global_memslice.len()
-                        );
-
                         let (offset, len) = match slice {
                             SLICE_ENDED => (0, 1),
                             v => v,
                         };
 
                         let (df, n_rows_in_chunk) =
-                            chunk_reader.read_chunk(bytes, n_lines, (offset, len), row_offset)?;
+                            chunk_reader.read_chunk(&bytes, n_lines, (offset, len), row_offset)?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
 
@@ -360,7 +354,7 @@ impl FileReader for CsvFileReader {
                             assert_eq!(slice, SLICE_ENDED);
 
                             let n_lines = if let Some(v) = alt_count_lines.as_deref() {
-                                v.count_lines(bytes)?
+                                v.count_lines(&bytes)?
                             } else {
                                 n_lines
                             };
@@ -408,22 +402,50 @@ impl FileReader for CsvFileReader {
 }
 
 impl CsvFileReader {
+    /// Returns the bytes of the file, or if the file is compressed, the decompressed start of the
+    /// file that is needed to infer the schema from `infer_schema_length` rows, together with a
+    /// decompressor for the rest of the file.
+    ///
     /// # Panics
     /// Panics if `self.cached_bytes` is None.
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
-        let mut out = vec![];
-        maybe_decompress_bytes(self.cached_bytes.as_deref().unwrap(), &mut out)?;
+    fn get_bytes_and_decompressor(
+        &self,
+        infer_schema_length: Option<usize>,
+    ) -> PolarsResult<(MemSlice, Option<DecompressingReader>)> {
+        let memslice = self.cached_bytes.clone().unwrap();
+
+        let Some(mut decompressor) = DecompressingReader::try_new(memslice.clone())? else {
+            return Ok((memslice, None));
+        };
 
-        if !out.is_empty() {
-            self.cached_bytes = Some(MemSlice::from_vec(out));
+        let mut prefix = vec![];
+        let reached_end = decompress_schema_inference_prefix(
+            &mut decompressor,
+            &self.options,
+            infer_schema_length,
+            &mut prefix,
+        )?;
+
+        if self.verbose {
+            eprintln!(
+                "[CsvFileReader]: decompressed {} bytes for schema inference, reached_end: {}",
+                prefix.len(),
+                reached_end
+            )
         }
 
-        Ok(self.cached_bytes.clone().unwrap())
+        Ok((
+            MemSlice::from_vec(prefix),
+            (!reached_end).then_some(decompressor),
+        ))
     }
 }
 
 struct LineBatchSource {
+    /// The entire file, or the decompressed start of a compressed file.
     memslice: MemSlice,
+    /// Decompresses the rest of a compressed file while reading.
+    decompressor: Option<DecompressingReader>,
     line_counter: CountLines,
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     options: Arc<CsvReadOptions>,
@@ -439,6 +461,7 @@ impl LineBatchSource {
     async fn run(self) -> PolarsResult<usize> {
         let LineBatchSource {
             memslice,
+            mut decompressor,
             line_counter,
             mut line_batch_tx,
             options,
@@ -469,9 +492,6 @@ impl LineBatchSource {
             eprintln!("[CsvSource]: Start line splitting",);
         }
 
-        let global_bytes: &[u8] = memslice.as_ref();
-        let global_bytes: &'static [u8] = unsafe { std::mem::transmute(global_bytes) };
-
         let i = {
             let parse_options = options.parse_options.as_ref();
 
@@ -485,7 +505,7 @@ impl LineBatchSource {
             let has_header = options.has_header;
 
             find_starting_point(
-                global_bytes,
+                &memslice,
                 quote_char,
                 eol_char,
                 file_schema_len,
@@ -497,12 +517,16 @@ impl LineBatchSource {
             )?
         };
 
-        let mut bytes = &global_bytes[i..];
+        let mut bytes = memslice.slice(i..memslice.len());
 
         let mut chunk_size = {
             let max_chunk_size = 16 * 1024 * 1024;
             let chunk_size = if global_slice.is_some() {
                 max_chunk_size
+            } else if decompressor.is_some() {
+                // The size of the decompressed file is unknown, use a smaller chunk size to keep
+                // the amount of decompressed data in memory low.
+                max_chunk_size / 4
             } else {
                 std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
             };
@@ -516,21 +540,38 @@ impl LineBatchSource {
         };
 
         loop {
+            // Decompress the next part of a compressed file once the remaining bytes no longer
+            // fill a chunk. The remaining bytes always start at the beginning of a line.
+            if let Some(d) = decompressor.as_mut() {
+                if bytes.len() <= chunk_size {
+                    let mut buf = Vec::with_capacity(bytes.len() + chunk_size);
+                    buf.extend_from_slice(&bytes);
+                    if d.read_chunk(&mut buf, chunk_size)? < chunk_size {
+                        decompressor = None;
+                    }
+                    bytes = MemSlice::from_vec(buf);
+                }
+            }
+
             if bytes.is_empty() {
                 break;
             }
 
-            let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
+            let (count, position) = line_counter.find_next(&bytes, &mut chunk_size);
             let (count, position) = if count == 0 {
+                if decompressor.is_some() {
+                    // The last line is incomplete, it is completed by the next decompressed part.
+                    // Note that `find_next` increased `chunk_size` to at least `bytes.len()`.
+                    continue;
+                }
                 (1, bytes.len())
             } else {
                 let pos = (position + 1).min(bytes.len()); // +1 for '\n'
                 (count, pos)
             };
 
-            let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
-
-            bytes = &bytes[position..];
+            let bytes_this_chunk = bytes.slice(0..position);
+            bytes = bytes.slice(position..bytes.len());
 
             let current_row_offset = *current_row_offset_ref;
             *current_row_offset_ref += count;
@@ -562,8 +603,6 @@ impl LineBatchSource {
                 NO_SLICE
             };
 
-            let bytes_this_chunk = &global_bytes[slice_start..slice_start + position];
-
             let morsel_seq = *morsel_seq_ref;
             *morsel_seq_ref = morsel_seq.successor();
 
@@ -0,0 +1,432 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::prelude::Column;
+use polars_core::schema::SchemaRef;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::fixed_width::{
+    FixedWidthChunkParser, FixedWidthReadOptions, count_rows_from_slice,
+    decompress_schema_inference_prefix, find_next_line_end, find_starting_point,
+    infer_fixed_width_schema,
+};
+use polars_io::utils::compression::DecompressingReader;
+use polars_io::utils::slice::SplitSlicePosition;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, calc_row_position_after_slice,
+};
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_executor::{AbortOnDropHandle, spawn};
+use crate::async_primitives::distributor_channel::{self, distributor_channel};
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::io_sources::multi_scan::reader_interface::Projection;
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::{MorselSeq, TaskPriority};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::fixed_width::FixedWidthReadOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::FixedWidthFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    impl FileReaderBuilder for Arc<FixedWidthReadOptions> {
+        fn reader_name(&self) -> &str {
+            "fixed_width"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = FixedWidthFileReader {
+                scan_source: source,
+                cloud_options,
+                options: self.clone(),
+                verbose: config::verbose(),
+                cached_bytes: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct LineBatch {
+    bytes: MemSlice,
+    /// Rows of the batch to output.
+    slice: Range<usize>,
+    /// Position of the first row of this batch in the file.
+    row_offset: usize,
+    morsel_seq: MorselSeq,
+}
+
+struct FixedWidthFileReader {
+    scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    cloud_options: Option<Arc<CloudOptions>>,
+    options: Arc<FixedWidthReadOptions>,
+    // Cached on first access - we may be called multiple times e.g. on negative slice. Note that
+    // these bytes may be compressed.
+    cached_bytes: Option<MemSlice>,
+    verbose: bool,
+}
+
+#[async_trait]
+impl FileReader for FixedWidthFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+
+        // Note: We do not decompress in `initialize()`.
+        self.cached_bytes = Some(memslice);
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        // IR lowering puts a negative slice in a separate node.
+        let global_slice = pre_slice.clone().map(|pre_slice| match pre_slice {
+            Slice::Positive { .. } => Range::<usize>::from(pre_slice),
+            Slice::Negative { .. } => unreachable!(),
+        });
+
+        let (memslice, decompressor) = self.get_bytes_and_decompressor()?;
+
+        // We need to infer the schema to get the columns of this file, but the data types of
+        // the projected columns are taken from the scan.
+        let start = find_starting_point(&memslice, &self.options);
+        let mut file_schema = infer_fixed_width_schema(&memslice[start..], &self.options)?;
+        for (name, dtype) in file_schema.iter_mut() {
+            if let Some(projected_dtype) = projected_schema.get(name) {
+                *dtype = projected_dtype.clone();
+            }
+        }
+        let file_schema: SchemaRef = Arc::new(file_schema);
+
+        if let Some(mut tx) = file_schema_tx {
+            _ = tx.try_send(file_schema.clone())
+        }
+
+        let projection: Vec<usize> = projected_schema
+            .iter_names()
+            .filter_map(|name| file_schema.index_of(name))
+            .collect();
+
+        if verbose {
+            eprintln!(
+                "[FixedWidthFileReader]: project: {} / {}, slice: {:?}, row_index: {:?}",
+                projection.len(),
+                file_schema.len(),
+                &pre_slice,
+                row_index,
+            )
+        }
+
+        let chunk_parser = Arc::new(FixedWidthChunkParser::try_new(
+            &self.options,
+            file_schema,
+            projection,
+        )?);
+
+        let (line_batch_tx, line_batch_receivers) =
+            distributor_channel(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let line_batch_source_handle = AbortOnDropHandle::new(spawn(
+            TaskPriority::Low,
+            LineBatchSource {
+                memslice: memslice.slice(start..memslice.len()),
+                decompressor,
+                line_batch_tx,
+                eol_char: self.options.eol_char,
+                global_slice,
+                needs_full_row_count: n_rows_in_file_tx.is_some(),
+                num_pipelines,
+                verbose,
+            }
+            .run(),
+        ));
+
+        let (morsel_senders, rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        let line_batch_decode_handles = line_batch_receivers
+            .into_iter()
+            .zip(morsel_senders)
+            .map(|(mut line_batch_rx, mut morsel_tx)| {
+                let chunk_parser = chunk_parser.clone();
+                let row_index = row_index.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(LineBatch {
+                        bytes,
+                        slice,
+                        row_offset,
+                        morsel_seq,
+                    }) = line_batch_rx.recv().await
+                    {
+                        let mut df = chunk_parser.parse(&bytes)?;
+                        df = df.slice(slice.start as i64, slice.len());
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let position = row_offset + slice.start;
+                            let offset = IdxSize::try_from(position)
+                                .ok()
+                                .and_then(|position| offset.checked_add(position))
+                                .ok_or_else(|| {
+                                    polars_err!(bigidx, ctx = "fixed-width file", size = position)
+                                })?;
+                            unsafe {
+                                df.with_column_unchecked(Column::new_row_index(
+                                    name.clone(),
+                                    offset,
+                                    df.height(),
+                                )?);
+                            }
+                        }
+
+                        let morsel = Morsel::new(df, morsel_seq, source_token.clone());
+
+                        if morsel_tx.send_morsel(morsel).await.is_err() {
+                            break;
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            rx,
+            spawn(TaskPriority::Low, async move {
+                for handle in line_batch_decode_handles {
+                    handle.await?;
+                }
+
+                // The source counts all rows of the file if the full row count is needed, and
+                // otherwise at least the rows up to the end of the slice.
+                let n_rows_seen = line_batch_source_handle.await?;
+                let n_rows_seen = IdxSize::try_from(n_rows_seen).map_err(|_| {
+                    polars_err!(bigidx, ctx = "fixed-width file", size = n_rows_seen)
+                })?;
+
+                if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+                    _ = n_rows_in_file_tx.try_send(n_rows_seen);
+                }
+
+                if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+                    _ = row_position_on_end_tx
+                        .try_send(calc_row_position_after_slice(n_rows_seen, pre_slice));
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+}
+
+impl FixedWidthFileReader {
+    /// Returns the bytes of the file, or if the file is compressed, the decompressed start of the
+    /// file that is needed to infer the schema, together with a decompressor for the rest of the
+    /// file.
+    ///
+    /// # Panics
+    /// Panics if `self.cached_bytes` is None.
+    fn get_bytes_and_decompressor(&self) -> PolarsResult<(MemSlice, Option<DecompressingReader>)> {
+        let memslice = self.cached_bytes.clone().unwrap();
+
+        let Some(mut decompressor) = DecompressingReader::try_new(memslice.clone())? else {
+            return Ok((memslice, None));
+        };
+
+        let mut prefix = vec![];
+        let reached_end =
+            decompress_schema_inference_prefix(&mut decompressor, &self.options, &mut prefix)?;
+
+        if self.verbose {
+            eprintln!(
+                "[FixedWidthFileReader]: decompressed {} bytes for schema inference, reached_end: {}",
+                prefix.len(),
+                reached_end
+            )
+        }
+
+        Ok((
+            MemSlice::from_vec(prefix),
+            (!reached_end).then_some(decompressor),
+        ))
+    }
+}
+
+struct LineBatchSource {
+    /// The rows of the file, or the decompressed start of the rows of a compressed file.
+    memslice: MemSlice,
+    /// Decompresses the rest of a compressed file while reading.
+    decompressor: Option<DecompressingReader>,
+    line_batch_tx: distributor_channel::Sender<LineBatch>,
+    eol_char: u8,
+    global_slice: Option<Range<usize>>,
+    needs_full_row_count: bool,
+    num_pipelines: usize,
+    verbose: bool,
+}
+
+impl LineBatchSource {
+    /// Returns the number of rows that were seen, which are all rows of the file unless the
+    /// slice ended before the end of the file.
+    async fn run(self) -> PolarsResult<usize> {
+        let LineBatchSource {
+            memslice,
+            mut decompressor,
+            mut line_batch_tx,
+            eol_char,
+            global_slice,
+            needs_full_row_count,
+            num_pipelines,
+            verbose,
+        } = self;
+
+        let mut bytes = memslice;
+        let mut morsel_seq = MorselSeq::default();
+        let mut current_row_offset = 0usize;
+
+        let mut chunk_size = {
+            let max_chunk_size = 16 * 1024 * 1024;
+            let chunk_size = if global_slice.is_some() {
+                max_chunk_size
+            } else if decompressor.is_some() {
+                // The size of the decompressed file is unknown, use a smaller chunk size to keep
+                // the amount of decompressed data in memory low.
+                max_chunk_size / 4
+            } else {
+                std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
+            };
+
+            // Use a small min chunk size to catch failures in tests.
+            #[cfg(debug_assertions)]
+            let min_chunk_size = 64;
+            #[cfg(not(debug_assertions))]
+            let min_chunk_size = 1024 * 4;
+            std::cmp::max(chunk_size, min_chunk_size)
+        };
+
+        if verbose {
+            eprintln!("[FixedWidthSource]: Start line splitting, chunk_size: {chunk_size}");
+        }
+
+        loop {
+            // Decompress the next part of a compressed file once the remaining bytes no longer
+            // fill a chunk. The remaining bytes always start at the beginning of a line.
+            if let Some(d) = decompressor.as_mut() {
+                if bytes.len() <= chunk_size {
+                    let mut buf = Vec::with_capacity(bytes.len() + chunk_size);
+                    buf.extend_from_slice(&bytes);
+                    if d.read_chunk(&mut buf, chunk_size)? < chunk_size {
+                        decompressor = None;
+                    }
+                    bytes = MemSlice::from_vec(buf);
+                }
+            }
+
+            if bytes.is_empty() {
+                break;
+            }
+
+            let position = match find_next_line_end(&bytes, chunk_size, eol_char) {
+                Some(position) => position,
+                None if decompressor.is_some() => {
+                    // The last line is incomplete, it is completed by the next decompressed part.
+                    chunk_size = bytes.len();
+                    continue;
+                },
+                None => bytes.len(),
+            };
+
+            let bytes_this_chunk = bytes.slice(0..position);
+            bytes = bytes.slice(position..bytes.len());
+
+            let n_rows = count_rows_from_slice(&bytes_this_chunk, eol_char);
+            let row_offset = current_row_offset;
+            current_row_offset += n_rows;
+
+            let slice = match &global_slice {
+                None => 0..n_rows,
+                Some(global_slice) => match SplitSlicePosition::split_slice_at_file(
+                    row_offset,
+                    n_rows,
+                    global_slice.clone(),
+                ) {
+                    SplitSlicePosition::Before => continue,
+                    SplitSlicePosition::Overlapping(offset, len) => offset..offset + len,
+                    // The rows after the slice are only counted.
+                    SplitSlicePosition::After if needs_full_row_count => continue,
+                    SplitSlicePosition::After => break,
+                },
+            };
+
+            let batch = LineBatch {
+                bytes: bytes_this_chunk,
+                slice,
+                row_offset,
+                morsel_seq,
+            };
+            morsel_seq = morsel_seq.successor();
+
+            if line_batch_tx.send(batch).await.is_err() {
+                break;
+            }
+        }
+
+        Ok(current_row_offset)
+    }
+}
@@ -1,11 +1,17 @@
 pub mod multi_scan;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
+#[cfg(feature = "fixed_width")]
+pub mod fixed_width;
 #[cfg(feature = "ipc")]
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod ndjson;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
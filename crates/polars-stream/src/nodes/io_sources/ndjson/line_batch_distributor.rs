@@ -1,6 +1,7 @@
 use polars_core::config;
 use polars_error::PolarsResult;
 use polars_io::prelude::json_lines;
+use polars_io::utils::compression::DecompressingReader;
 use polars_utils::idx_mapper::IdxMapper;
 use polars_utils::mmap::MemSlice;
 
@@ -8,7 +9,10 @@ use super::line_batch_processor::LineBatch;
 use crate::async_primitives::distributor_channel;
 
 pub(super) struct LineBatchDistributor {
+    /// The entire file, or the compressed file if `decompressor` is set.
     pub(super) global_bytes: MemSlice,
+    /// Decompresses the file while reading. Reverse reading is not supported in this case.
+    pub(super) decompressor: Option<DecompressingReader>,
     pub(super) chunk_size: usize,
     pub(super) n_rows_to_skip: usize,
     pub(super) reverse: bool,
@@ -20,15 +24,26 @@ impl LineBatchDistributor {
     pub(super) async fn run(self) -> PolarsResult<usize> {
         let LineBatchDistributor {
             global_bytes: global_bytes_mem_slice,
+            decompressor,
             chunk_size,
             n_rows_to_skip,
             reverse,
             mut line_batch_distribute_tx,
         } = self;
 
-        // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-        let global_bytes: &'static [u8] =
-            unsafe { std::mem::transmute(global_bytes_mem_slice.as_ref()) };
+        if let Some(decompressor) = decompressor {
+            assert!(!reverse);
+
+            return run_decompressing(
+                decompressor,
+                chunk_size,
+                n_rows_to_skip,
+                line_batch_distribute_tx,
+            )
+            .await;
+        }
+
+        let global_bytes: &[u8] = global_bytes_mem_slice.as_ref();
         let n_chunks = global_bytes.len().div_ceil(chunk_size);
         let verbose = config::verbose();
 
@@ -53,7 +68,7 @@ impl LineBatchDistributor {
         // The logic below processes in fixed chunks with remainder handling so that in the future
         // we can handle receiving data in a batched manner.
 
-        let mut prev_remainder: &'static [u8] = &[];
+        let mut prev_remainder: &[u8] = &[];
 
         let global_idx_map = IdxMapper::new(global_bytes.len(), reverse);
 
@@ -103,16 +118,17 @@ impl LineBatchDistributor {
                 prev_remainder = &[];
                 row_skipper.skip_rows(&mut full_chunk);
 
-                if !full_chunk.is_empty()
-                    && line_batch_distribute_tx
-                        .send(LineBatch {
-                            bytes: full_chunk,
-                            chunk_idx,
-                        })
+                if !full_chunk.is_empty() {
+                    let offset = full_chunk.as_ptr() as usize - global_bytes.as_ptr() as usize;
+                    let bytes = global_bytes_mem_slice.slice(offset..offset + full_chunk.len());
+
+                    if line_batch_distribute_tx
+                        .send(LineBatch { bytes, chunk_idx })
                         .await
                         .is_err()
-                {
-                    break;
+                    {
+                        break;
+                    }
                 }
             }
 
@@ -138,6 +154,74 @@ impl LineBatchDistributor {
     }
 }
 
+/// Sends the lines of a compressed file while decompressing it in chunks of `chunk_size` bytes, so
+/// that the decompressed file never has to be held in memory at once. Returns the number of rows
+/// skipped.
+async fn run_decompressing(
+    mut decompressor: DecompressingReader,
+    chunk_size: usize,
+    n_rows_to_skip: usize,
+    mut line_batch_distribute_tx: distributor_channel::Sender<LineBatch>,
+) -> PolarsResult<usize> {
+    if config::verbose() {
+        eprintln!(
+            "\
+            [NDJSON LineBatchDistributor]: \
+            decompressing, \
+            chunk_size: {chunk_size}, \
+            n_rows_to_skip: {n_rows_to_skip}\
+            "
+        )
+    }
+
+    let mut row_skipper = RowSkipper {
+        remaining_rows_to_skip: n_rows_to_skip,
+        reverse: false,
+    };
+
+    // Incomplete line at the end of the previous chunk.
+    let mut prev_remainder: Vec<u8> = vec![];
+
+    for chunk_idx in 0.. {
+        let mut buf = Vec::with_capacity(prev_remainder.len() + chunk_size);
+        buf.append(&mut prev_remainder);
+        let is_last = decompressor.read_chunk(&mut buf, chunk_size)? < chunk_size;
+
+        if !is_last {
+            // chunk:     ---------\n---
+            // remainder:            ---
+            let remainder_len = buf.rsplit(|&c| c == b'\n').next().unwrap().len();
+            prev_remainder.extend_from_slice(&buf[buf.len() - remainder_len..]);
+            buf.truncate(buf.len() - remainder_len);
+        }
+
+        let chunk = MemSlice::from_vec(buf);
+        let mut full_chunk: &[u8] = &chunk;
+        row_skipper.skip_rows(&mut full_chunk);
+
+        if !full_chunk.is_empty() {
+            let offset = chunk.len() - full_chunk.len();
+
+            if line_batch_distribute_tx
+                .send(LineBatch {
+                    bytes: chunk.slice(offset..chunk.len()),
+                    chunk_idx,
+                })
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+
+        if is_last {
+            break;
+        }
+    }
+
+    Ok(n_rows_to_skip - row_skipper.remaining_rows_to_skip)
+}
+
 struct RowSkipper {
     remaining_rows_to_skip: usize,
     reverse: bool,
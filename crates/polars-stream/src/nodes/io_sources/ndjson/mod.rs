@@ -12,7 +12,7 @@ use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::estimate_n_lines_in_file;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{DecompressingReader, maybe_decompress_bytes};
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -44,7 +44,8 @@ pub struct NDJsonFileReader {
     cloud_options: Option<Arc<CloudOptions>>,
     options: Arc<NDJsonReadOptions>,
     verbose: bool,
-    // Cached on first access - we may be called multiple times e.g. on negative slice.
+    // Cached on first access - we may be called multiple times e.g. on negative slice. Note that
+    // these bytes may be compressed.
     cached_bytes: Option<MemSlice>,
 }
 
@@ -80,9 +81,14 @@ impl FileReader for NDJsonFileReader {
             panic!("unsupported args: {:?}", &args)
         };
 
-        // TODO: This currently downloads and decompresses everything upfront in a blocking manner.
-        // Ideally we have a streaming download/decompression.
-        let global_bytes = self.get_bytes_maybe_decompress()?;
+        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
+
+        // TODO: This currently downloads everything upfront in a blocking manner.
+        // Ideally we have a streaming download.
+        //
+        // A compressed file is decompressed while it is being read, except for a negative slice,
+        // for which the file is read from the end.
+        let (global_bytes, decompressor) = self.get_bytes_and_decompressor(is_negative_slice)?;
 
         // NDJSON: We just use the projected schema - the parser will automatically append NULL if
         // the field is not found.
@@ -97,8 +103,6 @@ impl FileReader for NDJsonFileReader {
             _ = tx.try_send(schema.clone())
         }
 
-        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
-
         // Convert (offset, len) to Range
         // Note: This is converted to right-to-left for negative slice (i.e. range.start is position
         // from end).
@@ -133,8 +137,10 @@ impl FileReader for NDJsonFileReader {
                 && matches!(pre_slice, Some(Slice::Negative { .. })));
 
         let chunk_size: usize = {
+            // Note: For a compressed file we split based on the size of the compressed file, as
+            // the size of the decompressed file is unknown.
             let n_bytes_to_split = if let Some(x) = global_slice.as_ref() {
-                if needs_total_row_count {
+                if needs_total_row_count || decompressor.is_some() {
                     global_bytes.len()
                 } else {
                     // There may be early stopping, try to heuristically use a smaller chunk size to stop faster.
@@ -181,13 +187,15 @@ impl FileReader for NDJsonFileReader {
                 row_index: {:?}, \
                 chunk_size: {}, \
                 n_chunks: {}, \
-                is_negative_slice: {}",
+                is_negative_slice: {}, \
+                decompress_while_reading: {}",
                 schema.len(),
                 &global_slice,
                 &row_index,
                 chunk_size,
                 global_bytes.len().div_ceil(chunk_size),
                 is_negative_slice,
+                decompressor.is_some(),
             );
         }
 
@@ -308,7 +316,6 @@ impl FileReader for NDJsonFileReader {
             .enumerate()
             .rev()
             .map(|(worker_idx, line_batch_rx)| {
-                let global_bytes = global_bytes.clone();
                 let chunk_reader = chunk_reader.clone();
                 // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
                 let source_token = SourceToken::new();
@@ -318,7 +325,6 @@ impl FileReader for NDJsonFileReader {
                     LineBatchProcessor {
                         worker_idx,
 
-                        global_bytes,
                         chunk_reader,
 
                         line_batch_rx,
@@ -346,6 +352,7 @@ impl FileReader for NDJsonFileReader {
             TaskPriority::Low,
             line_batch_distributor::LineBatchDistributor {
                 global_bytes,
+                decompressor,
                 chunk_size,
                 n_rows_to_skip,
                 reverse: is_negative_slice,
@@ -439,7 +446,12 @@ impl NDJsonFileReader {
         ChunkReader::try_new(&self.options, schema)
     }
 
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+    /// Returns the bytes of the file. If the file is compressed, these are the compressed bytes
+    /// together with a decompressor, unless `decompress_upfront` is set.
+    fn get_bytes_and_decompressor(
+        &mut self,
+        decompress_upfront: bool,
+    ) -> PolarsResult<(MemSlice, Option<DecompressingReader>)> {
         if self.cached_bytes.is_none() {
             let run_async = self.scan_source.run_async();
             let source = self
@@ -447,20 +459,23 @@ impl NDJsonFileReader {
                 .as_scan_source_ref()
                 .to_memslice_async_assume_latest(run_async)?;
 
-            let memslice = {
-                let mut out = vec![];
-                maybe_decompress_bytes(&source, &mut out)?;
+            self.cached_bytes = Some(source);
+        }
 
-                if out.is_empty() {
-                    source
-                } else {
-                    MemSlice::from_vec(out)
-                }
-            };
+        let bytes = self.cached_bytes.clone().unwrap();
+
+        if decompress_upfront {
+            let mut out = vec![];
+            maybe_decompress_bytes(&bytes, &mut out)?;
+
+            if !out.is_empty() {
+                self.cached_bytes = Some(MemSlice::from_vec(out));
+            }
 
-            self.cached_bytes = Some(memslice);
+            return Ok((self.cached_bytes.clone().unwrap(), None));
         }
 
-        Ok(self.cached_bytes.clone().unwrap())
+        let decompressor = DecompressingReader::try_new(bytes.clone())?;
+        Ok((bytes, decompressor))
     }
 }
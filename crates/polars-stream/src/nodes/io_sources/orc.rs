@@ -0,0 +1,524 @@
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchemaRef;
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{Column, PlIndexMap, PlSmallStr};
+use polars_core::schema::Schema;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::orc::OrcMetadata;
+use polars_io::predicates::ScanIOPredicate;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::components::column_selector::ColumnSelector;
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_scan::reader_interface::{
+    FileReader, FileReaderCallbacks, Projection,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::OrcFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct OrcReaderBuilder;
+
+    impl FileReaderBuilder for OrcReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "orc"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX
+                | RC::PRE_SLICE
+                | RC::NEGATIVE_PRE_SLICE
+                | RC::PARTIAL_FILTER
+                | RC::MAPPED_COLUMN_PROJECTION
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            #[expect(unused)] scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = OrcFileReader {
+                scan_source: source,
+                cloud_options,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct OrcFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: Arc<OrcMetadata>,
+    arrow_schema: ArrowSchemaRef,
+    n_rows_in_file: IdxSize,
+}
+
+/// A projected column of the file.
+struct ColumnProjection {
+    /// Index of the column in the file schema.
+    file_index: usize,
+    output_name: PlSmallStr,
+    /// Cast applied to the decoded column, if any.
+    transform: Option<ColumnSelector>,
+}
+
+fn get_max_morsel_size() -> usize {
+    std::env::var("POLARS_STREAMING_ORC_SOURCE_MAX_MORSEL_SIZE")
+        .map_or_else(
+            |_| get_ideal_morsel_size(),
+            |v| {
+                v.parse::<usize>().expect(
+                    "POLARS_STREAMING_ORC_SOURCE_MAX_MORSEL_SIZE does not contain valid size",
+                )
+            },
+        )
+        .max(1)
+}
+
+#[async_trait]
+impl FileReader for OrcFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // check_latest: IR resolution only downloads the first file.
+        if let ScanSourceRef::Path(addr) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(addr.to_str())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let metadata = OrcMetadata::read(memslice.as_ref())?;
+        let arrow_schema = Arc::new(metadata.arrow_schema().clone());
+
+        let n_rows = metadata.num_rows();
+        let n_rows_in_file = IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "orc file", size = n_rows))?;
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            metadata: Arc::new(metadata),
+            arrow_schema,
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            memslice,
+            metadata,
+            arrow_schema,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args;
+
+        let normalized_pre_slice = pre_slice_arg
+            .clone()
+            .map(|pre_slice| pre_slice.restrict_to_bounds(n_rows_in_file as usize));
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(&arrow_schema)));
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[OrcFileReader]: early return: \
+                    n_rows_in_file: {n_rows_in_file}, \
+                    pre_slice: {pre_slice_arg:?}, \
+                    resolved_pre_slice: {normalized_pre_slice:?}"
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        // Always create a slice. If no slice was given, read the entire file.
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..n_rows_in_file as usize, Range::<usize>::from);
+
+        // Columns that are missing from the file are left to the multi-scan to fill in.
+        let columns: Arc<[ColumnProjection]> = projection
+            .iter_non_missing_columns()
+            .filter_map(|column| {
+                let file_index = arrow_schema.index_of(column.source_name.as_str())?;
+                Some(ColumnProjection {
+                    file_index,
+                    output_name: column.output_name.clone(),
+                    transform: column
+                        .resolved_transform
+                        .map(|t| t.attach_transforms(ColumnSelector::Position(0))),
+                })
+            })
+            .collect();
+
+        // Several output columns can be mapped from the same file column, but it is only decoded
+        // once.
+        let mut file_projection = columns.iter().map(|c| c.file_index).collect::<Vec<_>>();
+        file_projection.sort_unstable();
+        file_projection.dedup();
+        let file_projection: Arc<[usize]> = file_projection.into();
+
+        let skip_stripe = stripe_skipper(predicate, &projection, metadata.clone());
+
+        if verbose {
+            eprintln!(
+                "[OrcFileReader]: \
+                project: {} / {}, \
+                stripes: {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?}, \
+                predicate: {:?}",
+                columns.len(),
+                arrow_schema.len(),
+                metadata.num_stripes(),
+                pre_slice_arg,
+                normalized_pre_slice,
+                skip_stripe.as_ref().map(|_| "<predicate>"),
+            )
+        }
+
+        // Split size for morsels.
+        let max_morsel_size = get_max_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct StripeMessage {
+            /// Row position of the first row of `slice` in the file.
+            row_position: usize,
+            /// Rows to keep, relative to the start of the stripe.
+            slice: Range<usize>,
+            stripe: usize,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut stripe_tx, stripe_rxs) =
+            distributor_channel::<StripeMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Explicitly linearize here to redistribute morsels from large stripes over the
+        // pipelines.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Decompresses and decodes a stripe into a DataFrame, which is split into morsels if it
+        // is too large.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(stripe_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let metadata = metadata.clone();
+                let columns = columns.clone();
+                let file_projection = file_projection.clone();
+                let row_index = row_index.clone();
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(m) = rx.recv().await {
+                        let StripeMessage {
+                            row_position,
+                            slice,
+                            stripe,
+                            morsel_seq_base,
+                        } = m;
+
+                        // If we don't project any columns there is nothing to decode, so we just
+                        // create an empty frame with the proper height.
+                        let mut df = if columns.is_empty() {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let decoded = metadata.read_stripe(
+                                memslice.as_ref(),
+                                stripe,
+                                Some(&file_projection[..]),
+                            )?;
+                            let decoded = decoded.slice(slice.start as i64, slice.len());
+                            project_columns(&decoded, &file_projection, &columns)?
+                        };
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let offset = IdxSize::try_from(row_position)
+                                .ok()
+                                .and_then(|position| offset.checked_add(position))
+                                .ok_or_else(|| {
+                                    polars_err!(bigidx, ctx = "orc file", size = row_position)
+                                })?;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Supplies the stripes overlapping the slice to the decoder tasks, skipping the stripes
+        // that cannot match the predicate.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let mut morsel_seq: u64 = 0;
+            // Row position of the first row of the stripe.
+            let mut row_position = 0;
+            let mut n_skipped = 0;
+
+            for stripe in 0..metadata.num_stripes() {
+                let stripe_row_position = row_position;
+                row_position += metadata.stripe_num_rows(stripe);
+
+                if row_position <= slice.start {
+                    continue;
+                }
+                if stripe_row_position >= slice.end {
+                    break;
+                }
+
+                if let Some(skip_stripe) = &skip_stripe {
+                    if skip_stripe(stripe)? {
+                        n_skipped += 1;
+                        continue;
+                    }
+                }
+
+                let stripe_slice = slice.start.saturating_sub(stripe_row_position)
+                    ..slice.end.min(row_position) - stripe_row_position;
+                let stripe_slice_len = stripe_slice.len();
+
+                let message = StripeMessage {
+                    row_position: stripe_row_position + stripe_slice.start,
+                    slice: stripe_slice,
+                    stripe,
+                    morsel_seq_base: morsel_seq,
+                };
+
+                if stripe_tx.send(message).await.is_err() {
+                    // This should only happen if the receiver of the decoder has broken off,
+                    // meaning no further input will be needed.
+                    break;
+                }
+
+                // This might generate several morsels if the stripe is very large.
+                morsel_seq += stripe_slice_len.div_ceil(max_morsel_size) as u64;
+            }
+
+            if verbose && n_skipped > 0 {
+                eprintln!("[OrcFileReader]: skipped {n_skipped} stripes based on statistics");
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_arrow_schema(&mut self) -> PolarsResult<Option<ArrowSchemaRef>> {
+        Ok(Some(self.init_data.as_ref().unwrap().arrow_schema.clone()))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
+
+/// Rename and cast the decoded file columns to the output columns.
+fn project_columns(
+    decoded: &DataFrame,
+    file_projection: &[usize],
+    columns: &[ColumnProjection],
+) -> PolarsResult<DataFrame> {
+    let height = decoded.height();
+    let columns = columns
+        .iter()
+        .map(|c| {
+            let i = file_projection.binary_search(&c.file_index).unwrap();
+            let column = decoded.get_columns()[i].clone();
+            let column = match &c.transform {
+                None => column,
+                Some(transform) => transform.select_from_columns(&[column], height)?,
+            };
+            Ok(column.with_name(c.output_name.clone()))
+        })
+        .collect::<PolarsResult<Vec<Column>>>()?;
+    DataFrame::new_with_height(height, columns)
+}
+
+/// Returns a function that evaluates whether a stripe can be skipped based on its statistics.
+///
+/// The statistics are only used for the columns that are read from the file as-is, they are left
+/// out for columns that are cast.
+#[allow(clippy::type_complexity)]
+fn stripe_skipper(
+    predicate: Option<ScanIOPredicate>,
+    projection: &Projection,
+    metadata: Arc<OrcMetadata>,
+) -> Option<Box<dyn Fn(usize) -> PolarsResult<bool> + Send + Sync>> {
+    let predicate = predicate?;
+    let skip_batch_predicate = predicate.skip_batch_predicate?;
+    let live_columns = predicate.live_columns;
+
+    // (output_name, file_name) of the live columns that can use the file statistics.
+    let stat_columns: Vec<(PlSmallStr, PlSmallStr)> = live_columns
+        .iter()
+        .filter_map(|name| {
+            let column = projection.get_mapped_projection_ref_by_output_name(name)?;
+            column
+                .resolved_transform
+                .is_none()
+                .then(|| (column.output_name.clone(), column.source_name.clone()))
+        })
+        .collect();
+
+    Some(Box::new(move |stripe| {
+        let mut statistics = metadata.stripe_statistics(stripe);
+        let statistics: PlIndexMap<_, _> = stat_columns
+            .iter()
+            .filter_map(|(output_name, file_name)| {
+                Some((output_name.clone(), statistics.swap_remove(file_name)?))
+            })
+            .collect();
+        let batch_size = metadata.stripe_num_rows(stripe) as IdxSize;
+        skip_batch_predicate.can_skip_batch(batch_size, &live_columns, statistics)
+    }))
+}
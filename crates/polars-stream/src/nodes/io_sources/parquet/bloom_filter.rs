@@ -0,0 +1,194 @@
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_io::predicates::{ScanIOPredicate, SpecializedColumnPredicate};
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::{hash_byte, hash_native, is_in_set, read_from_slice};
+use polars_parquet::parquet::schema::types::PhysicalType;
+
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// Adds the row groups to `row_group_mask` for which a bloom filter shows that a column does
+/// not contain any of the values the predicate requires for that column.
+///
+/// Only `==` and `is_in` predicates on non-float columns that are read as-is are considered.
+/// Row groups that are already skipped are not looked at.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &FileMetadata,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    byte_source: &DynByteSource,
+    row_group_mask: Option<Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    if !use_statistics {
+        return Ok(row_group_mask);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(row_group_mask);
+    };
+
+    // The hashes of the values each column must be one of.
+    let mut column_hashes = Vec::new();
+    for projection in projected_arrow_fields {
+        let ArrowFieldProjection::Plain(arrow_field) = projection else {
+            continue;
+        };
+
+        let Some((_, Some(specialized))) = predicate
+            .column_predicates
+            .predicates
+            .get(&arrow_field.name)
+        else {
+            continue;
+        };
+
+        let scalars = match specialized {
+            SpecializedColumnPredicate::Equal(scalar) => std::slice::from_ref(scalar),
+            SpecializedColumnPredicate::EqualOneOf(scalars) => scalars.as_ref(),
+            _ => continue,
+        };
+
+        let dtype = DataType::from_arrow_field(arrow_field);
+        let Some(hashes) = scalars
+            .iter()
+            .map(|scalar| hash_scalar(scalar, &dtype))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        column_hashes.push((&arrow_field.name, hashes));
+    }
+
+    if column_hashes.is_empty() {
+        return Ok(row_group_mask);
+    }
+
+    let file_size = byte_source.get_size().await?;
+    let row_groups_slice = &metadata.row_groups[row_group_slice];
+    let num_row_groups = row_groups_slice.len();
+
+    // The bloom filters to check, as (row group, column, byte range).
+    let mut bloom_filters = Vec::new();
+    for (rg_idx, rg) in row_groups_slice.iter().enumerate() {
+        if row_group_mask
+            .as_ref()
+            .is_some_and(|mask| mask.get_bit(rg_idx))
+        {
+            continue;
+        }
+
+        for (col_idx, (name, hashes)) in column_hashes.iter().enumerate() {
+            // Nested columns don't get bloom filters.
+            let Some(&[idx]) = rg.columns_idxs_under_root_iter(name) else {
+                continue;
+            };
+
+            let column = &rg.parquet_columns()[idx];
+            let physical_type = column.physical_type();
+            if hashes.iter().any(|(pt, _)| *pt != physical_type) {
+                continue;
+            }
+
+            let column_metadata = column.metadata();
+            let (Some(offset), Some(length)) = (
+                column_metadata.bloom_filter_offset,
+                column_metadata.bloom_filter_length,
+            ) else {
+                continue;
+            };
+            let (Ok(offset), Ok(length)) = (usize::try_from(offset), usize::try_from(length))
+            else {
+                continue;
+            };
+            if offset.saturating_add(length) > file_size {
+                continue;
+            }
+
+            bloom_filters.push((rg_idx, col_idx, offset..offset + length));
+        }
+    }
+
+    if bloom_filters.is_empty() {
+        return Ok(row_group_mask);
+    }
+
+    let mut ranges = bloom_filters
+        .iter()
+        .map(|(_, _, range)| range.clone())
+        .collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut skip_mask = MutableBitmap::from_len_zeroed(num_row_groups);
+    for (rg_idx, col_idx, range) in bloom_filters {
+        let bytes = &bytes_map[&range.start];
+        let Ok(Some(bitset)) = read_from_slice(bytes.as_ref()) else {
+            continue;
+        };
+        if bitset.is_empty() || bitset.len() % 32 != 0 {
+            continue;
+        }
+
+        let (_, hashes) = &column_hashes[col_idx];
+        if hashes.iter().all(|(_, hash)| !is_in_set(bitset, *hash)) {
+            skip_mask.set(rg_idx, true);
+        }
+    }
+
+    let skip_mask = Bitmap::from(skip_mask);
+    let row_group_mask = match row_group_mask {
+        Some(row_group_mask) => &row_group_mask | &skip_mask,
+        None => skip_mask,
+    };
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            reading {} / {} row groups",
+            row_group_mask.unset_bits(),
+            num_row_groups,
+        );
+    }
+
+    Ok(Some(row_group_mask))
+}
+
+/// Returns the physical type and hash of the value as it is written to a column of `dtype`, or
+/// `None` if it can't be represented in that column.
+///
+/// Floats are not supported, as values that compare equal can have different bit patterns, such
+/// as `-0.0` and `0.0` or the different NaNs, and the bloom filter hashes the bit pattern.
+fn hash_scalar(scalar: &Scalar, dtype: &DataType) -> Option<(PhysicalType, u64)> {
+    use AnyValue as A;
+
+    let value = if scalar.dtype() == dtype {
+        scalar.value().clone()
+    } else {
+        scalar.value().strict_cast(dtype)?
+    };
+
+    // These casts MUST match the casts done when writing the bloom filter.
+    Some(match value.to_physical() {
+        A::Int8(v) => (PhysicalType::Int32, hash_native(v as i32)),
+        A::Int16(v) => (PhysicalType::Int32, hash_native(v as i32)),
+        A::Int32(v) => (PhysicalType::Int32, hash_native(v)),
+        A::UInt8(v) => (PhysicalType::Int32, hash_native(v as i32)),
+        A::UInt16(v) => (PhysicalType::Int32, hash_native(v as i32)),
+        A::UInt32(v) => (PhysicalType::Int32, hash_native(v as i32)),
+        A::Int64(v) => (PhysicalType::Int64, hash_native(v)),
+        A::UInt64(v) => (PhysicalType::Int64, hash_native(v as i64)),
+        A::String(v) => (PhysicalType::ByteArray, hash_byte(v)),
+        A::StringOwned(v) => (PhysicalType::ByteArray, hash_byte(v.as_str())),
+        A::Binary(v) => (PhysicalType::ByteArray, hash_byte(v)),
+        A::BinaryOwned(v) => (PhysicalType::ByteArray, hash_byte(v)),
+        // Nulls are not part of the bloom filter.
+        _ => return None,
+    })
+}
@@ -6,6 +6,8 @@ use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::ParallelStrategy;
 use polars_utils::IdxSize;
 
+use super::bloom_filter::calculate_row_group_bloom_filter_skip_mask;
+use super::page_index::calculate_row_group_page_selections;
 use super::row_group_data_fetch::RowGroupDataFetcher;
 use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
@@ -122,11 +124,40 @@ impl ParquetReadImpl {
                 predicate.as_ref(),
                 &metadata,
                 projected_arrow_fields.clone(),
-                row_index,
+                row_index.clone(),
                 verbose,
             )
             .await?;
 
+            let row_group_mask = calculate_row_group_bloom_filter_skip_mask(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &projected_arrow_fields,
+                &byte_source,
+                row_group_mask,
+                verbose,
+            )
+            .await?;
+
+            let (row_group_mask, page_selections) = if slice_range.is_none() {
+                calculate_row_group_page_selections(
+                    row_group_slice.clone(),
+                    use_statistics,
+                    predicate.as_ref(),
+                    &metadata,
+                    projected_arrow_fields.clone(),
+                    &byte_source,
+                    row_group_mask,
+                    row_index,
+                    verbose,
+                )
+                .await?
+            } else {
+                (row_group_mask, Default::default())
+            };
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
@@ -137,6 +168,7 @@ impl ParquetReadImpl {
                 byte_source,
                 row_group_slice,
                 row_group_mask,
+                page_selections,
                 row_offset,
             };
 
@@ -28,9 +28,11 @@ use crate::nodes::io_sources::parquet::projection::{
 use crate::nodes::{TaskPriority, io_sources};
 use crate::utils::task_handles_ext;
 
+mod bloom_filter;
 pub mod builder;
 mod init;
 mod metadata_utils;
+mod page_index;
 mod projection;
 mod row_group_data_fetch;
 mod row_group_decode;
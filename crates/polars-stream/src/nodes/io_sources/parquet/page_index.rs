@@ -0,0 +1,507 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_io::RowIndex;
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::read::{
+    ColumnIndex, OffsetIndex, deserialize_column_index, deserialize_offset_index,
+};
+use polars_parquet::read::statistics::deserialize_column_index as deserialize_page_statistics;
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
+
+use super::statistics::StatisticsColumns;
+use crate::async_executor::{self, TaskPriority};
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// The data pages of a column chunk that have to be read.
+pub(super) struct ColumnPageSelection {
+    /// The byte ranges of the dictionary page and the selected data pages. Adjacent ranges are
+    /// merged.
+    pub(super) byte_ranges: Vec<Range<usize>>,
+    /// The rows of the row group that are held by the selected data pages.
+    pub(super) row_ranges: Vec<Range<usize>>,
+}
+
+impl ColumnPageSelection {
+    pub(super) fn num_rows(&self) -> usize {
+        self.row_ranges.iter().map(|r| r.len()).sum()
+    }
+}
+
+/// The part of a row group that has to be read according to the page indexes.
+pub(super) struct RowGroupPageSelection {
+    /// The rows for which the page index can't rule out that they match the predicate.
+    pub(super) row_mask: Bitmap,
+    /// The pages to read of the column chunks, by index of the column chunk in the row group.
+    /// Column chunks that are not in here are read entirely.
+    pub(super) columns: PlHashMap<usize, ColumnPageSelection>,
+}
+
+struct ColumnPageIndex {
+    /// Only loaded for the columns of the predicate.
+    column_index: Option<ColumnIndex>,
+    offset_index: OffsetIndex,
+}
+
+/// Uses the page indexes (ColumnIndex / OffsetIndex) of the predicate columns to find the rows
+/// of each row group that may match the predicate. Row groups in which no row can match are
+/// added to `row_group_mask`, for the others a selection of the pages to read is returned by
+/// row group index.
+///
+/// The selected rows are decoded using a mask, so this must only be used without a pre-slice.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn calculate_row_group_page_selections(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &Arc<FileMetadata>,
+    projected_arrow_fields: Arc<[ArrowFieldProjection]>,
+    byte_source: &DynByteSource,
+    row_group_mask: Option<Bitmap>,
+    row_index: Option<RowIndex>,
+    verbose: bool,
+) -> PolarsResult<(Option<Bitmap>, PlHashMap<usize, Arc<RowGroupPageSelection>>)> {
+    if !use_statistics {
+        return Ok((row_group_mask, PlHashMap::new()));
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok((row_group_mask, PlHashMap::new()));
+    };
+
+    let Some(sbp) = predicate.skip_batch_predicate.clone() else {
+        return Ok((row_group_mask, PlHashMap::new()));
+    };
+
+    let live_columns = predicate.live_columns.clone();
+    let file_size = byte_source.get_size().await?;
+
+    // The row groups to look at, together with the byte ranges of the page indexes of their
+    // projected columns as (column chunk index, column index range, offset index range).
+    let mut row_groups = Vec::new();
+    for (i, rg) in metadata.row_groups[row_group_slice.clone()]
+        .iter()
+        .enumerate()
+    {
+        if rg.num_rows() == 0 || row_group_mask.as_ref().is_some_and(|m| m.get_bit(i)) {
+            continue;
+        }
+
+        let mut columns = Vec::new();
+        for projection in projected_arrow_fields.iter() {
+            let Some(idx) = flat_column_idx(rg, projection) else {
+                continue;
+            };
+
+            let column_chunk = rg.parquet_columns()[idx].column_chunk();
+            let Some(offset_index_range) = index_byte_range(
+                column_chunk.offset_index_offset,
+                column_chunk.offset_index_length,
+                file_size,
+            ) else {
+                continue;
+            };
+            let column_index_range = if live_columns.contains(projection.output_name()) {
+                index_byte_range(
+                    column_chunk.column_index_offset,
+                    column_chunk.column_index_length,
+                    file_size,
+                )
+            } else {
+                None
+            };
+
+            columns.push((idx, column_index_range, offset_index_range));
+        }
+
+        if columns.iter().any(|(_, range, _)| range.is_some()) {
+            row_groups.push((row_group_slice.start + i, columns));
+        }
+    }
+
+    if row_groups.is_empty() {
+        return Ok((row_group_mask, PlHashMap::new()));
+    }
+
+    let mut ranges = row_groups
+        .iter()
+        .flat_map(|(_, columns)| {
+            columns
+                .iter()
+                .flat_map(|(_, column_index_range, offset_index_range)| {
+                    column_index_range
+                        .iter()
+                        .cloned()
+                        .chain([offset_index_range.clone()])
+                })
+        })
+        .collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let metadata = metadata.clone();
+    let num_row_groups = row_group_slice.len();
+
+    // Deserializing the indexes and evaluating the predicate is CPU-bound, so we move off the
+    // tokio thread we are called from.
+    let (skip_mask, selections, num_rows_read, num_rows_total) =
+        async_executor::spawn(TaskPriority::High, async move {
+            let mut skip_mask = MutableBitmap::from_len_zeroed(num_row_groups);
+            let mut selections = PlHashMap::new();
+            let mut num_rows_read = 0;
+            let mut num_rows_total = 0;
+
+            for (rg_idx, columns) in row_groups {
+                let rg = &metadata.row_groups[rg_idx];
+                let num_rows = rg.num_rows();
+
+                let mut indexes = PlHashMap::with_capacity(columns.len());
+                for (idx, column_index_range, offset_index_range) in columns {
+                    // Indexes we can't make sense of are ignored, we then read the entire column
+                    // chunk.
+                    let Ok(offset_index) =
+                        deserialize_offset_index(&bytes_map[&offset_index_range.start])
+                    else {
+                        continue;
+                    };
+                    if !is_valid_offset_index(&offset_index, &rg.parquet_columns()[idx], num_rows) {
+                        continue;
+                    }
+
+                    let column_index = column_index_range
+                        .and_then(|range| deserialize_column_index(&bytes_map[&range.start]).ok())
+                        .filter(|ci| ci.null_pages.len() == offset_index.page_locations.len());
+
+                    indexes.insert(
+                        idx,
+                        ColumnPageIndex {
+                            column_index,
+                            offset_index,
+                        },
+                    );
+                }
+
+                // Split the row group at the page boundaries of the predicate columns, such that
+                // every interval lies within a single page of each of these columns.
+                let mut boundaries = indexes
+                    .values()
+                    .filter(|index| index.column_index.is_some())
+                    .flat_map(|index| {
+                        index
+                            .offset_index
+                            .page_locations
+                            .iter()
+                            .map(|page| page.first_row_index as usize)
+                    })
+                    .chain([num_rows])
+                    .collect::<Vec<_>>();
+                boundaries.sort_unstable();
+                boundaries.dedup();
+
+                // With a single page the row group statistics already tell us everything.
+                if boundaries.len() < 3 {
+                    continue;
+                }
+
+                let intervals = boundaries
+                    .windows(2)
+                    .map(|w| w[0]..w[1])
+                    .collect::<Vec<_>>();
+
+                let mut stat_columns = Vec::with_capacity(1 + live_columns.len() * 3);
+
+                let lengths: Vec<IdxSize> = intervals.iter().map(|r| r.len() as IdxSize).collect();
+                stat_columns.push(Column::new("len".into(), lengths));
+
+                for projection in projected_arrow_fields.iter() {
+                    let c = projection.output_name();
+
+                    if !live_columns.contains(c) {
+                        continue;
+                    }
+
+                    let mut statistics =
+                        load_page_statistics(rg, projection, &indexes, &intervals)?;
+
+                    // Note: Order is important here. We re-use the transform for the output column, meaning
+                    // that it may set the column name.
+                    statistics.min = projection.apply_transform(statistics.min)?;
+                    statistics.max = projection.apply_transform(statistics.max)?;
+
+                    let statistics = statistics.with_base_column_name(c);
+
+                    stat_columns.extend([statistics.min, statistics.max, statistics.null_count]);
+                }
+
+                if let Some(row_index) = &row_index {
+                    let row_offset = metadata.row_groups[..rg_idx]
+                        .iter()
+                        .map(|md| md.num_rows())
+                        .sum();
+                    let statistics = build_row_index_statistics(row_index, row_offset, &intervals)
+                        .with_base_column_name(&row_index.name);
+
+                    stat_columns.extend([statistics.min, statistics.max, statistics.null_count]);
+                }
+
+                let statistics_df = DataFrame::new_with_height(intervals.len(), stat_columns)?;
+                let skip_intervals = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+                let mut row_mask = MutableBitmap::with_capacity(num_rows);
+                for (interval, skip) in intervals.iter().zip(skip_intervals.iter()) {
+                    row_mask.extend_constant(interval.len(), !skip);
+                }
+                let row_mask = row_mask.freeze();
+
+                num_rows_total += num_rows;
+                num_rows_read += row_mask.set_bits();
+
+                if row_mask.unset_bits() == 0 {
+                    continue;
+                }
+
+                if row_mask.set_bits() == 0 {
+                    skip_mask.set(rg_idx - row_group_slice.start, true);
+                    continue;
+                }
+
+                let columns = indexes
+                    .iter()
+                    .map(|(&idx, index)| {
+                        let column = &rg.parquet_columns()[idx];
+                        (idx, select_pages(column, &index.offset_index, &row_mask))
+                    })
+                    .collect();
+
+                selections.insert(
+                    rg_idx,
+                    Arc::new(RowGroupPageSelection { row_mask, columns }),
+                );
+            }
+
+            PolarsResult::Ok((skip_mask, selections, num_rows_read, num_rows_total))
+        })
+        .await?;
+
+    if verbose && num_rows_total > 0 {
+        eprintln!(
+            "[ParquetFileReader]: Page index pushdown: \
+            reading {num_rows_read} / {num_rows_total} rows",
+        );
+    }
+
+    let row_group_mask = if skip_mask.set_bits() > 0 {
+        let skip_mask = Bitmap::from(skip_mask);
+        Some(match row_group_mask {
+            Some(row_group_mask) => &row_group_mask | &skip_mask,
+            None => skip_mask,
+        })
+    } else {
+        row_group_mask
+    };
+
+    Ok((row_group_mask, selections))
+}
+
+/// Returns the index of the column chunk of a projected column if it is a single flat column
+/// chunk. We only use the page index for those, as only there a page holds whole rows.
+fn flat_column_idx(rg: &RowGroupMetadata, projection: &ArrowFieldProjection) -> Option<usize> {
+    let arrow_field = projection.arrow_field();
+
+    if arrow_field.dtype().is_nested() {
+        return None;
+    }
+
+    match rg.columns_idxs_under_root_iter(&arrow_field.name)? {
+        &[idx] => Some(idx),
+        _ => None,
+    }
+}
+
+fn index_byte_range(
+    offset: Option<i64>,
+    length: Option<i32>,
+    file_size: usize,
+) -> Option<Range<usize>> {
+    let offset = usize::try_from(offset?).ok()?;
+    let length = usize::try_from(length?).ok()?;
+
+    (length > 0 && offset.saturating_add(length) <= file_size).then_some(offset..offset + length)
+}
+
+/// Checks that the pages are ordered and lie within the column chunk, as we use them to select
+/// the rows and bytes to read.
+fn is_valid_offset_index(
+    offset_index: &OffsetIndex,
+    column: &ColumnChunkMetadata,
+    num_rows: usize,
+) -> bool {
+    let pages = &offset_index.page_locations;
+    let byte_range = column.byte_range();
+
+    let (Some(first), Some(last)) = (pages.first(), pages.last()) else {
+        return false;
+    };
+
+    first.first_row_index == 0
+        && last.first_row_index < num_rows as i64
+        && first.offset >= byte_range.start as i64
+        && last.offset + last.compressed_page_size as i64 <= byte_range.end as i64
+        && pages.iter().all(|page| page.compressed_page_size > 0)
+        && pages.windows(2).all(|w| {
+            w[0].first_row_index < w[1].first_row_index
+                && w[0].offset + w[0].compressed_page_size as i64 <= w[1].offset
+        })
+}
+
+fn load_page_statistics(
+    rg: &RowGroupMetadata,
+    projection: &ArrowFieldProjection,
+    indexes: &PlHashMap<usize, ColumnPageIndex>,
+    intervals: &[Range<usize>],
+) -> PolarsResult<StatisticsColumns> {
+    let arrow_field = projection.arrow_field();
+
+    let null_statistics = || {
+        Ok(StatisticsColumns::new_null(
+            &DataType::from_arrow_field(arrow_field),
+            intervals.len(),
+        ))
+    };
+
+    let Some(idx) = flat_column_idx(rg, projection) else {
+        return null_statistics();
+    };
+
+    let Some(ColumnPageIndex {
+        column_index: Some(column_index),
+        offset_index,
+    }) = indexes.get(&idx)
+    else {
+        return null_statistics();
+    };
+
+    let primitive_type = &rg.parquet_columns()[idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+
+    let Some(statistics) = deserialize_page_statistics(arrow_field, primitive_type, column_index)?
+    else {
+        return null_statistics();
+    };
+
+    let page_null_counts = statistics.null_count.clone();
+    let statistics = StatisticsColumns::from_arrow_statistics(statistics, arrow_field)?;
+
+    // The page that holds each interval.
+    let pages = &offset_index.page_locations;
+    let page_idxs = intervals
+        .iter()
+        .map(|r| (pages.partition_point(|p| p.first_row_index as usize <= r.start) - 1) as IdxSize)
+        .collect::<Vec<_>>();
+
+    // The null count of a page only holds for a part of that page if there are no nulls at all.
+    let null_count: IdxCa = intervals
+        .iter()
+        .zip(&page_idxs)
+        .map(|(interval, &page_idx)| {
+            let page_idx = page_idx as usize;
+            let null_count = page_null_counts.get(page_idx)?;
+            let page_end = pages
+                .get(page_idx + 1)
+                .map_or(rg.num_rows(), |p| p.first_row_index as usize);
+            let is_entire_page = interval.start == pages[page_idx].first_row_index as usize
+                && interval.end == page_end;
+
+            (null_count == 0 || is_entire_page).then_some(null_count)
+        })
+        .collect();
+
+    Ok(StatisticsColumns {
+        min: statistics.min.take_slice(&page_idxs)?,
+        max: statistics.max.take_slice(&page_idxs)?,
+        null_count: null_count.into_column(),
+    })
+}
+
+fn build_row_index_statistics(
+    row_index: &RowIndex,
+    row_offset: usize,
+    intervals: &[Range<usize>],
+) -> StatisticsColumns {
+    let offset = row_index
+        .offset
+        .saturating_add(IdxSize::try_from(row_offset).unwrap_or(IdxSize::MAX));
+
+    let bounds = intervals.iter().map(|r| {
+        let start = offset.checked_add(IdxSize::try_from(r.start).ok()?)?;
+        let end = offset.checked_add(IdxSize::try_from(r.end).ok()?)?;
+        Some((start, end - 1))
+    });
+
+    let min: IdxCa = bounds.clone().map(|b| b.map(|(min, _)| min)).collect();
+    let max: IdxCa = bounds.map(|b| b.map(|(_, max)| max)).collect();
+
+    StatisticsColumns {
+        min: min.into_column(),
+        max: max.into_column(),
+        null_count: IdxCa::full(PlSmallStr::EMPTY, 0, intervals.len()).into_column(),
+    }
+}
+
+/// Selects the data pages of a column chunk that hold any of the rows in `row_mask`.
+fn select_pages(
+    column: &ColumnChunkMetadata,
+    offset_index: &OffsetIndex,
+    row_mask: &Bitmap,
+) -> ColumnPageSelection {
+    let pages = &offset_index.page_locations;
+    let column_start = column.byte_range().start as usize;
+
+    let mut byte_ranges = Vec::new();
+    let mut row_ranges = Vec::new();
+
+    // Everything before the first data page, i.e. the dictionary page.
+    let first_page_offset = pages[0].offset as usize;
+    if column_start < first_page_offset {
+        byte_ranges.push(column_start..first_page_offset);
+    }
+
+    for (i, page) in pages.iter().enumerate() {
+        let row_start = page.first_row_index as usize;
+        let row_end = pages
+            .get(i + 1)
+            .map_or(row_mask.len(), |p| p.first_row_index as usize);
+
+        if row_mask
+            .clone()
+            .sliced(row_start, row_end - row_start)
+            .set_bits()
+            == 0
+        {
+            continue;
+        }
+
+        let byte_start = page.offset as usize;
+        let byte_end = byte_start + page.compressed_page_size as usize;
+
+        push_merged(&mut byte_ranges, byte_start..byte_end);
+        push_merged(&mut row_ranges, row_start..row_end);
+    }
+
+    ColumnPageSelection {
+        byte_ranges,
+        row_ranges,
+    }
+}
+
+fn push_merged(ranges: &mut Vec<Range<usize>>, range: Range<usize>) {
+    match ranges.last_mut() {
+        Some(last) if last.end == range.start => last.end = range.end,
+        _ => ranges.push(range),
+    }
+}
@@ -12,6 +12,7 @@ use polars_parquet::read::RowGroupMetadata;
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::page_index::RowGroupPageSelection;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::utils::task_handles_ext;
 
@@ -22,6 +23,8 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: Vec<(usize, IsSorted)>,
+    /// Set if only some pages of the row group were fetched.
+    pub(super) page_selection: Option<Arc<RowGroupPageSelection>>,
 }
 
 pub(super) struct RowGroupDataFetcher {
@@ -36,6 +39,8 @@ pub(super) struct RowGroupDataFetcher {
 
     pub(super) row_group_slice: Range<usize>,
     pub(super) row_group_mask: Option<Bitmap>,
+    /// The pages to read of the row groups for which we used the page index.
+    pub(super) page_selections: PlHashMap<usize, Arc<RowGroupPageSelection>>,
 
     pub(super) row_offset: usize,
 }
@@ -82,6 +87,7 @@ impl RowGroupDataFetcher {
             let projection = self.projection.clone();
             let is_full_projection = self.is_full_projection;
             let memory_prefetch_func = self.memory_prefetch_func;
+            let page_selection = self.page_selections.get(&idx).cloned();
             let io_runtime = polars_io::pl_async::get_runtime();
 
             let handle = io_runtime.spawn(async move {
@@ -117,6 +123,21 @@ impl RowGroupDataFetcher {
                             offset: 0,
                             mem_slice,
                         }
+                    } else if let Some(page_selection) = page_selection.as_deref() {
+                        let mut ranges = get_row_group_byte_ranges_for_page_selection(
+                            row_group_metadata,
+                            &mut projection.iter().map(|x| &x.arrow_field().name),
+                            page_selection,
+                        )
+                        .collect::<Vec<_>>();
+
+                        let n_ranges = ranges.len();
+
+                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+
+                        assert_eq!(bytes_map.len(), n_ranges);
+
+                        FetchedBytes::BytesMap(bytes_map)
                     } else if !is_full_projection {
                         let mut ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
@@ -158,6 +179,7 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_selection,
                 })
             });
 
@@ -208,3 +230,25 @@ fn get_row_group_byte_ranges_for_projection<'a>(
             })
     })
 }
+
+/// Like [`get_row_group_byte_ranges_for_projection`], but only returns the byte ranges of the
+/// selected pages for column chunks with a page selection.
+fn get_row_group_byte_ranges_for_page_selection<'a>(
+    row_group_metadata: &'a RowGroupMetadata,
+    columns: &'a mut dyn Iterator<Item = &PlSmallStr>,
+    page_selection: &'a RowGroupPageSelection,
+) -> impl Iterator<Item = std::ops::Range<usize>> + 'a {
+    columns.flat_map(move |col_name| {
+        row_group_metadata
+            .columns_idxs_under_root_iter(col_name)
+            .into_iter()
+            .flatten()
+            .flat_map(move |&idx| match page_selection.columns.get(&idx) {
+                Some(column_pages) => column_pages.byte_ranges.clone(),
+                None => {
+                    let byte_range = row_group_metadata.parquet_columns()[idx].byte_range();
+                    vec![byte_range.start as usize..byte_range.end as usize]
+                },
+            })
+    })
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn};
 use polars_core::series::Series;
+use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
@@ -13,11 +14,17 @@ use polars_io::predicates::{
 pub use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::_internal::calc_prefilter_cost;
 use polars_io::prelude::try_set_sorted_flag;
-use polars_parquet::read::{Filter, ParquetType, PredicateFilter, PrimitiveLogicalType};
+use polars_parquet::parquet::read::PageMetaData;
+use polars_parquet::read::{
+    BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, ParquetType, PredicateFilter,
+    PrimitiveLogicalType, column_iter_to_arrays,
+};
 use polars_utils::IdxSize;
 use polars_utils::enum_unit_vec::EnumUnitVec;
+use polars_utils::mmap::{MemReader, MemSlice};
 use polars_utils::pl_str::PlSmallStr;
 
+use super::page_index::RowGroupPageSelection;
 use super::row_group_data_fetch::RowGroupData;
 use crate::async_primitives::opt_spawned_future::parallelize_first_to_local;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
@@ -48,8 +55,11 @@ impl RowGroupDecoder {
             slice.0 == 0 && slice.1 >= row_group_data.row_group_metadata.num_rows()
         });
 
+        // Rows that are not selected by the page index are masked out, which we don't support
+        // combining with prefiltering.
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
+            && row_group_data.page_selection.is_none()
             && !self.predicate_field_indices.is_empty()
         {
             self.row_group_data_to_df_prefiltered(row_group_data).await
@@ -75,19 +85,29 @@ impl RowGroupDecoder {
 
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
+        let filter = match &row_group_data.page_selection {
+            Some(page_selection) => {
+                debug_assert!(row_group_data.slice.is_none());
+                Filter::Mask(page_selection.row_mask.clone())
+            },
+            None => Filter::Range(slice_range.clone()),
+        };
+        let projection_height = filter.num_rows(row_group_data.row_group_metadata.num_rows());
+
         if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
+            let s = match &filter {
+                Filter::Mask(mask) => s.filter(&BooleanChunked::from_bitmap(
+                    PlSmallStr::EMPTY,
+                    mask.clone(),
+                ))?,
+                _ => s,
+            };
             out_columns.push(s);
         }
 
         let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
-
-        let projection_height = slice_range.len();
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, Some(filter))
+            .await?;
 
         out_columns.extend(decoded_cols);
 
@@ -236,10 +256,11 @@ fn decode_column(
     filter: Option<polars_parquet::read::Filter>,
     expected_num_rows: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
-    let Some(iter) = row_group_data
+    if row_group_data
         .row_group_metadata
         .columns_under_root_iter(&arrow_field.name)
-    else {
+        .is_none()
+    {
         return Ok((
             Column::full_null(
                 arrow_field.name.clone(),
@@ -250,33 +271,41 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
+    let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
+    let mut post_filter_mask = None;
+    let (array, pred_true_mask) = match (filter, row_group_data.page_selection.as_deref()) {
+        (Some(Filter::Mask(row_mask)), Some(page_selection)) => {
+            if arrow_field.dtype.is_nested() {
+                // We don't select pages of nested columns, they are filtered after decoding as
+                // in the pre-filtered decode.
+                post_filter_mask = Some(row_mask);
+                polars_io::prelude::_internal::to_deserializer(
+                    columns_to_deserialize(arrow_field, row_group_data),
+                    arrow_field.clone(),
+                    None,
+                )?
+            } else {
+                decode_selected_pages(arrow_field, row_group_data, page_selection, row_mask)?
+            }
+        },
+        (filter, _) => polars_io::prelude::_internal::to_deserializer(
+            columns_to_deserialize(arrow_field, row_group_data),
+            arrow_field.clone(),
+            filter,
+        )?,
+    };
 
-    let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
+    let mut series = Series::try_from((arrow_field, array))?;
 
-    let (array, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        filter,
-    )?;
+    if let Some(mask) = post_filter_mask {
+        series = series.filter(&BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask))?;
+    }
 
     if !skip_num_rows_check {
-        assert_eq!(array.len(), expected_num_rows);
+        assert_eq!(series.len(), expected_num_rows);
     }
 
-    let mut series = Series::try_from((arrow_field, array))?;
-
     if let Some(col_idxs) = row_group_data
         .row_group_metadata
         .columns_idxs_under_root_iter(&arrow_field.name)
@@ -291,6 +320,89 @@ fn decode_column(
     Ok((series.into_column(), pred_true_mask))
 }
 
+/// The column chunks of `arrow_field` together with their (entirely) fetched bytes.
+fn columns_to_deserialize<'a>(
+    arrow_field: &ArrowField,
+    row_group_data: &'a RowGroupData,
+) -> Vec<(&'a ColumnChunkMetadata, MemSlice)> {
+    row_group_data
+        .row_group_metadata
+        .columns_under_root_iter(&arrow_field.name)
+        .into_iter()
+        .flatten()
+        .map(|col_md| {
+            let byte_range = col_md.byte_range();
+
+            (
+                col_md,
+                row_group_data
+                    .fetched_bytes
+                    .get_range(byte_range.start as usize..byte_range.end as usize),
+            )
+        })
+        .collect()
+}
+
+/// Decodes the rows in `row_mask` of a flat column, of which only the pages in the
+/// `page_selection` may have been fetched.
+fn decode_selected_pages(
+    arrow_field: &ArrowField,
+    row_group_data: &RowGroupData,
+    page_selection: &RowGroupPageSelection,
+    row_mask: Bitmap,
+) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
+    let column_pages = match row_group_data
+        .row_group_metadata
+        .columns_idxs_under_root_iter(&arrow_field.name)
+    {
+        Some(&[idx]) => page_selection
+            .columns
+            .get(&idx)
+            .map(|column_pages| (idx, column_pages)),
+        _ => None,
+    };
+
+    let Some((idx, column_pages)) = column_pages else {
+        return polars_io::prelude::_internal::to_deserializer(
+            columns_to_deserialize(arrow_field, row_group_data),
+            arrow_field.clone(),
+            Some(Filter::Mask(row_mask)),
+        );
+    };
+
+    let col_md = &row_group_data.row_group_metadata.parquet_columns()[idx];
+
+    let bytes = match column_pages.byte_ranges.as_slice() {
+        [range] => row_group_data.fetched_bytes.get_range(range.clone()),
+        ranges => {
+            let mut bytes = Vec::with_capacity(ranges.iter().map(|r| r.len()).sum());
+            for range in ranges {
+                bytes.extend_from_slice(&row_group_data.fetched_bytes.get_range(range.clone()));
+            }
+            MemSlice::from_vec(bytes)
+        },
+    };
+
+    // For flat columns every value is a row.
+    let mut page_meta = PageMetaData::from(col_md);
+    page_meta.num_values = column_pages.num_rows() as i64;
+    let pages =
+        PageReader::new_with_page_meta(MemReader::new(bytes), page_meta, vec![], usize::MAX);
+
+    // The mask relative to the rows of the selected pages.
+    let mut mask = MutableBitmap::with_capacity(column_pages.num_rows());
+    for range in &column_pages.row_ranges {
+        mask.extend_from_bitmap(&row_mask.clone().sliced(range.start, range.len()));
+    }
+
+    column_iter_to_arrays(
+        vec![BasicDecompressor::new(pages, vec![])],
+        vec![&col_md.descriptor().descriptor.primitive_type],
+        arrow_field.clone(),
+        Some(Filter::Mask(mask.freeze())),
+    )
+}
+
 /// Filters columns, in parallel depending number of rows / columns.
 async fn filter_cols(
     cols: Vec<Column>,
@@ -633,10 +745,11 @@ fn decode_column_prefiltered(
     mask_bitmap: &Bitmap,
     expected_num_rows: usize,
 ) -> PolarsResult<Column> {
-    let Some(iter) = row_group_data
+    if row_group_data
         .row_group_metadata
         .columns_under_root_iter(&arrow_field.name)
-    else {
+        .is_none()
+    {
         return Ok(Column::full_null(
             arrow_field.name.clone(),
             expected_num_rows,
@@ -644,26 +757,13 @@ fn decode_column_prefiltered(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
-
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
-
     let prefilter = !arrow_field.dtype.is_nested();
 
     let deserialize_filter =
         prefilter.then(|| polars_parquet::read::Filter::Mask(mask_bitmap.clone()));
 
     let (array, _) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
+        columns_to_deserialize(arrow_field, row_group_data),
         arrow_field.clone(),
         deserialize_filter,
     )?;
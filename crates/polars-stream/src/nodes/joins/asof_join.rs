@@ -0,0 +1,441 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::series::SeriesMethods;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::InMemoryJoiner;
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::nodes::compute_node_prelude::*;
+
+/// An as-of join of two inputs which are both sorted on their `on` key.
+///
+/// With `by` groups the keys only have to be sorted within each group, so all state is kept per
+/// group.
+///
+/// The left input is streamed through, while the right input is only buffered as far as
+/// needed to join the current left morsel. Right rows which can no longer be matched are
+/// dropped from the buffer, except for the last row of each group before the left keys seen so
+/// far, which a backward or nearest search may still find.
+pub struct AsOfJoinNode {
+    right_input_schema: Arc<Schema>,
+    left_key: PlSmallStr,
+    right_key: PlSmallStr,
+    left_by: Vec<PlSmallStr>,
+    right_by: Vec<PlSmallStr>,
+    check_sortedness: bool,
+
+    right_done: bool,
+
+    // Left morsels we received but could not join yet.
+    left_unjoined: VecDeque<Morsel>,
+
+    // The state per group, keyed by the row encoding of the `by` columns. Without groups all
+    // rows are in the group with an empty key.
+    groups: PlHashMap<Vec<u8>, GroupState>,
+
+    joiners: Vec<InMemoryJoiner>,
+}
+
+struct GroupState {
+    // The right rows which may still be matched, without rows that have a null key.
+    right_buffer: DataFrame,
+
+    // The last non-null keys seen, to check sortedness across morsels.
+    left_last_key: Option<Series>,
+    right_last_key: Option<Series>,
+}
+
+impl GroupState {
+    fn new(right_input_schema: &Schema) -> Self {
+        Self {
+            right_buffer: DataFrame::empty_with_schema(right_input_schema),
+            left_last_key: None,
+            right_last_key: None,
+        }
+    }
+}
+
+impl AsOfJoinNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key: PlSmallStr,
+        right_key: PlSmallStr,
+        left_by: Vec<PlSmallStr>,
+        right_by: Vec<PlSmallStr>,
+        check_sortedness: bool,
+        joiners: Vec<InMemoryJoiner>,
+    ) -> Self {
+        Self {
+            right_input_schema,
+            left_key,
+            right_key,
+            left_by,
+            right_by,
+            check_sortedness,
+            right_done: false,
+            left_unjoined: VecDeque::new(),
+            groups: PlHashMap::new(),
+            joiners,
+        }
+    }
+}
+
+/// Splits the frame into its `by` groups, keyed by the row encoding of the group columns.
+fn split_groups(df: DataFrame, by: &[PlSmallStr]) -> PolarsResult<Vec<(Vec<u8>, DataFrame)>> {
+    if by.is_empty() {
+        return Ok(vec![(Vec::new(), df)]);
+    }
+
+    let by_columns = by
+        .iter()
+        .map(|name| df.column(name).cloned())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let rows = _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, &by_columns)?;
+    let mut group_idxs = PlIndexMap::<&[u8], Vec<IdxSize>>::default();
+    for (i, row) in rows.iter().enumerate() {
+        group_idxs
+            .entry(row.unwrap_or_default())
+            .or_default()
+            .push(i as IdxSize);
+    }
+
+    Ok(group_idxs
+        .into_iter()
+        .map(|(row, idxs)| {
+            // SAFETY: the indices are rows of the frame.
+            let group = unsafe { df.take_slice_unchecked(&idxs) };
+            (row.to_vec(), group)
+        })
+        .collect())
+}
+
+/// Returns the last non-null key, if any.
+fn last_non_null_key(df: &DataFrame, key: &str) -> PolarsResult<Option<Series>> {
+    let key = df.column(key)?.as_materialized_series();
+    let key = if key.has_nulls() {
+        key.drop_nulls()
+    } else {
+        key.clone()
+    };
+    Ok((!key.is_empty()).then(|| key.tail(Some(1))))
+}
+
+/// Checks that the (non-null) keys are sorted and don't precede the keys of earlier morsels.
+fn check_sorted(key: &Series, prev_last_key: Option<&Series>) -> PolarsResult<()> {
+    key.ensure_sorted_arg("asof_join")?;
+    if let Some(prev_last_key) = prev_last_key {
+        polars_ensure!(
+            key.is_empty() || !key.head(Some(1)).lt(prev_last_key)?.all(),
+            InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first"
+        );
+    }
+    Ok(())
+}
+
+/// Returns whether the right input has advanced beyond the given left key, such that all right
+/// rows which can match it are buffered.
+fn right_covers(right_last_key: Option<&Series>, left_key: &Series) -> PolarsResult<bool> {
+    match right_last_key {
+        Some(right_last_key) => Ok(right_last_key.gt(left_key)?.all()),
+        None => Ok(false),
+    }
+}
+
+fn buffer_right(
+    df: DataFrame,
+    right_key: &str,
+    check_sortedness: bool,
+    group: &mut GroupState,
+) -> PolarsResult<()> {
+    // Rows with a null key never match.
+    let key = df.column(right_key)?.as_materialized_series();
+    let df = if key.has_nulls() {
+        df.filter(&key.is_not_null())?
+    } else {
+        df
+    };
+    if df.height() == 0 {
+        return Ok(());
+    }
+
+    let key = df.column(right_key)?.as_materialized_series();
+    if check_sortedness {
+        check_sorted(key, group.right_last_key.as_ref())?;
+    }
+    group.right_last_key = Some(key.tail(Some(1)));
+    group.right_buffer.vstack_mut_owned(df)?;
+    Ok(())
+}
+
+/// Buffers a right morsel in the states of its groups.
+fn buffer_right_groups(
+    df: DataFrame,
+    right_key: &str,
+    right_by: &[PlSmallStr],
+    check_sortedness: bool,
+    right_input_schema: &Schema,
+    groups: &mut PlHashMap<Vec<u8>, GroupState>,
+) -> PolarsResult<()> {
+    for (row, df) in split_groups(df, right_by)? {
+        let group = groups
+            .entry(row)
+            .or_insert_with(|| GroupState::new(right_input_schema));
+        buffer_right(df, right_key, check_sortedness, group)?;
+    }
+    Ok(())
+}
+
+/// Drops the buffered right rows which can't be matched by left keys greater than or equal to
+/// `left_key`.
+fn prune_right(
+    right_buffer: &DataFrame,
+    right_key: &str,
+    left_key: &Series,
+) -> PolarsResult<DataFrame> {
+    // The keys are sorted, so this is the number of leading rows with a smaller key.
+    let key = right_buffer.column(right_key)?.as_materialized_series();
+    let num_smaller = key.lt(left_key)?.sum().unwrap_or(0) as usize;
+    if num_smaller <= 1 {
+        return Ok(right_buffer.clone());
+    }
+
+    // Keep the last row with a smaller key.
+    Ok(right_buffer.slice(num_smaller as i64 - 1, usize::MAX))
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // Every left row is joined exactly once, so we're done once the left side is done.
+        let left_done = recv[0] == PortState::Done && self.left_unjoined.is_empty();
+        if send[0] == PortState::Done || left_done {
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            send[0] = PortState::Done;
+            self.groups.clear();
+            self.left_unjoined.clear();
+            return Ok(());
+        }
+
+        self.right_done = recv[1] == PortState::Done;
+
+        let send_blocked = send[0] == PortState::Blocked;
+        let left_blocked = recv[0] == PortState::Blocked && self.left_unjoined.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        send[0] = if left_blocked || right_blocked {
+            PortState::Blocked
+        } else {
+            PortState::Ready
+        };
+        if recv[0] != PortState::Done {
+            recv[0] = if send_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        if recv[1] != PortState::Done {
+            recv[1] = if send_blocked || left_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        let senders = send_ports[0].take().unwrap().parallel();
+        let (mut distributor, dist_recv) =
+            distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let mut left = recv_ports[0].take().map(|p| p.serial());
+        let mut right = recv_ports[1].take().map(|p| p.serial());
+
+        let right_input_schema = &*self.right_input_schema;
+        let left_key = &self.left_key;
+        let right_key = &self.right_key;
+        let left_by = &*self.left_by;
+        let right_by = &*self.right_by;
+        let check_sortedness = self.check_sortedness;
+        let right_done = self.right_done;
+        let left_unjoined = &mut self.left_unjoined;
+        let groups = &mut self.groups;
+
+        join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+            // If we have to stop before we're done, we buffer everything that was already
+            // produced on the given port.
+            async fn stop_and_buffer(port: &mut Receiver<Morsel>, buffer: &mut Vec<Morsel>) {
+                let Ok(morsel) = port.recv().await else {
+                    return;
+                };
+                morsel.source_token().stop();
+                buffer.push(morsel);
+                while let Ok(morsel) = port.recv().await {
+                    buffer.push(morsel);
+                }
+            }
+
+            'joining: loop {
+                let morsel = match left_unjoined.pop_front() {
+                    Some(morsel) => morsel,
+                    None => {
+                        let Some(port) = &mut left else { break };
+                        let Ok(morsel) = port.recv().await else {
+                            break;
+                        };
+                        morsel
+                    },
+                };
+
+                // The groups of this morsel, with their part of the morsel and last key.
+                let left_groups = split_groups(morsel.df().clone(), left_by)?
+                    .into_iter()
+                    .map(|(row, df)| {
+                        let last_key = last_non_null_key(&df, left_key)?;
+                        Ok((row, df, last_key))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                // Make sure all right rows which can match this morsel are buffered.
+                for (row, _, last_key) in &left_groups {
+                    let Some(last_key) = last_key else {
+                        continue;
+                    };
+                    while !right_done
+                        && !right_covers(
+                            groups.get(row).and_then(|g| g.right_last_key.as_ref()),
+                            last_key,
+                        )?
+                    {
+                        let received = match &mut right {
+                            Some(port) => port.recv().await.ok(),
+                            None => None,
+                        };
+                        let Some(right_morsel) = received else {
+                            left_unjoined.push_front(morsel);
+                            break 'joining;
+                        };
+                        buffer_right_groups(
+                            right_morsel.into_df(),
+                            right_key,
+                            right_by,
+                            check_sortedness,
+                            right_input_schema,
+                            groups,
+                        )?;
+                    }
+                }
+
+                // Collect the buffered right rows of the groups in this morsel.
+                let mut right_dfs = Vec::with_capacity(left_groups.len());
+                for (row, df, last_key) in &left_groups {
+                    let group = groups
+                        .entry(row.clone())
+                        .or_insert_with(|| GroupState::new(right_input_schema));
+                    if check_sortedness {
+                        let key = df.column(left_key)?.as_materialized_series();
+                        check_sorted(&key.drop_nulls(), group.left_last_key.as_ref())?;
+                    }
+                    if last_key.is_some() {
+                        group.left_last_key = last_key.clone();
+                    }
+
+                    if group.right_buffer.should_rechunk() {
+                        group.right_buffer.rechunk_mut();
+                    }
+                    right_dfs.push(group.right_buffer.clone());
+                }
+                let right_df = if right_dfs.is_empty() {
+                    DataFrame::empty_with_schema(right_input_schema)
+                } else {
+                    accumulate_dataframes_vertical_unchecked(right_dfs)
+                };
+
+                let (df, seq, source_token, _) = morsel.into_inner();
+                if distributor
+                    .send((df, right_df, seq, source_token))
+                    .await
+                    .is_err()
+                {
+                    return Ok(());
+                }
+
+                for (row, _, last_key) in &left_groups {
+                    if let (Some(group), Some(last_key)) = (groups.get_mut(row), last_key) {
+                        group.right_buffer = prune_right(&group.right_buffer, right_key, last_key)?;
+                    }
+                }
+            }
+
+            // Make sure we don't lose any morsels which were already produced.
+            if let Some(port) = &mut left {
+                let mut buffered = Vec::new();
+                stop_and_buffer(port, &mut buffered).await;
+                left_unjoined.extend(buffered);
+            }
+            if let Some(port) = &mut right {
+                let mut buffered = Vec::new();
+                stop_and_buffer(port, &mut buffered).await;
+                for morsel in buffered {
+                    buffer_right_groups(
+                        morsel.into_df(),
+                        right_key,
+                        right_by,
+                        check_sortedness,
+                        right_input_schema,
+                        groups,
+                    )?;
+                }
+            }
+
+            Ok(())
+        }));
+
+        join_handles.extend(
+            dist_recv
+                .into_iter()
+                .zip(senders)
+                .zip(self.joiners.iter_mut())
+                .map(|((mut recv, mut send), joiner)| {
+                    scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok((left, right, seq, source_token)) = recv.recv().await {
+                            let df = joiner(left, right)?;
+                            if send.send(Morsel::new(df, seq, source_token)).await.is_err() {
+                                break;
+                            }
+                        }
+                        Ok(())
+                    })
+                }),
+        );
+    }
+}
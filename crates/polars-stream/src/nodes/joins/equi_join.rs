@@ -1,9 +1,10 @@
 use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, VecDeque};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use parking_lot::Mutex;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
@@ -25,12 +26,16 @@ use rayon::prelude::*;
 
 use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
 use crate::async_executor;
-use crate::async_primitives::connector::{Receiver, Sender};
+use crate::async_primitives::connector::{Receiver, Sender, connector};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::expression::StreamExpr;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    MemoryBudget, MemoryReservation, SpillFile, SpillReader, get_spill_memory_limit,
+    hstack_spill_keys, split_spill_keys,
+};
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -171,23 +176,30 @@ fn select_schema(schema: &Schema, selector: &[Option<PlSmallStr>]) -> Schema {
         .collect()
 }
 
-async fn select_keys(
+async fn select_key_df(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &EquiJoinParams,
     state: &ExecutionState,
-) -> PolarsResult<HashKeys> {
+) -> PolarsResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
-    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
-    Ok(HashKeys::from_df(
-        &keys,
-        params.random_state,
-        params.args.nulls_equal,
-        false,
-    ))
+    DataFrame::new_with_broadcast_len(key_columns, df.height())
+}
+
+fn hash_keys_from_df(keys: &DataFrame, params: &EquiJoinParams) -> HashKeys {
+    HashKeys::from_df(keys, params.random_state, params.args.nulls_equal, false)
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<HashKeys> {
+    let keys = select_key_df(df, key_selectors, state).await?;
+    Ok(hash_keys_from_df(&keys, params))
 }
 
 fn select_payload(df: DataFrame, selector: &[Option<PlSmallStr>]) -> DataFrame {
@@ -387,6 +399,7 @@ impl SampleState {
                         BuildState::partition_and_sink(
                             recv,
                             local_builder,
+                            &build_state.spill_budget,
                             partitioner.clone(),
                             params,
                             state,
@@ -407,7 +420,6 @@ impl SampleState {
     }
 }
 
-#[derive(Default)]
 struct LocalBuilder {
     // The complete list of morsels and their computed hashes seen by this builder.
     morsels: Vec<(MorselSeq, DataFrame, HashKeys)>,
@@ -421,11 +433,62 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // The key columns of morsels, only kept if we might have to spill them.
+    key_dfs: Vec<DataFrame>,
+    morsels_reservation: MemoryReservation,
+
+    // The spilled morsels per partition, each spilled frame consists of the
+    // key columns followed by the payload.
+    spilled_per_p: Vec<Vec<SpillFile>>,
+}
+
+impl LocalBuilder {
+    /// Writes the morsels to disk, with a separate spill file per partition.
+    fn spill_morsels(&mut self) -> PolarsResult<()> {
+        let num_partitions = self.morsel_idxs_values_per_p.len();
+        for p in 0..num_partitions {
+            let mut dfs = Vec::new();
+            for (i, ((_mseq, payload, _keys), key_df)) in
+                self.morsels.iter().zip(&self.key_dfs).enumerate()
+            {
+                let p_morsel_idxs_start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let p_morsel_idxs_stop =
+                    self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_morsel_idxs =
+                    &self.morsel_idxs_values_per_p[p][p_morsel_idxs_start..p_morsel_idxs_stop];
+                if !p_morsel_idxs.is_empty() {
+                    unsafe {
+                        dfs.push(hstack_spill_keys(
+                            key_df.take_slice_unchecked_impl(p_morsel_idxs, false),
+                            payload.take_slice_unchecked_impl(p_morsel_idxs, false),
+                        ));
+                    }
+                }
+            }
+
+            if let Some(df) = dfs.first() {
+                let file = SpillFile::write(df.schema().clone(), dfs)?;
+                self.spilled_per_p[p].push(file);
+            }
+        }
+
+        self.morsels.clear();
+        self.key_dfs.clear();
+        for idxs in &mut self.morsel_idxs_values_per_p {
+            idxs.clear();
+        }
+        self.morsel_idxs_offsets_per_p.clear();
+        self.morsel_idxs_offsets_per_p.resize(num_partitions, 0);
+        self.morsels_reservation.release();
+        Ok(())
+    }
 }
 
 struct BuildState {
     local_builders: Vec<LocalBuilder>,
     sampled_probe_morsels: BufferedStream,
+    spill_budget: MemoryBudget,
 }
 
 impl BuildState {
@@ -434,28 +497,39 @@ impl BuildState {
         num_partitions: usize,
         sampled_probe_morsels: BufferedStream,
     ) -> Self {
+        let spill_budget = MemoryBudget::new(get_spill_memory_limit());
         let local_builders = (0..num_pipelines)
             .map(|_| LocalBuilder {
                 morsels: Vec::new(),
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                key_dfs: Vec::new(),
+                morsels_reservation: spill_budget.empty_reservation(),
+                spilled_per_p: (0..num_partitions).map(|_| Vec::new()).collect(),
             })
             .collect();
         Self {
             local_builders,
             sampled_probe_morsels,
+            spill_budget,
         }
     }
 
     async fn partition_and_sink(
         mut recv: Receiver<Morsel>,
         local: &mut LocalBuilder,
+        spill_budget: &MemoryBudget,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let track_unmatchable = params.emit_unmatched_build();
+        // Spilled partitions are joined after the rest of the probe input, so we only spill if
+        // we don't have to preserve the order of either side.
+        let may_spill = spill_budget.limit().is_some()
+            && !params.preserve_order_build
+            && !params.preserve_order_probe;
         let (key_selectors, payload_selector);
         if params.left_is_build.unwrap() {
             payload_selector = &params.left_payload_select;
@@ -468,13 +542,9 @@ impl BuildState {
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let keys =
+                select_key_df(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys = hash_keys_from_df(&keys, params);
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
@@ -489,6 +559,15 @@ impl BuildState {
                 .morsel_idxs_offsets_per_p
                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
             local.morsels.push((morsel.seq(), payload, hash_keys));
+
+            if may_spill {
+                let (_, payload, _) = local.morsels.last().unwrap();
+                let size = payload.estimated_size() + keys.estimated_size();
+                local.key_dfs.push(keys);
+                if !local.morsels_reservation.try_grow(size) {
+                    local.spill_morsels()?;
+                }
+            }
         }
         Ok(())
     }
@@ -588,10 +667,16 @@ impl BuildState {
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
+            spilled: Vec::new(),
+            spill_budget: self.spill_budget.clone(),
         }
     }
 
-    fn finalize_unordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
+    fn finalize_unordered(
+        &mut self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeState> {
         let track_unmatchable = params.emit_unmatched_build();
         let payload_schema = if params.left_is_build.unwrap() {
             &params.left_payload_schema
@@ -614,6 +699,20 @@ impl BuildState {
         let local_builders = &self.local_builders;
         let probe_tables: SparseInitVec<ProbeTable> = SparseInitVec::with_capacity(num_partitions);
 
+        // Partitions which were (partially) spilled aren't built now, instead the rows we still
+        // have in memory are spilled as well and the partition is joined on its own once the
+        // probe input is exhausted.
+        let is_spilled_per_p = (0..num_partitions)
+            .map(|p| {
+                local_builders
+                    .iter()
+                    .any(|l| !l.spilled_per_p[p].is_empty())
+            })
+            .collect_vec();
+        let is_spilled_per_p = &is_spilled_per_p;
+        let late_spills: Mutex<Vec<(usize, SpillFile)>> = Mutex::default();
+        let late_spills_ref = &late_spills;
+
         async_executor::task_scope(|s| {
             // Wrap in outer Arc to move to each thread, performing the
             // expensive clone on that thread.
@@ -628,16 +727,19 @@ impl BuildState {
                     // Extract from outer arc and drop outer arc.
                     let morsels_per_local_builder =
                         Arc::unwrap_or_clone(arc_morsels_per_local_builder);
+                    let is_spilled = is_spilled_per_p[p];
 
                     // Compute cardinality estimate and total amount of
                     // payload for this partition.
                     let mut sketch = CardinalitySketch::new();
                     let mut payload_rows = 0;
-                    for l in local_builders {
-                        sketch.combine(&l.sketch_per_p[p]);
-                        let offsets_len = l.morsel_idxs_offsets_per_p.len();
-                        payload_rows +=
-                            l.morsel_idxs_offsets_per_p[offsets_len - num_partitions + p];
+                    if !is_spilled {
+                        for l in local_builders {
+                            sketch.combine(&l.sketch_per_p[p]);
+                            let offsets_len = l.morsel_idxs_offsets_per_p.len();
+                            payload_rows +=
+                                l.morsel_idxs_offsets_per_p[offsets_len - num_partitions + p];
+                        }
                     }
 
                     // Allocate hash table and payload builder.
@@ -645,6 +747,7 @@ impl BuildState {
                     p_table.reserve(sketch.estimate() * 5 / 4);
                     let mut p_payload = DataFrameBuilder::new(payload_schema.clone());
                     p_payload.reserve(payload_rows);
+                    let mut p_spill_dfs = Vec::new();
 
                     // Build.
                     let mut skip_drop_attempt = false;
@@ -663,6 +766,16 @@ impl BuildState {
                                     l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
                                 let p_morsel_idxs = &l.morsel_idxs_values_per_p[p]
                                     [p_morsel_idxs_start..p_morsel_idxs_stop];
+                                if is_spilled {
+                                    if !p_morsel_idxs.is_empty() {
+                                        p_spill_dfs.push(hstack_spill_keys(
+                                            l.key_dfs[i]
+                                                .take_slice_unchecked_impl(p_morsel_idxs, false),
+                                            payload.take_slice_unchecked_impl(p_morsel_idxs, false),
+                                        ));
+                                    }
+                                    continue;
+                                }
                                 p_table.insert_keys_subset(keys, p_morsel_idxs, track_unmatchable);
                                 p_payload.gather_extend(
                                     payload,
@@ -689,6 +802,11 @@ impl BuildState {
                         drop(l_morsels);
                     }
 
+                    if let Some(df) = p_spill_dfs.first() {
+                        let file = SpillFile::write(df.schema().clone(), p_spill_dfs)?;
+                        late_spills_ref.lock().push((p, file));
+                    }
+
                     probe_tables
                         .try_set(
                             p,
@@ -700,6 +818,7 @@ impl BuildState {
                         )
                         .ok()
                         .unwrap();
+                    PolarsResult::Ok(())
                 }));
             }
 
@@ -712,17 +831,97 @@ impl BuildState {
 
             polars_io::pl_async::get_runtime().block_on(async move {
                 for handle in join_handles {
-                    handle.await;
+                    handle.await?;
                 }
+                PolarsResult::Ok(())
+            })
+        })?;
+
+        let mut late_spills = late_spills.into_inner();
+        let mut spilled = Vec::new();
+        for (p, is_spilled) in is_spilled_per_p.iter().enumerate() {
+            if !*is_spilled {
+                continue;
+            }
+            let mut build = Vec::new();
+            for l in &mut self.local_builders {
+                build.append(&mut l.spilled_per_p[p]);
+            }
+            if let Some(i) = late_spills.iter().position(|(sp, _)| *sp == p) {
+                build.push(late_spills.swap_remove(i).1);
+            }
+            spilled.push(SpilledPartition {
+                partition: p,
+                build,
+                probe: Mutex::default(),
             });
-        });
+        }
 
-        ProbeState {
+        // The buffered morsels are now either part of a probe table or spilled.
+        for l in &mut self.local_builders {
+            l.key_dfs.clear();
+            l.morsels_reservation.release();
+        }
+
+        Ok(ProbeState {
             table_per_partition: probe_tables.try_assume_init().ok().unwrap(),
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
+            spilled,
+            spill_budget: self.spill_budget.clone(),
+        })
+    }
+}
+
+/// A partition of which the build side was spilled to disk. Probe rows belonging to it are
+/// spilled as well, and the partition is joined on its own once the probe input is exhausted.
+struct SpilledPartition {
+    partition: usize,
+    build: Vec<SpillFile>,
+    probe: Mutex<Vec<SpillFile>>,
+}
+
+impl SpilledPartition {
+    /// Loads the spilled build side into a probe table.
+    fn load_build(
+        &self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeTable> {
+        let track_unmatchable = params.emit_unmatched_build();
+        let (payload_schema, num_keys) = if params.left_is_build.unwrap() {
+            (&params.left_payload_schema, params.left_key_selectors.len())
+        } else {
+            (
+                &params.right_payload_schema,
+                params.right_key_selectors.len(),
+            )
+        };
+
+        let mut p_table = table.new_empty();
+        let mut p_payload = DataFrameBuilder::new(payload_schema.clone());
+        p_payload.reserve(self.build.iter().map(|f| f.height()).sum());
+        let mut all_idxs: Vec<IdxSize> = Vec::new();
+        for file in &self.build {
+            let mut reader = file.reader()?;
+            while let Some(df) = reader.next_df()? {
+                let (key_df, payload) = split_spill_keys(df, num_keys);
+                let keys = hash_keys_from_df(&key_df, params);
+                all_idxs.clear();
+                all_idxs.extend(0..keys.len() as IdxSize);
+                unsafe {
+                    p_table.insert_keys_subset(&keys, &all_idxs, track_unmatchable);
+                }
+                p_payload.extend(&payload, ShareStrategy::Always);
+            }
         }
+
+        Ok(ProbeTable {
+            hash_table: p_table,
+            payload: p_payload.freeze(),
+            seq_ids: Vec::new(),
+        })
     }
 }
 
@@ -739,19 +938,35 @@ struct ProbeState {
 
     // For unordered joins we relabel output morsels to speed up the linearizer.
     unordered_morsel_seq: AtomicU64,
+
+    // Partitions of which the build side was spilled, their probe rows are spilled as well.
+    spilled: Vec<SpilledPartition>,
+    spill_budget: MemoryBudget,
 }
 
 impl ProbeState {
     /// Returns the max morsel sequence sent.
+    #[allow(clippy::too_many_arguments)]
     async fn partition_and_probe(
         mut recv: Receiver<Morsel>,
         mut send: Sender<Morsel>,
         partitions: &[ProbeTable],
+        spilled: &[SpilledPartition],
+        spill_budget: &MemoryBudget,
         unordered_morsel_seq: &AtomicU64,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
     ) -> PolarsResult<MorselSeq> {
+        // Spilling only happens if we don't have to preserve the order.
+        debug_assert!(spilled.is_empty() || !params.preserve_order_probe);
+        let mut spilled_idx_per_p = vec![None; partitioner.num_partitions()];
+        for (i, s) in spilled.iter().enumerate() {
+            spilled_idx_per_p[s.partition] = Some(i);
+        }
+        let mut spill_dfs_per_spilled = vec![Vec::new(); spilled.len()];
+        let mut spill_reservation = spill_budget.empty_reservation();
+
         // TODO: shuffle after partitioning and keep probe tables thread-local.
         let mut partition_idxs = vec![Vec::new(); partitioner.num_partitions()];
         let mut probe_partitions = Vec::new();
@@ -795,9 +1010,15 @@ impl ProbeState {
 
             let hash_keys =
                 select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
+            let spill_df = (!spilled.is_empty()).then(|| {
+                let mut spill_df = df.clone();
+                spill_df.rechunk_mut();
+                spill_df
+            });
             let mut payload = select_payload(df, payload_selector);
             let mut payload_rechunked = false; // We don't eagerly rechunk because there might be no matches.
             let mut total_matches = 0;
+            let mut spill_size = 0;
 
             // Use selectivity estimate to reserve for morsel builders.
             let max_match_per_key_est = (selectivity_estimate * 1.2) as usize + 16;
@@ -916,7 +1137,21 @@ impl ProbeState {
                         emit_unmatched,
                     );
 
-                    for (p, idxs_in_p) in partitions.iter().zip(&partition_idxs) {
+                    for (p_idx, (p, idxs_in_p)) in
+                        partitions.iter().zip(&partition_idxs).enumerate()
+                    {
+                        if let Some(s) = spilled_idx_per_p[p_idx] {
+                            if !idxs_in_p.is_empty() {
+                                let df = spill_df
+                                    .as_ref()
+                                    .unwrap()
+                                    .take_slice_unchecked_impl(idxs_in_p, false);
+                                spill_size += df.estimated_size();
+                                spill_dfs_per_spilled[s].push(df);
+                            }
+                            continue;
+                        }
+
                         let mut offset = 0;
                         while offset < idxs_in_p.len() {
                             let matches_before_limit = probe_limit - probe_match.len() as IdxSize;
@@ -990,6 +1225,11 @@ impl ProbeState {
 
             drop(wait_token);
 
+            if spill_size > 0 && !spill_reservation.try_grow(spill_size) {
+                Self::spill_probe(spilled, &mut spill_dfs_per_spilled)?;
+                spill_reservation.release();
+            }
+
             // Move selectivity estimate a bit towards latest value. Allows rapid changes at first.
             // TODO: implement something more re-usable and robust.
             selectivity_estimate = selectivity_estimate_confidence * selectivity_estimate
@@ -998,9 +1238,24 @@ impl ProbeState {
             selectivity_estimate_confidence = (selectivity_estimate_confidence + 0.1).min(0.8);
         }
 
+        Self::spill_probe(spilled, &mut spill_dfs_per_spilled)?;
         Ok(max_seq)
     }
 
+    /// Writes the buffered probe rows of each spilled partition to disk.
+    fn spill_probe(
+        spilled: &[SpilledPartition],
+        spill_dfs_per_spilled: &mut [Vec<DataFrame>],
+    ) -> PolarsResult<()> {
+        for (s, dfs) in spilled.iter().zip(spill_dfs_per_spilled) {
+            if let Some(df) = dfs.first() {
+                let file = SpillFile::write(df.schema().clone(), dfs.drain(..))?;
+                s.probe.lock().push(file);
+            }
+        }
+        Ok(())
+    }
+
     fn ordered_unmatched(&mut self, params: &EquiJoinParams) -> DataFrame {
         // TODO: parallelize this operator.
 
@@ -1154,10 +1409,104 @@ impl EmitUnmatchedState {
     }
 }
 
+struct SpilledProbeInput {
+    file: SpillFile,
+    reader: Option<SpillReader>,
+}
+
+/// Joins a single spilled partition, by probing its spilled probe rows against the probe table
+/// loaded from its spilled build side.
+struct SpilledProbeState {
+    partition: usize,
+    probe_state: ProbeState,
+    probe_inputs: Mutex<Vec<SpilledProbeInput>>,
+}
+
+impl SpilledProbeState {
+    fn new(
+        spilled: SpilledPartition,
+        num_partitions: usize,
+        morsel_seq: MorselSeq,
+        spill_budget: MemoryBudget,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<Self> {
+        let payload_schema = if params.left_is_build.unwrap() {
+            &params.left_payload_schema
+        } else {
+            &params.right_payload_schema
+        };
+
+        // Only the spilled partition receives any probe rows, the others stay empty.
+        let mut probe_table = Some(spilled.load_build(params, table)?);
+        let table_per_partition = (0..num_partitions)
+            .map(|p| {
+                if p == spilled.partition {
+                    probe_table.take().unwrap()
+                } else {
+                    ProbeTable {
+                        hash_table: table.new_empty(),
+                        payload: DataFrame::empty_with_schema(payload_schema),
+                        seq_ids: Vec::new(),
+                    }
+                }
+            })
+            .collect();
+
+        let probe_inputs = spilled
+            .probe
+            .into_inner()
+            .into_iter()
+            .map(|file| SpilledProbeInput { file, reader: None })
+            .collect();
+
+        Ok(Self {
+            partition: spilled.partition,
+            probe_state: ProbeState {
+                table_per_partition,
+                max_seq_sent: morsel_seq,
+                sampled_probe_morsels: BufferedStream::default(),
+                unordered_morsel_seq: AtomicU64::new(morsel_seq.to_u64()),
+                spilled: Vec::new(),
+                spill_budget,
+            },
+            probe_inputs: Mutex::new(probe_inputs),
+        })
+    }
+
+    /// Sends the spilled probe rows as morsels, until they are exhausted or a stop is requested.
+    async fn feed(
+        probe_inputs: &Mutex<Vec<SpilledProbeInput>>,
+        mut send: Sender<Morsel>,
+    ) -> PolarsResult<()> {
+        let source_token = SourceToken::new();
+        loop {
+            let Some(mut input) = probe_inputs.lock().pop() else {
+                return Ok(());
+            };
+            if input.reader.is_none() {
+                input.reader = Some(input.file.reader()?);
+            }
+
+            while let Some(df) = input.reader.as_mut().unwrap().next_df()? {
+                let morsel = Morsel::new(df, MorselSeq::default(), source_token.clone());
+                if send.send(morsel).await.is_err() {
+                    return Ok(());
+                }
+                if source_token.stop_requested() {
+                    probe_inputs.lock().push(input);
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
 enum EquiJoinState {
     Sample(SampleState),
     Build(BuildState),
     Probe(ProbeState),
+    ProbeSpilled(SpilledProbeState),
     EmitUnmatchedBuild(EmitUnmatchedState),
     EmitUnmatchedBuildInOrder(InMemorySourceNode),
     Done,
@@ -1167,6 +1516,10 @@ pub struct EquiJoinNode {
     state: EquiJoinState,
     params: EquiJoinParams,
     table: Box<dyn IdxTable>,
+
+    // Spilled partitions which still have to be joined after the probe phase.
+    spilled: VecDeque<SpilledPartition>,
+    spill_budget: MemoryBudget,
 }
 
 impl EquiJoinNode {
@@ -1248,8 +1601,30 @@ impl EquiJoinNode {
                 random_state: PlRandomState::default(),
             },
             table: new_idx_table(unique_key_schema),
+            spilled: VecDeque::new(),
+            spill_budget: MemoryBudget::new(None),
         })
     }
+
+    /// Transitions to joining the next spilled partition, or to being done if there are none left.
+    fn next_spilled_partition(
+        &mut self,
+        morsel_seq: MorselSeq,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        self.state = match self.spilled.pop_front() {
+            Some(spilled) => EquiJoinState::ProbeSpilled(SpilledProbeState::new(
+                spilled,
+                state.num_pipelines,
+                morsel_seq,
+                self.spill_budget.clone(),
+                &self.params,
+                &*self.table,
+            )?),
+            None => EquiJoinState::Done,
+        };
+        Ok(())
+    }
 }
 
 impl ComputeNode for EquiJoinNode {
@@ -1292,7 +1667,7 @@ impl ComputeNode for EquiJoinNode {
                 let probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
-                    build_state.finalize_unordered(&self.params, &*self.table)
+                    build_state.finalize_unordered(&self.params, &*self.table)?
                 };
                 self.state = EquiJoinState::Probe(probe_state);
             }
@@ -1303,6 +1678,8 @@ impl ComputeNode for EquiJoinNode {
         if let EquiJoinState::Probe(probe_state) = &mut self.state {
             let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
             if samples_consumed && recv[probe_idx] == PortState::Done {
+                self.spilled = core::mem::take(&mut probe_state.spilled).into();
+                self.spill_budget = probe_state.spill_budget.clone();
                 if self.params.emit_unmatched_build() {
                     if self.params.preserve_order_build {
                         let unmatched = probe_state.ordered_unmatched(&self.params);
@@ -1320,15 +1697,41 @@ impl ComputeNode for EquiJoinNode {
                         });
                     }
                 } else {
-                    self.state = EquiJoinState::Done;
+                    let morsel_seq = probe_state.max_seq_sent.successor();
+                    self.next_spilled_partition(morsel_seq, state)?;
                 }
             }
         }
 
-        // Finally, check if we are done emitting unmatched keys.
-        if let EquiJoinState::EmitUnmatchedBuild(emit_state) = &mut self.state {
-            if emit_state.active_partition_idx >= emit_state.partitions.len() {
-                self.state = EquiJoinState::Done;
+        // Finally, check if we are done joining a spilled partition or emitting unmatched keys,
+        // and move on to the next spilled partition if so.
+        loop {
+            match &mut self.state {
+                EquiJoinState::ProbeSpilled(spilled_state)
+                    if spilled_state.probe_inputs.lock().is_empty() =>
+                {
+                    let probe_state = &mut spilled_state.probe_state;
+                    let morsel_seq = probe_state.max_seq_sent.successor();
+                    if self.params.emit_unmatched_build() {
+                        let partition = core::mem::take(&mut probe_state.table_per_partition)
+                            .swap_remove(spilled_state.partition);
+                        self.state = EquiJoinState::EmitUnmatchedBuild(EmitUnmatchedState {
+                            partitions: vec![partition],
+                            active_partition_idx: 0,
+                            offset_in_active_p: 0,
+                            morsel_seq,
+                        });
+                    } else {
+                        self.next_spilled_partition(morsel_seq, state)?;
+                    }
+                },
+                EquiJoinState::EmitUnmatchedBuild(emit_state)
+                    if emit_state.active_partition_idx >= emit_state.partitions.len() =>
+                {
+                    let morsel_seq = emit_state.morsel_seq;
+                    self.next_spilled_partition(morsel_seq, state)?;
+                },
+                _ => break,
             }
         }
 
@@ -1372,7 +1775,7 @@ impl ComputeNode for EquiJoinNode {
                 }
                 recv[build_idx] = PortState::Done;
             },
-            EquiJoinState::EmitUnmatchedBuild(_) => {
+            EquiJoinState::ProbeSpilled(_) | EquiJoinState::EmitUnmatchedBuild(_) => {
                 send[0] = PortState::Ready;
                 recv[build_idx] = PortState::Done;
                 recv[probe_idx] = PortState::Done;
@@ -1470,6 +1873,7 @@ impl ComputeNode for EquiJoinNode {
                         BuildState::partition_and_sink(
                             recv,
                             local_builder,
+                            &build_state.spill_budget,
                             partitioner.clone(),
                             &self.params,
                             state,
@@ -1501,6 +1905,49 @@ impl ComputeNode for EquiJoinNode {
                                 recv,
                                 send,
                                 &probe_state.table_per_partition,
+                                &probe_state.spilled,
+                                &probe_state.spill_budget,
+                                &probe_state.unordered_morsel_seq,
+                                partitioner.clone(),
+                                &self.params,
+                                state,
+                            ),
+                        )
+                    })
+                    .collect_vec();
+
+                let max_seq_sent = &mut probe_state.max_seq_sent;
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    for probe_task in probe_tasks {
+                        *max_seq_sent = (*max_seq_sent).max(probe_task.await?);
+                    }
+                    Ok(())
+                }));
+            },
+            EquiJoinState::ProbeSpilled(spilled_state) => {
+                assert!(recv_ports[build_idx].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                let senders = send_ports[0].take().unwrap().parallel();
+
+                let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+                let probe_state = &mut spilled_state.probe_state;
+                let probe_inputs = &spilled_state.probe_inputs;
+                let probe_tasks = senders
+                    .into_iter()
+                    .map(|send| {
+                        let (feed_send, feed_recv) = connector();
+                        join_handles.push(scope.spawn_task(
+                            TaskPriority::Low,
+                            SpilledProbeState::feed(probe_inputs, feed_send),
+                        ));
+                        scope.spawn_task(
+                            TaskPriority::High,
+                            ProbeState::partition_and_probe(
+                                feed_recv,
+                                send,
+                                &probe_state.table_per_partition,
+                                &[],
+                                &probe_state.spill_budget,
                                 &probe_state.unordered_morsel_seq,
                                 partitioner.clone(),
                                 &self.params,
@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_ops::frame::InequalityOperator;
+use polars_ops::series::{SearchSortedSide, search_sorted};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::InMemoryJoiner;
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::in_memory_sink::InMemorySinkNode;
+
+/// The build side, sorted ascending on the key of the first inequality.
+struct SortedBuildSide {
+    df: DataFrame,
+    /// Cast to the supertype of the probe and build keys, so that the keys of both sides can be
+    /// compared without a lossy cast.
+    keys: Series,
+}
+
+impl SortedBuildSide {
+    fn new(
+        df: DataFrame,
+        key_selector: &StreamExpr,
+        key_dtype: &DataType,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<Self> {
+        let keys = key_selector.evaluate_blocking(&df, &state.in_memory_exec_state)?;
+        let keys = if keys.len() == 1 && df.height() != 1 {
+            keys.new_from_index(0, df.height())
+        } else {
+            keys
+        };
+        let keys = keys.take_materialized_series().cast(key_dtype)?;
+
+        // Rows with a null key never match, so we drop them here.
+        let idxs = keys.arg_sort(SortOptions::default().with_nulls_last(true));
+        let idxs = idxs.slice(0, keys.len() - keys.null_count());
+        let (df, mut keys) = unsafe { (df.take_unchecked(&idxs), keys.take_unchecked(&idxs)) };
+        keys.set_sorted_flag(IsSorted::Ascending);
+        Ok(Self { df, keys })
+    }
+
+    /// Returns the build rows which can satisfy `probe_key op build_key` for at least one of
+    /// the given probe keys.
+    fn candidates(&self, probe_keys: &Series, op: InequalityOperator) -> PolarsResult<DataFrame> {
+        use InequalityOperator::*;
+        let probe_keys = probe_keys.cast(self.keys.dtype())?;
+        let bound = match op {
+            Lt | LtEq => probe_keys.min_reduce()?,
+            Gt | GtEq => probe_keys.max_reduce()?,
+        };
+        if bound.is_null() {
+            return Ok(self.df.clear());
+        }
+
+        let bound = bound.into_series(PlSmallStr::EMPTY);
+        let side = match op {
+            Lt | GtEq => SearchSortedSide::Right,
+            LtEq | Gt => SearchSortedSide::Left,
+        };
+        let idx = search_sorted(&self.keys, &bound, side, false)?
+            .get(0)
+            .unwrap() as usize;
+        Ok(match op {
+            Lt | LtEq => self.df.slice(idx as i64, usize::MAX),
+            Gt | GtEq => self.df.slice(0, idx),
+        })
+    }
+}
+
+enum IEJoinState {
+    Build(InMemorySinkNode),
+    Probe(Arc<SortedBuildSide>),
+    Done,
+}
+
+/// An inequality join which streams the left (probe) input.
+///
+/// The right (build) input is collected and sorted once on the key of the first inequality.
+/// For every left morsel only the build rows within the range of its keys are passed to the
+/// in-memory IEJoin, which handles the remaining predicates.
+pub struct IEJoinNode {
+    state: IEJoinState,
+    left_key_selector: StreamExpr,
+    right_key_selector: StreamExpr,
+    /// The supertype of the left and right key.
+    key_dtype: DataType,
+    operator: InequalityOperator,
+    joiners: Vec<InMemoryJoiner>,
+}
+
+impl IEJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key_selector: StreamExpr,
+        right_key_selector: StreamExpr,
+        key_dtype: DataType,
+        operator: InequalityOperator,
+        joiners: Vec<InMemoryJoiner>,
+    ) -> Self {
+        Self {
+            state: IEJoinState::Build(InMemorySinkNode::new(right_input_schema)),
+            left_key_selector,
+            right_key_selector,
+            key_dtype,
+            operator,
+            joiners,
+        }
+    }
+}
+
+impl ComputeNode for IEJoinNode {
+    fn name(&self) -> &str {
+        "iejoin"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        if send[0] == PortState::Done {
+            self.state = IEJoinState::Done;
+        }
+
+        // If the build side is done, sort it and start probing.
+        if let IEJoinState::Build(sink_node) = &mut self.state {
+            if recv[1] == PortState::Done {
+                let df = sink_node.get_output()?.unwrap();
+                let build_side =
+                    SortedBuildSide::new(df, &self.right_key_selector, &self.key_dtype, state)?;
+                self.state = IEJoinState::Probe(Arc::new(build_side));
+            }
+        }
+
+        // Every left row is probed exactly once, so we're done once the left side is done.
+        if let IEJoinState::Probe(build_side) = &self.state {
+            if recv[0] == PortState::Done || build_side.df.height() == 0 {
+                self.state = IEJoinState::Done;
+            }
+        }
+
+        match &mut self.state {
+            IEJoinState::Build(sink_node) => {
+                sink_node.update_state(&mut recv[1..2], &mut [], state)?;
+                if recv[0] != PortState::Done {
+                    recv[0] = PortState::Blocked;
+                }
+                send[0] = PortState::Blocked;
+            },
+            IEJoinState::Probe(_) => {
+                recv[1] = PortState::Done;
+                core::mem::swap(&mut recv[0], &mut send[0]);
+            },
+            IEJoinState::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, IEJoinState::Build(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        match &mut self.state {
+            IEJoinState::Build(sink_node) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[0].is_none());
+                sink_node.spawn(scope, &mut recv_ports[1..2], &mut [], state, join_handles);
+            },
+            IEJoinState::Probe(build_side) => {
+                assert!(recv_ports[1].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+
+                let left_key_selector = &self.left_key_selector;
+                let operator = self.operator;
+                for ((mut recv, mut send), joiner) in receivers
+                    .into_iter()
+                    .zip(senders)
+                    .zip(self.joiners.iter_mut())
+                {
+                    let build_side = build_side.clone();
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let morsel_size = get_ideal_morsel_size();
+                        while let Ok(morsel) = recv.recv().await {
+                            let (df, seq, source_token, consume_token) = morsel.into_inner();
+                            let keys = left_key_selector
+                                .evaluate(&df, &state.in_memory_exec_state)
+                                .await?;
+                            let candidates =
+                                build_side.candidates(keys.as_materialized_series(), operator)?;
+                            if candidates.height() == 0 {
+                                continue;
+                            }
+
+                            // The output of a range join can be much larger than its input, so
+                            // we split it up. The parts share the sequence id of the input.
+                            let out = joiner(df, candidates)?;
+                            drop(consume_token);
+                            let mut offset = 0;
+                            while offset < out.height() {
+                                let part = out.slice(offset as i64, morsel_size);
+                                offset += part.height();
+                                let morsel = Morsel::new(part, seq, source_token.clone());
+                                if send.send(morsel).await.is_err() {
+                                    return Ok(());
+                                }
+                            }
+                        }
+                        Ok(())
+                    }));
+                }
+            },
+            IEJoinState::Done => unreachable!(),
+        }
+    }
+}
@@ -2,6 +2,8 @@ use std::sync::LazyLock;
 
 use crossbeam_queue::ArrayQueue;
 use polars_core::POOL;
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+use polars_core::frame::DataFrame;
 use polars_error::PolarsResult;
 use polars_utils::itertools::Itertools;
 use rayon::prelude::*;
@@ -12,8 +14,12 @@ use crate::async_primitives::wait_group::WaitGroup;
 use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::pipe::RecvPort;
 
+#[cfg(feature = "asof_join")]
+pub mod asof_join;
 pub mod cross_join;
 pub mod equi_join;
+#[cfg(feature = "iejoin")]
+pub mod ie_join;
 pub mod in_memory;
 #[cfg(feature = "semi_anti_join")]
 pub mod semi_anti_join;
@@ -28,6 +34,11 @@ static JOIN_SAMPLE_LIMIT: LazyLock<usize> = LazyLock::new(|| {
 // smaller side as the build side without checking cardinalities.
 const LOPSIDED_SAMPLE_FACTOR: usize = 10;
 
+/// Joins a part of the left input with the part of the right input it can match against,
+/// using the in-memory engine.
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+pub type InMemoryJoiner = Box<dyn FnMut(DataFrame, DataFrame) -> PolarsResult<DataFrame> + Send>;
+
 // TODO: improve, generalize this, and move it away from here.
 struct BufferedStream {
     morsels: ArrayQueue<Morsel>,
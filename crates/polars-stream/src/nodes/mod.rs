@@ -1,5 +1,6 @@
 #[cfg(feature = "cum_agg")]
 pub mod cum_agg;
+pub mod distinct;
 pub mod dynamic_slice;
 pub mod filter;
 pub mod group_by;
@@ -23,6 +24,7 @@ pub mod rle;
 pub mod rle_id;
 pub mod select;
 pub mod simple_projection;
+pub mod sort;
 pub mod streaming_slice;
 pub mod top_k;
 pub mod with_row_index;
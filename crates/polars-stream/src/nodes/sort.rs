@@ -0,0 +1,578 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use parking_lot::Mutex;
+use polars_core::config;
+use polars_core::prelude::row_encode::_get_rows_encoded_ca;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets};
+use polars_ops::frame::_merge_sorted_dfs;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    MemoryBudget, MemoryReservation, SpillFile, SpillReader, get_spill_memory_limit,
+};
+
+/// The maximum number of spilled runs which are merged at once. Every spilled run taking part in
+/// a merge keeps its file open, so if there are more we first merge them into fewer, larger runs.
+const MAX_MERGE_FAN_IN: usize = 16;
+
+/// A sorted part of the input, either kept in memory or spilled to disk.
+///
+/// Every frame in a run carries the row-encoded sort key as its last column.
+enum SortedRun {
+    InMemory {
+        dfs: VecDeque<DataFrame>,
+        height: usize,
+        /// Released once the run is fully merged and dropped.
+        _reservation: MemoryReservation,
+    },
+    Spilled {
+        file: SpillFile,
+        reader: Option<SpillReader>,
+    },
+}
+
+impl SortedRun {
+    fn height(&self) -> usize {
+        match self {
+            Self::InMemory { height, .. } => *height,
+            Self::Spilled { file, .. } => file.height(),
+        }
+    }
+}
+
+/// A position in a [`SortedRun`] during the merge phase.
+struct RunCursor {
+    head: Option<DataFrame>,
+    run: SortedRun,
+}
+
+impl RunCursor {
+    fn new(run: SortedRun) -> Self {
+        Self { head: None, run }
+    }
+
+    /// Load the next non-empty frame of the run into `head` if there is none. Returns false if
+    /// the run is exhausted.
+    fn fill(&mut self) -> PolarsResult<bool> {
+        while self.head.as_ref().is_none_or(|df| df.height() == 0) {
+            self.head = match &mut self.run {
+                SortedRun::InMemory { dfs, .. } => dfs.pop_front(),
+                SortedRun::Spilled { file, reader } => {
+                    if reader.is_none() {
+                        *reader = Some(file.reader()?);
+                    }
+                    reader.as_mut().unwrap().next_df()?
+                },
+            };
+
+            if self.head.is_none() {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+fn key_array(df: &DataFrame, key_idx: usize) -> BinaryArray<i64> {
+    let keys = df[key_idx].binary_offset().unwrap().rechunk();
+    keys.downcast_as_array().clone()
+}
+
+/// The number of keys in the (sorted) key array that are smaller than or equal to `bound`.
+fn num_keys_le(keys: &BinaryArray<i64>, bound: &[u8]) -> usize {
+    let mut lo = 0;
+    let mut hi = keys.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if keys.value(mid) <= bound {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Find parts of the runs which can be merged without looking at the rest of the runs.
+///
+/// This is the k-way generalization of the merge-sorted node: everything up to the smallest of
+/// the last keys of the current run heads can safely be merged. The run owning that smallest
+/// key is fully consumed, so every call makes progress. Returns `None` once all runs are
+/// exhausted.
+fn find_mergeable(
+    cursors: &mut Vec<RunCursor>,
+    key_idx: usize,
+) -> PolarsResult<Option<Vec<DataFrame>>> {
+    let mut i = 0;
+    while i < cursors.len() {
+        if cursors[i].fill()? {
+            i += 1;
+        } else {
+            cursors.swap_remove(i);
+        }
+    }
+
+    match cursors.len() {
+        0 => return Ok(None),
+        1 => return Ok(Some(vec![cursors[0].head.take().unwrap()])),
+        _ => {},
+    }
+
+    let keys = cursors
+        .iter()
+        .map(|c| key_array(c.head.as_ref().unwrap(), key_idx))
+        .collect::<Vec<_>>();
+    let bound = keys
+        .iter()
+        .map(|k| k.value(k.len() - 1))
+        .min()
+        .unwrap()
+        .to_vec();
+
+    let mut mergeable = Vec::with_capacity(cursors.len());
+    for (cursor, keys) in cursors.iter_mut().zip(keys) {
+        let head = cursor.head.take().unwrap();
+        let (head, rest) = head.split_at(num_keys_le(&keys, &bound) as i64);
+        if head.height() > 0 {
+            mergeable.push(head);
+        }
+        if rest.height() > 0 {
+            cursor.head = Some(rest);
+        }
+    }
+
+    Ok(Some(mergeable))
+}
+
+/// Merge a set of sorted frames by repeatedly merging pairs of them.
+fn merge_sorted_runs(mut dfs: Vec<DataFrame>, key_idx: usize) -> PolarsResult<DataFrame> {
+    while dfs.len() > 1 {
+        let mut merged = Vec::with_capacity(dfs.len().div_ceil(2));
+        let mut iter = dfs.into_iter();
+        while let Some(left) = iter.next() {
+            match iter.next() {
+                Some(right) => {
+                    let left_s = left[key_idx].as_materialized_series();
+                    let right_s = right[key_idx].as_materialized_series();
+                    merged.push(_merge_sorted_dfs(&left, &right, left_s, right_s, false)?);
+                },
+                None => merged.push(left),
+            }
+        }
+        dfs = merged;
+    }
+
+    Ok(dfs.pop().unwrap())
+}
+
+/// Merge the spilled runs in groups of at most [`MAX_MERGE_FAN_IN`] into new spilled runs, until
+/// there are at most [`MAX_MERGE_FAN_IN`] of them left. Each pass writes its output back to disk.
+fn reduce_spilled_runs(
+    cursors: &mut Vec<RunCursor>,
+    run_schema: &Arc<Schema>,
+    key_idx: usize,
+) -> PolarsResult<()> {
+    let (mut spilled, in_memory): (Vec<_>, Vec<_>) = std::mem::take(cursors)
+        .into_iter()
+        .partition(|c| matches!(c.run, SortedRun::Spilled { .. }));
+
+    while spilled.len() > MAX_MERGE_FAN_IN {
+        let num_merged = spilled.len().div_ceil(MAX_MERGE_FAN_IN);
+        if config::verbose() {
+            eprintln!(
+                "[sort]: merging {} spilled runs into {num_merged}",
+                spilled.len()
+            );
+        }
+
+        let mut merged = Vec::with_capacity(num_merged);
+        let mut iter = spilled.into_iter().peekable();
+        while iter.peek().is_some() {
+            let mut group = iter.by_ref().take(MAX_MERGE_FAN_IN).collect::<Vec<_>>();
+            if group.len() == 1 {
+                merged.extend(group);
+                continue;
+            }
+
+            let dfs = std::iter::from_fn(|| {
+                find_mergeable(&mut group, key_idx)
+                    .transpose()
+                    .map(|dfs| merge_sorted_runs(dfs?, key_idx))
+            });
+            let run = SortedRun::Spilled {
+                file: SpillFile::try_write(run_schema.clone(), dfs)?,
+                reader: None,
+            };
+            merged.push(RunCursor::new(run));
+        }
+        spilled = merged;
+    }
+
+    *cursors = spilled;
+    cursors.extend(in_memory);
+    Ok(())
+}
+
+struct SortSinkState {
+    key_selectors: Vec<StreamExpr>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    key_name: PlSmallStr,
+    /// The input schema, extended with the row-encoded key.
+    run_schema: Arc<Schema>,
+    /// The (estimated) size in bytes of buffered input after which a pipeline sorts its buffer
+    /// into a run.
+    run_size: usize,
+    budget: MemoryBudget,
+    runs: Mutex<Vec<SortedRun>>,
+}
+
+impl SortSinkState {
+    async fn encode_keys(
+        &self,
+        df: &DataFrame,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<Column> {
+        let mut key_columns = Vec::with_capacity(self.key_selectors.len());
+        for selector in &self.key_selectors {
+            let s = selector.evaluate(df, &state.in_memory_exec_state).await?;
+            key_columns.push(s.into_column());
+        }
+        let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+        let keys = _get_rows_encoded_ca(
+            self.key_name.clone(),
+            keys.get_columns(),
+            &self.descending,
+            &self.nulls_last,
+        )?;
+        Ok(keys.into_column())
+    }
+
+    /// Sort the buffered frames into a run, spilling it if it doesn't fit in the memory budget.
+    fn add_run(&self, dfs: Vec<DataFrame>) -> PolarsResult<()> {
+        let dfs = dfs
+            .into_iter()
+            .filter(|df| df.height() > 0)
+            .collect::<Vec<_>>();
+        if dfs.is_empty() {
+            return Ok(());
+        }
+
+        let df = accumulate_dataframes_vertical_unchecked(dfs);
+        let key_idx = self.run_schema.len() - 1;
+        let idx = df[key_idx]
+            .binary_offset()?
+            .arg_sort(SortOptions::default().with_multithreaded(false));
+        let df = unsafe { df.take_unchecked(&idx) };
+
+        let height = df.height();
+        let morsel_size = get_ideal_morsel_size();
+        let chunks = (0..height.div_ceil(morsel_size))
+            .map(|i| df.slice((i * morsel_size) as i64, morsel_size))
+            .collect::<VecDeque<_>>();
+
+        let run = if let Some(reservation) = self.budget.try_reserve(df.estimated_size()) {
+            SortedRun::InMemory {
+                dfs: chunks,
+                height,
+                _reservation: reservation,
+            }
+        } else {
+            SortedRun::Spilled {
+                file: SpillFile::write(self.run_schema.clone(), chunks)?,
+                reader: None,
+            }
+        };
+        self.runs.lock().push(run);
+        Ok(())
+    }
+}
+
+struct SortSourceState {
+    cursors: Vec<RunCursor>,
+    run_schema: Arc<Schema>,
+    seq: MorselSeq,
+    /// The number of rows we still have to skip before emitting rows.
+    offset: usize,
+    /// The number of rows we still have to emit.
+    len: usize,
+}
+
+enum SortState {
+    Sink(SortSinkState),
+    Source(SortSourceState),
+    Empty(InMemorySourceNode),
+    Done,
+}
+
+/// A sort which generates sorted runs of its input, optionally spilled to disk, and merges those
+/// once the input is exhausted.
+pub struct SortNode {
+    output_schema: Arc<Schema>,
+    slice: Option<(i64, usize)>,
+    /// The column that is sorted in the output, if we sort by a plain column.
+    sorted_column: Option<(usize, IsSorted)>,
+    state: SortState,
+}
+
+impl SortNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input_schema: Arc<Schema>,
+        key_selectors: Vec<StreamExpr>,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+        slice: Option<(i64, usize)>,
+        sorted_column: Option<(usize, IsSorted)>,
+        key_name: PlSmallStr,
+        num_pipelines: usize,
+    ) -> Self {
+        let mut run_schema = (*input_schema).clone();
+        run_schema.insert(key_name.clone(), DataType::BinaryOffset);
+
+        let memory_limit = get_spill_memory_limit();
+        let run_size = memory_limit.map_or(usize::MAX, |limit| limit / (2 * num_pipelines));
+
+        Self {
+            output_schema: input_schema,
+            slice,
+            sorted_column,
+            state: SortState::Sink(SortSinkState {
+                key_selectors,
+                descending,
+                nulls_last,
+                key_name,
+                run_schema: Arc::new(run_schema),
+                run_size,
+                budget: MemoryBudget::new(memory_limit.map(|limit| limit / 2)),
+                runs: Mutex::default(),
+            }),
+        }
+    }
+}
+
+fn set_sorted_flag(df: &mut DataFrame, sorted_column: Option<(usize, IsSorted)>) {
+    if let Some((idx, flag)) = sorted_column {
+        // SAFETY: we only update the sorted flag, which doesn't touch the schema.
+        unsafe { df.get_columns_mut()[idx].set_sorted_flag(flag) };
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, transition to merging the sorted runs.
+            SortState::Sink(_) if recv[0] == PortState::Done => {
+                let SortState::Sink(sink) = std::mem::replace(&mut self.state, SortState::Done)
+                else {
+                    unreachable!()
+                };
+                let runs = sink.runs.into_inner();
+                let height = runs.iter().map(|r| r.height()).sum();
+                let (offset, len) = match self.slice {
+                    Some((offset, len)) => slice_offsets(offset, len, height),
+                    None => (0, height),
+                };
+
+                if len == 0 {
+                    let mut df = DataFrame::empty_with_schema(&self.output_schema);
+                    set_sorted_flag(&mut df, self.sorted_column);
+                    let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::default());
+                    self.state = SortState::Empty(source);
+                } else {
+                    self.state = SortState::Source(SortSourceState {
+                        cursors: runs.into_iter().map(RunCursor::new).collect(),
+                        run_schema: sink.run_schema,
+                        seq: MorselSeq::default(),
+                        offset,
+                        len,
+                    });
+                }
+            },
+            SortState::Source(source) if source.cursors.is_empty() => {
+                self.state = SortState::Done;
+            },
+            // Defer to source node implementation.
+            SortState::Empty(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = SortState::Done;
+                }
+            },
+            // Nothing to change.
+            SortState::Sink(_) | SortState::Source(_) | SortState::Done => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            SortState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::Source(_) | SortState::Empty(_) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let key_idx = self.output_schema.len();
+        let sorted_column = self.sorted_column;
+
+        match &mut self.state {
+            SortState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+
+                for mut recv in receivers {
+                    let sink = &*sink;
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let mut buffer = Vec::new();
+                        let mut buffer_size = 0;
+                        while let Ok(morsel) = recv.recv().await {
+                            let mut df = morsel.into_df();
+                            let keys = sink.encode_keys(&df, state).await?;
+                            unsafe { df.with_column_unchecked(keys) };
+
+                            buffer_size += df.estimated_size();
+                            buffer.push(df);
+                            if buffer_size >= sink.run_size {
+                                sink.add_run(std::mem::take(&mut buffer))?;
+                                buffer_size = 0;
+                            }
+                        }
+
+                        sink.add_run(buffer)
+                    }));
+                }
+            },
+
+            SortState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                let senders = send_ports[0].take().unwrap().parallel();
+                let (mut distributor, dist_recv) =
+                    distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+                let source_token = SourceToken::new();
+
+                // Task that finds the mergeable parts of the runs and applies the slice.
+                let token = source_token.clone();
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    reduce_spilled_runs(&mut source.cursors, &source.run_schema, key_idx)?;
+
+                    while let Some(mergeable) = find_mergeable(&mut source.cursors, key_idx)? {
+                        let height = mergeable.iter().map(|df| df.height()).sum::<usize>();
+                        if source.offset >= height {
+                            source.offset -= height;
+                            continue;
+                        }
+
+                        let slice_offset = std::mem::take(&mut source.offset);
+                        let slice_len = (height - slice_offset).min(source.len);
+                        source.len -= slice_len;
+                        let slice = (slice_offset > 0 || slice_len < height)
+                            .then_some((slice_offset, slice_len));
+
+                        let seq = source.seq;
+                        source.seq = seq.successor();
+                        if distributor.send((mergeable, seq, slice)).await.is_err() {
+                            break;
+                        }
+
+                        if source.len == 0 {
+                            source.cursors.clear();
+                            break;
+                        }
+                        if token.stop_requested() {
+                            break;
+                        }
+                    }
+
+                    Ok(())
+                }));
+
+                // Tasks that actually merge the parts, since this might be expensive this is
+                // split over several tasks.
+                join_handles.extend(dist_recv.into_iter().zip(senders).map(
+                    |(mut recv, mut send)| {
+                        let source_token = source_token.clone();
+                        let ideal_morsel_size = get_ideal_morsel_size();
+                        scope.spawn_task(TaskPriority::High, async move {
+                            while let Ok((dfs, seq, slice)) = recv.recv().await {
+                                let mut df = merge_sorted_runs(dfs, key_idx)?;
+                                if let Some((offset, len)) = slice {
+                                    df = df.slice(offset as i64, len);
+                                }
+                                df.pop();
+                                set_sorted_flag(&mut df, sorted_column);
+
+                                // MorselSeq have to be monotonely non-decreasing so we can pass
+                                // the same sequence token multiple times.
+                                for i in 0..df.height().div_ceil(ideal_morsel_size) {
+                                    let df =
+                                        df.slice((i * ideal_morsel_size) as i64, ideal_morsel_size);
+                                    let morsel = Morsel::new(df, seq, source_token.clone());
+                                    if send.send(morsel).await.is_err() {
+                                        return Ok(());
+                                    }
+                                }
+                            }
+
+                            Ok(())
+                        })
+                    },
+                ));
+            },
+
+            SortState::Empty(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+
+            SortState::Done => unreachable!(),
+        }
+    }
+}
@@ -32,6 +32,7 @@ impl NodeStyle {
             | K::InMemorySink { .. }
             | K::Sort { .. }
             | K::GroupBy { .. }
+            | K::Distinct { .. }
             | K::EquiJoin { .. }
             | K::SemiAntiJoin { .. }
             | K::InMemoryJoin { .. }
@@ -261,6 +262,8 @@ fn visualize_plan_rec(
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileType::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -285,6 +288,8 @@ fn visualize_plan_rec(
                 FileType::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileType::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -450,6 +455,10 @@ fn visualize_plan_rec(
 
             (out, &[][..])
         },
+        PhysNodeKind::Distinct { input, keys } => (
+            format!(r"distinct\nkeys: {}\nkeep: last", keys.join(", ")),
+            from_ref(input),
+        ),
         PhysNodeKind::GroupBy { input, key, aggs } => (
             format!(
                 "group-by\\nkey:\\n{}\\naggs:\\n{}",
@@ -525,6 +534,57 @@ fn visualize_plan_rec(
             }
             (label, &[*input_left, *input_right][..])
         },
+        #[cfg(feature = "asof_join")]
+        PhysNodeKind::AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            ..
+        } => {
+            let mut label = "asof-join".to_string();
+            write!(
+                label,
+                r"\nleft_on:\n{}",
+                fmt_exprs_to_label(left_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\nright_on:\n{}",
+                fmt_exprs_to_label(right_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            (label, &[*input_left, *input_right][..])
+        },
+        #[cfg(feature = "iejoin")]
+        PhysNodeKind::IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } => {
+            let mut label = "iejoin".to_string();
+            write!(
+                label,
+                r"\nleft_on:\n{}",
+                fmt_exprs_to_label(left_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\nright_on:\n{}",
+                fmt_exprs_to_label(right_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(label, r"\noperator1: {:?}", options.operator1).unwrap();
+            if let Some(operator2) = options.operator2 {
+                write!(label, r"\noperator2: {operator2:?}").unwrap();
+            }
+            (label, &[*input_left, *input_right][..])
+        },
         PhysNodeKind::CrossJoin {
             input_left,
             input_right,
@@ -8,7 +8,7 @@ use polars_core::scalar::Scalar;
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail};
 use polars_expr::state::ExecutionState;
-use polars_mem_engine::create_physical_plan;
+use polars_mem_engine::{Executor, create_physical_plan};
 use polars_plan::constants::get_literal_name;
 use polars_plan::dsl::default_values::DefaultFieldValues;
 use polars_plan::dsl::deletion::DeletionFilesList;
@@ -17,8 +17,9 @@ use polars_plan::dsl::{
     SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
+use polars_plan::plans::prune::prune;
 use polars_plan::plans::{AExpr, FunctionIR, IR, IRAggExpr, LiteralValue, write_ir_non_recursive};
-use polars_plan::prelude::GroupbyOptions;
+use polars_plan::prelude::{GroupbyOptions, RecursiveUnionOptions};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
 use polars_utils::slice_enum::Slice;
@@ -36,6 +37,7 @@ use crate::physical_plan::lower_expr::{
     is_elementwise_rec_cached, lower_exprs,
 };
 use crate::physical_plan::lower_group_by::build_group_by_stream;
+use crate::skeleton::StreamingQuery;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
 
 /// Creates a new PhysStream which outputs a slice of the input stream.
@@ -116,6 +118,111 @@ fn build_filter_stream(
     )
 }
 
+/// Runs a subplan of an in-memory engine plan on the streaming engine, as the in-memory engine
+/// does for scans.
+fn build_streaming_executor(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let node = ir_arena.add(IR::Sink {
+        input: node,
+        payload: SinkTypeIR::Memory,
+    });
+    let query = StreamingQuery::build(node, ir_arena, expr_arena)?;
+    Ok(Box::new(StreamingQueryExecutor(Mutex::new(Some(query)))))
+}
+
+struct StreamingQueryExecutor(Mutex<Option<StreamingQuery>>);
+
+impl Executor for StreamingQueryExecutor {
+    fn execute(&mut self, _state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let query = self.0.get_mut().take().expect("executed more than once");
+        query.execute().map(|result| result.unwrap_single())
+    }
+}
+
+/// Runs a recursive union on the in-memory engine, which plans its recursive term anew for every
+/// iteration. The initial term is lowered as usual.
+fn build_recursive_union_fallback(
+    input: PhysStream,
+    recursive: Node,
+    options: RecursiveUnionOptions,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+    format_str: Option<String>,
+) -> PolarsResult<PhysStream> {
+    let input_schema = phys_sm[input.node].output_schema.clone();
+    let lmdf = Arc::new(LateMaterializedDataFrame::default());
+    let mut lp_arena = Arena::default();
+    let mut fallback_expr_arena = Arena::default();
+    let [recursive] = prune(
+        &[recursive],
+        ir_arena,
+        expr_arena,
+        &mut lp_arena,
+        &mut fallback_expr_arena,
+    )
+    .try_into()
+    .unwrap();
+    let initial = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+    let union_lp_node = lp_arena.add(IR::RecursiveUnion {
+        initial,
+        recursive,
+        options,
+    });
+    let executor = Mutex::new(create_physical_plan(
+        union_lp_node,
+        &mut lp_arena,
+        &mut fallback_expr_arena,
+        Some(build_streaming_executor),
+    )?);
+
+    let union_node = PhysNode {
+        output_schema: input_schema,
+        kind: PhysNodeKind::InMemoryMap {
+            input,
+            map: Arc::new(move |df| {
+                lmdf.set_materialized_dataframe(df);
+                let mut state = ExecutionState::new();
+                executor.lock().execute(&mut state)
+            }),
+            format_str,
+        },
+    };
+
+    Ok(PhysStream::first(phys_sm.insert(union_node)))
+}
+
+/// Returns the `on` key columns if this as-of join can be done by the streaming as-of join.
+///
+/// The keys must be plain columns.
+#[cfg(feature = "asof_join")]
+fn streamable_asof_keys(
+    args: &polars_ops::frame::JoinArgs,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> Option<(
+    polars_utils::pl_str::PlSmallStr,
+    polars_utils::pl_str::PlSmallStr,
+)> {
+    use polars_ops::frame::JoinType;
+
+    if !matches!(args.how, JoinType::AsOf(_)) {
+        return None;
+    }
+    let key_name = |on: &[ExprIR]| match on {
+        [e] => match expr_arena.get(e.node()) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+    Some((key_name(left_on)?, key_name(right_on)?))
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct StreamingLowerIRContext {
     pub prepare_visualization: bool,
@@ -451,36 +558,38 @@ pub fn lower_ir(
             };
 
             let mut stream = phys_input;
-            if limit < u64::MAX {
-                // If we need to maintain order augment with row index.
-                if sort_options.maintain_order {
-                    let row_idx_name = unique_column_name();
-                    with_row_idx = Some(row_idx_name.clone());
-                    cur_out_schema
-                        .insert_at_index(0, row_idx_name.clone(), DataType::IDX_DTYPE)
-                        .unwrap();
-                    stream = PhysStream::first(phys_sm.insert(PhysNode {
-                        output_schema: Arc::new(cur_out_schema.clone()),
-                        kind: PhysNodeKind::WithRowIndex {
-                            input: stream,
-                            name: row_idx_name.clone(),
-                            offset: None,
-                        },
-                    }));
 
-                    // Add row index to sort columns.
-                    let row_idx_node = expr_arena.add(AExpr::Column(row_idx_name.clone()));
-                    by_column.push(ExprIR::new(
-                        row_idx_node,
-                        OutputName::ColumnLhs(row_idx_name.clone()),
-                    ));
-                    sort_options.descending.push(false);
-                    sort_options.nulls_last.push(true);
+            // If we need to maintain order augment with row index, the sorted runs are
+            // generated in parallel and thus lose the input order.
+            if sort_options.maintain_order {
+                let row_idx_name = unique_column_name();
+                with_row_idx = Some(row_idx_name.clone());
+                cur_out_schema
+                    .insert_at_index(0, row_idx_name.clone(), DataType::IDX_DTYPE)
+                    .unwrap();
+                stream = PhysStream::first(phys_sm.insert(PhysNode {
+                    output_schema: Arc::new(cur_out_schema.clone()),
+                    kind: PhysNodeKind::WithRowIndex {
+                        input: stream,
+                        name: row_idx_name.clone(),
+                        offset: None,
+                    },
+                }));
 
-                    // No longer needed for the actual sort itself, handled by row index.
-                    sort_options.maintain_order = false;
-                }
+                // Add row index to sort columns.
+                let row_idx_node = expr_arena.add(AExpr::Column(row_idx_name.clone()));
+                by_column.push(ExprIR::new(
+                    row_idx_node,
+                    OutputName::ColumnLhs(row_idx_name.clone()),
+                ));
+                sort_options.descending.push(false);
+                sort_options.nulls_last.push(true);
 
+                // No longer needed for the actual sort itself, handled by row index.
+                sort_options.maintain_order = false;
+            }
+
+            if limit < u64::MAX {
                 let k_node =
                     expr_arena.add(AExpr::Literal(LiteralValue::Scalar(Scalar::from(limit))));
                 let k_selector = ExprIR::from_node(k_node, expr_arena);
@@ -615,6 +724,18 @@ pub fn lower_ir(
                         first_metadata: first_metadata.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { options: _ } => {
+                        Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder)
+                            as Arc<dyn FileReaderBuilder>
+                    },
+
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc { options: _ } => {
+                        Arc::new(crate::nodes::io_sources::orc::builder::OrcReaderBuilder)
+                            as Arc<dyn FileReaderBuilder>
+                    },
+
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { options } => {
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
@@ -625,6 +746,11 @@ pub fn lower_ir(
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
                     },
 
+                    #[cfg(feature = "fixed_width")]
+                    FileScanIR::FixedWidth { options } => {
+                        Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
+                    },
+
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
                         dataset_object: _,
@@ -1027,6 +1153,49 @@ pub fn lower_ir(
                 }
                 return Ok(stream);
             } else {
+                #[cfg(feature = "asof_join")]
+                if let Some((left_key, right_key)) =
+                    streamable_asof_keys(&args, &left_on, &right_on, expr_arena)
+                {
+                    let node = phys_sm.insert(PhysNode::new(
+                        output_schema,
+                        PhysNodeKind::AsOfJoin {
+                            input_left: phys_left,
+                            input_right: phys_right,
+                            left_on,
+                            right_on,
+                            left_key,
+                            right_key,
+                            args: args.clone(),
+                        },
+                    ));
+                    let mut stream = PhysStream::first(node);
+                    if let Some((offset, len)) = args.slice {
+                        stream = build_slice_stream(stream, offset, len, phys_sm);
+                    }
+                    return Ok(stream);
+                }
+
+                #[cfg(feature = "iejoin")]
+                if let Some(polars_plan::dsl::JoinTypeOptionsIR::IEJoin(ie_options)) = &options {
+                    let node = phys_sm.insert(PhysNode::new(
+                        output_schema,
+                        PhysNodeKind::IEJoin {
+                            input_left: phys_left,
+                            input_right: phys_right,
+                            left_on,
+                            right_on,
+                            args: args.clone(),
+                            options: ie_options.clone(),
+                        },
+                    ));
+                    let mut stream = PhysStream::first(node);
+                    if let Some((offset, len)) = args.slice {
+                        stream = build_slice_stream(stream, offset, len, phys_sm);
+                    }
+                    return Ok(stream);
+                }
+
                 PhysNodeKind::InMemoryJoin {
                     input_left: phys_left,
                     input_right: phys_right,
@@ -1052,48 +1221,25 @@ pub fn lower_ir(
 
             if options.maintain_order && options.keep_strategy == UniqueKeepStrategy::Last {
                 // Unfortunately the order-preserving groupby always orders by the first occurrence
-                // of the group so we can't lower this and have to fallback.
-                let input_schema = phys_sm[phys_input.node].output_schema.clone();
-                let lmdf = Arc::new(LateMaterializedDataFrame::default());
-                let mut lp_arena = Arena::default();
-                let input_lp_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema));
-                let distinct_lp_node = lp_arena.add(IR::Distinct {
-                    input: input_lp_node,
-                    options,
-                });
-                let executor = Mutex::new(create_physical_plan(
-                    distinct_lp_node,
-                    &mut lp_arena,
-                    expr_arena,
-                    None,
-                )?);
-
-                let format_str = ctx.prepare_visualization.then(|| {
-                    let mut buffer = String::new();
-                    write_ir_non_recursive(
-                        &mut buffer,
-                        ir_arena.get(node),
-                        expr_arena,
-                        phys_sm.get(phys_input.node).unwrap().output_schema.as_ref(),
-                        0,
-                    )
-                    .unwrap();
-                    buffer
-                });
-                let distinct_node = PhysNode {
+                // of the group, so we use a node keeping the last occurrence instead.
+                let keys = if let Some(subset) = options.subset {
+                    subset.to_vec()
+                } else {
+                    input_schema.iter_names().cloned().collect_vec()
+                };
+                let node = phys_sm.insert(PhysNode::new(
                     output_schema,
-                    kind: PhysNodeKind::InMemoryMap {
+                    PhysNodeKind::Distinct {
                         input: phys_input,
-                        map: Arc::new(move |df| {
-                            lmdf.set_materialized_dataframe(df);
-                            let mut state = ExecutionState::new();
-                            executor.lock().execute(&mut state)
-                        }),
-                        format_str,
+                        keys,
                     },
-                };
+                ));
 
-                return Ok(PhysStream::first(phys_sm.insert(distinct_node)));
+                let mut stream = PhysStream::first(node);
+                if let Some((offset, length)) = options.slice {
+                    stream = build_slice_stream(stream, offset, length, phys_sm);
+                }
+                return Ok(stream);
             }
 
             // Create the key and aggregate expressions.
@@ -1192,6 +1338,32 @@ pub fn lower_ir(
 
             return Ok(stream);
         },
+        IR::RecursiveUnion {
+            initial,
+            recursive,
+            options,
+        } => {
+            let initial = *initial;
+            let recursive = *recursive;
+            let options = options.clone();
+            let format_str = ctx.prepare_visualization.then(|| {
+                let mut buffer = String::new();
+                write_ir_non_recursive(
+                    &mut buffer,
+                    ir_arena.get(node),
+                    expr_arena,
+                    &output_schema,
+                    0,
+                )
+                .unwrap();
+                buffer
+            });
+
+            let phys_input = lower_ir!(initial)?;
+            return build_recursive_union_fallback(
+                phys_input, recursive, options, ir_arena, expr_arena, phys_sm, format_str,
+            );
+        },
         IR::ExtContext { .. } => todo!(),
         IR::Invalid => unreachable!(),
     };
@@ -193,6 +193,12 @@ pub enum PhysNodeKind {
         sort_options: SortMultipleOptions,
     },
 
+    /// Keeps the last row for every distinct value of `keys`, in order.
+    Distinct {
+        input: PhysStream,
+        keys: Vec<PlSmallStr>,
+    },
+
     TopK {
         input: PhysStream,
         k: PhysStream,
@@ -299,6 +305,31 @@ pub enum PhysNodeKind {
         args: JoinArgs,
     },
 
+    /// An as-of join of two inputs sorted on `left_key` and `right_key`, the join itself
+    /// is done by the in-memory engine on each left morsel with the matching right window.
+    #[cfg(feature = "asof_join")]
+    AsOfJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        left_key: PlSmallStr,
+        right_key: PlSmallStr,
+        args: JoinArgs,
+    },
+
+    /// An inequality join which streams the left input against the right input sorted on the
+    /// key of the first inequality. The join itself is done by the in-memory engine.
+    #[cfg(feature = "iejoin")]
+    IEJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+        options: polars_ops::frame::IEJoinOptions,
+    },
+
     /// Generic fallback for (as-of-yet) unsupported streaming joins.
     /// Fully sinks all data to in-memory data frames and uses the in-memory
     /// engine to perform the join.
@@ -360,6 +391,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::Rle(input)
             | PhysNodeKind::RleId(input)
             | PhysNodeKind::PeakMinMax { input, .. }
+            | PhysNodeKind::Distinct { input, .. }
             | PhysNodeKind::GroupBy { input, .. } => {
                 rec!(input.node);
                 visit(input);
@@ -397,6 +429,30 @@ fn visit_node_inputs_mut(
                 visit(input_right);
             },
 
+            #[cfg(feature = "asof_join")]
+            PhysNodeKind::AsOfJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                rec!(input_left.node);
+                rec!(input_right.node);
+                visit(input_left);
+                visit(input_right);
+            },
+
+            #[cfg(feature = "iejoin")]
+            PhysNodeKind::IEJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                rec!(input_left.node);
+                rec!(input_right.node);
+                visit(input_left);
+                visit(input_right);
+            },
+
             #[cfg(feature = "merge_sorted")]
             PhysNodeKind::MergeSorted {
                 input_left,
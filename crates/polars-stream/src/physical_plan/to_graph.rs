@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
 use parking_lot::Mutex;
-use polars_core::prelude::PlRandomState;
+use polars_core::prelude::{IsSorted, PlRandomState};
 use polars_core::schema::Schema;
+#[cfg(feature = "iejoin")]
+use polars_core::utils::try_get_supertype;
 use polars_core::{POOL, config};
 use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_expr::groups::new_hash_grouper;
@@ -15,11 +17,11 @@ use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
 use polars_plan::prelude::{FileType, FunctionFlags};
 use polars_utils::arena::{Arena, Node};
-use polars_utils::format_pl_smallstr;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::plpath::PlPath;
 use polars_utils::relaxed_cell::RelaxedCell;
+use polars_utils::{format_pl_smallstr, unique_column_name};
 use recursive::recursive;
 use slotmap::{SecondaryMap, SlotMap};
 
@@ -62,6 +64,58 @@ fn create_stream_expr(
     Ok(StreamExpr::new(phys, reentrant))
 }
 
+/// Creates an in-memory join executor for every pipeline, such that parts of the inputs can be
+/// joined in parallel.
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+#[allow(clippy::too_many_arguments)]
+fn create_in_memory_joiners(
+    ctx: &mut GraphConversionContext<'_>,
+    left_input_schema: &Arc<Schema>,
+    right_input_schema: &Arc<Schema>,
+    output_schema: &Arc<Schema>,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    args: &polars_ops::frame::JoinArgs,
+    options: Option<polars_plan::dsl::JoinTypeOptionsIR>,
+) -> PolarsResult<Vec<nodes::joins::InMemoryJoiner>> {
+    (0..ctx.num_pipelines)
+        .map(|_| {
+            let mut lp_arena = Arena::default();
+            let left_lmdf = Arc::new(LateMaterializedDataFrame::default());
+            let right_lmdf = Arc::new(LateMaterializedDataFrame::default());
+
+            let left_node = lp_arena.add(left_lmdf.clone().as_ir_node(left_input_schema.clone()));
+            let right_node =
+                lp_arena.add(right_lmdf.clone().as_ir_node(right_input_schema.clone()));
+            let join_node = lp_arena.add(IR::Join {
+                input_left: left_node,
+                input_right: right_node,
+                schema: output_schema.clone(),
+                left_on: left_on.to_vec(),
+                right_on: right_on.to_vec(),
+                options: Arc::new(JoinOptionsIR {
+                    allow_parallel: false,
+                    force_parallel: false,
+                    args: args.clone(),
+                    options: options.clone(),
+                    rows_left: (None, 0),
+                    rows_right: (None, 0),
+                }),
+            });
+
+            let mut executor =
+                create_physical_plan(join_node, &mut lp_arena, ctx.expr_arena, None)?;
+            let joiner: nodes::joins::InMemoryJoiner = Box::new(move |left, right| {
+                left_lmdf.set_materialized_dataframe(left);
+                right_lmdf.set_materialized_dataframe(right);
+                let mut state = ExecutionState::new();
+                executor.execute(&mut state)
+            });
+            Ok(joiner)
+        })
+        .collect()
+}
+
 struct GraphConversionContext<'a> {
     phys_sm: &'a SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &'a mut Arena<AExpr>,
@@ -285,10 +339,11 @@ fn to_graph_rec<'a>(
                     [(input_key, input.port)],
                 ),
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
+                FileType::Json(json_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
                         target.clone(),
                         sink_options,
+                        *json_writer_options,
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -316,11 +371,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "avro")]
+                FileType::Avro(avro_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::avro::AvroSinkNode::new(
+                        target.clone(),
+                        input_schema,
+                        sink_options,
+                        avro_writer_options.clone(),
+                        cloud_options.clone(),
+                    )?),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "avro"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -457,31 +524,38 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: *slice,
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                None,
-            )?);
+            let key_selectors = by_column
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &input_schema))
+                .try_collect_vec()?;
+
+            // If we sort by a plain column, that column is sorted in the output.
+            let sorted_column =
+                by_column
+                    .first()
+                    .and_then(|e| match ctx.expr_arena.get(e.node()) {
+                        AExpr::Column(name) => {
+                            let flag = if sort_options.descending[0] {
+                                IsSorted::Descending
+                            } else {
+                                IsSorted::Ascending
+                            };
+                            input_schema.index_of(name).map(|idx| (idx, flag))
+                        },
+                        _ => None,
+                    });
 
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
+                nodes::sort::SortNode::new(
                     input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
+                    key_selectors,
+                    sort_options.descending.clone(),
+                    sort_options.nulls_last.clone(),
+                    *slice,
+                    sorted_column,
+                    unique_column_name(),
+                    ctx.num_pipelines,
                 ),
                 [(input_key, input.port)],
             )
@@ -538,6 +612,15 @@ fn to_graph_rec<'a>(
             )
         },
 
+        Distinct { input, keys } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            ctx.graph.add_node(
+                nodes::distinct::DistinctNode::new(input_schema, keys.clone()),
+                [(input_key, input.port)],
+            )
+        },
+
         Rle(input) => {
             let input_key = to_graph_rec(input.node, ctx)?;
             let input_schema = &ctx.phys_sm[input.node].output_schema;
@@ -784,6 +867,117 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            left_key,
+            right_key,
+            args,
+        } => {
+            use polars_ops::frame::JoinType;
+
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let JoinType::AsOf(asof_options) = &args.how else {
+                unreachable!()
+            };
+            let left_by = asof_options.left_by.clone().unwrap_or_default();
+            let right_by = asof_options.right_by.clone().unwrap_or_default();
+            let check_sortedness = asof_options.check_sortedness;
+
+            // The node checks sortedness across morsels itself, and slicing is done after the
+            // join.
+            let mut join_args = args.clone();
+            let mut join_asof_options = asof_options.clone();
+            join_asof_options.check_sortedness = false;
+            join_args.how = JoinType::AsOf(join_asof_options);
+            join_args.slice = None;
+
+            let joiners = create_in_memory_joiners(
+                ctx,
+                &left_input_schema,
+                &right_input_schema,
+                &node.output_schema,
+                left_on,
+                right_on,
+                &join_args,
+                None,
+            )?;
+
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    right_input_schema,
+                    left_key.clone(),
+                    right_key.clone(),
+                    left_by,
+                    right_by,
+                    check_sortedness,
+                    joiners,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "iejoin")]
+        IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let left_key_selector = create_stream_expr(&left_on[0], ctx, &left_input_schema)?;
+            let right_key_selector = create_stream_expr(&right_on[0], ctx, &right_input_schema)?;
+            let key_dtype = try_get_supertype(
+                left_on[0].dtype(&left_input_schema, ctx.expr_arena)?,
+                right_on[0].dtype(&right_input_schema, ctx.expr_arena)?,
+            )?;
+
+            // Slicing is done after the join.
+            let mut join_args = args.clone();
+            join_args.slice = None;
+            let joiners = create_in_memory_joiners(
+                ctx,
+                &left_input_schema,
+                &right_input_schema,
+                &node.output_schema,
+                left_on,
+                right_on,
+                &join_args,
+                Some(polars_plan::dsl::JoinTypeOptionsIR::IEJoin(options.clone())),
+            )?;
+
+            ctx.graph.add_node(
+                nodes::joins::ie_join::IEJoinNode::new(
+                    right_input_schema,
+                    left_key_selector,
+                    right_key_selector,
+                    key_dtype,
+                    options.operator1,
+                    joiners,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
         EquiJoin {
             input_left,
             input_right,
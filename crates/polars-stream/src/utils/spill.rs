@@ -0,0 +1,267 @@
+//! Spilling of [`DataFrame`]s to local disk for memory-intensive nodes.
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
+
+use arrow::array::TryExtend;
+use arrow::io::ipc::read::{FileReader, read_file_metadata};
+use polars_core::config;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::PolarsResult;
+use polars_io::SerWriter;
+use polars_io::ipc::{IpcCompression, IpcWriter};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+use polars_utils::format_pl_smallstr;
+
+static SPILL_DIR: LazyLock<Box<Path>> = LazyLock::new(|| {
+    let path = POLARS_TEMP_DIR_BASE_PATH.join("spill/").into_boxed_path();
+
+    if let Err(err) = std::fs::create_dir_all(path.as_ref()) {
+        if !path.is_dir() {
+            panic!(
+                "failed to create spill directory: path = {}, err = {}",
+                path.to_str().unwrap(),
+                err
+            );
+        }
+    }
+
+    path
+});
+
+static SPILL_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// The amount of memory (in bytes) memory-intensive nodes may hold on to before they spill
+/// their state to disk.
+///
+/// This is configured with `POLARS_STREAMING_MEMORY_LIMIT`. If it is not set, nothing is
+/// ever spilled.
+pub fn get_spill_memory_limit() -> Option<usize> {
+    std::env::var("POLARS_STREAMING_MEMORY_LIMIT")
+        .ok()
+        .map(|v| {
+            v.parse().unwrap_or_else(|_| {
+                panic!("POLARS_STREAMING_MEMORY_LIMIT must be a number of bytes, got: {v}")
+            })
+        })
+}
+
+/// Tracks the memory held in-memory by a node against its spill limit.
+#[derive(Clone)]
+pub struct MemoryBudget {
+    limit: Option<usize>,
+    used: Arc<AtomicUsize>,
+}
+
+impl MemoryBudget {
+    pub fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            used: Arc::default(),
+        }
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// An empty reservation which can be grown with [`MemoryReservation::try_grow`].
+    pub fn empty_reservation(&self) -> MemoryReservation {
+        MemoryReservation {
+            budget: self.clone(),
+            size: 0,
+        }
+    }
+
+    /// Try to reserve `size` bytes, returning `None` if the reservation would exceed the limit.
+    pub fn try_reserve(&self, size: usize) -> Option<MemoryReservation> {
+        let mut reservation = self.empty_reservation();
+        reservation.try_grow(size).then_some(reservation)
+    }
+}
+
+/// Bytes reserved from a [`MemoryBudget`], which are released again when this is dropped.
+pub struct MemoryReservation {
+    budget: MemoryBudget,
+    size: usize,
+}
+
+impl MemoryReservation {
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Try to grow the reservation by `size` bytes, returning false (and leaving the
+    /// reservation unchanged) if that would exceed the limit.
+    pub fn try_grow(&mut self, size: usize) -> bool {
+        let limit = self.budget.limit.unwrap_or(usize::MAX);
+        let reserved = self
+            .budget
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(size).filter(|new_used| *new_used <= limit)
+            })
+            .is_ok();
+        if reserved {
+            self.size += size;
+        }
+        reserved
+    }
+
+    /// Release all reserved bytes, e.g. after spilling them.
+    pub fn release(&mut self) {
+        self.budget
+            .used
+            .fetch_sub(std::mem::take(&mut self.size), Ordering::Relaxed);
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+/// Prepend the key columns to the payload such that they can be spilled together. The keys are
+/// renamed so they can't collide with the payload column names.
+pub fn hstack_spill_keys(keys: DataFrame, payload: DataFrame) -> DataFrame {
+    let height = payload.height();
+    let columns = keys
+        .take_columns()
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| c.with_name(format_pl_smallstr!("__POLARS_SPILL_KEY_{i}")))
+        .chain(payload.take_columns())
+        .collect();
+    unsafe { DataFrame::new_no_checks(height, columns) }
+}
+
+/// Inverse of [`hstack_spill_keys`], returns the keys and payload.
+pub fn split_spill_keys(df: DataFrame, num_keys: usize) -> (DataFrame, DataFrame) {
+    let height = df.height();
+    let mut keys = df.take_columns();
+    let payload = keys.split_off(num_keys);
+    unsafe {
+        (
+            DataFrame::new_no_checks(height, keys),
+            DataFrame::new_no_checks(height, payload),
+        )
+    }
+}
+
+/// A sequence of [`DataFrame`]s spilled to a temporary IPC file, removed on drop.
+pub struct SpillFile {
+    path: PathBuf,
+    schema: SchemaRef,
+    height: usize,
+}
+
+impl SpillFile {
+    /// Write the given frames to a new spill file. Each frame becomes a separate record batch
+    /// which can be read back one at a time.
+    pub fn write<I>(schema: SchemaRef, dfs: I) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = DataFrame>,
+    {
+        Self::try_write(schema, dfs.into_iter().map(Ok))
+    }
+
+    /// Like [`SpillFile::write`], but for frames which are computed while writing, such as the
+    /// result of merging other spill files.
+    pub fn try_write<I>(schema: SchemaRef, dfs: I) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = PolarsResult<DataFrame>>,
+    {
+        let id = SPILL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = SPILL_DIR.join(format!("{}-{id}.arrow", std::process::id()));
+
+        if config::verbose() {
+            eprintln!("[spill]: writing spill file {}", path.display());
+        }
+
+        // Construct the handle first, such that the file gets removed on error.
+        let mut spill_file = Self {
+            path,
+            schema,
+            height: 0,
+        };
+
+        let file = File::create(&spill_file.path)?;
+        let mut writer = IpcWriter::new(BufWriter::new(file))
+            .with_compression(Some(IpcCompression::LZ4))
+            .batched(&spill_file.schema)?;
+        for df in dfs {
+            let mut df = df?;
+            if df.height() == 0 {
+                continue;
+            }
+            df.align_chunks();
+            writer.write_batch(&df)?;
+            spill_file.height += df.height();
+        }
+        writer.finish()?;
+
+        Ok(spill_file)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Read all spilled frames back into a single [`DataFrame`].
+    pub fn read_df(&self) -> PolarsResult<DataFrame> {
+        let mut reader = self.reader()?;
+        let mut dfs = Vec::new();
+        while let Some(df) = reader.next_df()? {
+            dfs.push(df);
+        }
+        if dfs.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&self.schema));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    /// Open a reader which yields the spilled frames in the order they were written.
+    pub fn reader(&self) -> PolarsResult<SpillReader> {
+        let mut file = BufReader::new(File::open(&self.path)?);
+        let metadata = read_file_metadata(&mut file)?;
+        Ok(SpillReader {
+            reader: FileReader::new(file, metadata, None, None),
+            schema: self.schema.clone(),
+        })
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if let Err(err) = std::fs::remove_file(&self.path) {
+            if config::verbose() {
+                eprintln!(
+                    "[spill]: failed to remove spill file {}: {err}",
+                    self.path.display()
+                );
+            }
+        }
+    }
+}
+
+pub struct SpillReader {
+    reader: FileReader<BufReader<File>>,
+    schema: SchemaRef,
+}
+
+impl SpillReader {
+    /// Read the next spilled frame, returns `None` once the file is exhausted.
+    pub fn next_df(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let Some(batch) = self.reader.next() else {
+            return Ok(None);
+        };
+        let mut df = DataFrame::empty_with_schema(&self.schema);
+        df.try_extend([batch?])?;
+        Ok(Some(df))
+    }
+}
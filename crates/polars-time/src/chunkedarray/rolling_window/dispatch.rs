@@ -1,3 +1,12 @@
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _decimal_checked_mean, _decimal_checked_quantile, _decimal_checked_std, _decimal_checked_var,
+    _get_decimal_precision_quantile, _get_decimal_precision_sum, _get_decimal_scale_mean,
+    _get_decimal_scale_quantile, _get_decimal_scale_std, _get_decimal_scale_var,
+};
 use polars_core::{with_match_physical_float_polars_type, with_match_physical_numeric_polars_type};
 use polars_ops::series::SeriesMethods;
 
@@ -153,6 +162,129 @@ where
     Series::try_from((ca.name().clone(), out))
 }
 
+/// Slide the window over the decimal values. The non-null values entering and leaving the window
+/// are added to and removed from `state` with `update`, which returns `None` on overflow. The
+/// state and the number of values in every window are passed to `finish` to get the output value.
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+fn rolling_decimal<S, U, F>(
+    ca: &DecimalChunked,
+    options: &RollingOptionsFixedWindow,
+    mut state: S,
+    update: U,
+    finish: F,
+) -> PolarsResult<Int128Chunked>
+where
+    U: Fn(&mut S, i128, bool) -> Option<()>,
+    F: Fn(&S, usize) -> PolarsResult<Option<i128>>,
+{
+    polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+    let overflow = || polars_err!(ComputeError: "decimal arithmetic overflowed");
+    let values: Vec<Option<i128>> = ca.physical().iter().collect();
+    let len = values.len();
+    let window_size = options.window_size;
+
+    let (mut start, mut end) = (0, 0);
+    let mut count = 0_usize;
+    let mut out = Vec::with_capacity(len);
+    for i in 0..len {
+        let (new_start, new_end) = if options.center {
+            let right = window_size.div_ceil(2);
+            (i.saturating_sub(window_size - right), (i + right).min(len))
+        } else {
+            (i.saturating_sub(window_size - 1), i + 1)
+        };
+        for v in values[start..new_start].iter().flatten() {
+            update(&mut state, *v, false).ok_or_else(overflow)?;
+            count -= 1;
+        }
+        for v in values[end..new_end].iter().flatten() {
+            update(&mut state, *v, true).ok_or_else(overflow)?;
+            count += 1;
+        }
+        (start, end) = (new_start, new_end);
+
+        if count < options.min_periods {
+            out.push(None);
+        } else {
+            out.push(finish(&state, count)?);
+        }
+    }
+    Ok(Int128Chunked::from_iter_options(
+        ca.name().clone(),
+        out.into_iter(),
+    ))
+}
+
+/// Keep the sum of the values in a rolling window, see [`rolling_decimal`].
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+fn update_sum(sum: &mut i128, v: i128, add: bool) -> Option<()> {
+    *sum = if add {
+        sum.checked_add(v)?
+    } else {
+        sum.checked_sub(v)?
+    };
+    Some(())
+}
+
+/// Keep the sum and the sum of squares of the values in a rolling window, see
+/// [`rolling_decimal`].
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+fn update_moments((sum, sum_squares): &mut (i128, i128), v: i128, add: bool) -> Option<()> {
+    update_sum(sum, v, add)?;
+    update_sum(sum_squares, v.checked_mul(v)?, add)
+}
+
+/// Keep the values in a rolling window sorted, with their multiplicity, see [`rolling_decimal`].
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+fn update_sorted(sorted: &mut BTreeMap<i128, usize>, v: i128, add: bool) -> Option<()> {
+    if add {
+        *sorted.entry(v).or_default() += 1;
+    } else if let Some(n) = sorted.get_mut(&v) {
+        *n -= 1;
+        if *n == 0 {
+            sorted.remove(&v);
+        }
+    }
+    Some(())
+}
+
+/// Compute the exact rolling variance or standard deviation of decimals.
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+fn rolling_decimal_var_std(
+    ca: &DecimalChunked,
+    options: &RollingOptionsFixedWindow,
+    is_std: bool,
+) -> PolarsResult<Series> {
+    let ddof = match options.fn_params {
+        Some(RollingFnParams::Var(params)) => params.ddof,
+        _ => 1,
+    };
+    let scale = ca.scale();
+    let out = rolling_decimal(
+        ca,
+        options,
+        (0, 0),
+        update_moments,
+        |&(sum, sum_squares), count| {
+            let moments = (sum, sum_squares, count);
+            if is_std {
+                _decimal_checked_std(moments, ddof, scale)
+            } else {
+                _decimal_checked_var(moments, ddof, scale)
+            }
+        },
+    )?;
+    let out_scale = if is_std {
+        _get_decimal_scale_std(scale)
+    } else {
+        _get_decimal_scale_var(scale)
+    };
+    let precision = _get_decimal_precision_sum(ca.precision());
+    Ok(out
+        .into_decimal_unchecked(precision, out_scale)
+        .into_series())
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
@@ -177,6 +309,19 @@ pub trait SeriesOpsTime: AsSeries {
     /// See: [`RollingAgg::rolling_mean`]
     #[cfg(feature = "rolling_window")]
     fn rolling_mean(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let (Ok(ca), None) = (self.as_series().decimal(), &options.weights) {
+            let precision = _get_decimal_precision_sum(ca.precision());
+            let scale = _get_decimal_scale_mean(ca.scale());
+            let out = rolling_decimal(ca, &options, 0, update_sum, |&sum, count| {
+                if count == 0 {
+                    return Ok(None);
+                }
+                _decimal_checked_mean(sum, count, scale - ca.scale()).map(Some)
+            })?;
+            return Ok(out.into_decimal_unchecked(precision, scale).into_series());
+        }
+
         let s = self.as_series().to_float()?;
         with_match_physical_float_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -226,6 +371,13 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling sum to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_sum(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let (Ok(ca), None) = (self.as_series().decimal(), &options.weights) {
+            let precision = _get_decimal_precision_sum(ca.precision());
+            let out = rolling_decimal(ca, &options, 0, update_sum, |&sum, _| Ok(Some(sum)))?;
+            return Ok(out.into_decimal(precision, ca.scale())?.into_series());
+        }
+
         let mut s = self.as_series().clone();
         if options.weights.is_some() {
             s = s.to_float()?;
@@ -277,6 +429,25 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling quantile to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_quantile(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let (Ok(ca), None) = (self.as_series().decimal(), &options.weights) {
+            let Some(RollingFnParams::Quantile(params)) = options.fn_params else {
+                polars_bail!(InvalidOperation: "rolling quantile requires quantile parameters")
+            };
+            let precision = _get_decimal_precision_quantile(ca.precision(), ca.scale());
+            let scale = _get_decimal_scale_quantile(ca.scale());
+            let out =
+                rolling_decimal(ca, &options, BTreeMap::new(), update_sorted, |sorted, _| {
+                    let values = sorted
+                        .iter()
+                        .flat_map(|(v, n)| std::iter::repeat_n(*v, *n))
+                        .collect();
+                    let scale_increase = scale - ca.scale();
+                    _decimal_checked_quantile(values, params.prob, params.method, scale_increase)
+                })?;
+            return Ok(out.into_decimal_unchecked(precision, scale).into_series());
+        }
+
         let s = self.as_series().to_float()?;
         with_match_physical_float_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -350,6 +521,14 @@ pub trait SeriesOpsTime: AsSeries {
             dt if dt.is_temporal() => {
                 return s.to_physical_repr().rolling_min(options)?.cast(dt);
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(precision, scale) => {
+                let out = s.to_physical_repr().rolling_min(options)?;
+                let out = out.i128()?.clone();
+                return Ok(out
+                    .into_decimal_unchecked(*precision, scale.unwrap_or(0))
+                    .into_series());
+            },
             dt => {
                 polars_ensure!(
                     dt.is_primitive_numeric() && !dt.is_unknown(),
@@ -431,6 +610,14 @@ pub trait SeriesOpsTime: AsSeries {
             dt if dt.is_temporal() => {
                 return s.to_physical_repr().rolling_max(options)?.cast(dt);
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(precision, scale) => {
+                let out = s.to_physical_repr().rolling_max(options)?;
+                let out = out.i128()?.clone();
+                return Ok(out
+                    .into_decimal_unchecked(*precision, scale.unwrap_or(0))
+                    .into_series());
+            },
             dt => {
                 polars_ensure!(
                     dt.is_primitive_numeric() && !dt.is_unknown(),
@@ -476,6 +663,11 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling variance to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_var(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let (Ok(ca), None) = (self.as_series().decimal(), &options.weights) {
+            return rolling_decimal_var_std(ca, &options, false);
+        }
+
         let s = self.as_series().to_float()?;
 
         with_match_physical_float_polars_type!(s.dtype(), |$T| {
@@ -517,6 +709,11 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling std_dev to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_std(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let (Ok(ca), None) = (self.as_series().decimal(), &options.weights) {
+            return rolling_decimal_var_std(ca, &options, true);
+        }
+
         self.rolling_var(options).map(|mut s| {
             match s.dtype().clone() {
                 DataType::Float32 => {
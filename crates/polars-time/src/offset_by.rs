@@ -58,7 +58,94 @@ fn apply_offsets_to_datetime(
     }
 }
 
+/// Add calendar intervals by adding the months, the days and the nanoseconds one after the
+/// other, so that month ends are clamped before the days are added.
+#[cfg(feature = "dtype-interval")]
+fn apply_intervals_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let offset_fn = match datetime.time_unit() {
+        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Nanoseconds => Duration::add_ns,
+    };
+    broadcast_try_binary_elementwise(
+        datetime.physical(),
+        offsets.physical(),
+        |timestamp_opt, offset_opt| match (timestamp_opt, offset_opt) {
+            (Some(timestamp), Some(offset)) => {
+                let offset = i128_to_interval(offset);
+                [
+                    Duration::from_months(offset.months() as i64),
+                    Duration::from_days(offset.days() as i64),
+                    Duration::from_nsecs(offset.ns()),
+                ]
+                .iter()
+                .filter(|d| !d.is_zero())
+                .try_fold(timestamp, |t, d| offset_fn(d, t, time_zone))
+                .map(Some)
+            },
+            _ => Ok(None),
+        },
+    )
+}
+
+#[cfg(feature = "dtype-interval")]
+fn impl_offset_by_interval(ts: &Series, offsets: &IntervalChunked) -> PolarsResult<Series> {
+    polars_ensure!(
+        ts.len() == offsets.len() || offsets.len() == 1 || ts.len() == 1,
+        length_mismatch = "dt.offset_by",
+        ts.len(),
+        offsets.len()
+    );
+
+    // Only intervals without months and days are guaranteed to have a constant length.
+    let preserve_sortedness = offsets.len() == 1
+        && offsets.physical().get(0).is_some_and(|offset| {
+            let offset = i128_to_interval(offset);
+            offset.months() == 0 && offset.days() == 0
+        });
+
+    let mut out = match ts.dtype() {
+        DataType::Date => {
+            let ts = ts
+                .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+                .unwrap();
+            let out = apply_intervals_to_datetime(ts.datetime().unwrap(), offsets, None)?;
+            out.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+                .unwrap()
+                .cast(&DataType::Date)?
+        },
+        DataType::Datetime(tu, tz) => {
+            let datetime = ts.datetime().unwrap();
+            let out = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => {
+                    apply_intervals_to_datetime(datetime, offsets, tz.parse::<Tz>().ok().as_ref())?
+                },
+                _ => apply_intervals_to_datetime(datetime, offsets, None)?,
+            };
+            out.cast(&DataType::Datetime(*tu, tz.clone()))?
+        },
+        dt => polars_bail!(
+            InvalidOperation: "expected Date or Datetime, got {}", dt
+        ),
+    };
+    if preserve_sortedness {
+        out.set_sorted_flag(ts.is_sorted_flag());
+    } else {
+        out.set_sorted_flag(IsSorted::Not);
+    }
+    Ok(out)
+}
+
 pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-interval")]
+    if let Some(offsets) = offsets.try_interval() {
+        return impl_offset_by_interval(ts, offsets);
+    }
     let offsets = offsets.str()?;
 
     polars_ensure!(
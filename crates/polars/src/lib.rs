@@ -203,6 +203,8 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `orc` - Read and write Apache ORC format
+//!     - `fixed_width` - Read fixed-width text files
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
@@ -300,12 +302,17 @@
 //! | Datetime                | dtype-datetime    |
 //! | Time                    | dtype-time        |
 //! | Duration                | dtype-duration    |
+//! | Interval                | dtype-interval    |
 //! | Int8                    | dtype-i8          |
 //! | Int16                   | dtype-i16         |
 //! | UInt8                   | dtype-u8          |
 //! | UInt16                  | dtype-u16         |
+//! | UInt128                 | dtype-u128        |
+//! | Float16                 | dtype-f16         |
 //! | Categorical             | dtype-categorical |
 //! | Struct                  | dtype-struct      |
+//! | Map                     | dtype-map         |
+//! | Extension               | dtype-extension   |
 //!
 //!
 //! Or you can choose one of the preconfigured pre-sets.
@@ -60,7 +60,9 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Extension,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -68,7 +70,9 @@ from polars.datatypes import (
     Int32,
     Int64,
     Int128,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -78,6 +82,7 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
     Utf8,
 )
@@ -191,20 +196,25 @@ from polars.io import (
     read_database_uri,
     read_delta,
     read_excel,
+    read_fixed_width,
     read_ipc,
     read_ipc_schema,
     read_ipc_stream,
     read_json,
     read_ndjson,
     read_ods,
+    read_orc,
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
+    scan_avro,
     scan_csv,
     scan_delta,
+    scan_fixed_width,
     scan_iceberg,
     scan_ipc,
     scan_ndjson,
+    scan_orc,
     scan_parquet,
     scan_pyarrow_dataset,
 )
@@ -266,7 +276,9 @@ __all__ = [
     "Decimal",
     "Duration",
     "Enum",
+    "Extension",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
@@ -274,7 +286,9 @@ __all__ = [
     "Int32",
     "Int64",
     "Int128",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -284,6 +298,7 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "UInt128",
     "Unknown",
     "Utf8",
     # polars.io
@@ -303,20 +318,25 @@ __all__ = [
     "read_database_uri",
     "read_delta",
     "read_excel",
+    "read_fixed_width",
     "read_ipc",
     "read_ipc_schema",
     "read_ipc_stream",
     "read_json",
     "read_ndjson",
     "read_ods",
+    "read_orc",
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
+    "scan_fixed_width",
     "scan_iceberg",
     "scan_ipc",
     "scan_ndjson",
+    "scan_orc",
     "scan_parquet",
     "scan_pyarrow_dataset",
     "Catalog",
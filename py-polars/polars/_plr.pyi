@@ -49,6 +49,7 @@ StartBy: TypeAlias = Literal[
 ClosedWindow: TypeAlias = Literal["left", "right", "both", "none"]
 RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+ExternalCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinType: TypeAlias = Literal["inner", "left", "right", "full", "semi", "anti", "cross"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
@@ -764,11 +765,21 @@ class PyDataFrame:
         projection: Sequence[int] | None,
         n_rows: int | None,
     ) -> PyDataFrame: ...
+    @staticmethod
+    def read_orc(
+        py_f: Any,
+        columns: Sequence[str] | None,
+        projection: Sequence[int] | None,
+        n_rows: int | None,
+    ) -> PyDataFrame: ...
     def write_json(self, py_f: Any) -> None: ...
     def write_ipc_stream(
         self, py_f: Any, compression: Any, compat_level: Any
     ) -> None: ...
     def write_avro(self, py_f: Any, compression: Any, name: str) -> None: ...
+    def write_orc(
+        self, py_f: Any, compression: Any, stripe_size: int | None
+    ) -> None: ...
 
     # serde
     def serialize_binary(self, py_f: Any) -> None: ...
@@ -869,6 +880,67 @@ class PyLazyFrame:
         include_file_paths: str | None,
     ) -> PyLazyFrame: ...
     @staticmethod
+    def new_from_avro(
+        source: Any | None,
+        sources: Any,
+        n_rows: int | None,
+        cache: bool,
+        rechunk: bool,
+        row_index: tuple[str, int] | None,
+        cloud_options: dict[str, Any] | None,
+        credential_provider: Any | None,
+        hive_partitioning: bool | None,
+        hive_schema: Any | None,
+        try_parse_hive_dates: bool,
+        retries: int,
+        file_cache_ttl: int | None,
+        include_file_paths: str | None,
+    ) -> PyLazyFrame: ...
+    @staticmethod
+    def new_from_orc(
+        source: Any | None,
+        sources: Any,
+        n_rows: int | None,
+        cache: bool,
+        rechunk: bool,
+        row_index: tuple[str, int] | None,
+        cloud_options: dict[str, Any] | None,
+        credential_provider: Any | None,
+        hive_partitioning: bool | None,
+        hive_schema: Any | None,
+        try_parse_hive_dates: bool,
+        retries: int,
+        file_cache_ttl: int | None,
+        include_file_paths: str | None,
+    ) -> PyLazyFrame: ...
+    @staticmethod
+    def new_from_fixed_width(
+        source: Any | None,
+        sources: Any,
+        columns: Sequence[tuple[str, int, int]],
+        span_unit: Any,
+        overwrite_dtype: Sequence[tuple[str, Any]] | None,
+        skip_rows: int,
+        n_rows: int | None,
+        infer_schema_length: int | None,
+        null_values: Any | None,
+        missing_utf8_is_empty_string: bool,
+        ignore_errors: bool,
+        try_parse_dates: bool,
+        decimal_comma: bool,
+        encoding: Any,
+        eol_char: str,
+        cache: bool,
+        rechunk: bool,
+        row_index: tuple[str, int] | None,
+        glob: bool,
+        cloud_options: dict[str, Any] | None,
+        credential_provider: Any | None,
+        retries: int,
+        file_cache_ttl: int | None,
+        include_file_paths: str | None,
+    ) -> PyLazyFrame: ...
+    @staticmethod
     def new_from_dataset_object(dataset_object: Any) -> PyLazyFrame: ...
     @staticmethod
     def scan_from_python_function_arrow_schema(
@@ -944,6 +1016,7 @@ class PyLazyFrame:
         statistics: StatisticsOptions,
         row_group_size: int | None,
         data_page_size: int | None,
+        bloom_filter: tuple[float, int | None] | None,
         cloud_options: dict[str, Any] | None,
         credential_provider: Any | None,
         retries: int,
@@ -978,6 +1051,8 @@ class PyLazyFrame:
         decimal_comma: bool,
         null_value: str | None,
         quote_style: QuoteStyle | None,
+        compression: ExternalCompression,
+        compression_level: int | None,
         cloud_options: dict[str, Any] | None,
         credential_provider: Any | None,
         retries: int,
@@ -986,6 +1061,18 @@ class PyLazyFrame:
     def sink_json(
         self,
         target: SinkTarget,
+        compression: ExternalCompression,
+        compression_level: int | None,
+        cloud_options: dict[str, Any] | None,
+        credential_provider: Any | None,
+        retries: int,
+        sink_options: Any,
+    ) -> PyLazyFrame: ...
+    def sink_avro(
+        self,
+        target: SinkTarget,
+        compression: AvroCompression,
+        name: str,
         cloud_options: dict[str, Any] | None,
         credential_provider: Any | None,
         retries: int,
@@ -1569,6 +1656,7 @@ class PyExpr:
     def list_gather(self, index: PyExpr, null_on_oob: bool) -> PyExpr: ...
     def list_gather_every(self, n: PyExpr, offset: PyExpr) -> PyExpr: ...
     def list_to_array(self, width: int) -> PyExpr: ...
+    def list_to_map(self) -> PyExpr: ...
     def list_to_struct(self, names: Sequence[str]) -> PyExpr: ...
     def list_to_struct_fixed_width(self, names: Sequence[str]) -> PyExpr: ...
     def list_n_unique(self) -> PyExpr: ...
@@ -1854,8 +1942,17 @@ class PyExpr:
     def struct_multiple_fields(self, names: Sequence[str]) -> PyExpr: ...
     def struct_rename_fields(self, names: Sequence[str]) -> PyExpr: ...
     def struct_json_encode(self) -> PyExpr: ...
+    def struct_to_map(self) -> PyExpr: ...
     def struct_with_fields(self, fields: Sequence[PyExpr]) -> PyExpr: ...
 
+    # map
+    def map_get(self, key: PyExpr) -> PyExpr: ...
+    def map_contains_key(self, key: PyExpr) -> PyExpr: ...
+    def map_keys(self) -> PyExpr: ...
+    def map_values(self) -> PyExpr: ...
+    def map_to_list(self) -> PyExpr: ...
+    def map_to_struct(self, names: Sequence[str]) -> PyExpr: ...
+
 class PyDataTypeExpr:
     def __init__(self, inner: Any) -> None: ...
     @staticmethod
@@ -2412,7 +2509,7 @@ class PyCatalogClient:
 # sql
 class PySQLContext:
     @staticmethod
-    def new() -> PySQLContext: ...
+    def new(catalog: str | None) -> PySQLContext: ...
     def execute(self, query: str) -> PyLazyFrame: ...
     def get_tables(self) -> list[str]: ...
     def register(self, name: str, lf: PyLazyFrame) -> None: ...
@@ -109,6 +109,7 @@ ColumnNameOrSelector: TypeAlias = Union[str, SelectorType]
 # The following all have an equivalent Rust enum with the same name
 Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+OrcCompression: TypeAlias = Literal["uncompressed", "zlib", "snappy", "lz4", "zstd"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
@@ -119,6 +120,7 @@ DefaultFieldValues: TypeAlias = tuple[
 DeletionFiles: TypeAlias = tuple[
     Literal["iceberg-position-delete"], dict[int, list[str]]
 ]
+ExternalCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
@@ -394,6 +396,7 @@ __all__ = [
     "EpochTimeUnit",
     "ExcelSpreadsheetEngine",
     "ExplainFormat",
+    "ExternalCompression",
     "FileSource",
     "FillNullStrategy",
     "FloatFmt",
@@ -420,6 +423,7 @@ __all__ = [
     "NullBehavior",
     "NumericLiteral",
     "OneOrMoreDataTypes",
+    "OrcCompression",
     "Orientation",
     "ParallelStrategy",
     "ParametricProfileNames",
@@ -159,6 +159,7 @@ if TYPE_CHECKING:
         MultiColSelector,
         MultiIndexSelector,
         OneOrMoreDataTypes,
+        OrcCompression,
         Orientation,
         ParquetCompression,
         ParquetMetadata,
@@ -3179,6 +3180,50 @@ class DataFrame:
 
         self._df.write_avro(file, compression, name)
 
+    def write_orc(
+        self,
+        file: str | Path | IO[bytes],
+        *,
+        compression: OrcCompression = "zstd",
+        stripe_size: int | None = None,
+    ) -> None:
+        """
+        Write to Apache ORC file.
+
+        Data types that ORC cannot represent are widened: unsigned integers are written
+        as signed integers and categoricals as strings.
+
+        Parameters
+        ----------
+        file
+            File path or writable file-like object to which the data will be written.
+        compression : {'uncompressed', 'zlib', 'snappy', 'lz4', 'zstd'}
+            Compression method. Defaults to "zstd".
+        stripe_size
+            Number of rows per stripe. Defaults to 262144. Stripe statistics are
+            used to skip stripes when scanning with a filter.
+
+        Examples
+        --------
+        >>> import pathlib
+        >>>
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "foo": [1, 2, 3, 4, 5],
+        ...         "bar": [6, 7, 8, 9, 10],
+        ...         "ham": ["a", "b", "c", "d", "e"],
+        ...     }
+        ... )
+        >>> path: pathlib.Path = dirpath / "new_file.orc"
+        >>> df.write_orc(path)
+        """
+        if compression is None:
+            compression = "uncompressed"
+        if isinstance(file, (str, Path)):
+            file = normalize_filepath(file)
+
+        self._df.write_orc(file, compression, stripe_size)
+
     def write_excel(
         self,
         workbook: str | Workbook | IO[bytes] | Path | None = None,
@@ -3944,6 +3989,7 @@ class DataFrame:
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filter: bool | float = False,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
         partition_by: str | Sequence[str] | None = None,
@@ -3997,6 +4043,16 @@ class DataFrame:
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        bloom_filter
+            Write a bloom filter for every flat column, such that scans with an
+            equality predicate can skip the row groups that don't contain the value.
+            Cannot be combined with `use_pyarrow`.
+
+            Possible values:
+
+            - `False`: do not write bloom filters (default).
+            - `True`: write bloom filters with a false positive probability of 0.05.
+            - A float: write bloom filters with this false positive probability.
         use_pyarrow
             Use C++ parquet implementation vs Rust parquet implementation.
             At the moment C++ supports more features.
@@ -4091,6 +4147,9 @@ class DataFrame:
             if metadata is not None:
                 msg = "write_parquet with `use_pyarrow=True` cannot be combined with `metadata`"
                 raise ValueError(msg)
+            if bloom_filter is not False:
+                msg = "write_parquet with `use_pyarrow=True` cannot be combined with `bloom_filter`"
+                raise ValueError(msg)
 
             tbl = self.to_arrow()
             data = {}
@@ -4155,6 +4214,7 @@ class DataFrame:
             statistics=statistics,
             row_group_size=row_group_size,
             data_page_size=data_page_size,
+            bloom_filter=bloom_filter,
             storage_options=storage_options,
             credential_provider=credential_provider,
             retries=retries,
@@ -16,7 +16,9 @@ from polars.datatypes.classes import (
     Decimal,
     Duration,
     Enum,
+    Extension,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -25,7 +27,9 @@ from polars.datatypes.classes import (
     Int64,
     Int128,
     IntegerType,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -36,6 +40,7 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
     Utf8,
 )
@@ -74,7 +79,9 @@ __all__ = [
     "Decimal",
     "Duration",
     "Enum",
+    "Extension",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int16",
@@ -83,7 +90,9 @@ __all__ = [
     "Int64",
     "Int8",
     "IntegerType",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -93,6 +102,7 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "UInt128",
     "UInt8",
     "Unknown",
     "Utf8",
@@ -396,6 +396,32 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class UInt128(UnsignedIntegerType):
+    """
+    128-bit unsigned integer type.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
+class Float16(FloatType):
+    """
+    16-bit floating point type.
+
+    Values are stored in half precision. Arithmetic and aggregations are computed in
+    32-bit precision and rounded back to half precision where the result is a
+    `Float16`.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -420,6 +446,15 @@ class Decimal(NumericType):
         If set to `None` (default), the precision is inferred.
     scale
         Number of digits to the right of the decimal point in each number.
+
+    Notes
+    -----
+    Decimals are stored as 128-bit integers, so the precision is at most 38.
+
+    Arithmetic, `round`, cumulative sums and products, and the `sum`, `mean`,
+    `std`, `var`, `median` and `quantile` aggregations and their unweighted
+    `rolling_*` versions are exact and raise an error on overflow. The `ewm_*` and
+    `rolling_*_by` methods are computed as floating point and return `Float64`.
     """
 
     precision: int | None
@@ -644,6 +679,24 @@ class Duration(TemporalType):
         return f"{class_name}(time_unit={self.time_unit!r})"
 
 
+class Interval(TemporalType):
+    """
+    Data type representing a calendar-aware interval of months, days and nanoseconds.
+
+    Adding an interval to a `Date` or `Datetime` applies the months first, then the
+    days and finally the nanoseconds, so `2024-01-31 + 1 month` is `2024-02-29`.
+
+    Interval values are converted to Python as `(months, days, nanoseconds)`
+    tuples. They can be created with SQL `INTERVAL` literals, by casting a
+    `Duration`, or by importing Arrow `month_day_nano_interval` data.
+
+    Notes
+    -----
+    The underlying representation of this type is a 128-bit signed integer that
+    packs the 32-bit months, the 32-bit days and the 64-bit nanoseconds.
+    """
+
+
 class Categories:
     """
     A named collection of categories for `Categorical`.
@@ -1198,3 +1251,133 @@ class Struct(NestedType):
     def to_schema(self) -> OrderedDict[str, PolarsDataType]:
         """Return Struct dtype as a schema dict."""
         return OrderedDict(self)
+
+
+class Map(NestedType):
+    """
+    Map type, associating keys with values.
+
+    Every map is stored as a list of `{key, value}` structs. The keys cannot be
+    null.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys.
+    value
+        The `DataType` of the values.
+    keys_sorted
+        Whether the keys within every map are sorted. This is kept when reading
+        and writing Arrow, IPC and Parquet data, and doesn't affect whether two
+        map types are equal.
+
+    Examples
+    --------
+    >>> s = pl.Series(
+    ...     [[{"key": "a", "value": 1}, {"key": "b", "value": 2}]],
+    ...     dtype=pl.Map(pl.String, pl.Int64),
+    ... )
+    >>> s.dtype
+    Map(String, Int64)
+    >>> s.map.get("b")
+    shape: (1,)
+    Series: '' [i64]
+    [
+            2
+    ]
+    """
+
+    key: PolarsDataType
+    value: PolarsDataType
+    keys_sorted: bool
+
+    def __init__(
+        self,
+        key: PolarsDataType | PythonDataType,
+        value: PolarsDataType | PythonDataType,
+        *,
+        keys_sorted: bool = False,
+    ) -> None:
+        self.key = polars.datatypes.parse_into_dtype(key)
+        self.value = polars.datatypes.parse_into_dtype(value)
+        self.keys_sorted = keys_sorted
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Map):
+            return True
+        elif isinstance(other, Map):
+            return self.key == other.key and self.value == other.value
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.key, self.value))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        if self.keys_sorted:
+            return f"{class_name}({self.key!r}, {self.value!r}, keys_sorted=True)"
+        return f"{class_name}({self.key!r}, {self.value!r})"
+
+
+class Extension(DataType):
+    """
+    Extension data type, a named user-defined type on top of a storage data type.
+
+    Extension types are usually created by importing Arrow data that has an
+    extension type. Polars operates on the values of the storage type, but keeps
+    the name and the metadata, so they are written back as the Arrow extension
+    type when the data is exported to Arrow, IPC or Parquet.
+
+    Parameters
+    ----------
+    name
+        The name of the extension type.
+    storage
+        The `DataType` in which the values are stored.
+    metadata
+        The serialized metadata of the extension type.
+
+    Examples
+    --------
+    >>> s = pl.Series([1, 2, 3]).cast(pl.Extension("my.ext", pl.Int64))
+    >>> s.dtype
+    Extension('my.ext', Int64)
+    """
+
+    name: str
+    storage: PolarsDataType
+    metadata: str | None
+
+    def __init__(
+        self,
+        name: str,
+        storage: PolarsDataType | PythonDataType,
+        metadata: str | None = None,
+    ) -> None:
+        self.name = name
+        self.storage = polars.datatypes.parse_into_dtype(storage)
+        self.metadata = metadata
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Extension):
+            return True
+        elif isinstance(other, Extension):
+            return (
+                self.name == other.name
+                and self.storage == other.storage
+                and self.metadata == other.metadata
+            )
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.name, self.storage, self.metadata))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        if self.metadata is None:
+            return f"{class_name}({self.name!r}, {self.storage!r})"
+        return f"{class_name}({self.name!r}, {self.storage!r}, {self.metadata!r})"
@@ -59,6 +59,7 @@ from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
 from polars.expr.list import ExprListNameSpace
+from polars.expr.map import ExprMapNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
 from polars.expr.string import ExprStringNameSpace
@@ -11134,6 +11135,15 @@ Consider using {self}.implode() instead"""
         """
         return ExprArrayNameSpace(self)
 
+    @property
+    def map(self) -> ExprMapNameSpace:
+        """
+        Create an object namespace of all map related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprMapNameSpace(self)
+
     @property
     def meta(self) -> ExprMetaNameSpace:
         """
@@ -1103,6 +1103,26 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_to_array(width))
 
+    def to_map(self) -> Expr:
+        """
+        Convert a List column of structs into a Map column.
+
+        The first field of the structs holds the keys and the second field holds
+        the values. The keys are not allowed to be null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Map`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[{"k": "x", "v": 1}, {"k": "y", "v": 2}]]})
+        >>> df.select(pl.col("a").list.to_map()).schema
+        Schema({'a': Map(String, Int64)})
+        """
+        return wrap_expr(self._pyexpr.list_to_map())
+
     def to_struct(
         self,
         n_field_strategy: ListToStructWidthStrategy | None = None,
@@ -0,0 +1,198 @@
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprMapNameSpace:
+    """Namespace for map related expressions."""
+
+    _accessor = "map"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def get(self, key: IntoExpr) -> Expr:
+        """
+        Get the value belonging to `key` in every map.
+
+        Maps that do not contain the key give a null.
+
+        Parameters
+        ----------
+        key
+            Key to look up. Strings are parsed as literals, not as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "m": [
+        ...             [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...             [{"key": "a", "value": 3}],
+        ...             [],
+        ...         ]
+        ...     },
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.get("a"))
+        shape: (3, 1)
+        ┌──────┐
+        │ m    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ 1    │
+        │ 3    │
+        │ null │
+        └──────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_get(key_pyexpr))
+
+    def contains_key(self, key: IntoExpr) -> Expr:
+        """
+        Check if every map contains `key`.
+
+        Parameters
+        ----------
+        key
+            Key to look up. Strings are parsed as literals, not as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "m": [
+        ...             [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...             [{"key": "a", "value": 3}],
+        ...         ]
+        ...     },
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.contains_key("b"))
+        shape: (2, 1)
+        ┌───────┐
+        │ m     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        └───────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_contains_key(key_pyexpr))
+
+    def keys(self) -> Expr:
+        """
+        Get the keys of every map as a list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "m": [
+        ...             [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...             [{"key": "a", "value": 3}],
+        ...         ]
+        ...     },
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.keys())
+        shape: (2, 1)
+        ┌────────────┐
+        │ m          │
+        │ ---        │
+        │ list[str]  │
+        ╞════════════╡
+        │ ["a", "b"] │
+        │ ["a"]      │
+        └────────────┘
+        """
+        return wrap_expr(self._pyexpr.map_keys())
+
+    def values(self) -> Expr:
+        """
+        Get the values of every map as a list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "m": [
+        ...             [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...             [{"key": "a", "value": 3}],
+        ...         ]
+        ...     },
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.values())
+        shape: (2, 1)
+        ┌───────────┐
+        │ m         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 2]    │
+        │ [3]       │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.map_values())
+
+    def to_list(self) -> Expr:
+        """
+        Get the entries of every map as a list of `{key, value}` structs.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[{"key": "a", "value": 1}]]},
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.to_list()).schema
+        Schema({'m': List(Struct({'key': String, 'value': Int64}))})
+        """
+        return wrap_expr(self._pyexpr.map_to_list())
+
+    def to_struct(self, fields: Sequence[str]) -> Expr:
+        """
+        Get the values belonging to the given keys as the fields of a struct.
+
+        Parameters
+        ----------
+        fields
+            The keys to look up. They also become the names of the struct fields.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "m": [
+        ...             [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...             [{"key": "a", "value": 3}],
+        ...         ]
+        ...     },
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.to_struct(["a", "b"])).unnest("m")
+        shape: (2, 2)
+        ┌─────┬──────┐
+        │ a   ┆ b    │
+        │ --- ┆ ---  │
+        │ i64 ┆ i64  │
+        ╞═════╪══════╡
+        │ 1   ┆ 2    │
+        │ 3   ┆ null │
+        └─────┴──────┘
+        """
+        if isinstance(fields, str):
+            fields = [fields]
+        return wrap_expr(self._pyexpr.map_to_struct(list(fields)))
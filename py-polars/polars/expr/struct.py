@@ -274,6 +274,20 @@ class ExprStructNameSpace:
         """
         return wrap_expr(self._pyexpr.struct_json_encode())
 
+    def to_map(self) -> Expr:
+        """
+        Convert this struct to a map from the field names to the field values.
+
+        The values are cast to the supertype of all fields.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [{"x": 1, "y": 2.5}]})
+        >>> df.select(pl.col("a").struct.to_map()).schema
+        Schema({'a': Map(String, Float64)})
+        """
+        return wrap_expr(self._pyexpr.struct_to_map())
+
     def with_fields(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
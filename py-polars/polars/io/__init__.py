@@ -1,14 +1,16 @@
 """Functions for reading data."""
 
-from polars.io.avro import read_avro
+from polars.io.avro import read_avro, scan_avro
 from polars.io.clipboard import read_clipboard
 from polars.io.csv import read_csv, read_csv_batched, scan_csv
 from polars.io.database import read_database, read_database_uri
 from polars.io.delta import read_delta, scan_delta
+from polars.io.fixed_width import read_fixed_width, scan_fixed_width
 from polars.io.iceberg import scan_iceberg
 from polars.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
 from polars.io.json import read_json
 from polars.io.ndjson import read_ndjson, scan_ndjson
+from polars.io.orc import read_orc, scan_orc
 from polars.io.parquet import (
     read_parquet,
     read_parquet_metadata,
@@ -44,20 +46,25 @@ __all__ = [
     "read_database_uri",
     "read_delta",
     "read_excel",
+    "read_fixed_width",
     "read_ipc",
     "read_ipc_schema",
     "read_ipc_stream",
     "read_json",
     "read_ndjson",
     "read_ods",
+    "read_orc",
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
+    "scan_fixed_width",
     "scan_iceberg",
     "scan_ipc",
     "scan_ndjson",
+    "scan_orc",
     "scan_parquet",
     "scan_pyarrow_dataset",
     "ScanCastOptions",
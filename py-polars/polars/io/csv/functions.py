@@ -1120,7 +1120,8 @@ def scan_csv(
     source
         Path(s) to a file or directory
         When needing to authenticate for scanning cloud locations, see the
-        `storage_options` parameter.
+        `storage_options` parameter. Files compressed with gzip, zlib, zstd, bzip2,
+        xz or lz4 are decompressed while they are read.
     has_header
         Indicate if the first row of the dataset is a header or not. If set to False,
         column names will be autogenerated in the following format: `column_x`, with
@@ -0,0 +1,371 @@
+from __future__ import annotations
+
+import contextlib
+from pathlib import Path
+from typing import IO, TYPE_CHECKING, Any, Literal
+
+from polars._utils.various import (
+    _process_null_values,
+    is_path_or_str_sequence,
+    normalize_filepath,
+)
+from polars._utils.wrap import wrap_ldf
+from polars.datatypes import N_INFER_DEFAULT, parse_into_dtype
+from polars.io._utils import parse_row_index_args
+from polars.io.cloud.credential_provider._builder import (
+    _init_credential_provider_builder,
+)
+from polars.io.csv._utils import _check_arg_is_1byte
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars._plr import PyLazyFrame
+
+if TYPE_CHECKING:
+    from collections.abc import Mapping
+
+    from polars import DataFrame, LazyFrame
+    from polars._typing import CsvEncoding, SchemaDict
+    from polars.io.cloud import CredentialProviderFunction
+
+
+def read_fixed_width(
+    source: str | Path | IO[bytes] | bytes,
+    spans: Mapping[str, tuple[int, int]],
+    *,
+    span_unit: Literal["bytes", "chars"] = "bytes",
+    schema_overrides: SchemaDict | None = None,
+    skip_rows: int = 0,
+    n_rows: int | None = None,
+    infer_schema_length: int | None = N_INFER_DEFAULT,
+    null_values: str | list[str] | dict[str, str] | None = None,
+    missing_utf8_is_empty_string: bool = False,
+    ignore_errors: bool = False,
+    try_parse_dates: bool = False,
+    decimal_comma: bool = False,
+    encoding: CsvEncoding = "utf8",
+    eol_char: str = "\n",
+    row_index_name: str | None = None,
+    row_index_offset: int = 0,
+) -> DataFrame:
+    """
+    Read a fixed-width text file into a DataFrame.
+
+    Every line of the file is a row, and every column is located at the same span of
+    each line. The padding around the fields is removed before they are parsed.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object (by "file-like object" we refer to objects
+        that have a `read()` method, such as a file handler like the builtin `open`
+        function, or a `BytesIO` instance). Files compressed with gzip, zlib, zstd,
+        bzip2, xz or lz4 are decompressed while they are read.
+    spans
+        A `{colname: (start, end)}` dict with the half-open span of every column in
+        the lines. Lines that end before the end of a span are allowed; the missing
+        part of the field is treated as empty.
+    span_unit : {'bytes', 'chars'}
+        Whether the spans count bytes or characters.
+    schema_overrides
+        Overwrite the inferred dtypes of the columns; should be a `{colname: dtype}`
+        dict.
+    skip_rows
+        Start reading after `skip_rows` lines.
+    n_rows
+        Stop reading after reading `n_rows` rows.
+    infer_schema_length
+        The maximum number of rows to scan for schema inference.
+        If set to `None`, the full data may be scanned *(this is slow)*.
+        Set to 0 to read all columns as `pl.String`.
+    null_values
+        Values to interpret as null values. You can provide a:
+
+        - `str`: All values equal to this string will be null.
+        - `List[str]`: All values equal to any string in this list will be null.
+        - `Dict[str, str]`: A dictionary that maps column name to a
+          null value string.
+
+    missing_utf8_is_empty_string
+        By default an empty field is considered to be null; if you would prefer empty
+        utf8 values to be treated as the empty string you can set this param True.
+    ignore_errors
+        Set fields that cannot be parsed as the dtype of their column to null instead
+        of raising an error.
+    try_parse_dates
+        Try to automatically parse dates. Most ISO8601-like formats
+        can be inferred, as well as a handful of others. If this does not succeed,
+        the column remains of data type `pl.String`.
+    decimal_comma
+        Parse floats using a comma as the decimal separator instead of a period.
+    encoding : {'utf8', 'utf8-lossy'}
+        Lossy means that invalid utf8 values are replaced with `�`
+        characters. Defaults to "utf8".
+    eol_char
+        Single byte end of line character (default: `\\n`). A `\\r` before the end of
+        line character is removed.
+    row_index_name
+        Insert a row index column with the given name into the DataFrame as the first
+        column. If set to `None` (default), no row index column is created.
+    row_index_offset
+        Start the row index at this offset. Cannot be negative.
+        Only used if `row_index_name` is set.
+
+    Returns
+    -------
+    DataFrame
+
+    See Also
+    --------
+    scan_fixed_width : Lazily read from a fixed-width text file.
+
+    Examples
+    --------
+    >>> source = b"1   alice   1.5\\n22  bob     10.0\\n"
+    >>> pl.read_fixed_width(
+    ...     source, {"id": (0, 4), "name": (4, 12), "score": (12, 17)}
+    ... )
+    shape: (2, 3)
+    ┌─────┬───────┬───────┐
+    │ id  ┆ name  ┆ score │
+    │ --- ┆ ---   ┆ ---   │
+    │ i64 ┆ str   ┆ f64   │
+    ╞═════╪═══════╪═══════╡
+    │ 1   ┆ alice ┆ 1.5   │
+    │ 22  ┆ bob   ┆ 10.0  │
+    └─────┴───────┴───────┘
+    """
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+
+    return scan_fixed_width(
+        source,
+        spans,
+        span_unit=span_unit,
+        schema_overrides=schema_overrides,
+        skip_rows=skip_rows,
+        n_rows=n_rows,
+        infer_schema_length=infer_schema_length,
+        null_values=null_values,
+        missing_utf8_is_empty_string=missing_utf8_is_empty_string,
+        ignore_errors=ignore_errors,
+        try_parse_dates=try_parse_dates,
+        decimal_comma=decimal_comma,
+        encoding=encoding,
+        eol_char=eol_char,
+        row_index_name=row_index_name,
+        row_index_offset=row_index_offset,
+        glob=False,
+    ).collect()
+
+
+def scan_fixed_width(
+    source: (
+        str
+        | Path
+        | IO[bytes]
+        | bytes
+        | list[str]
+        | list[Path]
+        | list[IO[bytes]]
+        | list[bytes]
+    ),
+    spans: Mapping[str, tuple[int, int]],
+    *,
+    span_unit: Literal["bytes", "chars"] = "bytes",
+    schema_overrides: SchemaDict | None = None,
+    skip_rows: int = 0,
+    n_rows: int | None = None,
+    infer_schema_length: int | None = N_INFER_DEFAULT,
+    null_values: str | list[str] | dict[str, str] | None = None,
+    missing_utf8_is_empty_string: bool = False,
+    ignore_errors: bool = False,
+    try_parse_dates: bool = False,
+    decimal_comma: bool = False,
+    encoding: CsvEncoding = "utf8",
+    eol_char: str = "\n",
+    cache: bool = True,
+    rechunk: bool = False,
+    row_index_name: str | None = None,
+    row_index_offset: int = 0,
+    glob: bool = True,
+    storage_options: dict[str, Any] | None = None,
+    credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
+    retries: int = 2,
+    file_cache_ttl: int | None = None,
+    include_file_paths: str | None = None,
+) -> LazyFrame:
+    """
+    Lazily read from a fixed-width text file or multiple files via glob patterns.
+
+    This allows the query optimizer to push down projections and slices to the scan
+    level, thereby potentially reducing memory overhead. The lines of a file are
+    parsed in parallel.
+
+    Parameters
+    ----------
+    source
+        Path(s) to a file or directory
+        When needing to authenticate for scanning cloud locations, see the
+        `storage_options` parameter. Files compressed with gzip, zlib, zstd, bzip2,
+        xz or lz4 are decompressed while they are read.
+    spans
+        A `{colname: (start, end)}` dict with the half-open span of every column in
+        the lines. Lines that end before the end of a span are allowed; the missing
+        part of the field is treated as empty.
+    span_unit : {'bytes', 'chars'}
+        Whether the spans count bytes or characters.
+    schema_overrides
+        Overwrite the inferred dtypes of the columns; should be a `{colname: dtype}`
+        dict.
+    skip_rows
+        Start reading after `skip_rows` lines.
+    n_rows
+        Stop reading after reading `n_rows` rows.
+    infer_schema_length
+        The maximum number of rows to scan for schema inference.
+        If set to `None`, the full data may be scanned *(this is slow)*.
+        Set to 0 to read all columns as `pl.String`.
+    null_values
+        Values to interpret as null values. You can provide a:
+
+        - `str`: All values equal to this string will be null.
+        - `List[str]`: All values equal to any string in this list will be null.
+        - `Dict[str, str]`: A dictionary that maps column name to a
+          null value string.
+
+    missing_utf8_is_empty_string
+        By default an empty field is considered to be null; if you would prefer empty
+        utf8 values to be treated as the empty string you can set this param True.
+    ignore_errors
+        Set fields that cannot be parsed as the dtype of their column to null instead
+        of raising an error.
+    try_parse_dates
+        Try to automatically parse dates. Most ISO8601-like formats
+        can be inferred, as well as a handful of others. If this does not succeed,
+        the column remains of data type `pl.String`.
+    decimal_comma
+        Parse floats using a comma as the decimal separator instead of a period.
+    encoding : {'utf8', 'utf8-lossy'}
+        Lossy means that invalid utf8 values are replaced with `�`
+        characters. Defaults to "utf8".
+    eol_char
+        Single byte end of line character (default: `\\n`). A `\\r` before the end of
+        line character is removed.
+    cache
+        Cache the result after reading.
+    rechunk
+        Reallocate to contiguous memory when all chunks/ files are parsed.
+    row_index_name
+        If not None, this will insert a row index column with the given name into
+        the DataFrame.
+    row_index_offset
+        Offset to start the row index column (only used if the name is set).
+    glob
+        Expand path given via globbing rules.
+    storage_options
+        Options that indicate how to connect to a cloud provider.
+
+        The cloud providers currently supported are AWS, GCP, and Azure.
+        See supported keys here:
+
+        * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+        * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+        * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+        * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+          `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+        If `storage_options` is not provided, Polars will try to infer the information
+        from environment variables.
+    credential_provider
+        Provide a function that can be called to provide cloud storage
+        credentials. The function is expected to return a dictionary of
+        credential keys along with an optional credential expiry time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    retries
+        Number of retries if accessing a cloud instance fails.
+    file_cache_ttl
+        Amount of time to keep downloaded cloud files since their last access time,
+        in seconds. Uses the `POLARS_FILE_CACHE_TTL` environment variable
+        (which defaults to 1 hour) if not given.
+    include_file_paths
+        Include the path of the source file(s) as a column with this name.
+
+    Returns
+    -------
+    LazyFrame
+
+    See Also
+    --------
+    read_fixed_width : Read a fixed-width text file into a DataFrame.
+
+    Examples
+    --------
+    >>> pl.scan_fixed_width(
+    ...     "data.txt", {"id": (0, 4), "name": (4, 12)}
+    ... ).collect()  # doctest: +SKIP
+    """
+    _check_arg_is_1byte("eol_char", eol_char, can_be_empty=False)
+
+    columns = [(name, start, end) for name, (start, end) in spans.items()]
+
+    dtype_list: list[tuple[str, Any]] | None = None
+    if schema_overrides is not None:
+        dtype_list = [
+            (name, parse_into_dtype(dtype)) for name, dtype in schema_overrides.items()
+        ]
+
+    sources: list[str] | list[Path] | list[IO[bytes]] | list[bytes] = []
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source, check_not_directory=False)
+    elif isinstance(source, list):
+        if is_path_or_str_sequence(source):
+            sources = [
+                normalize_filepath(source, check_not_directory=False)
+                for source in source
+            ]
+        else:
+            sources = source
+
+        source = None  # type: ignore[assignment]
+
+    credential_provider_builder = _init_credential_provider_builder(
+        credential_provider, source, storage_options, "scan_fixed_width"
+    )
+    del credential_provider
+
+    if storage_options:
+        storage_options = list(storage_options.items())  # type: ignore[assignment]
+    else:
+        # Handle empty dict input
+        storage_options = None
+
+    pylf = PyLazyFrame.new_from_fixed_width(
+        source,
+        sources,
+        columns,
+        span_unit,
+        overwrite_dtype=dtype_list,
+        skip_rows=skip_rows,
+        n_rows=n_rows,
+        infer_schema_length=infer_schema_length,
+        null_values=_process_null_values(null_values),
+        missing_utf8_is_empty_string=missing_utf8_is_empty_string,
+        ignore_errors=ignore_errors,
+        try_parse_dates=try_parse_dates,
+        decimal_comma=decimal_comma,
+        encoding=encoding,
+        eol_char=eol_char,
+        cache=cache,
+        rechunk=rechunk,
+        row_index=parse_row_index_args(row_index_name, row_index_offset),
+        glob=glob,
+        cloud_options=storage_options,
+        credential_provider=credential_provider_builder,
+        retries=retries,
+        file_cache_ttl=file_cache_ttl,
+        include_file_paths=include_file_paths,
+    )
+    return wrap_ldf(pylf)
@@ -218,7 +218,8 @@ def scan_ndjson(
     Parameters
     ----------
     source
-        Path to a file.
+        Path to a file. Files compressed with gzip, zlib, zstd, bzip2, xz or lz4 are
+        decompressed while they are read.
     schema : Sequence of str, (str,DataType) pairs, or a {str:DataType,} dict
         The DataFrame schema may be declared in several ways:
 
@@ -46,6 +46,10 @@ def _parquet_field_overwrites_to_dict(pqo: ParquetFieldOverwrites) -> dict[str,
     if pqo.required is not None:
         d["required"] = pqo.required
 
+    # Bloom filter
+    if pqo.bloom_filter:
+        d["bloom_filter"] = (pqo.bloom_filter_fpp, pqo.bloom_filter_ndv)
+
     return d
 
 
@@ -89,6 +93,16 @@ class ParquetFieldOverwrites:
     ...         ),
     ...     },
     ... )  # doctest: +SKIP
+
+    Write a bloom filter for an ID column, such that scans with an equality
+    predicate on that column can skip the row groups that don't contain the ID.
+
+    >>> lf.sink_parquet(
+    ...     "./out/parquet",
+    ...     field_overwrites=ParquetFieldOverwrites(
+    ...         name="a", bloom_filter=True, bloom_filter_fpp=0.01
+    ...     ),
+    ... )  # doctest: +SKIP
     """
 
     name: None | str  #: Name of the column or field
@@ -108,6 +122,9 @@ class ParquetFieldOverwrites:
         dict[str, None | str] | None
     )  #: Arrow metadata added to the field before writing
     required: bool | None = None  #: Is the field not allowed to have missing values
+    bloom_filter: bool = False  #: Write a bloom filter for the (flat) field
+    bloom_filter_fpp: float = 0.05  #: False positive probability of the bloom filter
+    bloom_filter_ndv: int | None = None  #: Distinct values per row group to size for
 
     def __init__(
         self,
@@ -122,6 +139,9 @@ class ParquetFieldOverwrites:
         field_id: int | None = None,
         metadata: Mapping[str, None | str] | None = None,
         required: bool | None = None,
+        bloom_filter: bool = False,
+        bloom_filter_fpp: float = 0.05,
+        bloom_filter_ndv: int | None = None,
     ) -> None:
         self.name = name
 
@@ -138,3 +158,13 @@ class ParquetFieldOverwrites:
         else:
             self.metadata = metadata
         self.required = required
+
+        if not 0.0 < bloom_filter_fpp < 1.0:
+            msg = f"`bloom_filter_fpp` must be between 0 and 1, got {bloom_filter_fpp}"
+            raise ValueError(msg)
+        if bloom_filter_ndv is not None and bloom_filter_ndv < 0:
+            msg = f"`bloom_filter_ndv` must be non-negative, got {bloom_filter_ndv}"
+            raise ValueError(msg)
+        self.bloom_filter = bloom_filter
+        self.bloom_filter_fpp = bloom_filter_fpp
+        self.bloom_filter_ndv = bloom_filter_ndv
@@ -121,11 +121,13 @@ if TYPE_CHECKING:
     from polars import DataFrame, DataType, Expr
     from polars._typing import (
         AsofJoinStrategy,
+        AvroCompression,
         ClosedInterval,
         ColumnNameOrSelector,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
+        ExternalCompression,
         FillNullStrategy,
         FrameInitTypes,
         IntoExpr,
@@ -2497,6 +2499,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filter: bool | float = False,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -2525,6 +2528,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filter: bool | float = False,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -2552,6 +2556,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filter: bool | float = False,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -2615,6 +2620,17 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         data_page_size
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        bloom_filter
+            Write a bloom filter for every flat column, such that scans with an
+            equality predicate can skip the row groups that don't contain the value.
+
+            Possible values:
+
+            - `False`: do not write bloom filters (default).
+            - `True`: write bloom filters with a false positive probability of 0.05.
+            - A float: write bloom filters with this false positive probability.
+
+            The bloom filter of a single field can be set with `field_overwrites`.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -2763,6 +2779,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         elif callable(metadata):
             metadata = wrap_parquet_metadata_callback(metadata)  # type: ignore[assignment]
 
+        bloom_filter_options: tuple[float, int | None] | None = None
+        if isinstance(bloom_filter, bool):
+            if bloom_filter:
+                bloom_filter_options = (0.05, None)
+        elif 0.0 < bloom_filter < 1.0:
+            bloom_filter_options = (bloom_filter, None)
+        else:
+            msg = f"`bloom_filter` must be a boolean or between 0 and 1, got {bloom_filter}"
+            raise ValueError(msg)
+
         # Convert the field overwrites into something that can be ingested by Rust.
         field_overwrites_dicts: list[dict[str, Any]] = []
         if field_overwrites is not None:
@@ -2797,6 +2823,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             statistics=statistics,
             row_group_size=row_group_size,
             data_page_size=data_page_size,
+            bloom_filter=bloom_filter_options,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -3039,6 +3066,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3071,6 +3100,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3102,6 +3133,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3177,6 +3210,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm.
+        compression_level
+            The level of compression to use. Higher compression means smaller files
+            on disk, but slower writing.
+
+            - "gzip" : min-level: 0, max-level: 9, default: 6.
+            - "zstd" : min-level: 1, max-level: 22, default: 3.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3302,6 +3343,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            compression=compression,
+            compression_level=compression_level,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -3320,6 +3363,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3338,6 +3383,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3355,6 +3402,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3376,6 +3425,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ----------
         path
             File path to which the file should be written.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm.
+        compression_level
+            The level of compression to use. Higher compression means smaller files
+            on disk, but slower writing.
+
+            - "gzip" : min-level: 0, max-level: 9, default: 6.
+            - "zstd" : min-level: 1, max-level: 22, default: 3.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3481,6 +3538,199 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         ldf_py = self._ldf.sink_json(
             target=target,
+            compression=compression,
+            compression_level=compression_level,
+            cloud_options=storage_options,
+            credential_provider=credential_provider_builder,
+            retries=retries,
+            sink_options=sink_options,
+        )
+
+        if not lazy:
+            ldf_py = ldf_py.with_optimizations(optimizations._pyoptflags)
+            ldf = LazyFrame._from_pyldf(ldf_py)
+            ldf.collect(engine=engine)
+            return None
+        return LazyFrame._from_pyldf(ldf_py)
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[False] = ...,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None: ...
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[True],
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame: ...
+
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame | None:
+        """
+        Evaluate the query in streaming mode and write to an Apache Avro file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        compression : {'uncompressed', 'snappy', 'deflate'}
+            Codec used to compress the blocks of the file.
+        name
+            Schema name. Defaults to empty string.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            The cloud providers currently supported are AWS, GCP, and Azure.
+            See supported keys here:
+
+            * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+            * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+            * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+            * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+            `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+            If `storage_options` is not provided, Polars will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        retries
+            Number of retries if accessing a cloud instance fails.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file.
+
+            * `None` does not sync.
+            * `data` syncs the file contents.
+            * `all` syncs the file contents and metadata.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        mkdir: bool
+            Recursively create all the directories in the path.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        lazy: bool
+            Wait to start execution until `collect` is called.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine. Polars will also
+            attempt to use the engine set by the `POLARS_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the polars streaming
+            engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+            This has no effect if `lazy` is set to `True`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_avro("out.avro", compression="snappy")  # doctest: +SKIP
+        """
+        engine = _select_engine(engine)
+
+        from polars.io.cloud.credential_provider._builder import (
+            _init_credential_provider_builder,
+        )
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, path, storage_options, "sink_avro"
+        )
+        del credential_provider
+
+        if storage_options:
+            storage_options = list(storage_options.items())  # type: ignore[assignment]
+        else:
+            # Handle empty dict input
+            storage_options = None
+
+        target = _to_sink_target(path)
+        sink_options = {
+            "sync_on_close": sync_on_close or "none",
+            "maintain_order": maintain_order,
+            "mkdir": mkdir,
+        }
+
+        ldf_py = self._ldf.sink_avro(
+            target=target,
+            compression=compression,
+            name=name,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
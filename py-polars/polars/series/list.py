@@ -866,6 +866,25 @@ class ListNameSpace:
         ]
         """
 
+    def to_map(self) -> Series:
+        """
+        Convert a List column of structs into a Map column.
+
+        The first field of the structs holds the keys and the second field holds
+        the values. The keys are not allowed to be null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Map`.
+
+        Examples
+        --------
+        >>> s = pl.Series([[{"k": "x", "v": 1}, {"k": "y", "v": 2}]])
+        >>> s.list.to_map().dtype
+        Map(String, Int64)
+        """
+
     def to_struct(
         self,
         n_field_strategy: ListToStructWidthStrategy = "first_non_null",
@@ -0,0 +1,172 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import Series
+    from polars._plr import PySeries
+    from polars._typing import IntoExpr
+
+
+@expr_dispatch
+class MapNameSpace:
+    """Namespace for map related methods."""
+
+    _accessor = "map"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def get(self, key: IntoExpr) -> Series:
+        """
+        Get the value belonging to `key` in every map.
+
+        Maps that do not contain the key give a null.
+
+        Parameters
+        ----------
+        key
+            Key to look up.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m",
+        ...     [
+        ...         [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...         [{"key": "b", "value": 3}],
+        ...     ],
+        ...     dtype=pl.Map(pl.String, pl.Int64),
+        ... )
+        >>> s.map.get("a")
+        shape: (2,)
+        Series: 'm' [i64]
+        [
+            1
+            null
+        ]
+        """
+
+    def contains_key(self, key: IntoExpr) -> Series:
+        """
+        Check if every map contains `key`.
+
+        Parameters
+        ----------
+        key
+            Key to look up.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m",
+        ...     [
+        ...         [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...         [{"key": "b", "value": 3}],
+        ...     ],
+        ...     dtype=pl.Map(pl.String, pl.Int64),
+        ... )
+        >>> s.map.contains_key("a")
+        shape: (2,)
+        Series: 'm' [bool]
+        [
+            true
+            false
+        ]
+        """
+
+    def keys(self) -> Series:
+        """
+        Get the keys of every map as a list.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m",
+        ...     [
+        ...         [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...         [{"key": "b", "value": 3}],
+        ...     ],
+        ...     dtype=pl.Map(pl.String, pl.Int64),
+        ... )
+        >>> s.map.keys()
+        shape: (2,)
+        Series: 'm' [list[str]]
+        [
+            ["a", "b"]
+            ["b"]
+        ]
+        """
+
+    def values(self) -> Series:
+        """
+        Get the values of every map as a list.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m",
+        ...     [
+        ...         [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...         [{"key": "b", "value": 3}],
+        ...     ],
+        ...     dtype=pl.Map(pl.String, pl.Int64),
+        ... )
+        >>> s.map.values()
+        shape: (2,)
+        Series: 'm' [list[i64]]
+        [
+            [1, 2]
+            [3]
+        ]
+        """
+
+    def to_list(self) -> Series:
+        """
+        Get the entries of every map as a list of `{key, value}` structs.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m",
+        ...     [[{"key": "a", "value": 1}]],
+        ...     dtype=pl.Map(pl.String, pl.Int64),
+        ... )
+        >>> s.map.to_list().dtype
+        List(Struct({'key': String, 'value': Int64}))
+        """
+
+    def to_struct(self, fields: Sequence[str]) -> Series:
+        """
+        Get the values belonging to the given keys as the fields of a struct.
+
+        Parameters
+        ----------
+        fields
+            The keys to look up. They also become the names of the struct fields.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m",
+        ...     [
+        ...         [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...         [{"key": "b", "value": 3}],
+        ...     ],
+        ...     dtype=pl.Map(pl.String, pl.Int64),
+        ... )
+        >>> s.map.to_struct(["a", "b"]).struct.unnest()
+        shape: (2, 2)
+        ┌──────┬─────┐
+        │ a    ┆ b   │
+        │ ---  ┆ --- │
+        │ i64  ┆ i64 │
+        ╞══════╪═════╡
+        │ 1    ┆ 2   │
+        │ null ┆ 3   │
+        └──────┴─────┘
+        """
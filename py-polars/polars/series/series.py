@@ -64,6 +64,7 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
     Float32,
     Float64,
     Int32,
@@ -105,6 +106,7 @@ from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
 from polars.series.datetime import DateTimeNameSpace
 from polars.series.list import ListNameSpace
+from polars.series.map import MapNameSpace
 from polars.series.plotting import SeriesPlot
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
@@ -811,6 +813,10 @@ class Series:
         elif self.dtype in [Categorical, Enum] and not isinstance(other, Series):
             other = Series([other])
 
+        elif self.dtype == Float16 and isinstance(other, (int, float)):
+            # there are no scalar kernels for half precision floats
+            other = Series([other])
+
         elif isinstance(other, date) and self.dtype == Date:
             d = date_to_int(other)
             f = get_ffi_func(op + "_<>", Int32, self._s)
@@ -1084,6 +1090,13 @@ class Series:
                 return self._from_pyseries(getattr(_s, op_s)(self._s))
             else:
                 return self._from_pyseries(getattr(self._s, op_s)(_s))
+        elif isinstance(other, (int, float)) and self.dtype == Float16:
+            # there are no scalar kernels for half precision floats
+            _s = sequence_to_pyseries(self.name, [other], dtype=Float16)
+            if "rhs" in op_ffi:
+                return self._from_pyseries(getattr(_s, op_s)(self._s))
+            else:
+                return self._from_pyseries(getattr(self._s, op_s)(_s))
 
         if self.dtype.is_decimal() and isinstance(other, (PyDecimal, int)):
             if isinstance(other, int):
@@ -9119,6 +9132,11 @@ class Series:
         """Create an object namespace of all array related methods."""
         return ArrayNameSpace(self)
 
+    @property
+    def map(self) -> MapNameSpace:
+        """Create an object namespace of all map related methods."""
+        return MapNameSpace(self)
+
     @property
     def str(self) -> StringNameSpace:
         """Create an object namespace of all string related methods."""
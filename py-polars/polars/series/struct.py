@@ -152,3 +152,16 @@ class StructNameSpace:
             "{"a":[9,1,3],"b":null}"
         ]
         """
+
+    def to_map(self) -> Series:
+        """
+        Convert this struct to a map from the field names to the field values.
+
+        The values are cast to the supertype of all fields.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [{"x": 1, "y": 2.5}])
+        >>> s.struct.to_map().dtype
+        Map(String, Float64)
+        """
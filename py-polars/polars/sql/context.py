@@ -14,7 +14,11 @@ from polars._typing import FrameType
 from polars._utils.deprecation import deprecate_renamed_parameter
 from polars._utils.pycapsule import is_pycapsule
 from polars._utils.unstable import issue_unstable_warning
-from polars._utils.various import _get_stack_locals, qualified_type_name
+from polars._utils.various import (
+    _get_stack_locals,
+    normalize_filepath,
+    qualified_type_name,
+)
 from polars._utils.wrap import wrap_ldf
 from polars.convert import from_arrow, from_pandas
 from polars.dataframe import DataFrame
@@ -30,6 +34,7 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 if TYPE_CHECKING:
     import sys
     from collections.abc import Collection, Mapping
+    from pathlib import Path
     from types import TracebackType
     from typing import Any, Final, Literal
 
@@ -124,6 +129,7 @@ class SQLContext(Generic[FrameType]):
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
         eager: Literal[False] = False,
+        catalog: str | Path | None = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -135,6 +141,7 @@ class SQLContext(Generic[FrameType]):
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
         eager: Literal[True],
+        catalog: str | Path | None = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -146,6 +153,7 @@ class SQLContext(Generic[FrameType]):
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
         eager: bool,
+        catalog: str | Path | None = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -156,6 +164,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = False,
         eager: bool = False,
+        catalog: str | Path | None = None,
         **named_frames: CompatibleFrameType | None,
     ) -> None:
         """
@@ -179,6 +188,14 @@ class SQLContext(Generic[FrameType]):
             If True, returns execution results as `DataFrame` instead of `LazyFrame`.
             (Note that the query itself is always executed in lazy-mode; this parameter
             impacts whether :meth:`execute` returns an eager or lazy result frame).
+        catalog
+            Path to a directory holding an on-disk catalog of tables and views (it is
+            created if it does not exist). The tables and views in the catalog are
+            registered, and those created with `CREATE TABLE` and `CREATE VIEW` are
+            stored in it (unless they are `TEMPORARY`), so they can be shared across
+            sessions. Tables are stored as their serialized query plan, so a table
+            read from files refers to these files rather than holding a copy of the
+            data.
         **named_frames
             Named eager/lazy frames, provided as kwargs.
 
@@ -202,7 +219,9 @@ class SQLContext(Generic[FrameType]):
         issue_unstable_warning(
             "`SQLContext` is considered **unstable**, although it is close to being considered stable."
         )
-        self._ctxt = PySQLContext.new()
+        self._ctxt = PySQLContext.new(
+            None if catalog is None else normalize_filepath(catalog)
+        )
         self._eager_execution = eager
 
         frames = dict(frames or {})
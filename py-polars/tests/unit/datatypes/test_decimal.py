@@ -7,7 +7,7 @@ from dataclasses import dataclass
 from decimal import Decimal as D
 from math import ceil, floor
 from random import choice, randrange, seed
-from typing import Any, Callable, NamedTuple
+from typing import TYPE_CHECKING, Any, Callable, NamedTuple
 
 import pyarrow as pa
 import pytest
@@ -15,6 +15,9 @@ import pytest
 import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import QuantileMethod
+
 
 @pytest.fixture(scope="module")
 def permutations_int_dec_none() -> list[tuple[D | int | None, ...]]:
@@ -249,7 +252,7 @@ def test_decimal_arithmetic() -> None:
         pl.Decimal(precision=None, scale=2),
         pl.Decimal(precision=None, scale=6),
         pl.Decimal(precision=None, scale=2),
-        pl.Decimal(precision=None, scale=14),
+        pl.Decimal(precision=34, scale=14),
     ]
 
     assert out.to_dict(as_series=False) == {
@@ -317,8 +320,8 @@ def test_decimal_aggregations() -> None:
             "sum": [D("10.20"), D("9100.13")],
             "min": [D("0.10"), D("100.01")],
             "max": [D("10.10"), D("9000.12")],
-            "mean": [5.1, 4550.065],
-            "median": [5.1, 4550.065],
+            "mean": [D("5.100000"), D("4550.065000")],
+            "median": [D("5.100000"), D("4550.065000")],
         }
     )
     assert_frame_equal(result, expected)
@@ -335,8 +338,8 @@ def test_decimal_aggregations() -> None:
             "sum": [D("9110.33")],
             "min": [D("0.10")],
             "max": [D("9000.12")],
-            "mean": [2277.5825],
-            "median": [55.055],
+            "mean": [D("2277.582500")],
+            "median": [D("55.055000")],
         }
     )
     assert_frame_equal(res, expected)
@@ -359,7 +362,7 @@ def test_decimal_aggregations() -> None:
                 4.0,
                 0.0,
                 2277.5825,
-                4481.916846516863,
+                4481.916846,
                 0.1,
                 10.1,
                 100.01,
@@ -388,6 +391,265 @@ def test_decimal_cumulative_aggregations() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_decimal_precision_derivation() -> None:
+    lf = pl.LazyFrame(
+        {"a": [D("1.50")], "b": [D("2.25")]},
+        schema={"a": pl.Decimal(15, 2), "b": pl.Decimal(15, 2)},
+    )
+    q = lf.select(
+        add=pl.col("a") + pl.col("b"),
+        sub=pl.col("a") - pl.col("b"),
+        mul=pl.col("a") * pl.col("b"),
+        div=pl.col("a") / pl.col("b"),
+        sum=pl.col("a").sum(),
+        mean=pl.col("a").mean(),
+    )
+    expected = pl.Schema(
+        {
+            "add": pl.Decimal(16, 2),
+            "sub": pl.Decimal(16, 2),
+            "mul": pl.Decimal(31, 4),
+            "div": pl.Decimal(21, 6),
+            "sum": pl.Decimal(38, 2),
+            "mean": pl.Decimal(38, 6),
+        }
+    )
+    assert q.collect_schema() == expected
+
+    result = q.collect()
+    assert result.schema == expected
+    assert result.row(0) == (
+        D("3.75"),
+        D("-0.75"),
+        D("3.3750"),
+        D("0.666666"),
+        D("1.50"),
+        D("1.500000"),
+    )
+
+
+def test_decimal_overflow() -> None:
+    s = pl.Series([D("9" * 30)], dtype=pl.Decimal(38, 0))
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        s * s
+
+    s = pl.Series([D("9" * 38)] * 2, dtype=pl.Decimal(38, 0))
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        s + s
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        s.sum()
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        s.cum_sum()
+
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        s.std()
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        s.var()
+
+    gb = pl.LazyFrame({"g": [1, 1], "a": s}).group_by("g")
+    aggs = [pl.col("a").sum(), pl.col("a").mean(), pl.col("a").std(), pl.col("a").var()]
+    for agg in aggs:
+        with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+            gb.agg(agg).collect(engine="in-memory")
+        with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+            gb.agg(agg).collect(engine="streaming")
+
+
+def test_decimal_exact_mean() -> None:
+    df = pl.DataFrame(
+        {"g": [1, 1, 2], "a": [D("0.01"), D("0.02"), None]},
+        schema={"g": pl.Int64, "a": pl.Decimal(10, 2)},
+    )
+    assert df["a"].mean() == D("0.015000")
+
+    result = df.group_by("g", maintain_order=True).agg(pl.col("a").mean())
+    expected = pl.DataFrame(
+        {"g": [1, 2], "a": [D("0.015000"), None]},
+        schema={"g": pl.Int64, "a": pl.Decimal(38, 6)},
+    )
+    assert_frame_equal(result, expected)
+    assert_frame_equal(
+        df.lazy()
+        .group_by("g", maintain_order=True)
+        .agg(pl.col("a").mean())
+        .collect(engine="streaming"),
+        expected,
+    )
+
+
+def test_decimal_exact_std_var() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2, 3],
+            "a": [D("0.10"), D("10.10"), D("100.01"), D("9000.12"), D("1.00")],
+        },
+        schema={"g": pl.Int64, "a": pl.Decimal(10, 2)},
+    )
+    s = df["a"].head(4)
+    assert s.std() == D("4481.916846")
+    assert s.var() == D("20087578.619091")
+    assert s.std(ddof=0) == D("3881.453846")
+    assert s.var(ddof=0) == D("15065683.964318")
+
+    q = df.lazy().group_by("g", maintain_order=True).agg(
+        std=pl.col("a").std(), var=pl.col("a").var()
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "std": [D("7.071067"), D("6293.328134"), None],
+            "var": [D("50.000000"), D("39605979.006050"), None],
+        },
+        schema={"g": pl.Int64, "std": pl.Decimal(38, 6), "var": pl.Decimal(38, 6)},
+    )
+    assert q.collect_schema() == expected.schema
+    assert_frame_equal(q.collect(engine="in-memory"), expected)
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+
+    # The variance has twice the decimal places of the values.
+    s = pl.Series("a", [D("0.0001"), D("0.0004")], pl.Decimal(10, 4))
+    assert s.var() == D("0.00000004")
+    assert s.to_frame().select(pl.col("a").var()).dtypes == [pl.Decimal(38, 8)]
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        ("linear", D("1.900000")),
+        ("midpoint", D("1.500000")),
+        ("nearest", D("2.000000")),
+        ("lower", D("1.000000")),
+        ("higher", D("2.000000")),
+        ("equiprobable", D("2.000000")),
+    ],
+)
+def test_decimal_exact_quantile(method: QuantileMethod, expected: D) -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 1, 2, 2],
+            "a": [D("8.00"), D("1.00"), D("4.00"), D("2.00"), D("0.01"), None],
+        },
+        schema={"g": pl.Int64, "a": pl.Decimal(10, 2)},
+    )
+    s = df["a"].head(4)
+    assert s.median() == D("3.000000")
+    assert s.quantile(0.3, method) == expected
+
+    q = df.lazy().group_by("g", maintain_order=True).agg(
+        median=pl.col("a").median(), quantile=pl.col("a").quantile(0.3, method)
+    )
+    expected_df = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "median": [D("3.000000"), D("0.010000")],
+            "quantile": [expected, D("0.010000")],
+        },
+        schema={
+            "g": pl.Int64,
+            "median": pl.Decimal(14, 6),
+            "quantile": pl.Decimal(14, 6),
+        },
+    )
+    assert q.collect_schema() == expected_df.schema
+    assert_frame_equal(q.collect(engine="in-memory"), expected_df)
+    assert_frame_equal(q.collect(engine="streaming"), expected_df)
+
+
+def test_decimal_cum_prod() -> None:
+    s = pl.Series("a", [D("1.5"), None, D("2.0"), D("0.5")], pl.Decimal(10, 1))
+    expected = pl.Series("a", [D("1.5"), None, D("3.0"), D("1.5")], pl.Decimal(38, 1))
+    assert_series_equal(s.cum_prod(), expected)
+
+
+def test_decimal_pow() -> None:
+    s = pl.Series("a", [D("1.10"), D("-2.00"), None], pl.Decimal(10, 2))
+
+    # Every multiplication is truncated to the scale of the base.
+    expected = pl.Series("a", [D("1.33"), D("-8.00"), None], pl.Decimal(38, 2))
+    assert_series_equal(s**3, expected)
+    assert_series_equal(s.to_frame().select(pl.col("a").pow(3)).to_series(), expected)
+
+    assert (s**0.5).dtype == pl.Float64
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        s ** pl.Series([-1, 1, 1])
+
+
+def test_decimal_rolling() -> None:
+    s = pl.Series("a", [D("1.10"), D("2.20"), None, D("4.40")], pl.Decimal(10, 2))
+
+    assert_series_equal(
+        s.rolling_sum(2, min_samples=1),
+        pl.Series("a", [D("1.10"), D("3.30"), D("2.20"), D("4.40")], pl.Decimal(38, 2)),
+    )
+    assert_series_equal(
+        s.rolling_sum(2),
+        pl.Series("a", [None, D("3.30"), None, None], pl.Decimal(38, 2)),
+    )
+    assert_series_equal(
+        s.rolling_mean(3, min_samples=1, center=True),
+        pl.Series(
+            "a",
+            [D("1.650000"), D("1.650000"), D("3.300000"), D("4.400000")],
+            pl.Decimal(38, 6),
+        ),
+    )
+    assert_series_equal(
+        s.rolling_min(2, min_samples=1),
+        pl.Series("a", [D("1.10"), D("1.10"), D("2.20"), D("4.40")], pl.Decimal(10, 2)),
+    )
+    assert_series_equal(
+        s.rolling_max(2, min_samples=1),
+        pl.Series("a", [D("1.10"), D("2.20"), D("2.20"), D("4.40")], pl.Decimal(10, 2)),
+    )
+    assert_series_equal(
+        s.rolling_std(2, min_samples=1),
+        pl.Series("a", [None, D("0.777817"), None, None], pl.Decimal(38, 6)),
+    )
+    assert_series_equal(
+        s.rolling_var(2, min_samples=1),
+        pl.Series("a", [None, D("0.605000"), None, None], pl.Decimal(38, 6)),
+    )
+    assert_series_equal(
+        s.rolling_median(3, min_samples=1, center=True),
+        pl.Series(
+            "a",
+            [D("1.650000"), D("1.650000"), D("3.300000"), D("4.400000")],
+            pl.Decimal(14, 6),
+        ),
+    )
+    assert_series_equal(
+        s.rolling_quantile(0.25, "lower", window_size=3, min_samples=1),
+        pl.Series(
+            "a",
+            [D("1.100000"), D("1.100000"), D("1.100000"), D("2.200000")],
+            pl.Decimal(14, 6),
+        ),
+    )
+
+    lf = s.to_frame().lazy()
+    for expr in [
+        pl.col("a").rolling_mean(2),
+        pl.col("a").rolling_std(2),
+        pl.col("a").rolling_var(2),
+        pl.col("a").rolling_median(2),
+    ]:
+        q = lf.select(expr)
+        assert q.collect_schema() == q.collect().schema
+
+
+def test_decimal256_from_arrow() -> None:
+    arr = pa.array([D("1.23"), None, D("-4.56")], pa.decimal256(10, 2))
+    s = pl.from_arrow(arr)
+    assert_series_equal(
+        s,  # type: ignore[arg-type]
+        pl.Series([D("1.23"), None, D("-4.56")], dtype=pl.Decimal(10, 2)),
+    )
+
+    arr = pa.array([D("1.23")], pa.decimal256(40, 2))
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="38 precision"):
+        pl.from_arrow(arr)
+
+
 def test_decimal_df_vertical_sum() -> None:
     df = pl.DataFrame({"a": [D("1.1"), D("2.2")]})
     expected = pl.DataFrame({"a": [D("3.3")]})
@@ -568,11 +830,42 @@ def test_decimal_round() -> None:
 
     for decimals in range(10):
         got_s = i_s.round(decimals)
-        expected_s = pl.Series("a", [round(v, decimals) for v in values], dtype)
+        # Rounding drops the decimal places beyond `decimals` and can carry into a new
+        # integer digit.
+        expected_dtype = pl.Decimal(2 + decimals, decimals) if decimals < 2 else dtype
+        expected_s = pl.Series(
+            "a", [round(v, decimals) for v in values], expected_dtype
+        )
 
         assert_series_equal(got_s, expected_s)
 
 
+def test_decimal_round_scale() -> None:
+    s = pl.Series("a", [D("9.95"), D("-9.95"), D("0.05"), None], pl.Decimal(3, 2))
+
+    assert_series_equal(
+        s.round(1),
+        pl.Series("a", [D("10.0"), D("-10.0"), D("0.0"), None], pl.Decimal(3, 1)),
+    )
+    assert_series_equal(
+        s.round(1, mode="half_away_from_zero"),
+        pl.Series("a", [D("10.0"), D("-10.0"), D("0.1"), None], pl.Decimal(3, 1)),
+    )
+    assert_series_equal(
+        s.round(0), pl.Series("a", [10, -10, 0, None], pl.Decimal(2, 0))
+    )
+
+    # The largest values don't overflow while rounding.
+    big = D("9" * 37 + ".9")
+    s = pl.Series("a", [big, -big], pl.Decimal(38, 1))
+    assert s.round(0).to_list() == [D(10**37), D(-(10**37))]
+
+    q = pl.LazyFrame({"a": [D("1.25")]}, schema={"a": pl.Decimal(10, 2)})
+    q = q.select(pl.col("a").round(1))
+    assert q.collect_schema() == q.collect().schema
+    assert q.collect_schema()["a"] == pl.Decimal(10, 1)
+
+
 def test_decimal_arithmetic_schema() -> None:
     q = pl.LazyFrame({"x": [1.0]}, schema={"x": pl.Decimal(15, 2)})
 
@@ -0,0 +1,106 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+EXT_NAME = "polars.test.unit"
+
+
+class UnitType(pa.ExtensionType):
+    def __init__(self, unit: str) -> None:
+        self.unit = unit
+        super().__init__(pa.int64(), EXT_NAME)
+
+    def __arrow_ext_serialize__(self) -> bytes:
+        return self.unit.encode()
+
+    @classmethod
+    def __arrow_ext_deserialize__(
+        cls, storage_type: pa.DataType, serialized: bytes
+    ) -> UnitType:
+        return cls(serialized.decode())
+
+
+@pytest.fixture(autouse=True)
+def _register_unit_type() -> Iterator[None]:
+    pa.register_extension_type(UnitType("m"))
+    yield
+    pa.unregister_extension_type(EXT_NAME)
+
+
+def test_extension_dtype() -> None:
+    s = pl.Series("length", [3, None, 1, 2]).cast(
+        pl.Extension(EXT_NAME, pl.Int64, "m")
+    )
+    assert s.dtype == pl.Extension(EXT_NAME, pl.Int64, "m")
+    assert s.dtype == pl.Extension
+    assert s.dtype != pl.Extension(EXT_NAME, pl.Int64, "km")
+    assert repr(s.dtype) == "Extension('polars.test.unit', Int64, 'm')"
+    assert s.null_count() == 1
+    assert s.to_list() == [3, None, 1, 2]
+
+
+def test_extension_arrow_round_trip() -> None:
+    s = pl.Series("length", [3, None, 1, 2]).cast(
+        pl.Extension(EXT_NAME, pl.Int64, "m")
+    )
+    out = s.to_arrow()
+    assert isinstance(out.type, UnitType)
+    assert out.type.unit == "m"
+    assert out.storage.to_pylist() == [3, None, 1, 2]
+
+    tbl = s.to_frame().to_arrow()
+    assert isinstance(tbl.schema.field("length").type, UnitType)
+    assert_frame_equal(pl.from_arrow(tbl), s.to_frame())  # type: ignore[arg-type]
+
+
+def test_extension_ops_keep_dtype() -> None:
+    s = pl.Series("length", [3, None, 1, 2]).cast(
+        pl.Extension(EXT_NAME, pl.Int64, "m")
+    )
+    dtype = s.dtype
+
+    assert s.filter(s.is_not_null()).dtype == dtype
+    assert s.filter(s.is_not_null()).to_list() == [3, 1, 2]
+    assert s.sort().dtype == dtype
+    assert s.sort().to_list() == [None, 1, 2, 3]
+    assert s.gather([2, 0]).to_list() == [1, 3]
+    assert s.reverse().to_list() == [2, 1, None, 3]
+
+    out = pl.concat([s, s])
+    assert out.dtype == dtype
+    assert out.len() == 8
+
+
+def test_extension_join_and_group_by() -> None:
+    df = pl.DataFrame({"id": [1, 2, 3, 4], "length": [3, None, 1, 2]}).cast(
+        {"length": pl.Extension(EXT_NAME, pl.Int64, "m")}
+    )
+    other = pl.DataFrame({"id": [4, 1], "name": ["a", "b"]})
+
+    out = df.join(other, on="id").sort("id")
+    assert out.schema["length"] == df.schema["length"]
+    assert out["length"].to_list() == [3, 2]
+
+    out = df.group_by("length", maintain_order=True).agg(pl.col("id"))
+    assert out.schema["length"] == df.schema["length"]
+    assert out["id"].to_list() == [[1], [2], [3], [4]]
+
+
+def test_extension_cast() -> None:
+    s = pl.Series("length", [3, None, 1, 2]).cast(
+        pl.Extension(EXT_NAME, pl.Int64, "m")
+    )
+    storage = s.cast(pl.Int64)
+    assert_series_equal(storage, pl.Series("length", [3, None, 1, 2]))
+
+    assert_series_equal(storage.cast(s.dtype), s)
+    assert s.cast(pl.String).to_list() == ["3", None, "1", "2"]
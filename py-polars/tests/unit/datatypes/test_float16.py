@@ -0,0 +1,81 @@
+from __future__ import annotations
+
+import numpy as np
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+
+def test_float16_dtype() -> None:
+    s = pl.Series("x", [1.5, None, -2.0, 0.25], dtype=pl.Float16)
+    assert s.dtype == pl.Float16
+    assert s.dtype.is_float()
+    assert s.null_count() == 1
+    assert s.to_list() == [1.5, None, -2.0, 0.25]
+
+
+def test_float16_rounds_to_half_precision() -> None:
+    s = pl.Series([0.1], dtype=pl.Float16)
+    assert s.item() == float(np.float16(0.1))
+
+
+def test_float16_arrow_round_trip() -> None:
+    arr = pa.array(np.array([1.5, 2.0, -0.5], dtype=np.float16))
+    s = pl.from_arrow(arr)
+    assert isinstance(s, pl.Series)
+    assert s.dtype == pl.Float16
+    assert s.to_list() == [1.5, 2.0, -0.5]
+
+    out = s.to_arrow()
+    assert out.type == pa.float16()
+    assert out.equals(arr)
+
+
+def test_float16_cast() -> None:
+    s = pl.Series("x", [1.5, None, -2.0, 0.25], dtype=pl.Float16)
+    assert_series_equal(
+        s.cast(pl.Float32), pl.Series("x", [1.5, None, -2.0, 0.25], dtype=pl.Float32)
+    )
+    assert_series_equal(s.cast(pl.Float64).cast(pl.Float16), s)
+    assert s.cast(pl.Int32).to_list() == [1, None, -2, 0]
+    assert pl.Series([70000.0], dtype=pl.Float32).cast(pl.Float16).item() == float(
+        "inf"
+    )
+
+
+def test_float16_arithmetic() -> None:
+    s = pl.Series("x", [1.5, None, -2.0, 0.25], dtype=pl.Float16)
+    assert_series_equal(s + s, pl.Series("x", [3.0, None, -4.0, 0.5], dtype=pl.Float16))
+    assert_series_equal(s * 2, pl.Series("x", [3.0, None, -4.0, 0.5], dtype=pl.Float16))
+    assert (s + pl.Series([1.0, 1.0, 1.0, 1.0])).dtype == pl.Float64
+    assert_series_equal(-s, pl.Series("x", [-1.5, None, 2.0, -0.25], dtype=pl.Float16))
+    assert (s > 0).to_list() == [True, None, False, True]
+
+
+def test_float16_aggregations() -> None:
+    s = pl.Series("x", [1.5, None, -2.0, 0.25], dtype=pl.Float16)
+    assert s.sum() == -0.25
+    assert s.min() == -2.0
+    assert s.max() == 1.5
+    assert s.mean() == pytest.approx(-0.25 / 3)
+
+    df = pl.DataFrame(
+        {"g": [1, 1, 2, 2], "x": [1.5, None, -2.0, 0.25]},
+        schema={"g": pl.Int64, "x": pl.Float16},
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").sum().alias("sum"), pl.col("x").max().alias("max")
+    )
+    assert out.schema["sum"] == pl.Float16
+    assert out.schema["max"] == pl.Float16
+    assert out["sum"].to_list() == [1.5, -1.75]
+    assert out["max"].to_list() == [1.5, 0.25]
+
+
+def test_float16_sort_and_unique() -> None:
+    s = pl.Series([2.0, None, -1.0, 2.0], dtype=pl.Float16)
+    assert s.sort().to_list() == [None, -1.0, 2.0, 2.0]
+    assert s.unique().sort().to_list() == [None, -1.0, 2.0]
+    assert s.n_unique() == 3
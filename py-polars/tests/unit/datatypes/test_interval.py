@@ -0,0 +1,137 @@
+from __future__ import annotations
+
+from datetime import date, datetime, timedelta
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_series_equal
+
+NS_PER_HOUR = 3_600_000_000_000
+
+
+def test_interval_dtype() -> None:
+    s = pl.Series(
+        "iv",
+        pa.array(
+            [(1, 0, 0), (0, 1, 0), None, (-1, 2, NS_PER_HOUR)],
+            type=pa.month_day_nano_interval(),
+        ),
+    )
+    assert s.dtype == pl.Interval
+    assert repr(s.dtype) == "Interval"
+    assert s.null_count() == 1
+    assert s.to_list() == [(1, 0, 0), (0, 1, 0), None, (-1, 2, NS_PER_HOUR)]
+
+
+def test_interval_arrow_round_trip() -> None:
+    s = pl.Series(
+        "iv",
+        pa.array(
+            [(1, 0, 0), (0, 1, 0), None, (-1, 2, NS_PER_HOUR)],
+            type=pa.month_day_nano_interval(),
+        ),
+    )
+    out = s.to_arrow()
+    assert out.type == pa.month_day_nano_interval()
+    assert out.to_pylist() == [(1, 0, 0), (0, 1, 0), None, (-1, 2, NS_PER_HOUR)]
+
+
+def test_interval_add_to_date() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [date(2024, 1, 31), date(2024, 3, 31), None, date(2023, 3, 30)],
+            "iv": pa.array(
+                [(1, 0, 0), (0, 1, 0), None, (-1, 2, NS_PER_HOUR)],
+                type=pa.month_day_nano_interval(),
+            ),
+        }
+    )
+    out = df.select(
+        plus=pl.col("dt") + pl.col("iv"),
+        minus=pl.col("dt") - pl.col("iv"),
+        rplus=pl.col("iv") + pl.col("dt"),
+    )
+    assert out.schema == {"plus": pl.Date, "minus": pl.Date, "rplus": pl.Date}
+    assert out.to_dict(as_series=False) == {
+        "plus": [date(2024, 2, 29), date(2024, 4, 1), None, date(2023, 3, 2)],
+        "minus": [date(2023, 12, 31), date(2024, 3, 30), None, date(2023, 4, 27)],
+        "rplus": [date(2024, 2, 29), date(2024, 4, 1), None, date(2023, 3, 2)],
+    }
+
+
+def test_interval_add_to_datetime() -> None:
+    df = pl.DataFrame(
+        {
+            "dtm": [
+                datetime(2024, 1, 31, 12),
+                datetime(2024, 3, 9, 12),
+                datetime(2024, 1, 1),
+                datetime(2024, 3, 31, 22),
+            ],
+            "iv": pa.array(
+                [(1, 0, 0), (0, 1, 0), None, (-1, 2, NS_PER_HOUR)],
+                type=pa.month_day_nano_interval(),
+            ),
+        }
+    )
+    out = df.select(pl.col("dtm") + pl.col("iv"))
+    assert out["dtm"].to_list() == [
+        datetime(2024, 2, 29, 12),
+        datetime(2024, 3, 10, 12),
+        None,
+        datetime(2024, 3, 2, 23),
+    ]
+
+    # a day is a calendar day, not 24 hours
+    tz_df = df.with_columns(pl.col("dtm").dt.replace_time_zone("America/New_York"))
+    out = tz_df.select(pl.col("dtm") + pl.col("iv"))
+    assert out["dtm"].dt.hour().to_list() == [12, 12, None, 23]
+
+
+def test_interval_add_invalid() -> None:
+    df = pl.DataFrame(
+        {"x": [1], "iv": pa.array([(1, 0, 0)], type=pa.month_day_nano_interval())}
+    )
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.col("x") + pl.col("iv"))
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.col("iv") - pl.lit(date(2024, 1, 1)))
+
+
+def test_interval_negate() -> None:
+    s = pl.Series(
+        "iv",
+        pa.array(
+            [(1, 0, 0), (0, 1, 0), None, (-1, 2, NS_PER_HOUR)],
+            type=pa.month_day_nano_interval(),
+        ),
+    )
+    assert (-s).to_list() == [(-1, 0, 0), (0, -1, 0), None, (1, -2, -NS_PER_HOUR)]
+
+
+def test_duration_to_interval_cast() -> None:
+    s = pl.Series("d", [timedelta(hours=1), None], dtype=pl.Duration("ms"))
+    out = s.cast(pl.Interval)
+    assert out.dtype == pl.Interval
+    assert out.to_list() == [(0, 0, NS_PER_HOUR), None]
+
+    with pytest.raises(InvalidOperationError):
+        out.cast(pl.Int64)
+
+
+def test_interval_ops() -> None:
+    s = pl.Series(
+        "iv",
+        pa.array(
+            [(1, 0, 0), (0, 1, 0), None, (-1, 2, NS_PER_HOUR)],
+            type=pa.month_day_nano_interval(),
+        ),
+    )
+    assert_series_equal(s.filter(s.is_not_null()).head(1), s.head(1))
+    assert s.gather([3, 0]).to_list() == [(-1, 2, NS_PER_HOUR), (1, 0, 0)]
+    assert s.max() == (1, 0, 0)
+    assert s.min() == (-1, 2, NS_PER_HOUR)
+    assert pl.concat([s, s]).len() == 8
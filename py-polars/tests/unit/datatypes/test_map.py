@@ -0,0 +1,212 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_map_dtype() -> None:
+    dtype = pl.Map(pl.String, pl.Int64)
+    assert dtype == pl.Map
+    assert dtype == pl.Map(pl.String, pl.Int64)
+    assert dtype != pl.Map(pl.String, pl.Int32)
+    assert dtype != pl.List(pl.Struct({"key": pl.String, "value": pl.Int64}))
+    assert dtype.is_nested()
+    assert repr(dtype) == "Map(String, Int64)"
+
+    s = pl.Series(
+        "m",
+        [
+            [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+            [{"key": "b", "value": 3}],
+            [],
+            None,
+        ],
+        dtype=pl.Map(pl.String, pl.Int64),
+    )
+    assert s.dtype == dtype
+    assert s.null_count() == 1
+    assert s.to_list() == [
+        [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        [{"key": "b", "value": 3}],
+        [],
+        None,
+    ]
+
+
+def test_map_null_keys_not_allowed() -> None:
+    with pytest.raises(ComputeError, match="map keys cannot be null"):
+        pl.Series([[{"key": None, "value": 1}]], dtype=pl.Map(pl.String, pl.Int64))
+
+
+def test_map_from_arrow() -> None:
+    arr = pa.array(
+        [[("a", 1), ("b", 2)], None, [("c", 3)]],
+        type=pa.map_(pa.string(), pa.int64()),
+    )
+    s = pl.from_arrow(arr)
+    assert isinstance(s, pl.Series)
+    assert s.dtype == pl.Map(pl.String, pl.Int64)
+    assert s.map.get("b").to_list() == [2, None, None]
+
+    out = s.to_arrow()
+    assert pa.types.is_map(out.type)
+    assert out.to_pylist() == arr.to_pylist()
+
+
+def test_map_keys_sorted() -> None:
+    arr = pa.array(
+        [[("a", 1), ("b", 2)], None],
+        type=pa.map_(pa.string(), pa.int64(), keys_sorted=True),
+    )
+    s = pl.from_arrow(arr)
+    assert isinstance(s, pl.Series)
+    assert s.dtype.keys_sorted  # type: ignore[attr-defined]
+    assert repr(s.dtype) == "Map(String, Int64, keys_sorted=True)"
+    assert s.dtype == pl.Map(pl.String, pl.Int64)
+    assert s.to_arrow().type.keys_sorted
+
+
+def test_map_get() -> None:
+    s = pl.Series(
+        "m",
+        [
+            [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+            [{"key": "b", "value": 3}],
+            [],
+            None,
+        ],
+        dtype=pl.Map(pl.String, pl.Int64),
+    )
+    assert_series_equal(s.map.get("b"), pl.Series("m", [2, 3, None, None]))
+    assert s.map.get("z").to_list() == [None, None, None, None]
+
+    df = pl.DataFrame({"m": s, "k": ["a", "b", "a", "a"]})
+    assert df.select(pl.col("m").map.get(pl.col("k")))["m"].to_list() == [
+        1,
+        3,
+        None,
+        None,
+    ]
+
+
+def test_map_contains_key() -> None:
+    s = pl.Series(
+        "m",
+        [
+            [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+            [{"key": "b", "value": 3}],
+            [],
+            None,
+        ],
+        dtype=pl.Map(pl.String, pl.Int64),
+    )
+    assert s.map.contains_key("a").to_list() == [True, False, False, None]
+
+
+def test_map_keys_values() -> None:
+    s = pl.Series(
+        "m",
+        [
+            [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+            [{"key": "b", "value": 3}],
+            [],
+            None,
+        ],
+        dtype=pl.Map(pl.String, pl.Int64),
+    )
+    assert_series_equal(
+        s.map.keys(),
+        pl.Series("m", [["a", "b"], ["b"], [], None], dtype=pl.List(pl.String)),
+    )
+    assert_series_equal(
+        s.map.values(),
+        pl.Series("m", [[1, 2], [3], [], None], dtype=pl.List(pl.Int64)),
+    )
+
+
+def test_map_to_list_and_back() -> None:
+    s = pl.Series(
+        "m",
+        [
+            [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+            [{"key": "b", "value": 3}],
+            [],
+            None,
+        ],
+        dtype=pl.Map(pl.String, pl.Int64),
+    )
+    entries = s.map.to_list()
+    assert entries.dtype == pl.List(pl.Struct({"key": pl.String, "value": pl.Int64}))
+    assert_series_equal(entries.list.to_map(), s)
+
+    # the field names of the entries do not matter
+    renamed = pl.Series([[{"k": 1, "v": "x"}]]).list.to_map()
+    assert renamed.dtype == pl.Map(pl.Int64, pl.String)
+    assert renamed.map.get(1).to_list() == ["x"]
+
+
+def test_map_struct_conversion() -> None:
+    s = pl.Series("s", [{"a": 1, "b": 2.5}, None, {"a": 3, "b": None}])
+    m = s.struct.to_map()
+    assert m.dtype == pl.Map(pl.String, pl.Float64)
+    assert m.map.get("a").to_list() == [1.0, None, 3.0]
+    assert m.is_null().to_list() == [False, True, False]
+
+    out = m.map.to_struct(["b", "c"])
+    assert out.dtype == pl.Struct({"b": pl.Float64, "c": pl.Float64})
+    assert out.struct.unnest().to_dict(as_series=False) == {
+        "b": [2.5, None, None],
+        "c": [None, None, None],
+    }
+
+
+def test_map_cast() -> None:
+    s = pl.Series(
+        "m",
+        [
+            [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+            [{"key": "b", "value": 3}],
+            [],
+            None,
+        ],
+        dtype=pl.Map(pl.String, pl.Int64),
+    )
+    out = s.cast(pl.Map(pl.String, pl.Float64))
+    assert out.dtype == pl.Map(pl.String, pl.Float64)
+    assert out.map.get("a").to_list() == [1.0, None, None, None]
+
+    as_list = s.cast(pl.List(pl.Struct({"key": pl.String, "value": pl.Int64})))
+    assert_series_equal(as_list, s.map.to_list())
+    assert_series_equal(as_list.cast(s.dtype), s)
+
+    with pytest.raises(InvalidOperationError):
+        s.cast(pl.String)
+
+
+def test_map_namespace_wrong_dtype() -> None:
+    with pytest.raises(InvalidOperationError, match="expected Map type"):
+        pl.Series([1, 2]).map.keys()
+
+
+def test_map_filter_gather_concat() -> None:
+    s = pl.Series(
+        "m",
+        [
+            [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+            [{"key": "b", "value": 3}],
+            [],
+            None,
+        ],
+        dtype=pl.Map(pl.String, pl.Int64),
+    )
+    assert_series_equal(s.filter(pl.Series([True, False, True, False])), s[[0, 2]])
+    assert s.gather([1, 0]).map.get("b").to_list() == [3, 2]
+
+    out = pl.concat([s, s.slice(1, 2)])
+    assert out.dtype == s.dtype
+    assert out.map.get("b").to_list() == [2, 3, None, None, 3, None]
+    assert out.slice(4).map.keys().to_list() == [["b"], []]
@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+U128_MAX = 2**128 - 1
+
+
+def test_uint128_dtype() -> None:
+    s = pl.Series("x", [U128_MAX, None, 0, 2**64], dtype=pl.UInt128)
+    assert s.dtype == pl.UInt128
+    assert s.dtype.is_unsigned_integer()
+    assert s.to_list() == [U128_MAX, None, 0, 2**64]
+
+
+def test_uint128_cast() -> None:
+    s = pl.Series([1, 2, None], dtype=pl.UInt128)
+    assert_series_equal(s.cast(pl.Int64), pl.Series([1, 2, None], dtype=pl.Int64))
+    assert_series_equal(s.cast(pl.Int128).cast(pl.UInt128), s)
+    assert s.cast(pl.String).to_list() == ["1", "2", None]
+
+    assert pl.Series([str(U128_MAX)]).cast(pl.UInt128).item() == U128_MAX
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        pl.Series([-1]).cast(pl.UInt128)
+
+
+def test_uint128_arithmetic_and_aggregations() -> None:
+    s = pl.Series("x", [2**100, 3, None], dtype=pl.UInt128)
+    assert (s + 1).to_list() == [2**100 + 1, 4, None]
+    assert (s * 2).dtype == pl.UInt128
+    assert s.sum() == 2**100 + 3
+    assert s.min() == 3
+    assert s.max() == 2**100
+    assert (s > 3).to_list() == [True, False, None]
+
+
+def test_uint128_group_by_and_join() -> None:
+    df = pl.DataFrame(
+        {"k": [U128_MAX, None, 0, 2**64], "v": [1, 2, 3, 4]},
+        schema={"k": pl.UInt128, "v": pl.Int64},
+    )
+    out = df.group_by("k").agg(pl.col("v").sum()).sort("v")
+    assert out.schema["k"] == pl.UInt128
+    assert out["k"].to_list() == [U128_MAX, None, 0, 2**64]
+
+    other = pl.DataFrame({"k": [0, U128_MAX]}, schema={"k": pl.UInt128})
+    out = df.join(other, on="k").sort("v")
+    assert out["v"].to_list() == [1, 3]
+
+
+def test_uint128_sort() -> None:
+    s = pl.Series("x", [U128_MAX, None, 0, 2**64], dtype=pl.UInt128)
+    assert s.sort().to_list() == [None, 0, 2**64, U128_MAX]
+    assert s.sort(descending=True, nulls_last=True).to_list() == [
+        U128_MAX,
+        2**64,
+        0,
+        None,
+    ]
@@ -11,7 +11,7 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import AvroCompression
+    from polars._typing import AvroCompression, EngineType
 
 
 COMPRESSIONS = ["uncompressed", "snappy", "deflate"]
@@ -89,3 +89,112 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+@pytest.fixture
+def multi_block_df() -> pl.DataFrame:
+    # Every chunk is written as a separate Avro block.
+    return pl.concat(
+        [
+            pl.DataFrame({"a": range(i * 100, (i + 1) * 100), "b": str(i)})
+            for i in range(10)
+        ],
+        rechunk=False,
+    )
+
+
+def test_write_multiple_chunks(multi_block_df: pl.DataFrame) -> None:
+    f = io.BytesIO()
+    multi_block_df.write_avro(f)
+    f.seek(0)
+
+    assert_frame_equal(pl.read_avro(f), multi_block_df)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_scan_avro(
+    multi_block_df: pl.DataFrame, compression: AvroCompression, tmp_path: Path
+) -> None:
+    file_path = tmp_path / "blocks.avro"
+    multi_block_df.write_avro(file_path, compression=compression)
+
+    lf = pl.scan_avro(file_path)
+    assert lf.collect_schema() == multi_block_df.schema
+    assert_frame_equal(lf.collect(), multi_block_df)
+    assert_frame_equal(lf.collect(engine="streaming"), multi_block_df)
+
+    assert_frame_equal(lf.select("b").collect(), multi_block_df.select("b"))
+    assert_frame_equal(
+        lf.filter(pl.col("a") % 7 == 0).collect(),
+        multi_block_df.filter(pl.col("a") % 7 == 0),
+    )
+    assert lf.select(pl.len()).collect().item() == 1000
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("offset", "length"), [(0, 0), (50, 100), (250, 1000), (-150, 120)]
+)
+def test_scan_avro_slice(
+    multi_block_df: pl.DataFrame, offset: int, length: int, tmp_path: Path
+) -> None:
+    file_path = tmp_path / "blocks.avro"
+    multi_block_df.write_avro(file_path)
+
+    assert_frame_equal(
+        pl.scan_avro(file_path).slice(offset, length).collect(),
+        multi_block_df.slice(offset, length),
+    )
+    assert_frame_equal(
+        pl.scan_avro(file_path, n_rows=length).collect(),
+        multi_block_df.head(length),
+    )
+
+
+@pytest.mark.write_disk
+def test_scan_avro_row_index_and_multiple_files(
+    multi_block_df: pl.DataFrame, tmp_path: Path
+) -> None:
+    multi_block_df.write_avro(tmp_path / "1.avro")
+    multi_block_df.write_avro(tmp_path / "2.avro")
+
+    expected = pl.concat([multi_block_df, multi_block_df]).with_row_index(offset=10)
+
+    lf = pl.scan_avro(tmp_path / "*.avro", row_index_name="index", row_index_offset=10)
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(
+        lf.slice(950, 100).select("index", "b").collect(),
+        expected.slice(950, 100).select("index", "b"),
+    )
+
+
+def test_scan_avro_buffer(example_df: pl.DataFrame) -> None:
+    f = io.BytesIO()
+    example_df.write_avro(f)
+    f.seek(0)
+
+    assert_frame_equal(pl.scan_avro(f).collect(), example_df)
+
+
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_sink_avro(
+    multi_block_df: pl.DataFrame, compression: AvroCompression, engine: EngineType
+) -> None:
+    f = io.BytesIO()
+    multi_block_df.lazy().sink_avro(f, compression=compression, engine=engine)
+    f.seek(0)
+
+    assert_frame_equal(pl.read_avro(f), multi_block_df)
+
+
+@pytest.mark.write_disk
+def test_sink_avro_partitioned(multi_block_df: pl.DataFrame, tmp_path: Path) -> None:
+    multi_block_df.lazy().sink_avro(
+        pl.PartitionMaxSize(tmp_path, max_size=300), engine="streaming"
+    )
+
+    files = sorted(tmp_path.iterdir())
+    assert [f.suffix for f in files] == [".avro"] * 4
+    assert_frame_equal(pl.scan_avro(files).collect(), multi_block_df)
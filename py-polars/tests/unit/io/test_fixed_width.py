@@ -0,0 +1,233 @@
+from __future__ import annotations
+
+import gzip
+import io
+from datetime import date
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from polars._typing import EngineType
+
+SPANS = {"id": (0, 4), "name": (4, 12), "score": (12, 18)}
+
+DATA = (
+    b"1   alice   1.5   \n"
+    b"22  bob     10.0  \n"
+    b"333 carol   -2.25 \n"
+    b"4   dave    0.0   \n"
+)
+
+EXPECTED = pl.DataFrame(
+    {
+        "id": [1, 22, 333, 4],
+        "name": ["alice", "bob", "carol", "dave"],
+        "score": [1.5, 10.0, -2.25, 0.0],
+    }
+)
+
+
+def test_read_fixed_width() -> None:
+    df = pl.read_fixed_width(DATA, SPANS)
+    assert_frame_equal(df, EXPECTED)
+
+
+@pytest.mark.write_disk
+def test_read_fixed_width_file(tmp_path: Path) -> None:
+    path = tmp_path / "data.txt"
+    path.write_bytes(DATA)
+
+    assert_frame_equal(pl.read_fixed_width(path, SPANS), EXPECTED)
+    assert_frame_equal(pl.read_fixed_width(str(path), SPANS), EXPECTED)
+
+
+def test_read_fixed_width_crlf_and_empty_lines() -> None:
+    data = DATA.replace(b"\n", b"\r\n").replace(b"22 ", b"\r\n22 ")
+    assert_frame_equal(pl.read_fixed_width(data, SPANS), EXPECTED)
+
+
+def test_read_fixed_width_column_order_and_gaps() -> None:
+    df = pl.read_fixed_width(DATA, {"score": (12, 18), "id": (0, 2)})
+    expected = pl.DataFrame({"score": [1.5, 10.0, -2.25, 0.0], "id": [1, 22, 33, 4]})
+    assert_frame_equal(df, expected)
+
+
+def test_read_fixed_width_short_lines() -> None:
+    data = b"1   alice   1.5\n22  bob\n3\n"
+    df = pl.read_fixed_width(data, SPANS)
+    expected = pl.DataFrame(
+        {"id": [1, 22, 3], "name": ["alice", "bob", None], "score": [1.5, None, None]}
+    )
+    assert_frame_equal(df, expected)
+
+    df = pl.read_fixed_width(data, SPANS, missing_utf8_is_empty_string=True)
+    assert df["name"].to_list() == ["alice", "bob", ""]
+
+
+def test_read_fixed_width_span_unit_chars() -> None:
+    data = "ü   äöü   1\nabc ab    22\n".encode()
+    spans = {"a": (0, 4), "b": (4, 10), "c": (10, 12)}
+
+    df = pl.read_fixed_width(data, spans, span_unit="chars")
+    expected = pl.DataFrame({"a": ["ü", "abc"], "b": ["äöü", "ab"], "c": [1, 22]})
+    assert_frame_equal(df, expected)
+
+    with pytest.raises(ComputeError, match="splits a multi-byte character"):
+        pl.read_fixed_width(data, {"a": (0, 1)}, span_unit="bytes")
+
+
+def test_read_fixed_width_schema_overrides_and_inference() -> None:
+    data = b"2024-01-02 07  x\n2023-12-31 010 y\n"
+    spans = {"date": (0, 10), "code": (11, 14), "flag": (15, 16)}
+
+    df = pl.read_fixed_width(data, spans, try_parse_dates=True)
+    assert df.schema == pl.Schema(
+        {"date": pl.Date, "code": pl.Int64, "flag": pl.String}
+    )
+    assert df["date"].to_list() == [date(2024, 1, 2), date(2023, 12, 31)]
+
+    df = pl.read_fixed_width(
+        data, spans, schema_overrides={"code": pl.String, "flag": pl.Categorical}
+    )
+    assert df.schema == pl.Schema(
+        {"date": pl.String, "code": pl.String, "flag": pl.Categorical}
+    )
+    assert df["code"].to_list() == ["07", "010"]
+
+    df = pl.read_fixed_width(data, spans, infer_schema_length=0)
+    assert df.schema == pl.Schema(
+        {"date": pl.String, "code": pl.String, "flag": pl.String}
+    )
+
+
+def test_read_fixed_width_null_values() -> None:
+    data = b"1   NA\n-   2\n3   -\n"
+    spans = {"a": (0, 4), "b": (4, 6)}
+
+    df = pl.read_fixed_width(data, spans, null_values=["NA", "-"])
+    expected = pl.DataFrame({"a": [1, None, 3], "b": [None, 2, None]})
+    assert_frame_equal(df, expected)
+
+    df = pl.read_fixed_width(data, spans, null_values={"a": "-"})
+    expected = pl.DataFrame({"a": [1, None, 3], "b": ["NA", "2", "-"]})
+    assert_frame_equal(df, expected)
+
+
+def test_read_fixed_width_parse_errors() -> None:
+    data = b"1  \nx  \n3  \n"
+    spans = {"a": (0, 3)}
+
+    with pytest.raises(ComputeError, match="could not parse `x` as dtype `i64`"):
+        pl.read_fixed_width(data, spans, schema_overrides={"a": pl.Int64})
+
+    df = pl.read_fixed_width(
+        data, spans, schema_overrides={"a": pl.Int64}, ignore_errors=True
+    )
+    assert df["a"].to_list() == [1, None, 3]
+
+
+def test_read_fixed_width_skip_rows_and_n_rows() -> None:
+    data = b"a header line\nanother one\n" + DATA
+
+    df = pl.read_fixed_width(data, SPANS, skip_rows=2)
+    assert_frame_equal(df, EXPECTED)
+
+    df = pl.read_fixed_width(data, SPANS, skip_rows=2, n_rows=2)
+    assert_frame_equal(df, EXPECTED.head(2))
+
+
+def test_read_fixed_width_row_index() -> None:
+    df = pl.read_fixed_width(DATA, SPANS, row_index_name="idx", row_index_offset=10)
+    assert_frame_equal(df, EXPECTED.with_row_index("idx", offset=10))
+
+
+def test_read_fixed_width_decimal_comma() -> None:
+    df = pl.read_fixed_width(b"1,5 \n-2,25\n", {"a": (0, 5)}, decimal_comma=True)
+    assert df["a"].to_list() == [1.5, -2.25]
+
+
+def test_read_fixed_width_compressed() -> None:
+    assert_frame_equal(pl.read_fixed_width(gzip.compress(DATA), SPANS), EXPECTED)
+
+
+def test_read_fixed_width_invalid_spans() -> None:
+    with pytest.raises(InvalidOperationError, match="at least one column"):
+        pl.read_fixed_width(DATA, {})
+
+    with pytest.raises(InvalidOperationError, match="is empty"):
+        pl.read_fixed_width(DATA, {"a": (4, 4)})
+
+    with pytest.raises(ValueError, match="`span_unit` must be one of"):
+        pl.read_fixed_width(DATA, SPANS, span_unit="bits")  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scan_fixed_width_projection_and_slice(engine: EngineType) -> None:
+    lf = pl.scan_fixed_width(io.BytesIO(DATA), SPANS)
+
+    assert lf.collect_schema() == EXPECTED.schema
+    assert_frame_equal(lf.collect(engine=engine), EXPECTED)
+    assert_frame_equal(
+        lf.select("score", "id").collect(engine=engine),
+        EXPECTED.select("score", "id"),
+    )
+    assert_frame_equal(lf.slice(1, 2).collect(engine=engine), EXPECTED.slice(1, 2))
+    assert_frame_equal(lf.tail(3).collect(engine=engine), EXPECTED.tail(3))
+    assert_frame_equal(
+        lf.with_row_index().filter(pl.col("index") > 1).collect(engine=engine),
+        EXPECTED.with_row_index().filter(pl.col("index") > 1),
+    )
+    assert lf.select(pl.len()).collect(engine=engine).item() == 4
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scan_fixed_width_multiple_files(tmp_path: Path, engine: EngineType) -> None:
+    (tmp_path / "a.txt").write_bytes(DATA)
+    (tmp_path / "b.txt.gz").write_bytes(gzip.compress(DATA * 2))
+
+    lf = pl.scan_fixed_width(
+        tmp_path / "*", SPANS, row_index_name="idx", include_file_paths="path"
+    )
+    df = lf.collect(engine=engine)
+
+    assert df.height == 12
+    assert df["idx"].to_list() == list(range(12))
+    assert_frame_equal(df.select(SPANS.keys()), pl.concat([EXPECTED] * 3))
+    assert df["path"].str.ends_with("a.txt").sum() == 4
+
+    assert_frame_equal(
+        lf.slice(2, 5).collect(engine=engine),
+        df.slice(2, 5),
+    )
+    assert lf.select(pl.len()).collect(engine=engine).item() == 12
+
+
+def test_scan_fixed_width_large_streaming() -> None:
+    n = 20_000
+    data = b"".join(f"{i:<8}{i * 2:>8}\n".encode() for i in range(n))
+    lf = pl.scan_fixed_width(data, {"a": (0, 8), "b": (8, 16)})
+
+    df = lf.collect(engine="streaming")
+    assert df.height == n
+    assert df["a"].to_list() == list(range(n))
+    assert (df["b"] == df["a"] * 2).all()
+
+    assert_frame_equal(
+        lf.slice(12_345, 3).collect(engine="streaming"),
+        df.slice(12_345, 3),
+    )
+    assert_frame_equal(
+        pl.scan_fixed_width(gzip.compress(data), {"a": (0, 8), "b": (8, 16)})
+        .with_row_index()
+        .collect(engine="streaming"),
+        df.with_row_index(),
+    )
+
@@ -5,6 +5,7 @@ from decimal import Decimal
 from typing import TYPE_CHECKING, Any, no_type_check
 
 import pandas as pd
+import pyarrow as pa
 import pytest
 
 import polars as pl
@@ -467,3 +468,74 @@ def test_roundtrip_empty_str_list_21163() -> None:
     bytes = df.serialize()
     deserialized = pl.DataFrame.deserialize(io.BytesIO(bytes))
     assert_frame_equal(df, deserialized)
+
+
+def test_ipc_map_roundtrip() -> None:
+    df = pl.DataFrame(
+        {
+            "m": [
+                [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+                [{"key": "b", "value": 3}],
+                [],
+                None,
+            ]
+        },
+        schema={"m": pl.Map(pl.String, pl.Int64)},
+    )
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+
+def test_ipc_map_keys_sorted_roundtrip() -> None:
+    arr = pa.array(
+        [[("a", 1), ("b", 2)], None],
+        type=pa.map_(pa.string(), pa.int64(), keys_sorted=True),
+    )
+    f = io.BytesIO()
+    pl.DataFrame({"m": pl.from_arrow(arr)}).write_ipc(f)
+    f.seek(0)
+    assert pl.read_ipc(f)["m"].dtype.keys_sorted  # type: ignore[attr-defined]
+
+
+def test_ipc_interval_roundtrip() -> None:
+    df = pl.DataFrame(
+        {
+            "iv": pa.array(
+                [(1, 0, 0), (0, 1, 0), None, (-1, 2, 3_600_000_000_000)],
+                type=pa.month_day_nano_interval(),
+            )
+        }
+    )
+    assert df.schema == {"iv": pl.Interval}
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+
+def test_ipc_extension_roundtrip() -> None:
+    df = pl.DataFrame({"length": [3, None, 1, 2]}).cast(
+        {"length": pl.Extension("polars.test.unit", pl.Int64, "m")}
+    )
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+
+def test_ipc_float16_roundtrip() -> None:
+    df = pl.DataFrame({"x": [1.5, None, -2.0, 0.25]}, schema={"x": pl.Float16})
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+
+def test_ipc_uint128_roundtrip() -> None:
+    df = pl.DataFrame({"x": [2**128 - 1, None, 0, 2**64]}, schema={"x": pl.UInt128})
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
@@ -1,12 +1,18 @@
 from __future__ import annotations
 
+import bz2
+import gzip
 import io
+import lzma
 import tempfile
 from collections import OrderedDict
+from functools import partial
 from pathlib import Path
+from typing import Any
 
 import numpy as np
 import pytest
+import zstandard
 
 import polars as pl
 from polars.exceptions import ComputeError, ShapeError
@@ -522,3 +528,49 @@ def test_csv_io_object_utf8_23629() -> None:
         f_str.seek(0)
         df_str = pl.read_csv(f_str)
         assert_frame_equal(df, df_str)
+
+
+COMPRESSORS = {
+    "gzip": gzip.compress,
+    "zstd": zstandard.compress,
+    "bzip2": partial(bz2.compress, compresslevel=1),
+    "xz": partial(lzma.compress, preset=0),
+}
+
+
+@pytest.mark.parametrize("compress", COMPRESSORS.values(), ids=COMPRESSORS.keys())
+def test_scan_csv_compressed_streaming(compress: Any) -> None:
+    # Large enough to be decompressed in multiple parts, with quoted newlines that may
+    # cross the boundaries between those parts.
+    df = pl.DataFrame(
+        {"a": range(600_000), "b": ["x", "multi\nline", None] * 200_000}
+    )
+    f = io.BytesIO(compress(df.write_csv().encode()))
+
+    lf = pl.scan_csv(f)
+    assert_frame_equal(lf.collect(engine="streaming"), df)
+    assert_frame_equal(lf.head(10).collect(engine="streaming"), df.head(10))
+    assert_frame_equal(
+        lf.slice(450_000, 100).collect(engine="streaming"), df.slice(450_000, 100)
+    )
+    assert_frame_equal(
+        lf.with_row_index().tail(5).collect(engine="streaming"),
+        df.with_row_index().tail(5),
+    )
+
+
+def test_scan_csv_compressed_schema_inference() -> None:
+    # The schema is inferred from the start of the file only.
+    csv = "a,b\n" + "1,x\n" * 10 + "2.5,y\n"
+    f = io.BytesIO(gzip.compress(csv.encode()))
+
+    lf = pl.scan_csv(f, infer_schema_length=5)
+    assert lf.collect_schema() == {"a": pl.Int64, "b": pl.String}
+
+    f.seek(0)
+    lf = pl.scan_csv(f, infer_schema_length=None)
+    assert lf.collect_schema() == {"a": pl.Float64, "b": pl.String}
+    assert_frame_equal(
+        lf.collect(engine="streaming"),
+        pl.DataFrame({"a": [1.0] * 10 + [2.5], "b": ["x"] * 10 + ["y"]}),
+    )
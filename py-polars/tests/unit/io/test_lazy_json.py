@@ -1,8 +1,13 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+import bz2
+import gzip
+import io
+import lzma
+from typing import TYPE_CHECKING, Any
 
 import pytest
+import zstandard
 
 import polars as pl
 from polars.testing import assert_frame_equal
@@ -327,3 +332,30 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     q = pl.scan_ndjson(buf, schema_overrides={"a": pl.String})
     assert q.collect_schema() == {"a": pl.String}
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
+
+
+@pytest.mark.parametrize(
+    "compress",
+    [gzip.compress, zstandard.compress, bz2.compress, lzma.compress],
+)
+def test_scan_ndjson_compressed_streaming(
+    compress: Any, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    # Decompress in many small parts.
+    monkeypatch.setenv("POLARS_FORCE_NDJSON_CHUNK_SIZE", "100")
+
+    df = pl.DataFrame({"a": range(1000), "b": ["x", "yy", None, "zzz"] * 250})
+    f = io.BytesIO()
+    df.write_ndjson(f)
+    f = io.BytesIO(compress(f.getvalue()))
+
+    lf = pl.scan_ndjson(f)
+    assert_frame_equal(lf.collect(engine="streaming"), df)
+    assert_frame_equal(
+        lf.slice(123, 456).collect(engine="streaming"), df.slice(123, 456)
+    )
+    assert_frame_equal(
+        lf.with_row_index().tail(10).collect(engine="streaming"),
+        df.with_row_index().tail(10),
+    )
+    assert lf.select(pl.len()).collect(engine="streaming").item() == 1000
@@ -0,0 +1,272 @@
+from __future__ import annotations
+
+import io
+from datetime import date, datetime
+from decimal import Decimal
+from typing import TYPE_CHECKING, Any
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from polars._typing import OrcCompression
+
+
+COMPRESSIONS = ["uncompressed", "zlib", "snappy", "lz4", "zstd"]
+
+
+@pytest.fixture
+def example_df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "bool": [True, None, False],
+            "i8": pl.Series([1, -2, None], dtype=pl.Int8),
+            "i16": pl.Series([None, 300, -300], dtype=pl.Int16),
+            "i32": pl.Series([70_000, None, -1], dtype=pl.Int32),
+            "i64": [1, 2**40, None],
+            "f32": pl.Series([0.5, None, -1.5], dtype=pl.Float32),
+            "f64": [0.1, float("nan"), None],
+            "str": ["a", None, "ccc"],
+            "bin": [b"\x00\x01", b"", None],
+            "date": [date(1969, 12, 31), None, date(2024, 2, 29)],
+            "datetime": pl.Series(
+                [datetime(1950, 1, 1, 0, 0, 0, 1), None, datetime(2024, 1, 1, 12)],
+                dtype=pl.Datetime("ns"),
+            ),
+            "decimal": pl.Series(
+                [Decimal("1.25"), None, Decimal("-100.50")],
+                dtype=pl.Decimal(10, 2),
+            ),
+            "list": [[1, None], None, []],
+            "struct": [{"x": 1, "y": "a"}, None, {"x": None, "y": "c"}],
+        }
+    )
+
+
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_from_to_buffer(example_df: pl.DataFrame, compression: OrcCompression) -> None:
+    buf = io.BytesIO()
+    example_df.write_orc(buf, compression=compression)
+    buf.seek(0)
+
+    read_df = pl.read_orc(buf)
+    assert_frame_equal(example_df, read_df)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_from_to_file(
+    example_df: pl.DataFrame, compression: OrcCompression, tmp_path: Path
+) -> None:
+    file_path = tmp_path / "small.orc"
+    example_df.write_orc(file_path, compression=compression)
+    df_read = pl.read_orc(file_path)
+
+    assert_frame_equal(example_df, df_read)
+
+
+def test_write_widened_dtypes() -> None:
+    df = pl.DataFrame(
+        {
+            "u8": pl.Series([0, 255], dtype=pl.UInt8),
+            "u32": pl.Series([0, 2**32 - 1], dtype=pl.UInt32),
+            "cat": pl.Series(["a", "b"], dtype=pl.Categorical),
+            "tz": pl.Series([datetime(2020, 1, 1), None]).dt.replace_time_zone("UTC"),
+        }
+    )
+
+    f = io.BytesIO()
+    df.write_orc(f)
+    f.seek(0)
+
+    assert pl.read_orc(f).schema == pl.Schema(
+        {
+            "u8": pl.Int16,
+            "u32": pl.Int64,
+            "cat": pl.String,
+            "tz": pl.Datetime("ns", "UTC"),
+        }
+    )
+
+
+def test_write_unsupported_dtype() -> None:
+    df = pl.DataFrame({"a": [None]}, schema={"a": pl.Null})
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="ORC"):
+        df.write_orc(io.BytesIO())
+
+
+@pytest.mark.parametrize("columns", [["b", "c"], [1, 2]])
+def test_select_columns(columns: list[str] | list[int]) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [True, False, True], "c": ["a", "b", "c"]})
+    expected = pl.DataFrame({"b": [True, False, True], "c": ["a", "b", "c"]})
+
+    f = io.BytesIO()
+    df.write_orc(f)
+    f.seek(0)
+
+    read_df = pl.read_orc(f, columns=columns)
+    assert_frame_equal(expected, read_df)
+
+
+@pytest.fixture
+def multi_stripe_df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {"a": range(1000), "b": [str(i // 100) for i in range(1000)]},
+        schema={"a": pl.Int64, "b": pl.String},
+    )
+
+
+def test_read_multiple_stripes(multi_stripe_df: pl.DataFrame) -> None:
+    f = io.BytesIO()
+    multi_stripe_df.write_orc(f, stripe_size=100)
+    f.seek(0)
+
+    assert_frame_equal(pl.read_orc(f), multi_stripe_df)
+    f.seek(0)
+    assert_frame_equal(pl.read_orc(f, n_rows=150), multi_stripe_df.head(150))
+
+
+def test_read_empty() -> None:
+    df = pl.DataFrame(schema={"a": pl.Int32, "b": pl.String})
+
+    f = io.BytesIO()
+    df.write_orc(f)
+    f.seek(0)
+
+    assert_frame_equal(pl.read_orc(f), df)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_scan_orc(
+    multi_stripe_df: pl.DataFrame, compression: OrcCompression, tmp_path: Path
+) -> None:
+    file_path = tmp_path / "stripes.orc"
+    multi_stripe_df.write_orc(file_path, compression=compression, stripe_size=100)
+
+    lf = pl.scan_orc(file_path)
+    assert lf.collect_schema() == multi_stripe_df.schema
+    assert_frame_equal(lf.collect(), multi_stripe_df)
+    assert_frame_equal(lf.collect(engine="streaming"), multi_stripe_df)
+
+    assert_frame_equal(lf.select("b").collect(), multi_stripe_df.select("b"))
+    assert_frame_equal(
+        lf.filter(pl.col("a") % 7 == 0).collect(),
+        multi_stripe_df.filter(pl.col("a") % 7 == 0),
+    )
+    assert lf.select(pl.len()).collect().item() == 1000
+
+
+@pytest.mark.write_disk
+def test_scan_orc_skip_stripes(
+    multi_stripe_df: pl.DataFrame,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    capfd: Any,
+) -> None:
+    file_path = tmp_path / "stripes.orc"
+    multi_stripe_df.write_orc(file_path, stripe_size=100)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+
+    predicate = (pl.col("a") >= 250) & (pl.col("a") < 420)
+    assert_frame_equal(
+        pl.scan_orc(file_path).filter(predicate).collect(),
+        multi_stripe_df.filter(predicate),
+    )
+    assert "skipped 7 stripes based on statistics" in capfd.readouterr().err
+
+    assert_frame_equal(
+        pl.scan_orc(file_path).filter(pl.col("b") == "3").collect(),
+        multi_stripe_df.filter(pl.col("b") == "3"),
+    )
+    assert "skipped 9 stripes based on statistics" in capfd.readouterr().err
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("offset", "length"), [(0, 0), (50, 100), (250, 1000), (-150, 120)]
+)
+def test_scan_orc_slice(
+    multi_stripe_df: pl.DataFrame, offset: int, length: int, tmp_path: Path
+) -> None:
+    file_path = tmp_path / "stripes.orc"
+    multi_stripe_df.write_orc(file_path, stripe_size=100)
+
+    assert_frame_equal(
+        pl.scan_orc(file_path).slice(offset, length).collect(),
+        multi_stripe_df.slice(offset, length),
+    )
+    assert_frame_equal(
+        pl.scan_orc(file_path, n_rows=length).collect(),
+        multi_stripe_df.head(length),
+    )
+
+
+@pytest.mark.write_disk
+def test_scan_orc_row_index_and_multiple_files(
+    multi_stripe_df: pl.DataFrame, tmp_path: Path
+) -> None:
+    multi_stripe_df.write_orc(tmp_path / "1.orc", stripe_size=100)
+    multi_stripe_df.write_orc(tmp_path / "2.orc", stripe_size=300)
+
+    expected = pl.concat([multi_stripe_df, multi_stripe_df]).with_row_index(offset=10)
+
+    lf = pl.scan_orc(tmp_path / "*.orc", row_index_name="index", row_index_offset=10)
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(
+        lf.slice(950, 100).select("index", "b").collect(),
+        expected.slice(950, 100).select("index", "b"),
+    )
+    assert lf.select(pl.len()).collect().item() == 2000
+
+
+def test_scan_orc_buffer(example_df: pl.DataFrame) -> None:
+    f = io.BytesIO()
+    example_df.write_orc(f)
+    f.seek(0)
+
+    assert_frame_equal(pl.scan_orc(f).collect(), example_df)
+
+
+@pytest.mark.write_disk
+def test_read_pyarrow_orc(tmp_path: Path) -> None:
+    orc = pytest.importorskip("pyarrow.orc")
+
+    # Low cardinality strings are dictionary encoded and the integers use the various
+    # RLE v2 run types.
+    df = pl.DataFrame(
+        {
+            "int": [i if i % 13 else None for i in range(5000)],
+            "repeated": [7] * 2500 + [-3] * 2500,
+            "str": [["x", "yy", "zzz"][i % 3] for i in range(5000)],
+            "float": [i / 3 for i in range(5000)],
+        }
+    )
+    table = df.to_arrow(compat_level=pl.CompatLevel.oldest())
+    file_path = tmp_path / "pyarrow.orc"
+    for compression in ["uncompressed", "zlib", "snappy", "lz4", "zstd"]:
+        orc.write_table(
+            table,
+            file_path,
+            compression=compression,
+            stripe_size=16 * 1024,
+            dictionary_key_size_threshold=1.0,
+        )
+
+        assert_frame_equal(pl.read_orc(file_path), df)
+        assert_frame_equal(
+            pl.scan_orc(file_path).filter(pl.col("int") > 4000).collect(),
+            df.filter(pl.col("int") > 4000),
+        )
+
+    f = io.BytesIO()
+    df.write_orc(f)
+    f.seek(0)
+    assert_frame_equal(pl.from_arrow(orc.read_table(f)), df)  # type: ignore[arg-type]
@@ -3509,6 +3509,97 @@ def test_roundtrip_int128() -> None:
     assert_series_equal(pl.read_parquet(f).to_series(), s)
 
 
+@pytest.mark.write_disk
+def test_roundtrip_map(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "m": [
+                [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+                [{"key": "b", "value": 3}],
+                [],
+                None,
+            ]
+        },
+        schema={"m": pl.Map(pl.String, pl.Int64)},
+    )
+    path = tmp_path / "map.parquet"
+    df.write_parquet(path)
+
+    assert_frame_equal(pl.read_parquet(path), df)
+    assert_frame_equal(pl.scan_parquet(path).collect(), df)
+
+    table = pq.read_table(path)
+    assert pa.types.is_map(table.schema.field("m").type)
+    assert table.column("m").to_pylist() == [
+        [("a", 1), ("b", 2)],
+        [("b", 3)],
+        [],
+        None,
+    ]
+
+
+def test_roundtrip_map_keys_sorted() -> None:
+    arr = pa.array(
+        [[("a", 1), ("b", 2)], None],
+        type=pa.map_(pa.string(), pa.int64(), keys_sorted=True),
+    )
+    f = io.BytesIO()
+    pl.DataFrame({"m": pl.from_arrow(arr)}).write_parquet(f)
+    f.seek(0)
+    assert pl.read_parquet(f)["m"].dtype.keys_sorted  # type: ignore[attr-defined]
+
+
+@pytest.mark.write_disk
+def test_roundtrip_interval(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "iv": pa.array(
+                [(1, 0, 0), (0, 1, 0), None, (-1, 2, 3_600_000_000_000)],
+                type=pa.month_day_nano_interval(),
+            )
+        }
+    )
+    assert df.schema == {"iv": pl.Interval}
+    path = tmp_path / "interval.parquet"
+    df.write_parquet(path)
+
+    assert_frame_equal(pl.read_parquet(path), df)
+    assert_frame_equal(pl.scan_parquet(path).collect(), df)
+
+
+@pytest.mark.write_disk
+def test_roundtrip_extension(tmp_path: Path) -> None:
+    df = pl.DataFrame({"length": [3, None, 1, 2]}).cast(
+        {"length": pl.Extension("polars.test.unit", pl.Int64, "m")}
+    )
+    path = tmp_path / "extension.parquet"
+    df.write_parquet(path)
+
+    assert_frame_equal(pl.read_parquet(path), df)
+    assert_frame_equal(pl.scan_parquet(path).collect(), df)
+
+
+@pytest.mark.write_disk
+def test_roundtrip_float16(tmp_path: Path) -> None:
+    df = pl.DataFrame({"x": [1.5, None, -2.0, 0.25]}, schema={"x": pl.Float16})
+    path = tmp_path / "float16.parquet"
+    df.write_parquet(path)
+
+    assert pq.read_schema(path).field("x").type == pa.float16()
+    assert_frame_equal(pl.read_parquet(path), df)
+    assert_frame_equal(pl.scan_parquet(path).collect(), df)
+
+
+@pytest.mark.write_disk
+def test_roundtrip_uint128(tmp_path: Path) -> None:
+    df = pl.DataFrame({"x": [2**128 - 1, None, 0, 2**64]}, schema={"x": pl.UInt128})
+    path = tmp_path / "uint128.parquet"
+    df.write_parquet(path)
+
+    assert_frame_equal(pl.read_parquet(path), df)
+    assert_frame_equal(pl.scan_parquet(path).collect(), df)
+
+
 def test_write_nested_categoricals() -> None:
     df = pl.select(
         pl.lit(pl.Series("col", ["a", "b"], dtype=pl.Categorical)).implode().implode(),
@@ -3529,3 +3620,100 @@ def test_literal_predicate_23901() -> None:
 
     f.seek(0)
     assert_frame_equal(pl.scan_parquet(f).filter(pl.lit(1) == 1).collect(), df)
+
+
+@pytest.mark.parametrize("writer", ["polars", "pyarrow"])
+def test_page_index_pushdown(
+    writer: str,
+    monkeypatch: pytest.MonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    n = 10_000
+    df = pl.DataFrame(
+        {
+            "a": pl.int_range(n, eager=True),
+            "b": pl.int_range(n, eager=True).cast(pl.String),
+            "c": pl.int_range(n, eager=True) % 7,
+        }
+    )
+
+    # A single row group with many small pages.
+    f = io.BytesIO()
+    if writer == "polars":
+        df.write_parquet(f, row_group_size=n, data_page_size=1024)
+    else:
+        pq.write_table(
+            df.to_arrow(),
+            f,
+            row_group_size=n,
+            data_page_size=1024,
+            write_page_index=True,
+        )
+    f.seek(0)
+    assert pq.ParquetFile(f).metadata.num_row_groups == 1
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    for predicate in [
+        (pl.col("a") >= 5000) & (pl.col("a") < 5010),
+        pl.col("a").is_in([17, 9000]),
+        (pl.col("a") < 100) & (pl.col("c") == 3),
+        pl.col("a") > 9990,
+    ]:
+        f.seek(0)
+        capfd.readouterr()
+        result = pl.scan_parquet(f).filter(predicate).collect()
+        assert_frame_equal(result, df.filter(predicate))
+
+        captured = capfd.readouterr().err
+        num_read = int(
+            captured.split("Page index pushdown: reading ")[1].split(" / ")[0]
+        )
+        assert 0 < num_read < n // 2
+
+    # The row index is filtered along with the columns.
+    f.seek(0)
+    q = (
+        pl.scan_parquet(f, row_index_name="idx")
+        .filter(pl.col("a").is_between(4000, 4005))
+        .select("idx", "c")
+    )
+    assert_frame_equal(
+        q.collect(),
+        df.with_row_index("idx")
+        .filter(pl.col("a").is_between(4000, 4005))
+        .select("idx", "c"),
+    )
+
+
+@pytest.mark.parametrize("sink", [True, False])
+def test_bloom_filter_write_option(
+    sink: bool, monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str]
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    # Every row group spans the whole range of values, so only the bloom filter
+    # can tell which row groups contain a value.
+    df = pl.DataFrame({"id": pl.int_range(1000, eager=True)}).sort(
+        pl.col("id") % 4, maintain_order=True
+    )
+
+    f = io.BytesIO()
+    if sink:
+        df.lazy().sink_parquet(f, row_group_size=250, bloom_filter=0.001)
+    else:
+        df.write_parquet(f, row_group_size=250, bloom_filter=0.001)
+
+    f.seek(0)
+    assert pq.ParquetFile(f).metadata.num_row_groups == 4
+
+    f.seek(0)
+    capfd.readouterr()
+    result = pl.scan_parquet(f).filter(pl.col("id") == 5).collect()
+    assert_frame_equal(result, df.filter(pl.col("id") == 5))
+    assert "Bloom filter pushdown: reading 1 / 4 row groups" in capfd.readouterr().err
+
+
+def test_bloom_filter_write_option_invalid() -> None:
+    with pytest.raises(ValueError, match="bloom_filter"):
+        pl.DataFrame({"a": [1]}).write_parquet(io.BytesIO(), bloom_filter=1.5)
@@ -1,10 +1,12 @@
 import io
+from typing import Any
 
 import pyarrow.parquet as pq
 import pytest
 
 import polars as pl
 from polars.io.parquet import ParquetFieldOverwrites
+from polars.testing import assert_frame_equal
 
 
 def test_required_flat() -> None:
@@ -141,3 +143,62 @@ def test_required_struct() -> None:
     schema = pq.read_schema(f)
     assert not schema.field(0).nullable
     assert not schema.field(0).type.fields[0].nullable
+
+
+def test_bloom_filter_pruning(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    # Every row group spans the whole range of values, so only the bloom filter
+    # can tell which row groups contain a value.
+    df = (
+        pl.DataFrame({"id": pl.int_range(1000, eager=True)})
+        .sort(pl.col("id") % 4, maintain_order=True)
+        .with_columns(s=pl.format("id{}", "id"))
+    )
+
+    f = io.BytesIO()
+    df.lazy().sink_parquet(
+        f,
+        row_group_size=250,
+        field_overwrites=[
+            ParquetFieldOverwrites(
+                name="id", bloom_filter=True, bloom_filter_fpp=0.001
+            ),
+            ParquetFieldOverwrites(
+                name="s", bloom_filter=True, bloom_filter_fpp=0.001
+            ),
+        ],
+    )
+    f.seek(0)
+    assert pq.ParquetFile(f).metadata.num_row_groups == 4
+
+    for predicate, num_read in [
+        (pl.col("id") == 5, 1),
+        (pl.col("s").is_in(["id5", "id6"]), 2),
+        (pl.col("s") == "id5x", 0),
+    ]:
+        f.seek(0)
+        capfd.readouterr()
+        result = pl.scan_parquet(f).filter(predicate).collect()
+        assert_frame_equal(result, df.filter(predicate))
+
+        captured = capfd.readouterr().err
+        assert f"Bloom filter pushdown: reading {num_read} / 4 row groups" in captured
+
+
+def test_bloom_filter_floats_not_pruned() -> None:
+    # -0.0 == 0.0, but their bit patterns differ
+    df = pl.DataFrame({"x": [-0.0, 1.5]})
+
+    f = io.BytesIO()
+    df.lazy().sink_parquet(
+        f, field_overwrites=ParquetFieldOverwrites(name="x", bloom_filter=True)
+    )
+
+    f.seek(0)
+    assert pl.scan_parquet(f).filter(pl.col("x") == 0.0).collect().height == 1
+
+
+def test_bloom_filter_invalid_fpp() -> None:
+    with pytest.raises(ValueError, match="bloom_filter_fpp"):
+        ParquetFieldOverwrites(name="a", bloom_filter=True, bloom_filter_fpp=1.5)
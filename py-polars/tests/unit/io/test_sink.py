@@ -1,4 +1,5 @@
 import io
+import zlib
 from pathlib import Path
 from typing import Any
 
@@ -169,3 +170,63 @@ def test_sink_null_upcast(scan: Any, sink: Any) -> None:
         scan([f, g], **scan_kwargs).collect(),
         pl.concat([df1, df2]),
     )
+
+
+@pytest.mark.parametrize(
+    ("read", "sink"),
+    [
+        (pl.read_csv, pl.LazyFrame.sink_csv),
+        (pl.read_ndjson, pl.LazyFrame.sink_ndjson),
+    ],
+)
+@pytest.mark.parametrize("compression", ["gzip", "zstd"])
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_sink_compressed(
+    read: Any, sink: Any, compression: str, engine: EngineType
+) -> None:
+    # Enough rows to be split over multiple morsels by the streaming engine.
+    df = pl.DataFrame({"a": range(100_000), "b": ["x", "yy", "zzz", None] * 25_000})
+
+    f = io.BytesIO()
+    sink(df.lazy(), f, compression=compression, engine=engine)
+    f.seek(0)
+    magic = {"gzip": b"\x1f\x8b", "zstd": b"\x28\xb5\x2f\xfd"}[compression]
+    assert f.read(len(magic)) == magic
+
+    f.seek(0)
+    assert_frame_equal(read(f), df)
+
+
+@pytest.mark.parametrize("sink", [pl.LazyFrame.sink_csv, pl.LazyFrame.sink_ndjson])
+def test_sink_gzip_single_member(sink: Any) -> None:
+    df = pl.DataFrame({"a": range(100_000)})
+
+    f = io.BytesIO()
+    sink(df.lazy(), f, compression="gzip", engine="streaming")
+
+    # A gzip decoder stops after the first member.
+    decoder = zlib.decompressobj(wbits=31)
+    decoder.decompress(f.getvalue())
+    assert decoder.eof
+    assert decoder.unused_data == b""
+
+
+@pytest.mark.write_disk
+def test_sink_compressed_partitioned(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+    df.lazy().sink_csv(
+        pl.PartitionMaxSize(tmp_path, max_size=2),
+        compression="gzip",
+        engine="streaming",
+    )
+
+    files = sorted(tmp_path.iterdir())
+    assert len(files) == 3
+    assert all(f.name.endswith(".csv.gz") for f in files)
+    assert_frame_equal(pl.concat([pl.read_csv(f) for f in files]), df)
+
+
+def test_sink_compression_level_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="compression level"):
+        lf.sink_csv(io.BytesIO(), compression="gzip", compression_level=10)
@@ -638,8 +638,9 @@ def test_rolling_var_zero_weight() -> None:
 def test_rolling_unsupported_22065() -> None:
     with pytest.raises(pl.exceptions.InvalidOperationError):
         pl.Series("a", [[]]).rolling_sum(10)
-    with pytest.raises(pl.exceptions.InvalidOperationError):
-        pl.Series("a", ["1.0"], pl.Decimal).rolling_min(1)
+    # Decimals are supported now and keep their type.
+    s = pl.Series("a", ["1.0"], pl.Decimal)
+    assert_series_equal(s.rolling_min(1), s)
     with pytest.raises(pl.exceptions.InvalidOperationError):
         pl.Series("a", [None]).rolling_sum(10)
     with pytest.raises(pl.exceptions.InvalidOperationError):
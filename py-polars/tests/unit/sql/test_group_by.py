@@ -6,7 +6,7 @@ from pathlib import Path
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLSyntaxError
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -302,3 +302,173 @@ def test_group_by_struct_cat_24049(maintain_order: bool) -> None:
         expected,
         check_row_order=maintain_order,
     )
+
+
+@pytest.fixture
+def df_sales() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "year": [2018, 2018, 2019, 2019],
+            "country": ["US", "UK", "US", "UK"],
+            "sales": [1, 2, 3, 4],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    "group_by",
+    [
+        "ROLLUP (year, country)",
+        "GROUPING SETS ((year, country), (year), ())",
+        "year, country WITH ROLLUP",
+    ],
+)
+def test_group_by_rollup(df_sales: pl.DataFrame, group_by: str) -> None:
+    res = df_sales.sql(
+        f"""
+        SELECT
+          year,
+          country,
+          SUM(sales) AS sales,
+          GROUPING(year, country) AS g,
+          GROUPING(country) AS g_country
+        FROM self
+        GROUP BY {group_by}
+        """
+    ).sort("year", "country", nulls_last=True)
+
+    expected = pl.DataFrame(
+        {
+            "year": [2018, 2018, 2018, 2019, 2019, 2019, None],
+            "country": ["UK", "US", None, "UK", "US", None, None],
+            "sales": [2, 1, 3, 4, 3, 7, 10],
+            "g": [0, 0, 1, 0, 0, 1, 3],
+            "g_country": [0, 0, 1, 0, 0, 1, 1],
+        },
+        schema_overrides={"g": pl.Int32, "g_country": pl.Int32},
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_group_by_cube(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT year, country, SUM(sales) AS sales
+        FROM self
+        GROUP BY CUBE (year, country)
+        """
+    ).sort("year", "country", nulls_last=True)
+
+    expected = pl.DataFrame(
+        {
+            "year": [2018, 2018, 2018, 2019, 2019, 2019, None, None, None],
+            "country": ["UK", "US", None, "UK", "US", None, "UK", "US", None],
+            "sales": [2, 1, 3, 4, 3, 7, 6, 4, 10],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_group_by_grouping_sets(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT year, country, SUM(sales) AS sales, COUNT(*) AS n
+        FROM self
+        GROUP BY GROUPING SETS ((year), (country))
+        """
+    ).sort("year", "country", nulls_last=True)
+
+    expected = pl.DataFrame(
+        {
+            "year": [2018, 2019, None, None],
+            "country": [None, None, "UK", "US"],
+            "sales": [3, 7, 6, 4],
+            "n": [2, 2, 2, 2],
+        },
+        schema_overrides={"n": pl.UInt32},
+    )
+    assert_frame_equal(res, expected)
+
+    # plain keys are part of every grouping set
+    res = df_sales.sql(
+        """
+        SELECT year, country, SUM(sales) AS sales
+        FROM self
+        GROUP BY year, ROLLUP (country)
+        """
+    ).sort("year", "country", nulls_last=True)
+
+    expected = pl.DataFrame(
+        {
+            "year": [2018, 2018, 2018, 2019, 2019, 2019],
+            "country": ["UK", "US", None, "UK", "US", None],
+            "sales": [2, 1, 3, 4, 3, 7],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_group_by_aggregate_modifiers() -> None:
+    df = pl.DataFrame(
+        {
+            "grp": ["a", "a", "b", "b", "b"],
+            "status": ["ok", "err", "ok", "ok", "err"],
+            "latency": [10, 20, 30, 40, 50],
+            "name": ["x", "y", "z", "w", "v"],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT
+          grp,
+          COUNT(*) FILTER (WHERE status = 'ok') AS n_ok,
+          SUM(latency) FILTER (WHERE status = 'err') AS err_latency,
+          PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY latency) AS p50,
+          PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY latency) AS p50_disc,
+          PERCENTILE_CONT(0.25) WITHIN GROUP (ORDER BY latency DESC) AS p75,
+          STRING_AGG(name, ',') WITHIN GROUP (ORDER BY latency DESC) AS names
+        FROM self
+        GROUP BY grp
+        ORDER BY grp
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "grp": ["a", "b"],
+            "n_ok": [1, 2],
+            "err_latency": [20, 50],
+            "p50": [15.0, 40.0],
+            "p50_disc": [10.0, 40.0],
+            "p75": [17.5, 45.0],
+            "names": ["y,x", "v,w,z"],
+        },
+        schema_overrides={"n_ok": pl.UInt32},
+    )
+    assert_frame_equal(res, expected)
+
+
+@pytest.mark.parametrize(
+    ("query", "error"),
+    [
+        (
+            "SELECT PERCENTILE_CONT(0.5) FROM self",
+            "requires a WITHIN GROUP",
+        ),
+        (
+            "SELECT PERCENTILE_CONT(1.5) WITHIN GROUP (ORDER BY x) FROM self",
+            "must be between 0 and 1",
+        ),
+        (
+            "SELECT SUM(x) WITHIN GROUP (ORDER BY x) FROM self",
+            "'WITHIN GROUP' is not supported",
+        ),
+        (
+            "SELECT SUM(x) IGNORE NULLS FROM self",
+            "'IGNORE|RESPECT NULLS' is not supported",
+        ),
+    ],
+)
+def test_group_by_aggregate_modifiers_errors(query: str, error: str) -> None:
+    df = pl.DataFrame({"x": [1, 2, 3]})
+    with pytest.raises((SQLInterfaceError, SQLSyntaxError), match=error):
+        df.sql(query)
@@ -309,49 +309,94 @@ def test_join_misc_16255() -> None:
 
 
 @pytest.mark.parametrize(
-    "constraint", ["tbl.a != tbl.b", "tbl.a > tbl.b", "a >= b", "a < b", "b <= a"]
+    ("join_type", "expected"),
+    [
+        ("INNER JOIN", [(1, 3), (2, 7), (2, 8)]),
+        ("LEFT JOIN", [(1, 3), (2, 7), (2, 8), (3, None)]),
+        ("RIGHT JOIN", [(1, 3), (2, 7), (2, 8), (None, 20)]),
+        ("FULL JOIN", [(1, 3), (2, 7), (2, 8), (3, None), (None, 20)]),
+    ],
 )
-def test_non_equi_joins(constraint: str) -> None:
-    # no support (yet) for non equi-joins in polars joins
-    # TODO: integrate awareness of new IEJoin
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"only equi-join constraints \(combined with 'AND'\) are currently supported",
-        ),
-        pl.SQLContext({"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})}) as ctx,
-    ):
-        ctx.execute(
+def test_non_equi_joins(join_type: str, expected: list[tuple[Any, ...]]) -> None:
+    ranges = pl.DataFrame({"id": [1, 2, 3], "lo": [0, 5, 10], "hi": [4, 9, 14]})
+    points = pl.DataFrame({"v": [3, 7, 8, 20]})
+
+    with pl.SQLContext(ranges=ranges, points=points) as ctx:
+        res = ctx.execute(
             f"""
-            SELECT *
-            FROM tbl
-            LEFT JOIN tbl ON {constraint}  -- not an equi-join
+            SELECT r.id, p.v
+            FROM ranges r
+            {join_type} points p ON p.v BETWEEN r.lo AND r.hi
+            ORDER BY id NULLS LAST, v
+            """,
+            eager=True,
+        )
+    assert res.rows() == expected
+
+
+@pytest.mark.parametrize(
+    ("join_type", "expected_ids"),
+    [
+        ("LEFT SEMI JOIN", [1, 2]),
+        ("LEFT ANTI JOIN", [3]),
+    ],
+)
+def test_non_equi_joins_anti_semi(join_type: str, expected_ids: list[int]) -> None:
+    ranges = pl.DataFrame({"id": [1, 2, 3], "lo": [0, 5, 10], "hi": [4, 9, 14]})
+    points = pl.DataFrame({"v": [3, 7, 8, 20]})
+
+    with pl.SQLContext(ranges=ranges, points=points) as ctx:
+        res = ctx.execute(
+            f"""
+            SELECT id FROM ranges r
+            {join_type} points p ON p.v >= r.lo AND p.v <= r.hi
+            ORDER BY id
+            """,
+            eager=True,
+        )
+    assert res["id"].to_list() == expected_ids
+
+
+def test_non_equi_joins_self_join() -> None:
+    # predicates with OR/expressions, and clashing column names
+    with pl.SQLContext({"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})}) as ctx:
+        res = ctx.execute(
             """
+            SELECT t1.a, t2.a AS a2
+            FROM tbl t1
+            JOIN tbl t2 ON t1.a = t2.b OR t1.a + t2.a = 2
+            ORDER BY t1.a, a2
+            """,
+            eager=True,
         )
+    assert res.rows() == [(1, 1), (2, 3), (3, 2)]
 
 
 def test_implicit_joins() -> None:
-    # no support for this yet; ensure we catch it
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"not currently supported .* use explicit JOIN syntax instead",
-        ),
-        pl.SQLContext(
-            {
-                "tbl": pl.DataFrame(
-                    {"a": [1, 2, 3], "b": [4, 3, 2], "c": ["x", "y", "z"]}
-                )
-            }
-        ) as ctx,
-    ):
-        ctx.execute(
+    with pl.SQLContext(
+        {"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2], "c": ["x", "y", "z"]})}
+    ) as ctx:
+        res = ctx.execute(
             """
-            SELECT t1.*
+            SELECT t1.*, t2.c AS c2
             FROM tbl AS t1, tbl AS t2
             WHERE t1.a = t2.b
+            ORDER BY t1.a
+            """,
+            eager=True,
+        )
+        assert res.rows() == [(2, 3, "y", "z"), (3, 2, "z", "y")]
+
+        # implicit joins can be combined with explicit joins
+        res = ctx.execute(
             """
+            SELECT t1.a, t2.c, t3.b
+            FROM tbl t1, tbl t2 JOIN tbl t3 ON t2.a = t3.a
+            WHERE t1.a < t2.b AND t1.c = 'z'
+            """,
+            eager=True,
         )
+        assert res.rows() == [(3, "x", 4)]
 
 
 @pytest.mark.parametrize(
@@ -145,11 +145,25 @@ def test_fixed_intervals() -> None:
         ):
             ctx.execute("SELECT INTERVAL -'7d' AS one_week_ago FROM df")
 
-        with pytest.raises(
-            SQLSyntaxError,
-            match="fixed-duration interval cannot contain years, quarters, or months",
-        ):
-            ctx.execute("SELECT INTERVAL '1 quarter 1 month' AS q FROM df")
+
+def test_calendar_intervals() -> None:
+    out = pl.sql(
+        """
+        SELECT
+          INTERVAL '1 quarter 1 month' AS i1,
+          INTERVAL '1 year, 2 weeks, 3 days, 4 hours' AS i2
+        """
+    ).collect()
+    assert out.schema == {"i1": pl.Interval, "i2": pl.Interval}
+    assert out.row(0) == (
+        (4, 0, 0),
+        (12, 17, 4 * 3_600_000_000_000),
+    )
+
+    df = pl.DataFrame({"dt": [date(2024, 1, 31)]}).with_columns(
+        iv=pl.sql_expr("INTERVAL '1 month'")
+    )
+    assert df.select(pl.col("dt") + pl.col("iv")).item() == date(2024, 2, 29)
 
 
 def test_interval_offsets() -> None:
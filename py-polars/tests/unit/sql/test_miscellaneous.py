@@ -12,6 +12,7 @@ from polars.testing import assert_frame_equal
 from tests.unit.utils.pycapsule_utils import PyCapsuleStreamHolder
 
 if TYPE_CHECKING:
+    from polars._typing import PivotAgg
     from polars.datatypes import DataType
 
 
@@ -300,6 +301,99 @@ def test_nested_cte_column_aliasing() -> None:
     }
 
 
+def test_recursive_cte() -> None:
+    df = pl.sql(
+        """
+        WITH RECURSIVE series (n) AS (
+          SELECT 1
+          UNION ALL
+          SELECT n + 1 FROM series WHERE n < 5
+        )
+        SELECT n FROM series ORDER BY n
+        """,
+        eager=True,
+    )
+    assert df["n"].to_list() == [1, 2, 3, 4, 5]
+
+
+def test_recursive_cte_hierarchy() -> None:
+    employees = pl.DataFrame(  # noqa: F841
+        {
+            "id": [1, 2, 3, 4, 5],
+            "name": ["Alice", "Bob", "Carol", "Dave", "Eve"],
+            "manager_id": [None, 1, 1, 2, 4],
+        }
+    )
+    df = pl.sql(
+        """
+        WITH RECURSIVE chain AS (
+          SELECT id, name, 0 AS depth FROM employees WHERE manager_id IS NULL
+          UNION ALL
+          SELECT e.id, e.name, c.depth + 1
+          FROM employees e JOIN chain c ON e.manager_id = c.id
+        )
+        SELECT name, depth FROM chain ORDER BY depth, name
+        """,
+        eager=True,
+    )
+    assert df.rows() == [
+        ("Alice", 0),
+        ("Bob", 1),
+        ("Carol", 1),
+        ("Dave", 2),
+        ("Eve", 3),
+    ]
+
+
+def test_recursive_cte_union_distinct() -> None:
+    # the cycle 1 -> 2 -> 3 -> 1 only terminates because UNION discards found rows
+    edges = pl.DataFrame({"src": [1, 2, 3, 3], "dst": [2, 3, 1, 4]})  # noqa: F841
+    df = pl.sql(
+        """
+        WITH RECURSIVE reachable (node) AS (
+          SELECT CAST(1 AS BIGINT)
+          UNION
+          SELECT dst FROM edges JOIN reachable ON edges.src = reachable.node
+        ),
+        doubled AS (SELECT node * 2 AS node FROM reachable)
+        SELECT node FROM doubled ORDER BY node
+        """,
+        eager=True,
+    )
+    assert df["node"].to_list() == [2, 4, 6, 8]
+
+
+def test_recursive_cte_column_named_like_cte() -> None:
+    # only reading from the CTE makes it recursive, not a column with the same name
+    nums = pl.DataFrame({"n": [1, 2]})  # noqa: F841
+    df = pl.sql(
+        """
+        WITH RECURSIVE total AS (
+          SELECT CAST(0 AS BIGINT) AS total
+          UNION ALL
+          SELECT n AS total FROM nums
+        )
+        SELECT total FROM total ORDER BY total
+        """,
+        eager=True,
+    )
+    assert df["total"].to_list() == [0, 1, 2]
+
+
+def test_recursive_cte_errors() -> None:
+    with pytest.raises(SQLInterfaceError, match="cannot refer to itself"):
+        pl.sql(
+            """
+            WITH RECURSIVE t (n) AS (
+              SELECT n FROM t
+              UNION ALL
+              SELECT n + 1 FROM t
+            )
+            SELECT * FROM t
+            """
+        )
+
+
 def test_invalid_derived_table_column_aliases() -> None:
     values_query = "SELECT * FROM (VALUES (1,2), (3,4))"
 
@@ -540,3 +634,357 @@ def test_count_partition_22665(query: str, result: list[Any]) -> None:
     out = df.sql(query).select("b")
     expected = pl.DataFrame({"b": result}).cast({"b": pl.UInt32})
     assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize(
+    ("frame", "result"),
+    [
+        (
+            "SUM(x) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)",
+            [7, 10, 1, 1, 40, 3],
+        ),
+        (
+            "SUM(x) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN CURRENT ROW AND 1 FOLLOWING)",
+            [4, 40, 1, 3, 30, 7],
+        ),
+        (
+            "SUM(x) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING)",
+            [4, None, None, 1, 10, 1],
+        ),
+        (
+            "SUM(x) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN 1 FOLLOWING AND UNBOUNDED FOLLOWING)",
+            [None, 30, 7, 7, None, 4],
+        ),
+        (
+            "SUM(x) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING)",
+            [8, 40, 8, 8, 40, 8],
+        ),
+        (
+            "COUNT(x) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING)",
+            [2, 2, 1, 2, 2, 2],
+        ),
+        (
+            "COUNT(*) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING)",
+            [2, 0, 0, 1, 1, 2],
+        ),
+        (
+            "AVG(x) OVER (PARTITION BY g ORDER BY t ROWS UNBOUNDED PRECEDING)",
+            [8 / 3, 10.0, 1.0, 1.0, 20.0, 2.0],
+        ),
+        (
+            "MIN(x) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING)",
+            [3, 10, 1, 1, 10, 3],
+        ),
+        (
+            "MAX(x) OVER (PARTITION BY g ORDER BY t DESC ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING)",
+            [4, 10, 1, 1, 30, 3],
+        ),
+    ],
+)
+def test_window_frame_rows(frame: str, result: list[Any]) -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "a", "b", "a"],
+            "t": [4, 1, 1, 2, 3, 3],
+            "x": [4, 10, 1, None, 30, 3],
+        }
+    )
+    out = df.sql(f"SELECT {frame} AS y FROM self")
+    assert out.to_series().to_list() == result
+
+
+def test_window_frame_range() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 1, 2),
+                date(2024, 1, 4),
+                date(2024, 1, 7),
+            ],
+            "t": [1, 2, 2, 3, 5],
+            "x": [1, 2, 3, 4, 5],
+        }
+    )
+    out = df.sql(
+        """
+        SELECT
+          SUM(x) OVER (
+            ORDER BY dt RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW
+          ) AS sum_2d,
+          MAX(x) OVER (
+            ORDER BY dt RANGE BETWEEN INTERVAL '1 day' PRECEDING AND CURRENT ROW
+          ) AS max_1d,
+          SUM(x) OVER (
+            ORDER BY dt RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+          ) AS sum_cumulative,
+          COUNT(*) OVER (
+            ORDER BY t RANGE BETWEEN 1 PRECEDING AND CURRENT ROW
+          ) AS n_1
+        FROM self
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "sum_2d": [1, 6, 6, 9, 5],
+            "max_1d": [1, 3, 3, 4, 5],
+            "sum_cumulative": [1, 6, 6, 10, 15],
+            "n_1": [1, 3, 3, 3, 1],
+        },
+        schema_overrides={"n_1": pl.UInt32},
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize(
+    "frame",
+    [
+        "SUM(x) OVER (ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)",
+        "SUM(x) OVER (ORDER BY t GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW)",
+        "SUM(x) OVER (ORDER BY t RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING)",
+        "SUM(x) OVER (ORDER BY t DESC RANGE BETWEEN 1 PRECEDING AND CURRENT ROW)",
+        "SUM(x) OVER (ORDER BY t ASC, x DESC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)",
+    ],
+)
+def test_window_frame_unsupported(frame: str) -> None:
+    df = pl.DataFrame({"t": [1, 2, 3], "x": [1, 2, 3]})
+    with pytest.raises(SQLInterfaceError):
+        df.sql(f"SELECT {frame} AS y FROM self")
+
+
+def test_ranking_window_functions() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "a", "b", "b"],
+            "t": [3, 1, 1, 2, 5, 2],
+            "x": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    out = df.sql(
+        """
+        SELECT
+          ROW_NUMBER() OVER (PARTITION BY g ORDER BY t, x) AS rn,
+          RANK() OVER (PARTITION BY g ORDER BY t) AS rnk,
+          DENSE_RANK() OVER (PARTITION BY g ORDER BY t) AS dense_rnk,
+          ROW_NUMBER() OVER (ORDER BY x DESC) AS rn_desc
+        FROM self
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "rn": [4, 1, 2, 3, 2, 1],
+            "rnk": [4, 1, 1, 3, 2, 1],
+            "dense_rnk": [3, 1, 1, 2, 2, 1],
+            "rn_desc": [6, 5, 4, 3, 2, 1],
+        },
+        schema=dict.fromkeys(["rn", "rnk", "dense_rnk", "rn_desc"], pl.UInt32),
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(SQLSyntaxError, match="requires an OVER clause"):
+        df.sql("SELECT RANK() AS r FROM self")
+
+
+def test_qualify() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "a", "b", "b"],
+            "t": [3, 1, 1, 2, 5, 2],
+            "x": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    out = df.sql(
+        """
+        SELECT g, x FROM self
+        QUALIFY ROW_NUMBER() OVER (PARTITION BY g ORDER BY x DESC) = 1
+        ORDER BY g
+        """
+    )
+    assert out.to_dict(as_series=False) == {"g": ["a", "b"], "x": [4, 6]}
+
+    # QUALIFY can refer to the (aliased) window functions of the projection
+    out = df.sql(
+        """
+        SELECT g, x, RANK() OVER (PARTITION BY g ORDER BY t) AS r FROM self
+        QUALIFY r = 1
+        ORDER BY x
+        """
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": ["a", "a", "b"],
+        "x": [2, 3, 6],
+        "r": [1, 1, 1],
+    }
+
+
+def test_pivot() -> None:
+    sales = pl.DataFrame(
+        {
+            "region": ["N", "N", "S", "S", "N"],
+            "quarter": ["Q1", "Q2", "Q1", "Q1", "Q1"],
+            "amount": [10, 20, 30, 40, 50],
+        }
+    )
+    with pl.SQLContext(sales=sales) as ctx:
+        out = ctx.execute(
+            """
+            SELECT * FROM sales
+            PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2', 'Q3'))
+            ORDER BY region
+            """
+        )
+        # the pivot values are known up front, so the plan stays lazy
+        assert isinstance(out, pl.LazyFrame)
+        assert_frame_equal(
+            out.collect(),
+            pl.DataFrame(
+                {
+                    "region": ["N", "S"],
+                    "Q1": [60, 70],
+                    "Q2": [20, None],
+                    "Q3": [None, None],
+                },
+                schema_overrides={"Q3": pl.Int64},
+            ),
+        )
+
+        out = ctx.execute(
+            """
+            SELECT p.* FROM sales
+            PIVOT (
+              SUM(amount) AS total, COUNT(*) AS n
+              FOR quarter IN ('Q1' AS q1, 'Q2' AS q2)
+            ) AS p
+            ORDER BY p.region
+            """,
+            eager=True,
+        )
+        assert out.to_dict(as_series=False) == {
+            "region": ["N", "S"],
+            "q1_total": [60, 70],
+            "q1_n": [2, 2],
+            "q2_total": [20, None],
+            "q2_n": [1, None],
+        }
+
+        with pytest.raises(SQLInterfaceError, match="explicit list of values"):
+            ctx.execute(
+                """
+                SELECT * FROM sales
+                PIVOT (SUM(amount) FOR quarter IN (SELECT DISTINCT quarter FROM sales))
+                """
+            )
+
+
+@pytest.mark.parametrize(
+    ("sql_agg", "agg"), [("SUM", "sum"), ("MAX", "max"), ("FIRST", "first")]
+)
+def test_pivot_matches_dataframe_pivot(sql_agg: str, agg: PivotAgg) -> None:
+    df = pl.DataFrame(
+        {
+            "region": ["N", "S", None, "N", "S", "N", None, "W"],
+            "quarter": ["Q2", "Q1", "Q1", "Q2", "Q3", "Q1", "Q2", None],
+            "amount": [10, None, 5, 20, None, 30, None, 1],
+        }
+    )
+    # the SQL values can't match a null, so it has no column for it
+    expected = df.pivot(
+        "quarter", index="region", values="amount", aggregate_function=agg
+    ).drop("null")
+
+    out = df.sql(
+        f"SELECT * FROM self PIVOT ({sql_agg}(amount) "
+        "FOR quarter IN ('Q2', 'Q1', 'Q3'))"
+    )
+    assert_frame_equal(out, expected)
+
+    # the columns follow the order of the values
+    out = df.sql(
+        f"SELECT * FROM self PIVOT ({sql_agg}(amount) FOR quarter IN ('Q3', 'Q1'))"
+    )
+    assert_frame_equal(out, expected.select("region", "Q3", "Q1"))
+
+
+def test_unpivot() -> None:
+    df = pl.DataFrame({"id": [1, 2], "q1": [10, None], "q2": [20, 30]})
+    out = df.sql(
+        """
+        SELECT * FROM self
+        UNPIVOT (amount FOR quarter IN (q1, q2))
+        ORDER BY id, quarter
+        """
+    )
+    assert out.to_dict(as_series=False) == {
+        "id": [1, 1, 2],
+        "quarter": ["q1", "q2", "q2"],
+        "amount": [10, 20, 30],
+    }
+
+
+def test_first_last_value_ignore_nulls() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b"],
+            "t": [1, 2, 3, 1, 2],
+            "x": [None, 2, None, 4, None],
+        }
+    )
+    out = df.sql(
+        """
+        SELECT
+          LAST_VALUE(x) OVER (PARTITION BY g ORDER BY t) AS last_x,
+          LAST_VALUE(x IGNORE NULLS) OVER (PARTITION BY g ORDER BY t) AS filled_x,
+          FIRST_VALUE(x) IGNORE NULLS OVER (PARTITION BY g ORDER BY t) AS first_x,
+          LAST_VALUE(x IGNORE NULLS) OVER (PARTITION BY g) AS group_last_x,
+          FIRST(x IGNORE NULLS) OVER (PARTITION BY g) AS group_first_x
+        FROM self
+        """
+    )
+    assert out.to_dict(as_series=False) == {
+        "last_x": [None, 2, None, 4, None],
+        "filled_x": [None, 2, 2, 4, 4],
+        "first_x": [None, 2, 2, 4, 4],
+        "group_last_x": [2, 2, 2, 4, 4],
+        "group_first_x": [2, 2, 2, 4, 4],
+    }
+
+
+def test_first_value_ignore_nulls_leading_nulls() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "b", "b"],
+            "t": [1, 2, 3, 1, 1, 2, 3],
+            "x": [None, None, 3, None, 5, None, 7],
+        }
+    )
+    out = df.sql(
+        """
+        SELECT
+          g,
+          FIRST_VALUE(x) IGNORE NULLS OVER (PARTITION BY g ORDER BY t) AS first_x,
+          FIRST_VALUE(x) IGNORE NULLS OVER (PARTITION BY g ORDER BY t DESC) AS desc_x
+        FROM self
+        """
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": ["a", "a", "a", "b", "b", "b", "b"],
+        # the peers of a row are part of its frame
+        "first_x": [None, None, 3, 5, 5, 5, 5],
+        "desc_x": [3, 3, 3, 7, 7, 7, 7],
+    }
+
+
+def test_aggregate_filter_over_partition() -> None:
+    df = pl.DataFrame({"g": ["a", "a", "b"], "x": [1, 2, 3]})
+    out = df.sql(
+        """
+        SELECT g, x, SUM(x) FILTER (WHERE x > 1) OVER (PARTITION BY g) AS s
+        FROM self
+        """
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": ["a", "a", "b"],
+        "x": [1, 2, 3],
+        "s": [2, 2, 3],
+    }
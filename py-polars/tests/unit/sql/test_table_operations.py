@@ -1,12 +1,18 @@
 from __future__ import annotations
 
 import re
+from pathlib import Path
 from datetime import date
 
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError
+from polars.exceptions import (
+    ColumnNotFoundError,
+    DuplicateError,
+    SQLInterfaceError,
+    SQLSyntaxError,
+)
 from polars.testing import assert_frame_equal
 
 
@@ -117,3 +123,297 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_insert_into(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute("INSERT INTO frame VALUES (4, 'ddd', '2024-01-01')")
+        ctx.execute("INSERT INTO frame (y, x) SELECT y, x + 10 FROM frame WHERE x < 2")
+        res = ctx.execute("SELECT * FROM frame")
+
+    expected = pl.DataFrame(
+        {
+            "x": [1, 2, 3, 4, 11],
+            "y": ["aaa", "bbb", "ccc", "ddd", "aaa"],
+            "z": [
+                date(2000, 12, 31),
+                date(1978, 11, 15),
+                date(2077, 10, 20),
+                date(2024, 1, 1),
+                None,
+            ],
+        },
+        schema_overrides={"x": pl.UInt8},
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_insert_into_errors(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame) as ctx:
+        with pytest.raises(SQLSyntaxError, match="expects 3 values per row; found 2"):
+            ctx.execute("INSERT INTO frame VALUES (4, 'ddd')")
+        with pytest.raises(SQLInterfaceError, match="column 'w' does not exist"):
+            ctx.execute("INSERT INTO frame (w) VALUES (4)")
+        with pytest.raises(SQLInterfaceError, match="table 'missing' does not exist"):
+            ctx.execute("INSERT INTO missing VALUES (4)")
+
+
+@pytest.mark.parametrize(
+    ("update_sql", "expected_y"),
+    [
+        ("UPDATE frame SET y = 'zzz'", ["zzz", "zzz", "zzz"]),
+        ("UPDATE frame SET y = 'zzz' WHERE x >= 2", ["aaa", "zzz", "zzz"]),
+        (
+            "UPDATE frame AS f SET y = UPPER(f.y) WHERE f.x = 2",
+            ["aaa", "BBB", "ccc"],
+        ),
+        (
+            "UPDATE frame SET y = y || y WHERE z > '2000-01-01'",
+            ["aaaaaa", "bbb", "cccccc"],
+        ),
+        ("UPDATE frame SET y = 'zzz' WHERE FALSE", ["aaa", "bbb", "ccc"]),
+    ],
+)
+def test_update(
+    update_sql: str,
+    expected_y: list[str],
+    test_frame: pl.LazyFrame,
+) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute(update_sql)
+        res = ctx.execute("SELECT * FROM frame")
+
+    assert res["y"].to_list() == expected_y
+    assert_frame_equal(res.drop("y"), test_frame.collect().drop("y"))
+
+
+def test_update_multiple_columns(test_frame: pl.LazyFrame) -> None:
+    # all assignments see the values from before the update
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute("UPDATE frame SET x = x + 1, y = CAST(x AS VARCHAR)")
+
+    assert res.schema == test_frame.collect_schema()
+    assert res.select("x", "y").rows() == [(2, "1"), (3, "2"), (4, "3")]
+
+
+def test_merge_into() -> None:
+    target = pl.DataFrame({"id": [1, 2, 3], "value": ["a", "b", "c"]})
+    source = pl.DataFrame({"id": [2, 3, 4], "value": ["bb", None, "dd"]})
+
+    with pl.SQLContext(target=target, source=source, eager=True) as ctx:
+        ctx.execute(
+            """
+            MERGE INTO target AS t
+            USING source AS s ON t.id = s.id
+            WHEN MATCHED AND s.value IS NULL THEN DELETE
+            WHEN MATCHED THEN UPDATE SET value = s.value
+            WHEN NOT MATCHED THEN INSERT (id, value) VALUES (s.id, s.value)
+            """
+        )
+        res = ctx.execute("SELECT * FROM target")
+
+    assert res.rows() == [(1, "a"), (2, "bb"), (4, "dd")]
+
+
+def test_merge_into_not_matched_by_source() -> None:
+    target = pl.DataFrame({"id": [1, 2, 3], "n": [10, 20, 30]})
+    source = pl.DataFrame({"id": [1, 2], "n": [1, 2]})
+
+    with pl.SQLContext(target=target, source=source, eager=True) as ctx:
+        res = ctx.execute(
+            """
+            MERGE INTO target
+            USING source ON target.id = source.id
+            WHEN MATCHED THEN UPDATE SET n = target.n + source.n
+            WHEN NOT MATCHED BY SOURCE THEN UPDATE SET n = 0
+            """
+        )
+
+    assert res.rows() == [(1, 11), (2, 22), (3, 0)]
+
+
+def test_merge_into_errors() -> None:
+    target = pl.DataFrame({"id": [1], "n": [10]})  # noqa: F841
+    source = pl.DataFrame({"id": [1], "n": [1]})  # noqa: F841
+
+    with pl.SQLContext(target=target, source=source) as ctx:
+        with pytest.raises(SQLInterfaceError, match="table 'missing' does not exist"):
+            ctx.execute(
+                """
+                MERGE INTO missing USING source ON missing.id = source.id
+                WHEN MATCHED THEN DELETE
+                """
+            )
+        with pytest.raises(SQLInterfaceError, match="column 'x' does not exist"):
+            ctx.execute(
+                """
+                MERGE INTO target USING source ON target.id = source.id
+                WHEN MATCHED THEN UPDATE SET x = source.n
+                """
+            )
+
+
+def test_merge_into_multiple_matches() -> None:
+    target = pl.DataFrame({"id": [1, 2], "n": [10, 20]})
+    source = pl.DataFrame({"id": [1, 1, 3], "n": [1, 2, 3]})
+
+    for clause in [
+        "WHEN MATCHED THEN UPDATE SET n = source.n",
+        "WHEN NOT MATCHED THEN INSERT VALUES (source.id, source.n)",
+    ]:
+        with pl.SQLContext(target=target, source=source) as ctx:
+            # the check is part of the plan, so it only fails when collecting
+            lf = ctx.execute(
+                f"MERGE INTO target USING source ON target.id = source.id {clause}"
+            )
+            with pytest.raises(
+                SQLInterfaceError, match="matches more than one source row"
+            ):
+                lf.collect()
+
+
+def test_create_view(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute("CREATE VIEW v (a, b) AS SELECT x, y FROM frame WHERE x > 1")
+        assert res.rows() == [("CREATE VIEW",)]
+        assert ctx.tables() == ["frame", "v"]
+
+        # views are planned when used, so they see changes to the underlying tables
+        ctx.execute("INSERT INTO frame VALUES (4, 'ddd', '2020-01-01')")
+        res = ctx.execute("SELECT v.b FROM v WHERE v.a < 4 ORDER BY a")
+        assert res.rows() == [("bbb",), ("ccc",)]
+        assert ctx.execute("SELECT COUNT(*) AS n FROM v").item() == 3
+
+        with pytest.raises(SQLInterfaceError, match="view 'v' already exists"):
+            ctx.execute("CREATE VIEW v AS SELECT 1 AS one")
+        ctx.execute("CREATE VIEW IF NOT EXISTS v AS SELECT 1 AS one")
+        assert ctx.execute("SELECT * FROM v").columns == ["a", "b"]
+
+        ctx.execute("CREATE OR REPLACE VIEW v AS SELECT x FROM frame WHERE x = 2")
+        assert ctx.execute("SELECT * FROM v").rows() == [(2,)]
+
+        # a view that fails to plan does not replace the existing view
+        with pytest.raises(ColumnNotFoundError):
+            ctx.execute("CREATE OR REPLACE VIEW v AS SELECT missing FROM frame")
+        assert ctx.execute("SELECT * FROM v").rows() == [(2,)]
+
+        ctx.execute("DROP VIEW v")
+        assert ctx.tables() == ["frame"]
+        ctx.execute("DROP VIEW IF EXISTS v")
+        with pytest.raises(SQLInterfaceError, match="view 'v' does not exist"):
+            ctx.execute("DROP VIEW v")
+
+
+def test_create_view_errors(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame) as ctx:
+        with pytest.raises(SQLInterfaceError, match="already exists as a table"):
+            ctx.execute("CREATE VIEW frame AS SELECT 1 AS one")
+        with pytest.raises(SQLSyntaxError, match="number of columns"):
+            ctx.execute("CREATE VIEW v (a, b) AS SELECT x FROM frame")
+
+        ctx.execute("CREATE VIEW v AS SELECT x FROM frame")
+        with pytest.raises(SQLInterfaceError, match="already exists as a view"):
+            ctx.execute("CREATE TABLE v AS SELECT 1 AS one")
+        with pytest.raises(SQLInterfaceError, match="refers to itself"):
+            ctx.execute("CREATE OR REPLACE VIEW v AS SELECT * FROM v")
+        with pytest.raises(SQLInterfaceError, match="MATERIALIZED VIEW"):
+            ctx.execute("CREATE MATERIALIZED VIEW w AS SELECT x FROM frame")
+
+
+def test_create_table_variants(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute("CREATE TEMPORARY TABLE t AS SELECT x FROM frame")
+        assert ctx.execute("SELECT * FROM t").rows() == [(1,), (2,), (3,)]
+
+        # IF NOT EXISTS keeps the existing table
+        ctx.execute("CREATE TABLE IF NOT EXISTS t AS SELECT y FROM frame")
+        assert ctx.execute("SELECT * FROM t").columns == ["x"]
+
+        # a table can also be created from column definitions
+        ctx.execute("CREATE TABLE e (id INTEGER, name VARCHAR)")
+        res = ctx.execute("SELECT * FROM e")
+        assert res.schema == {"id": pl.Int32, "name": pl.String}
+        assert res.height == 0
+
+
+def test_alter_table(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute("ALTER TABLE frame RENAME COLUMN y TO label")
+        ctx.execute("ALTER TABLE frame ADD COLUMN flag BOOLEAN DEFAULT TRUE")
+        ctx.execute("ALTER TABLE frame ADD COLUMN note VARCHAR")
+        ctx.execute("ALTER TABLE frame DROP COLUMN z")
+        ctx.execute("ALTER TABLE frame RENAME TO tbl")
+        assert ctx.tables() == ["tbl"]
+
+        res = ctx.execute("SELECT * FROM tbl")
+        assert res.schema == {
+            "x": pl.UInt8,
+            "label": pl.String,
+            "flag": pl.Boolean,
+            "note": pl.String,
+        }
+        assert res.rows() == [
+            (1, "aaa", True, None),
+            (2, "bbb", True, None),
+            (3, "ccc", True, None),
+        ]
+
+        ctx.execute("ALTER TABLE tbl ADD COLUMN IF NOT EXISTS x INTEGER")
+        ctx.execute("ALTER TABLE tbl DROP COLUMN IF EXISTS z")
+        ctx.execute("ALTER TABLE IF EXISTS missing RENAME TO other")
+        res = ctx.execute("SELECT * FROM tbl")
+        assert res.columns == ["x", "label", "flag", "note"]
+
+        with pytest.raises(DuplicateError, match="'x' already exists"):
+            ctx.execute("ALTER TABLE tbl ADD COLUMN x INTEGER")
+        with pytest.raises(ColumnNotFoundError, match="'z' does not"):
+            ctx.execute("ALTER TABLE tbl RENAME COLUMN z TO zz")
+        with pytest.raises(SQLInterfaceError, match="table 'missing' does not exist"):
+            ctx.execute("ALTER TABLE missing DROP COLUMN x")
+
+
+def test_describe(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        expected = pl.DataFrame(
+            {
+                "column_name": ["x", "y", "z"],
+                "data_type": ["u8", "str", "date"],
+            }
+        )
+        assert_frame_equal(ctx.execute("DESCRIBE frame"), expected)
+
+        ctx.execute("CREATE VIEW v AS SELECT x * 2 AS x2 FROM frame")
+        res = ctx.execute("DESCRIBE v")
+        assert res.rows() == [("x2", "u8")]
+
+        with pytest.raises(SQLInterfaceError, match="'missing' was not found"):
+            ctx.execute("DESCRIBE missing")
+
+
+def test_catalog(tmp_path: Path) -> None:
+    df = pl.DataFrame({"id": [1, 2, 3], "value": ["a", "b", "c"]})
+    path = tmp_path / "data.parquet"
+    df.write_parquet(path)
+
+    ctx = pl.SQLContext(mem=df, catalog=tmp_path / "catalog", eager=True)
+    ctx.execute(f"CREATE TABLE data AS SELECT * FROM read_parquet('{path}')")
+    ctx.execute("CREATE TABLE small AS SELECT * FROM mem WHERE id < 3")
+    ctx.execute("CREATE TEMPORARY TABLE tmp AS SELECT * FROM data")
+    ctx.execute("CREATE VIEW big AS SELECT value FROM data WHERE id > 1")
+    ctx.execute("CREATE TEMPORARY VIEW tmp_view AS SELECT 1 AS one")
+    ctx.execute("INSERT INTO small VALUES (10, 'x')")
+    ctx.execute("ALTER TABLE small RENAME TO smaller")
+
+    # tables read from files refer to the files, so changes to the files are seen
+    pl.DataFrame({"id": [4], "value": ["d"]}).write_parquet(path)
+
+    ctx = pl.SQLContext(catalog=tmp_path / "catalog", eager=True)
+    assert ctx.tables() == ["big", "data", "smaller"]
+    assert ctx.execute("SELECT * FROM big").rows() == [("d",)]
+    res = ctx.execute("SELECT * FROM smaller")
+    assert res.rows() == [(1, "a"), (2, "b"), (10, "x")]
+
+    ctx.execute("DROP TABLE smaller")
+    ctx.execute("DROP VIEW big")
+    ctx = pl.SQLContext(catalog=tmp_path / "catalog")
+    assert ctx.tables() == ["data"]
@@ -512,3 +512,57 @@ def test_streaming_group_by_all_null_21593() -> None:
 
     out = df.lazy().group_by(pl.all()).min().collect(engine="streaming")
     assert_frame_equal(df, out, check_row_order=False)
+
+
+@pytest.mark.parametrize("keys", [["a"], ["a", "b"]])
+def test_streaming_group_by_spill(
+    monkeypatch: pytest.MonkeyPatch, tmp_path: Path, keys: list[str]
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1000")
+    monkeypatch.setenv("POLARS_HOT_TABLE_SIZE", "4")
+
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1_000, 10_000),
+            "b": [None, "x", "yz", "xyz"] * 2_500,
+            "c": np.arange(10_000),
+        }
+    )
+    q = (
+        df.lazy()
+        .group_by(keys)
+        .agg(
+            pl.col("c").sum().alias("sum"),
+            pl.col("c").first().alias("first"),
+            pl.col("c").last().alias("last"),
+            pl.len(),
+        )
+        .sort(keys)
+    )
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_group_by_spill_repeatedly(
+    monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str], tmp_path: Path
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1000")
+    monkeypatch.setenv("POLARS_HOT_TABLE_SIZE", "4")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1_000, 10_000),
+            "c": np.arange(10_000),
+        }
+    )
+
+    # Many small morsels, such that every pipeline has to spill over and over.
+    lf = pl.concat([df.slice(i, 100).lazy() for i in range(0, df.height, 100)])
+    q = lf.group_by("a").agg(pl.col("c").sum(), pl.len()).sort("a")
+    out = q.collect(engine="streaming")
+    assert capfd.readouterr().err.count("[spill]: writing spill file") > 100
+
+    assert_frame_equal(out, q.collect(engine="in-memory"))
@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import JoinStrategy
+    from polars._typing import AsofJoinStrategy, JoinStrategy
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -386,3 +386,184 @@ def test_invert_order_full_join_22295() -> None:
     lf.join(lf, on=["value", "value_at"], how="full", coalesce=True).collect(
         engine="streaming"
     )
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "full"])
+def test_streaming_join_spill(
+    monkeypatch: pytest.MonkeyPatch, tmp_path: Path, how: JoinStrategy
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1000")
+
+    left = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1_000, 10_000),
+            "b": [None, "x", "yz", "xyz"] * 2_500,
+        }
+    )
+    right = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1_000, 5_000),
+            "c": np.arange(5_000),
+        }
+    )
+    q = left.lazy().join(right.lazy(), on="a", how=how).sort(pl.all())
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full"])
+def test_streaming_join_spill_repeatedly(
+    monkeypatch: pytest.MonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+    tmp_path: Path,
+    how: JoinStrategy,
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1000")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    left = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1_000, 10_000),
+            "b": [None, "x", "yz", "xyz"] * 2_500,
+        }
+    )
+    right = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1_000, 10_000),
+            "c": np.arange(10_000),
+        }
+    )
+
+    # Many small morsels, such that every pipeline has to spill over and over.
+    def chunked(df: pl.DataFrame) -> pl.LazyFrame:
+        return pl.concat([df.slice(i, 100).lazy() for i in range(0, df.height, 100)])
+
+    q = chunked(left).join(chunked(right), on="a", how=how).sort(pl.all())
+    out = q.collect(engine="streaming")
+    assert capfd.readouterr().err.count("[spill]: writing spill file") > 100
+
+    assert_frame_equal(out, q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("by", [None, "g"])
+@pytest.mark.parametrize("tolerance", [None, 3])
+def test_streaming_join_asof(
+    strategy: AsofJoinStrategy, by: str | None, tolerance: int | None
+) -> None:
+    left = pl.DataFrame(
+        {
+            "t": np.sort(np.random.randint(0, 5_000, 10_000)),
+            "g": np.random.randint(0, 5, 10_000),
+            "x": np.arange(10_000),
+        }
+    )
+    right = pl.DataFrame(
+        {
+            "t": np.sort(np.random.randint(0, 5_000, 3_000)),
+            "g": np.random.randint(0, 5, 3_000),
+            "y": np.arange(3_000),
+        }
+    )
+    q = left.lazy().join_asof(
+        right.lazy(),
+        on="t",
+        by=by,
+        strategy=strategy,
+        tolerance=tolerance,
+        check_sortedness=False,
+    )
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+    assert_frame_equal(
+        q.slice(100, 1_000).collect(engine="streaming"),
+        q.slice(100, 1_000).collect(engine="in-memory"),
+    )
+
+
+def test_streaming_join_asof_sorted_within_by_groups() -> None:
+    # The keys only have to be sorted within each group.
+    left = pl.LazyFrame({"t": [3, 4, 1, 2], "g": [1, 1, 2, 2]})
+    right = pl.LazyFrame({"t": [2, 5, 0, 1], "g": [1, 1, 2, 2], "y": [1, 2, 3, 4]})
+    q = left.join_asof(right, on="t", by="g", check_sortedness=False)
+
+    expected = pl.DataFrame({"t": [3, 4, 1, 2], "g": [1, 1, 2, 2], "y": [1, 1, 4, 4]})
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+def test_streaming_join_asof_by_groups_chunked(strategy: AsofJoinStrategy) -> None:
+    # Every group is sorted on its own and the groups follow each other, so the keys are
+    # not sorted globally and a group's right rows can arrive in later morsels.
+    def grouped(n: int, extra: dict[str, np.ndarray]) -> pl.DataFrame:
+        return pl.DataFrame(
+            {
+                "g": np.repeat(np.arange(5), n // 5),
+                "t": np.concatenate(
+                    [np.sort(np.random.randint(0, 1_000, n // 5)) for _ in range(5)]
+                ),
+                **extra,
+            }
+        )
+
+    def chunked(df: pl.DataFrame) -> pl.LazyFrame:
+        return pl.concat([df.slice(i, 100).lazy() for i in range(0, df.height, 100)])
+
+    left = grouped(5_000, {"x": np.arange(5_000)})
+    right = grouped(2_000, {"y": np.arange(2_000)}).sort(
+        "g", descending=True, maintain_order=True
+    )
+    q = chunked(left).join_asof(
+        chunked(right), on="t", by="g", strategy=strategy, check_sortedness=False
+    )
+
+    plan = q.show_graph(raw_output=True, plan_stage="physical", engine="streaming")
+    assert "asof-join" in plan
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize(
+    "predicates",
+    [
+        [pl.col("start") <= pl.col("ts"), pl.col("ts") < pl.col("end")],
+        [pl.col("end") > pl.col("ts"), pl.col("start") <= pl.col("ts")],
+        [pl.col("start") >= pl.col("ts")],
+        [pl.col("end") < pl.col("ts")],
+    ],
+)
+def test_streaming_join_where_range(predicates: list[pl.Expr]) -> None:
+    start = np.random.randint(0, 10_000, 2_000)
+    intervals = pl.DataFrame(
+        {
+            "start": start,
+            "end": start + np.random.randint(1, 50, 2_000),
+            "id": np.arange(2_000),
+        }
+    ).with_columns(
+        start=pl.when(pl.col("id") % 100 == 0).then(None).otherwise(pl.col("start"))
+    )
+    events = pl.DataFrame(
+        {
+            "ts": np.random.randint(0, 10_000, 1_000),
+            "event": np.arange(1_000),
+        }
+    )
+    q = intervals.lazy().join_where(events.lazy(), *predicates)
+
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+def test_streaming_join_where_range_mixed_dtypes() -> None:
+    # casting 1.5 to the integer keys would give 1, which isn't greater than 1
+    left = pl.LazyFrame({"x": [1.5]})
+    right = pl.LazyFrame({"y": pl.Series([1, 2], dtype=pl.Int32)})
+    q = left.join_where(right, pl.col("x") > pl.col("y"))
+
+    expected = pl.DataFrame({"x": [1.5], "y": pl.Series([1], dtype=pl.Int32)})
+    assert_frame_equal(q.collect(engine="streaming"), expected)
@@ -183,3 +183,60 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.parametrize(
+    ("by", "maintain_order"), [(["a", "c", "b"], False), (["a", "c"], True)]
+)
+def test_streaming_sort_spill(
+    monkeypatch: pytest.MonkeyPatch,
+    tmp_path: Path,
+    by: list[str],
+    maintain_order: bool,
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1000")
+
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 100, 10_000),
+            "b": np.arange(10_000),
+            "c": [None, "x", "yz", "xyz"] * 2_500,
+        }
+    )
+    q = df.lazy().sort(
+        by, descending=[True] + [False] * (len(by) - 1), maintain_order=maintain_order
+    )
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+    assert_frame_equal(
+        q.slice(1_234, 5_000).collect(engine="streaming"),
+        q.slice(1_234, 5_000).collect(engine="in-memory"),
+    )
+    assert_frame_equal(
+        q.tail(100).collect(engine="streaming"),
+        q.tail(100).collect(engine="in-memory"),
+    )
+
+
+def test_streaming_sort_spill_multi_pass_merge(
+    monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str], tmp_path: Path
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1000")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1_000, 30_000),
+            "b": np.arange(30_000),
+        }
+    )
+
+    # Every small morsel becomes its own spilled run, more than can be merged at once.
+    lf = pl.concat([df.slice(i, 100).lazy() for i in range(0, df.height, 100)])
+    q = lf.sort("a", "b")
+    out = q.collect(engine="streaming")
+    assert capfd.readouterr().err.count("[sort]: merging") >= 2
+
+    assert_frame_equal(out, q.collect(engine="in-memory"))
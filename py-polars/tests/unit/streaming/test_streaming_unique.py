@@ -2,6 +2,7 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING, Any
 
+import numpy as np
 import pytest
 
 import polars as pl
@@ -10,6 +11,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars._typing import UniqueKeepStrategy
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -49,3 +52,37 @@ def test_streaming_unique() -> None:
 
     q = df.lazy().unique(subset=None, maintain_order=False).sort(["a", "b", "c"])
     assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("keep", ["first", "last", "any", "none"])
+@pytest.mark.parametrize("maintain_order", [False, True])
+@pytest.mark.parametrize("subset", [None, ["a"], ["a", "b"]])
+def test_streaming_unique_keep(
+    keep: UniqueKeepStrategy, maintain_order: bool, subset: list[str] | None
+) -> None:
+    n = 300_000
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1_000, n),
+            "b": np.random.randint(0, 100, n),
+            "c": np.arange(n),
+        }
+    ).with_columns(
+        a=pl.when(pl.col("c") % 7 == 0).then(None).otherwise(pl.col("a"))
+    )
+    q = df.lazy().unique(subset=subset, keep=keep, maintain_order=maintain_order)
+
+    if keep == "any":
+        # Which row is kept is not defined, so we only check the keys.
+        key_cols = subset or df.columns
+        q = q.select(key_cols)
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=maintain_order,
+    )
+    if maintain_order:
+        assert_frame_equal(
+            q.slice(10, 100).collect(engine="streaming"),
+            q.slice(10, 100).collect(engine="in-memory"),
+        )